@@ -16,12 +16,55 @@ extern "C" {
                                                                                     indy_error_t  err,
                                                                                     const char*   claim_def_json)
                                                                );
+
+    extern indy_error_t indy_issuer_create_and_store_claim_def_with_tag(indy_handle_t command_handle,
+                                                                        indy_handle_t wallet_handle,
+                                                                        const char *  issuer_did,
+                                                                        const char *  schema_json,
+                                                                        const char *  signature_type,
+                                                                        indy_bool_t   create_non_revoc,
+                                                                        const char *  tag,
+
+                                                                        void           (*cb)(indy_handle_t xcommand_handle,
+                                                                                             indy_error_t  err,
+                                                                                             const char*   claim_def_json)
+                                                                        );
+
+    extern indy_error_t indy_issuer_set_active_claim_def(indy_handle_t command_handle,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  issuer_did,
+                                                         const char *  schema_json,
+                                                         const char *  tag,
+
+                                                         void           (*cb)(indy_handle_t xcommand_handle,
+                                                                              indy_error_t  err)
+                                                         );
+
+    extern indy_error_t indy_issuer_list_claim_defs(indy_handle_t command_handle,
+                                                    indy_handle_t wallet_handle,
+                                                    const char *  issuer_did,
+                                                    const char *  schema_json,
+
+                                                    void           (*cb)(indy_handle_t xcommand_handle,
+                                                                         indy_error_t  err,
+                                                                         const char*   claim_defs_json)
+                                                    );
+
+    extern indy_error_t indy_issuer_compare_schemas(indy_handle_t command_handle,
+                                                    const char *  schema_json,
+                                                    const char *  other_schema_json,
+
+                                                    void           (*cb)(indy_handle_t xcommand_handle,
+                                                                         indy_error_t  err,
+                                                                         const char*   schema_diff_json)
+                                                    );
     
     extern indy_error_t indy_issuer_create_and_store_revoc_reg(indy_handle_t command_handle,
                                                                indy_handle_t wallet_handle,
                                                                const char *  issuer_did,
                                                                const char *  schema_json,
                                                                indy_u32_t    max_claim_num,
+                                                               const char *  tails_writer_config_json,
 
                                                                void           (*cb)(indy_handle_t xcommand_handle,
                                                                                     indy_error_t  err,
@@ -39,6 +82,31 @@ extern "C" {
                                                                             const char*   claim_offer_json)
                                                        );
     
+    extern indy_error_t indy_issuer_create_claim_offer_with_expiration(indy_handle_t command_handle,
+                                                                       indy_handle_t wallet_handle,
+                                                                       const char *  schema_json,
+                                                                       const char *  issuer_did,
+                                                                       const char *  prover_did,
+                                                                       indy_u32_t    expires_in,
+
+                                                                       void           (*cb)(indy_handle_t xcommand_handle,
+                                                                                            indy_error_t  err,
+                                                                                            const char*   claim_offer_json)
+                                                                       );
+    
+    extern indy_error_t indy_issuer_withdraw_claim_offer(indy_handle_t command_handle,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  issuer_did,
+                                                         const char *  schema_json,
+                                                         const char *  claim_def_tag,
+                                                         const char *  prover_did,
+
+                                                         void           (*cb)(indy_handle_t xcommand_handle,
+                                                                              indy_error_t  err)
+                                                         );
+    
+    // Claim offer the request is created for is fulfilled by the issued claim, so issuing
+    // the claim for the same request again fails with AnoncredsClaimOfferNotFoundError
     extern indy_error_t indy_issuer_create_claim(indy_handle_t command_handle,
                                                  indy_handle_t wallet_handle,
                                                  const char *  claim_req_json,
@@ -50,6 +118,18 @@ extern "C" {
                                                                       const char*   revoc_reg_update_json,
                                                                       const char*   xclaim_json   )
                                                  );
+
+    // Claim offers are fulfilled the same way as by indy_issuer_create_claim, a request for
+    // fulfilled offer gets AnoncredsClaimOfferNotFoundError in its result within the batch
+    extern indy_error_t indy_issuer_create_claims(indy_handle_t command_handle,
+                                                  indy_handle_t wallet_handle,
+                                                  const char *  claim_reqs_json,
+
+                                                  void           (*cb)(indy_handle_t xcommand_handle,
+                                                                       indy_error_t  err,
+                                                                       const char*   revoc_reg_delta_json,
+                                                                       const char*   claims_json)
+                                                  );
     
     
     extern indy_error_t indy_issuer_revoke_claim(indy_handle_t command_handle,
@@ -62,6 +142,41 @@ extern "C" {
                                                                       indy_error_t  err,
                                                                       const char*   revoc_reg_update_json)
                                                  );
+
+    extern indy_error_t indy_issuer_revoke_claim_with_tag(indy_handle_t command_handle,
+                                                          indy_handle_t wallet_handle,
+                                                          const char *  issuer_did,
+                                                          const char *  schema_json,
+                                                          const char *  claim_def_tag,
+                                                          indy_u32_t    user_revoc_index,
+
+                                                          void           (*cb)(indy_handle_t xcommand_handle,
+                                                                               indy_error_t  err,
+                                                                               const char*   revoc_reg_update_json)
+                                                          );
+
+    extern indy_error_t indy_issuer_recover_claim(indy_handle_t command_handle,
+                                                  indy_handle_t wallet_handle,
+                                                  const char *  issuer_did,
+                                                  const char *  schema_json,
+                                                  const char *  claim_def_tag,
+                                                  indy_u32_t    user_revoc_index,
+
+                                                  void           (*cb)(indy_handle_t xcommand_handle,
+                                                                       indy_error_t  err,
+                                                                       const char*   revoc_reg_update_json)
+                                                  );
+
+    extern indy_error_t indy_issuer_get_revoc_reg_indices(indy_handle_t command_handle,
+                                                          indy_handle_t wallet_handle,
+                                                          const char *  issuer_did,
+                                                          const char *  schema_json,
+                                                          const char *  claim_def_tag,
+
+                                                          void           (*cb)(indy_handle_t xcommand_handle,
+                                                                               indy_error_t  err,
+                                                                               const char*   revoc_reg_indices_json)
+                                                          );
     
     extern indy_error_t indy_prover_store_claim_offer(indy_handle_t command_handle,
                                                       indy_handle_t wallet_handle,
@@ -88,6 +203,34 @@ extern "C" {
                                                          void           (*cb)(indy_handle_t xcommand_handle,
                                                                               indy_error_t  err)
                                                          );
+
+    extern indy_error_t indy_prover_list_master_secrets(indy_handle_t command_handle,
+                                                        indy_handle_t wallet_handle,
+
+                                                        void           (*cb)(indy_handle_t xcommand_handle,
+                                                                             indy_error_t  err,
+                                                                             const char*   master_secret_names_json)
+                                                        );
+
+    extern indy_error_t indy_prover_export_master_secret(indy_handle_t command_handle,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  master_secret_name,
+                                                         const char *  passphrase,
+
+                                                         void           (*cb)(indy_handle_t xcommand_handle,
+                                                                              indy_error_t  err,
+                                                                              const char*   exported_master_secret_json)
+                                                         );
+
+    extern indy_error_t indy_prover_import_master_secret(indy_handle_t command_handle,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  master_secret_name,
+                                                         const char *  exported_master_secret_json,
+                                                         const char *  passphrase,
+
+                                                         void           (*cb)(indy_handle_t xcommand_handle,
+                                                                              indy_error_t  err)
+                                                         );
     
     
     extern indy_error_t indy_prover_create_and_store_claim_req(indy_handle_t command_handle,
@@ -108,11 +251,38 @@ extern "C" {
                                                 indy_handle_t wallet_handle,
                                                 const char *  claims_json,
                                                 const char *  rev_reg_json,
+                                                const char *  tails_reader_config_json,
 
                                                 void           (*cb)(indy_handle_t xcommand_handle,
                                                                      indy_error_t  err)
                                                 );
     
+    extern indy_error_t indy_prover_delete_claim(indy_handle_t command_handle,
+                                                 indy_handle_t wallet_handle,
+                                                 const char *  referent,
+
+                                                 void           (*cb)(indy_handle_t xcommand_handle,
+                                                                      indy_error_t  err)
+                                                 );
+
+    extern indy_error_t indy_prover_set_claim_metadata(indy_handle_t command_handle,
+                                                       indy_handle_t wallet_handle,
+                                                       const char *  referent,
+                                                       const char *  metadata,
+
+                                                       void           (*cb)(indy_handle_t xcommand_handle,
+                                                                            indy_error_t  err)
+                                                       );
+
+    extern indy_error_t indy_prover_get_claim_metadata(indy_handle_t command_handle,
+                                                       indy_handle_t wallet_handle,
+                                                       const char *  referent,
+
+                                                       void           (*cb)(indy_handle_t xcommand_handle,
+                                                                            indy_error_t  err,
+                                                                            const char*   metadata)
+                                                       );
+
     extern indy_error_t indy_prover_get_claims(indy_handle_t command_handle,
                                                indy_handle_t wallet_handle,
                                                const char *  filter_json,
@@ -121,6 +291,31 @@ extern "C" {
                                                                     indy_error_t  err,
                                                                     const char*   claims_json)
                                                );
+
+    extern indy_error_t indy_prover_search_claims(indy_handle_t command_handle,
+                                                  indy_handle_t wallet_handle,
+                                                  const char *  filter_json,
+
+                                                  void           (*cb)(indy_handle_t xcommand_handle,
+                                                                       indy_error_t  err,
+                                                                       indy_handle_t search_handle)
+                                                  );
+
+    extern indy_error_t indy_prover_fetch_claims(indy_handle_t command_handle,
+                                                 indy_handle_t search_handle,
+                                                 indy_u32_t    count,
+
+                                                 void           (*cb)(indy_handle_t xcommand_handle,
+                                                                      indy_error_t  err,
+                                                                      const char*   claims_json)
+                                                 );
+
+    extern indy_error_t indy_prover_close_claims_search(indy_handle_t command_handle,
+                                                        indy_handle_t search_handle,
+
+                                                        void           (*cb)(indy_handle_t xcommand_handle,
+                                                                             indy_error_t  err)
+                                                        );
     
     
     extern indy_error_t indy_prover_get_claims_for_proof_req(indy_handle_t command_handle,
@@ -131,6 +326,42 @@ extern "C" {
                                                                                   indy_error_t  err,
                                                                                   const char*   claims_json)
                                                              );
+
+    extern indy_error_t indy_prover_search_claims_for_proof_req(indy_handle_t command_handle,
+                                                                indy_handle_t wallet_handle,
+                                                                const char *  proof_request_json,
+
+                                                                void           (*cb)(indy_handle_t xcommand_handle,
+                                                                                     indy_error_t  err,
+                                                                                     indy_handle_t search_handle)
+                                                                );
+
+    extern indy_error_t indy_prover_fetch_claims_for_proof_req(indy_handle_t command_handle,
+                                                               indy_handle_t search_handle,
+                                                               const char *  item_referent,
+                                                               indy_u32_t    count,
+
+                                                               void           (*cb)(indy_handle_t xcommand_handle,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   claims_json)
+                                                               );
+
+    extern indy_error_t indy_prover_close_claims_search_for_proof_req(indy_handle_t command_handle,
+                                                                      indy_handle_t search_handle,
+
+                                                                      void           (*cb)(indy_handle_t xcommand_handle,
+                                                                                           indy_error_t  err)
+                                                                      );
+
+    extern indy_error_t indy_prover_select_claims_for_proof_req(indy_handle_t command_handle,
+                                                                indy_handle_t wallet_handle,
+                                                                const char *  proof_request_json,
+                                                                const char *  self_attested_values_json,
+
+                                                                void           (*cb)(indy_handle_t xcommand_handle,
+                                                                                     indy_error_t  err,
+                                                                                     const char*   claims_selection_json)
+                                                                );
     
     
     extern indy_error_t indy_prover_create_proof(indy_handle_t command_handle,
@@ -141,6 +372,7 @@ extern "C" {
                                                  const char *  master_secret_name,
                                                  const char *  claim_defs_json,
                                                  const char *  revoc_regs_json,
+                                                 const char *  tails_reader_config_json,
 
                                                  void           (*cb)(indy_handle_t xcommand_handle,
                                                                       indy_error_t  err,
@@ -154,11 +386,56 @@ extern "C" {
                                                    const char *  schemas_json,
                                                    const char *  claim_defs_jsons,
                                                    const char *  revoc_regs_json,
+                                                   const char *  tails_reader_config_json,
 
                                                    void           (*cb)(indy_handle_t xcommand_handle,
                                                                         indy_error_t  err,
                                                                         indy_bool_t   valid )
                                                    );
+
+    extern indy_error_t indy_verifier_verify_proof_with_ledger(indy_handle_t command_handle,
+                                                               indy_handle_t pool_handle,
+                                                               const char *  submitter_did,
+                                                               const char *  proof_request_json,
+                                                               const char *  proof_json,
+                                                               const char *  tails_reader_config_json,
+
+                                                               void           (*cb)(indy_handle_t xcommand_handle,
+                                                                                    indy_error_t  err,
+                                                                                    indy_bool_t   valid,
+                                                                                    const char*   resolved_json)
+                                                               );
+    
+    
+    extern indy_error_t indy_verifier_generate_nonce(indy_handle_t command_handle,
+
+                                                     void           (*cb)(indy_handle_t xcommand_handle,
+                                                                          indy_error_t  err,
+                                                                          const char*   nonce)
+                                                     );
+    
+    
+    extern indy_error_t indy_verifier_build_proof_request(indy_handle_t command_handle,
+                                                          const char *  name,
+                                                          const char *  version,
+                                                          const char *  requested_attrs_json,
+                                                          const char *  requested_predicates_json,
+                                                          const char *  non_revoked_json,
+
+                                                          void           (*cb)(indy_handle_t xcommand_handle,
+                                                                               indy_error_t  err,
+                                                                               const char*   proof_request_json)
+                                                          );
+    
+    
+    extern indy_error_t indy_verifier_inspect_proof(indy_handle_t command_handle,
+                                                    const char *  proof_request_json,
+                                                    const char *  proof_json,
+
+                                                    void           (*cb)(indy_handle_t xcommand_handle,
+                                                                         indy_error_t  err,
+                                                                         const char*   inspection_json)
+                                                    );
     
 #ifdef __cplusplus
 }
@@ -0,0 +1,63 @@
+#ifndef __indy__blob_storage__included__
+#define __indy__blob_storage__included__
+
+#include "indy_types.h"
+#include "indy_mod.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+    /// Registers custom blob storage implementation.
+    ///
+    /// Blob storage keeps revocation registry tails. Blobs are content-addressed: they are written
+    /// and read by base58 encoded sha256 hash of their data, libindy checks read data against the hash.
+    /// Type and config of storage are passed to anoncreds functions as tails writer/reader config
+    /// {"type": <xtype>, "config": <json passed to open_writer/open_reader>}.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// xtype: Blob storage type name.
+    /// open_writer: opens writer for new blob, returns its handle
+    /// append: appends chunk of data to the blob of writer
+    /// finalize: persists the blob of writer so it can be read by given hash
+    /// open_reader: opens reader of the blob with given hash, returns its handle
+    /// read: reads next chunk of the blob into buffer of buf_len bytes, sets read_len to number
+    ///     of read bytes (0 at the end of the blob)
+    /// close: closes writer or reader handle, data of not finalized writer must be discarded
+    ///
+    /// #Returns
+    /// Error code
+
+    extern indy_error_t indy_register_blob_storage_type(indy_handle_t  command_handle,
+                                                        const char*    xtype,
+
+                                                        indy_error_t (*openWriterFn)(const char* config,
+                                                                                     indy_handle_t* handle),
+
+                                                        indy_error_t (*appendFn)(indy_handle_t handle,
+                                                                                 const indy_u8_t* bytes,
+                                                                                 indy_u32_t bytes_len),
+
+                                                        indy_error_t (*finalizeFn)(indy_handle_t handle,
+                                                                                   const char* hash),
+
+                                                        indy_error_t (*openReaderFn)(const char* config,
+                                                                                     const char* hash,
+                                                                                     indy_handle_t* handle),
+
+                                                        indy_error_t (*readFn)(indy_handle_t handle,
+                                                                               indy_u8_t* buf,
+                                                                               indy_u32_t buf_len,
+                                                                               indy_u32_t* read_len),
+
+                                                        indy_error_t (*closeFn)(indy_handle_t handle),
+
+                                                        void         (*fn)(indy_handle_t xcommand_handle, indy_error_t err)
+                                                        );
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
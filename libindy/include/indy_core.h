@@ -5,6 +5,7 @@
 #include "indy_types.h"
 
 #include "indy_anoncreds.h"
+#include "indy_blob_storage.h"
 #include "indy_crypto.h"
 #include "indy_ledger.h"
 #include "indy_pairwise.h"
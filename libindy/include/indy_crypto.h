@@ -15,7 +15,9 @@ extern "C" {
     /// key_json: Key information as json. Example:
     /// {
     ///     "seed": string, // Optional (if not set random one will be used); Seed information that allows deterministic key creation.
-    ///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently only 'ed25519' value is supported for this field.
+    ///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); 'ed25519' and 'secp256k1' (if built with secp256k1_libsecp256k1 feature) values are supported.
+    ///     "derivation_path": string, // Optional; Path of the key derived from the wallet master seed (see indy_set_master_seed), for example "m/1'/0'".
+    ///                                // Only hardened indices and 'ed25519' crypto type are supported. Can't be used together with seed.
     /// }
     /// cb: Callback that takes command result as parameter.
     ///
@@ -314,6 +316,587 @@ extern "C" {
                                                                       indy_u32_t        decrypted_msg_len)
                                                  );
 
+    /// Packs a message for one or several recipients.
+    ///
+    /// The message is encrypted once with a random content key and the content key is encrypted
+    /// separately for every recipient. If sender verkey is passed, the content key is encrypted
+    /// by authenticated-encryption scheme (see indy_crypto_auth_crypt) and the sender verkey is
+    /// sealed for every recipient, so recipients can authenticate the sender. Otherwise the content key
+    /// is encrypted by anonymous-encryption scheme (see indy_crypto_anon_crypt).
+    ///
+    /// Packed message is utf-8 encoded json (all binary values are base64 encoded):
+    /// {
+    ///     "protected": base64 of {"enc": "xsalsa20poly1305", "typ": "JWM/1.0", "alg": "Authcrypt" or "Anoncrypt"},
+    ///     "recipients": [
+    ///         {
+    ///             "encrypted_key": encrypted content key,
+    ///             "header": {
+    ///                 "kid": recipient verkey,
+    ///                 "sender": sender verkey sealed for recipient (only for Authcrypt),
+    ///                 "iv": nonce of content key encryption (only for Authcrypt)
+    ///             }
+    ///         }
+    ///     ],
+    ///     "iv": nonce of message encryption,
+    ///     "ciphertext": encrypted message
+    /// }
+    ///
+    /// Note to use DID keys with this function you can call indy_key_for_did to get key id (verkey)
+    /// for specific DID.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// message_raw: a pointer to first byte of message that to be packed
+    /// message_len: a message length
+    /// receiver_keys: json array of recipients verkeys: ["verkey1", "verkey2"]
+    /// sender_vk: (optional) id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+    ///            If not set, the message is packed anonymously.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// packed message
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_pack_msg(indy_handle_t      command_handle,
+                                             indy_handle_t      wallet_handle,
+                                             const indy_u8_t *  message_raw,
+                                             indy_u32_t         message_len,
+                                             const char *       receiver_keys,
+                                             const char *       sender_vk,
+
+                                             void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                  indy_error_t      err,
+                                                                  const indy_u8_t*  packed_msg_raw,
+                                                                  indy_u32_t        packed_msg_len)
+                                             );
+
+    /// Unpacks a message packed by indy_crypto_pack_msg.
+    ///
+    /// The wallet is searched for a key of one of the message recipients and this key is used
+    /// to decrypt the message. For authenticated packing the sender verkey is returned.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// packed_msg: a pointer to first byte of packed message
+    /// packed_len: a packed message length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// unpacked message info json:
+    /// {
+    ///     "recipient_verkey": verkey of my key used to unpack the message,
+    ///     "sender_verkey": verkey of the sender or null if the message was packed anonymously
+    /// }
+    /// and unpacked message
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_unpack_msg(indy_handle_t      command_handle,
+                                               indy_handle_t      wallet_handle,
+                                               const indy_u8_t*   packed_msg,
+                                               indy_u32_t         packed_len,
+
+                                               void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                    indy_error_t      err,
+                                                                    const char *      unpacked_info_json,
+                                                                    const indy_u8_t*  msg_raw,
+                                                                    indy_u32_t        msg_len)
+                                               );
+
+    /// Starts streaming signing of a large message by key stored in the wallet.
+    ///
+    /// Message is passed by parts with indy_crypto_sign_stream_update calls and signature
+    /// is returned by indy_crypto_sign_stream_finish. Streamed signature is the signature of
+    /// sha256 digest of the whole message prefixed with fixed context tag, so it must be verified
+    /// with indy_crypto_verify_stream_* functions (not with indy_crypto_verify) and can't be taken
+    /// for a signature made by indy_crypto_sign. Unfinished stream is closed by indy_crypto_close_stream.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// my_vk: id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// stream handle
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_sign_stream_init(indy_handle_t      command_handle,
+                                                     indy_handle_t      wallet_handle,
+                                                     const char *       my_vk,
+
+                                                     void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                          indy_error_t      err,
+                                                                          indy_handle_t     stream_handle)
+                                                     );
+
+    /// Passes next part of the message to the signing stream.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle (created by indy_crypto_sign_stream_init).
+    /// message_raw: a pointer to first byte of message part
+    /// message_len: a message part length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_crypto_sign_stream_update(indy_handle_t      command_handle,
+                                                       indy_handle_t      stream_handle,
+                                                       const indy_u8_t *  message_raw,
+                                                       indy_u32_t         message_len,
+
+                                                       void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                            indy_error_t      err)
+                                                       );
+
+    /// Finishes the signing stream and returns the signature. Stream handle is closed.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle (created by indy_crypto_sign_stream_init).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// a signature
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_sign_stream_finish(indy_handle_t      command_handle,
+                                                       indy_handle_t      stream_handle,
+
+                                                       void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                            indy_error_t      err,
+                                                                            const indy_u8_t*  signature_raw,
+                                                                            indy_u32_t        signature_len)
+                                                       );
+
+    /// Starts streaming verification of a signature created by indy_crypto_sign_stream_finish.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// their_vk: verkey to use
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// stream handle
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_verify_stream_init(indy_handle_t      command_handle,
+                                                       const char *       their_vk,
+
+                                                       void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                            indy_error_t      err,
+                                                                            indy_handle_t     stream_handle)
+                                                       );
+
+    /// Passes next part of the message to the verification stream.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle (created by indy_crypto_verify_stream_init).
+    /// message_raw: a pointer to first byte of message part
+    /// message_len: a message part length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_crypto_verify_stream_update(indy_handle_t      command_handle,
+                                                         indy_handle_t      stream_handle,
+                                                         const indy_u8_t *  message_raw,
+                                                         indy_u32_t         message_len,
+
+                                                         void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                              indy_error_t      err)
+                                                         );
+
+    /// Finishes the verification stream and checks the signature. Stream handle is closed.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle (created by indy_crypto_verify_stream_init).
+    /// signature_raw: a pointer to first byte of signature to be verified
+    /// signature_len: a signature length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// valid: true - if signature is valid, false - otherwise
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_verify_stream_finish(indy_handle_t      command_handle,
+                                                         indy_handle_t      stream_handle,
+                                                         const indy_u8_t *  signature_raw,
+                                                         indy_u32_t         signature_len,
+
+                                                         void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                              indy_error_t      err,
+                                                                              indy_bool_t       valid)
+                                                         );
+
+    /// Starts streaming encryption of a large message by authenticated-encryption scheme.
+    ///
+    /// Message is passed by parts with indy_crypto_crypt_stream_update calls and the rest of
+    /// encrypted stream is returned by indy_crypto_crypt_stream_finish. Encrypted stream is
+    /// the concatenation of all returned parts and must be decrypted with
+    /// indy_crypto_auth_decrypt_stream_* functions.
+    ///
+    /// Encrypted stream consists of frames, every frame is body length (4 bytes, big-endian) || body.
+    /// The first frame is the header: stream key (32 bytes) || nonce prefix (16 bytes) encrypted
+    /// as indy_crypto_auth_crypt does. Next frames are chunks: xsalsa20-poly1305 encryption under
+    /// stream key of tag (1 byte: 0 - chunk, 1 - final chunk) || data (up to 64 KiB) with
+    /// nonce = nonce prefix || chunk number (8 bytes, big-endian, starts from 0). Chunk numbers in
+    /// nonces protect against reordering and dropping of chunks, final tag protects against truncation.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// my_vk: id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+    /// their_vk: id (verkey) of their key
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// stream handle
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_auth_crypt_stream_init(indy_handle_t      command_handle,
+                                                           indy_handle_t      wallet_handle,
+                                                           const char *       my_vk,
+                                                           const char *       their_vk,
+
+                                                           void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                                indy_error_t      err,
+                                                                                indy_handle_t     stream_handle)
+                                                           );
+
+    /// Starts streaming encryption of a large message by anonymous-encryption scheme.
+    ///
+    /// Framing of encrypted stream is the same as for indy_crypto_auth_crypt_stream_init, but
+    /// the header is encrypted as indy_crypto_anon_crypt does. Encrypted stream must be decrypted
+    /// with indy_crypto_anon_decrypt_stream_* functions.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// their_vk: id (verkey) of their key
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// stream handle
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_anon_crypt_stream_init(indy_handle_t      command_handle,
+                                                           const char *       their_vk,
+
+                                                           void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                                indy_error_t      err,
+                                                                                indy_handle_t     stream_handle)
+                                                           );
+
+    /// Passes next part of the message to the encryption stream.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle (created by indy_crypto_auth_crypt_stream_init or indy_crypto_anon_crypt_stream_init).
+    /// message_raw: a pointer to first byte of message part
+    /// message_len: a message part length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// next part of encrypted stream (can be empty)
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_crypto_crypt_stream_update(indy_handle_t      command_handle,
+                                                        indy_handle_t      stream_handle,
+                                                        const indy_u8_t *  message_raw,
+                                                        indy_u32_t         message_len,
+
+                                                        void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                             indy_error_t      err,
+                                                                             const indy_u8_t*  encrypted_msg_raw,
+                                                                             indy_u32_t        encrypted_msg_len)
+                                                        );
+
+    /// Finishes the encryption stream. Stream handle is closed.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle (created by indy_crypto_auth_crypt_stream_init or indy_crypto_anon_crypt_stream_init).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// the last part of encrypted stream
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_crypto_crypt_stream_finish(indy_handle_t      command_handle,
+                                                        indy_handle_t      stream_handle,
+
+                                                        void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                             indy_error_t      err,
+                                                                             const indy_u8_t*  encrypted_msg_raw,
+                                                                             indy_u32_t        encrypted_msg_len)
+                                                        );
+
+    /// Starts streaming decryption of a stream encrypted by indy_crypto_auth_crypt_stream_* functions.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// my_vk: id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// stream handle
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_auth_decrypt_stream_init(indy_handle_t      command_handle,
+                                                             indy_handle_t      wallet_handle,
+                                                             const char *       my_vk,
+
+                                                             void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                                  indy_error_t      err,
+                                                                                  indy_handle_t     stream_handle)
+                                                             );
+
+    /// Starts streaming decryption of a stream encrypted by indy_crypto_anon_crypt_stream_* functions.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// my_vk: id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// stream handle
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_anon_decrypt_stream_init(indy_handle_t      command_handle,
+                                                             indy_handle_t      wallet_handle,
+                                                             const char *       my_vk,
+
+                                                             void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                                  indy_error_t      err,
+                                                                                  indy_handle_t     stream_handle)
+                                                             );
+
+    /// Passes next part of encrypted stream to the decryption stream.
+    ///
+    /// Parts can be of any size, they don't need to match parts returned by encryption stream.
+    /// Stream handle is closed if encrypted stream part is invalid.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle (created by indy_crypto_auth_decrypt_stream_init or indy_crypto_anon_decrypt_stream_init).
+    /// encrypted_msg_raw: a pointer to first byte of encrypted stream part
+    /// encrypted_msg_len: an encrypted stream part length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// next part of decrypted message (can be empty)
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_decrypt_stream_update(indy_handle_t      command_handle,
+                                                          indy_handle_t      stream_handle,
+                                                          const indy_u8_t *  encrypted_msg_raw,
+                                                          indy_u32_t         encrypted_msg_len,
+
+                                                          void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                               indy_error_t      err,
+                                                                               const indy_u8_t*  msg_raw,
+                                                                               indy_u32_t        msg_len)
+                                                          );
+
+    /// Finishes the decryption stream. Stream handle is closed.
+    ///
+    /// Returns an error if the encrypted stream was truncated.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle (created by indy_crypto_auth_decrypt_stream_init or indy_crypto_anon_decrypt_stream_init).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// sender verkey for authenticated-encryption stream or NULL for anonymous-encryption stream
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_crypto_decrypt_stream_finish(indy_handle_t      command_handle,
+                                                          indy_handle_t      stream_handle,
+
+                                                          void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                               indy_error_t      err,
+                                                                               const char *      sender_vk)
+                                                          );
+
+    /// Closes signing, verification, encryption or decryption stream without finishing it,
+    /// i.e. to abort processing of a message. Stream handle is closed.
+    ///
+    /// Decryption stream is also closed by indy_crypto_decrypt_stream_update on invalid encrypted data.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle (created by indy_crypto_*_stream_init).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_crypto_close_stream(indy_handle_t      command_handle,
+                                                 indy_handle_t      stream_handle,
+
+                                                 void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                      indy_error_t      err)
+                                                 );
+
+    /// Lists all keys stored in the wallet.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - xcommand_handle: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - keys_json: List of keys as json array:
+    ///   [{
+    ///     "verkey": string, // Ver key of the key
+    ///     "metadata": Optional<string>, // The meta information stored with the key
+    ///     "derivation_path": Optional<string>, // Derivation path for keys derived from the master seed
+    ///   }]
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_list_keys(indy_handle_t     command_handle,
+                                       indy_handle_t     wallet_handle,
+
+                                       void              (*cb)(indy_handle_t     command_handle,
+                                                               indy_error_t      err,
+                                                               const char *const keys_json)
+                                      );
+
+    /// Deletes the key and its meta information from the wallet.
+    ///
+    /// Keys that are still used by some of my DIDs can't be deleted (CryptoKeyUsedByDidError).
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// verkey - The key (verkey, key id) to delete.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - xcommand_handle: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_delete_key(indy_handle_t     command_handle,
+                                        indy_handle_t     wallet_handle,
+                                        const char *const verkey,
+
+                                        void              (*cb)(indy_handle_t     command_handle,
+                                                                indy_error_t      err)
+                                       );
+
+    /// Exports the verkey in one of the standard formats.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// verkey - The key (verkey, key id) to export.
+    /// format - Export format. One of:
+    ///   "jwk" - JSON Web Key (RFC 8037), supported for ed25519 keys only
+    ///   "multibase" - base58btc multibase of multicodec prefixed key
+    ///   "hex" - lowercase hex of raw key bytes
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - xcommand_handle: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - exported: The key in requested format.
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_export_verkey(indy_handle_t     command_handle,
+                                           const char *const verkey,
+                                           const char *const format,
+
+                                           void              (*cb)(indy_handle_t     command_handle,
+                                                                   indy_error_t      err,
+                                                                   const char *const exported)
+                                          );
+
+    /// Imports an existing ed25519 secret key from JSON Web Key (RFC 8037) and stores it in the wallet.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// jwk: JSON Web Key:
+    ///   {
+    ///     "kty": "OKP",
+    ///     "crv": "Ed25519",
+    ///     "d": string, // base64url encoded 32 bytes secret key
+    ///     "x": Optional<string>, // base64url encoded public key. If present it must match the secret key
+    ///   }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - xcommand_handle: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - verkey: Ver key of imported key pair to use in other api calls.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_import_key_from_jwk(indy_handle_t     command_handle,
+                                                 indy_handle_t     wallet_handle,
+                                                 const char *const jwk,
+
+                                                 void              (*cb)(indy_handle_t     command_handle,
+                                                                         indy_error_t      err,
+                                                                         const char *const verkey)
+                                                );
+
 #ifdef __cplusplus
 }
 #endif
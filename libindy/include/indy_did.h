@@ -20,7 +20,10 @@ extern "C" {
     ///             as a new DID; if provided, then keys will be replaced - key rotation use case)
     ///     "seed": string, (optional; if not provide then a random one will be created)
     ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
-    ///               currently only 'ed25519' value is supported for this field)
+    ///               'ed25519' and 'secp256k1' (if built with secp256k1_libsecp256k1 feature) values are supported)
+    ///     "derivation_path": string, (optional; path of the key derived from the wallet master seed
+    ///               (see indy_set_master_seed), for example "m/0'/1'"; only hardened indices and
+    ///               'ed25519' crypto type are supported; can't be used together with seed)
     /// }
     /// cb: Callback that takes command result as parameter.
     ///
@@ -52,7 +55,7 @@ extern "C" {
     /// {
     ///     "seed": string, (optional; if not provide then a random one will be created)
     ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
-    ///               currently only 'ed25519' value is supported for this field)
+    ///               'ed25519' and 'secp256k1' (if built with secp256k1_libsecp256k1 feature) values are supported)
     /// }
     /// cb: Callback that takes command result as parameter.
     ///
@@ -222,6 +225,62 @@ extern "C" {
                                                                           const char *const transport_vk)
                                                  );
 
+    /// Resolves DID Document for the given DID.
+    ///
+    /// DID Document is built from the ledger NYM transaction (verification key) and ATTRIB
+    /// transactions (endpoint and other raw service attributes). Replies are checked with state
+    /// proofs the same way as for GET_NYM and GET_ATTR requests. Resolved DID Document is cached
+    /// in the wallet per requested attribs and cached value is returned while it is fresh according
+    /// to "freshness_time" wallet config.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// pool_handle: Pool handle (created by open_pool).
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// submitter_did: Id of Identity used as identifier of GET_NYM and GET_ATTR requests.
+    /// did - The DID to resolve.
+    /// options_json: (optional) resolution options:
+    ///     {
+    ///         "no_cache": (optional, false by default) Skip wallet cache and resolve from the ledger,
+    ///         "attribs": (optional, ["endpoint"] by default) Raw ATTRIB names to represent as services.
+    ///             "endpoint" attrib is represented as IndyAgent service. Other attribs must contain
+    ///             service description {"<name>": {"type": string, "serviceEndpoint": string}}
+    ///             and are skipped otherwise.
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - xcommand_handle: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - did_doc_json: DID Document json:
+    ///     {
+    ///         "@context": "https://w3id.org/did/v1",
+    ///         "id": "did:sov:<did>",
+    ///         "publicKey": [{"id", "type", "owner", "publicKeyBase58"}],
+    ///         "authentication": [{"type", "publicKey"}],
+    ///         "service": [{"id", "type", "serviceEndpoint", "recipientKeys" (optional)}]
+    ///     }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+    /// Crypto*
+    /// Did*
+    extern indy_error_t indy_resolve_did(indy_handle_t     command_handle,
+                                         indy_handle_t     pool_handle,
+                                         indy_handle_t     wallet_handle,
+                                         const char *const submitter_did,
+                                         const char *const did,
+                                         const char *const options_json,
+
+                                         void              (*cb)(indy_handle_t     command_handle,
+                                                                 indy_error_t      err,
+                                                                 const char *const did_doc_json)
+                                        );
+
     /// Saves/replaces the meta information for the giving DID in the wallet.
     ///
     /// #Params
@@ -300,6 +359,71 @@ extern "C" {
                                                                  const char *const verkey)
                                             );
 
+    /// Sets master seed of the wallet.
+    ///
+    /// Keys and DIDs can be derived from the master seed by passing "derivation_path" to
+    /// indy_create_key and indy_create_and_store_my_did. Derivation is hierarchical deterministic
+    /// derivation of ed25519 keys described in SLIP-0010, so backup of the master seed is enough
+    /// to restore all derived keys and DIDs (see indy_recover_from_master_seed).
+    /// Master seed can be set only once for the wallet, DidMasterSeedAlreadySetError is returned
+    /// if the wallet already has one.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// master_seed: master seed (from 16 to 64 bytes), in the same format as "seed" of did_json
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Did*
+
+    extern indy_error_t indy_set_master_seed(indy_handle_t command_handle,
+                                             indy_handle_t wallet_handle,
+                                             const char *const master_seed,
+                                             void          (*fn)(indy_handle_t xcommand_handle,
+                                                                 indy_error_t err)
+                                            );
+
+    /// Recovers DIDs and keys derived from the wallet master seed (see indy_set_master_seed).
+    ///
+    /// Scans first "count" hardened children of the given paths, for example for "did_path" "m/0'"
+    /// DIDs with paths "m/0'/0'", "m/0'/1'", ... are created. Recovered DIDs and keys are stored
+    /// in the wallet with their derivation paths, existing ones are overwritten by the same values.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// recovery_json: recovery options as json:
+    /// {
+    ///     "did_path": string, (optional; parent path of derived DIDs)
+    ///     "key_path": string, (optional; parent path of derived keys)
+    ///     "count": int, (optional; number of scanned children of every path, 20 by default, 1000 at most)
+    /// }
+    /// At least one of paths must be set.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// recovered DIDs and keys as json:
+    /// {
+    ///     "dids": [{"did": string, "verkey": string, "derivation_path": string}],
+    ///     "keys": [{"verkey": string, "derivation_path": string}]
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+
+    extern indy_error_t indy_recover_from_master_seed(indy_handle_t command_handle,
+                                                      indy_handle_t wallet_handle,
+                                                      const char *const recovery_json,
+                                                      void          (*fn)(indy_handle_t xcommand_handle,
+                                                                          indy_error_t err,
+                                                                          const char *const recovered_json)
+                                                     );
+
 #ifdef __cplusplus
 }
 #endif
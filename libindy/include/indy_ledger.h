@@ -69,6 +69,48 @@ extern "C" {
                                            );
 
 
+    /// Sends an action request to the nodes of the validator pool and collects one reply per node.
+    ///
+    /// Unlike submit_request it doesn't wait for consensus (f + 1 equal replies): every addressed
+    /// node is asked individually and its reply is returned as is. Every node has its own deadline:
+    /// node that doesn't answer within the timeout is reported with timeout error instead of a reply
+    /// and the result is returned as soon as every node either replied or timed out.
+    /// Only POOL_RESTART and GET_VALIDATOR_INFO requests are supported.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// request_json: Request data json (must be signed, see sign_request).
+    /// nodes: (Optional) json array of node names to send the request to:
+    ///     ["Node1", "Node2", ...]
+    ///     The request is sent to all nodes of the pool if not specified.
+    ///     Empty list is rejected.
+    /// timeout: (Optional) time in seconds to wait for the reply of every node, -1 to use the default one (10 seconds).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Json map of node name to its reply:
+    ///     {
+    ///         "Node1": string - raw reply json of the node,
+    ///         "Node2": string - '{"node": "Node2", "error": "timeout"}' json for the node timed out,
+    ///         ...
+    ///     }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_submit_action(indy_handle_t command_handle,
+                                           indy_handle_t pool_handle,
+                                           const char *  request_json,
+                                           const char *  nodes,
+                                           indy_i32_t    timeout,
+
+                                           void           (*cb)(indy_handle_t xcommand_handle,
+                                                                indy_error_t  err,
+                                                                const char*   request_result_json)
+                                           );
+
     /// Signs request message.
     ///
     /// Adds submitter information to passed request json, signs it with submitter
@@ -446,7 +488,500 @@ extern "C" {
                                                                              indy_error_t  err,
                                                                              const char*   request_json)
                                                         );
-    
+
+    /// Builds a POOL_RESTART request.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Id of Identity stored in secured Wallet.
+    /// action: Either start or cancel
+    /// datetime: (Optional) restart time in ISO 8601 format, restart is performed immediately if not specified.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_build_pool_restart_request(indy_handle_t command_handle,
+                                                        const char *  submitter_did,
+                                                        const char *  action,
+                                                        const char *  datetime,
+
+                                                        void           (*cb)(indy_handle_t xcommand_handle,
+                                                                             indy_error_t  err,
+                                                                             const char*   request_json)
+                                                        );
+
+    /// Builds a GET_VALIDATOR_INFO request.
+    ///
+    /// The request must be signed and sent with submit_action to get the info of every node.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Id of Identity stored in secured Wallet.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_build_get_validator_info_request(indy_handle_t command_handle,
+                                                              const char *  submitter_did,
+
+                                                              void           (*cb)(indy_handle_t xcommand_handle,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   request_json)
+                                                              );
+
+    /// Builds a REVOC_REG_DEF request. Request to add the definition of revocation registry
+    /// to an exists claim definition.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Id of Identity stored in secured Wallet.
+    /// data: Revocation registry definition json:
+    ///     {
+    ///         "id": string - ID of the revocation registry definition,
+    ///         "revocDefType": string - revocation registry type (only CL_ACCUM is supported for now),
+    ///         "tag": string - unique descriptive ID of the registry,
+    ///         "credDefId": string - ID of the corresponding claim definition,
+    ///         "value": {
+    ///             "issuanceType": string - ISSUANCE_BY_DEFAULT or ISSUANCE_ON_DEMAND,
+    ///             "maxCredNum": number - maximum number of claims the registry can serve,
+    ///             "publicKeys": object - registry's public keys,
+    ///             "tailsHash": (optional) string - hash of the tails file,
+    ///             "tailsLocation": (optional) string - location of the tails file
+    ///         }
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_revoc_reg_def_request(indy_handle_t command_handle,
+                                                         const char *  submitter_did,
+                                                         const char *  data,
+
+                                                         void           (*cb)(indy_handle_t xcommand_handle,
+                                                                              indy_error_t  err,
+                                                                              const char*   request_json)
+                                                         );
+
+    /// Builds a REVOC_REG_ENTRY request. Request to add the revocation registry entry record
+    /// (accumulator value and delta of issued/revoked indices) to the ledger.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Id of Identity stored in secured Wallet.
+    /// revoc_reg_def_id: ID of the corresponding revocation registry definition.
+    /// revoc_def_type: Revocation registry type (only CL_ACCUM is supported for now).
+    /// value: Registry-specific data:
+    ///     {
+    ///         "prevAccum": (optional) string - previous accumulator value,
+    ///         "accum": string - current accumulator value,
+    ///         "issued": (optional) array<number> - indices issued since previous entry,
+    ///         "revoked": (optional) array<number> - indices revoked since previous entry
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_revoc_reg_entry_request(indy_handle_t command_handle,
+                                                           const char *  submitter_did,
+                                                           const char *  revoc_reg_def_id,
+                                                           const char *  revoc_def_type,
+                                                           const char *  value,
+
+                                                           void           (*cb)(indy_handle_t xcommand_handle,
+                                                                                indy_error_t  err,
+                                                                                const char*   request_json)
+                                                           );
+
+    /// Builds a GET_REVOC_REG_DEF request. Request to get a revocation registry definition.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Id of Identity stored in secured Wallet.
+    /// id: ID of the revocation registry definition.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_get_revoc_reg_def_request(indy_handle_t command_handle,
+                                                             const char *  submitter_did,
+                                                             const char *  id,
+
+                                                             void           (*cb)(indy_handle_t xcommand_handle,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   request_json)
+                                                             );
+
+    /// Builds a GET_REVOC_REG request. Request to get the accumulated state of the revocation registry
+    /// by ID. The state is defined by the given timestamp.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Id of Identity stored in secured Wallet.
+    /// revoc_reg_def_id: ID of the corresponding revocation registry definition.
+    /// timestamp: Requested time represented as a total number of seconds from Unix Epoch.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_get_revoc_reg_request(indy_handle_t command_handle,
+                                                         const char *  submitter_did,
+                                                         const char *  revoc_reg_def_id,
+                                                         indy_i64_t    timestamp,
+
+                                                         void           (*cb)(indy_handle_t xcommand_handle,
+                                                                              indy_error_t  err,
+                                                                              const char*   request_json)
+                                                         );
+
+    /// Builds a GET_REVOC_REG_DELTA request. Request to get the delta of the accumulated state of
+    /// the revocation registry. The delta is defined by "from" and "to" timestamp fields.
+    /// If "from" is not specified, then the whole state till "to" will be returned.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Id of Identity stored in secured Wallet.
+    /// revoc_reg_def_id: ID of the corresponding revocation registry definition.
+    /// from: Requested time represented as a total number of seconds from Unix Epoch (-1 to skip).
+    /// to: Requested time represented as a total number of seconds from Unix Epoch.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_get_revoc_reg_delta_request(indy_handle_t command_handle,
+                                                               const char *  submitter_did,
+                                                               const char *  revoc_reg_def_id,
+                                                               indy_i64_t    from,
+                                                               indy_i64_t    to,
+
+                                                               void           (*cb)(indy_handle_t xcommand_handle,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   request_json)
+                                                               );
+
+    /// Parse a GET_REVOC_REG_DEF response to get the revocation registry definition.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_revoc_reg_def_response: response of GET_REVOC_REG_DEF request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Revocation registry definition id and revocation registry definition json (see indy_build_revoc_reg_def_request).
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_parse_get_revoc_reg_def_response(indy_handle_t command_handle,
+                                                              const char *  get_revoc_reg_def_response,
+
+                                                              void           (*cb)(indy_handle_t xcommand_handle,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   revoc_reg_def_id,
+                                                                                   const char*   revoc_reg_def_json)
+                                                              );
+
+    /// Parse a GET_REVOC_REG response to get the revocation registry state at the requested time.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_revoc_reg_response: response of GET_REVOC_REG request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Revocation registry definition id, revocation registry json ({"accum": string}) and
+    /// the time of the registry entry as a total number of seconds from Unix Epoch.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_parse_get_revoc_reg_response(indy_handle_t command_handle,
+                                                          const char *  get_revoc_reg_response,
+
+                                                          void           (*cb)(indy_handle_t xcommand_handle,
+                                                                               indy_error_t  err,
+                                                                               const char*   revoc_reg_def_id,
+                                                                               const char*   revoc_reg_json,
+                                                                               indy_u64_t    timestamp)
+                                                          );
+
+    /// Parse a GET_REVOC_REG_DELTA response to get the revocation registry delta at the requested time.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_revoc_reg_delta_response: response of GET_REVOC_REG_DELTA request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Revocation registry definition id, revocation registry delta json:
+    ///     {
+    ///         "prevAccum": (optional) string - accumulator value at "from" time,
+    ///         "accum": string - accumulator value at "to" time,
+    ///         "issued": array<number> - indices issued in the interval,
+    ///         "revoked": array<number> - indices revoked in the interval
+    ///     }
+    /// and the time of the last registry entry as a total number of seconds from Unix Epoch.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_parse_get_revoc_reg_delta_response(indy_handle_t command_handle,
+                                                                const char *  get_revoc_reg_delta_response,
+
+                                                                void           (*cb)(indy_handle_t xcommand_handle,
+                                                                                     indy_error_t  err,
+                                                                                     const char*   revoc_reg_def_id,
+                                                                                     const char*   revoc_reg_delta_json,
+                                                                                     indy_u64_t    timestamp)
+                                                                );
+
+    /// Gets GET_SCHEMA reply using the wallet cache of ledger objects.
+    ///
+    /// Reply is fetched from the ledger only if there is no fresh enough cached reply for the schema
+    /// in the wallet. Cached value freshness is checked the same way as for their DIDs: by "freshness_time"
+    /// wallet config and additionally by "min_fresh" option. Successful replies are stored in the cache.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: Id of Identity stored in secured Wallet.
+    /// dest: Schema issuer did.
+    /// data: name, version
+    /// options_json: (optional) cache options:
+    ///     {
+    ///         "no_cache": (optional, false by default) Skip usage of the wallet cache,
+    ///         "no_update": (optional, false by default) Use only cached data, do not fetch the ledger,
+    ///         "no_store": (optional, false by default) Skip storing of the fetched data in the wallet cache,
+    ///         "min_fresh": (optional, wallet "freshness_time" by default) Return cached data only if it
+    ///                      was fetched not more than min_fresh seconds ago
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// GET_SCHEMA reply json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+    extern indy_error_t indy_get_schema(indy_handle_t command_handle,
+                                        indy_handle_t pool_handle,
+                                        indy_handle_t wallet_handle,
+                                        const char *  submitter_did,
+                                        const char *  dest,
+                                        const char *  data,
+                                        const char *  options_json,
+
+                                        void           (*cb)(indy_handle_t xcommand_handle,
+                                                             indy_error_t  err,
+                                                             const char*   schema_reply_json)
+                                        );
+
+    /// Gets GET_CLAIM_DEF reply using the wallet cache of ledger objects.
+    ///
+    /// See indy_get_schema for the cache behaviour.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: Id of Identity stored in secured Wallet.
+    /// xref: Seq. number of schema
+    /// signature_type: signature type (only CL supported now)
+    /// origin: issuer did
+    /// options_json: (optional) cache options:
+    ///     {
+    ///         "no_cache": (optional, false by default) Skip usage of the wallet cache,
+    ///         "no_update": (optional, false by default) Use only cached data, do not fetch the ledger,
+    ///         "no_store": (optional, false by default) Skip storing of the fetched data in the wallet cache,
+    ///         "min_fresh": (optional, wallet "freshness_time" by default) Return cached data only if it
+    ///                      was fetched not more than min_fresh seconds ago
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// GET_CLAIM_DEF reply json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+    extern indy_error_t indy_get_claim_def(indy_handle_t command_handle,
+                                           indy_handle_t pool_handle,
+                                           indy_handle_t wallet_handle,
+                                           const char *  submitter_did,
+                                           indy_i32_t    xref,
+                                           const char *  signature_type,
+                                           const char *  origin,
+                                           const char *  options_json,
+
+                                           void           (*cb)(indy_handle_t xcommand_handle,
+                                                                indy_error_t  err,
+                                                                const char*   claim_def_reply_json)
+                                           );
+
+    /// Purges the wallet cache of schemas fetched from the ledger.
+    ///
+    /// Note that plugged wallets don't support records deletion yet.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// options_json: (optional) purge options:
+    ///     {
+    ///         "max_age": (optional, all entries by default) Purge only entries fetched more than max_age seconds ago
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_purge_schema_cache(indy_handle_t command_handle,
+                                                indy_handle_t wallet_handle,
+                                                const char *  options_json,
+
+                                                void           (*cb)(indy_handle_t xcommand_handle,
+                                                                     indy_error_t  err)
+                                                );
+
+    /// Purges the wallet cache of claim definitions fetched from the ledger.
+    ///
+    /// Note that plugged wallets don't support records deletion yet.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// options_json: (optional) purge options:
+    ///     {
+    ///         "max_age": (optional, all entries by default) Purge only entries fetched more than max_age seconds ago
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_purge_claim_def_cache(indy_handle_t command_handle,
+                                                   indy_handle_t wallet_handle,
+                                                   const char *  options_json,
+
+                                                   void           (*cb)(indy_handle_t xcommand_handle,
+                                                                        indy_error_t  err)
+                                                   );
+
+    /// Opens a reader over a range of ledger transactions.
+    ///
+    /// Transactions are fetched with GET_TXN requests and every transaction is verified
+    /// against the ledger root hash using the merkle audit path returned by the nodes.
+    /// The root hash must be covered by the pool BLS multi-signature (or agreed by f+1 nodes)
+    /// and every new root must be proven consistent with the previously verified root.
+    /// Transactions without valid proofs are rejected.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// submitter_did: Id of Identity stored in secured Wallet.
+    /// ledger_id: ledger to read: 0 - pool ledger, 1 - domain ledger, 2 - config ledger.
+    /// from: sequence number of the first transaction to read (starting from 1).
+    /// to: sequence number of the last transaction to read, -1 to read until the end of the ledger.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Handle of the reader to use in fetch_ledger_txns.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_open_ledger_txn_reader(indy_handle_t command_handle,
+                                                    indy_handle_t pool_handle,
+                                                    const char *  submitter_did,
+                                                    indy_i32_t    ledger_id,
+                                                    indy_i32_t    from,
+                                                    indy_i32_t    to,
+
+                                                    void           (*cb)(indy_handle_t xcommand_handle,
+                                                                         indy_error_t  err,
+                                                                         indy_handle_t reader_handle)
+                                                    );
+
+    /// Fetches next verified transactions from the ledger transactions reader.
+    ///
+    /// Transactions are requested from the pool in batches and returned in order of their sequence numbers.
+    /// Empty array is returned when there are no more transactions in the requested range.
+    /// Fetch is atomic: if any transaction can't be fetched or verified, none are returned and
+    /// the reader stays at the position the fetch started from, so the fetch can be retried.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// reader_handle: reader handle (created by open_ledger_txn_reader).
+    /// count: maximum number of transactions to fetch.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Transactions json:
+    ///     [{
+    ///         "seqNo": int - sequence number of the transaction,
+    ///         "ledgerId": int - ledger of the transaction,
+    ///         "txn": object - transaction,
+    ///         "rootHash": string - base58 ledger root hash the transaction was verified against,
+    ///         "ledgerSize": int - size of the ledger for the root hash
+    ///     }]
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_fetch_ledger_txns(indy_handle_t command_handle,
+                                               indy_handle_t reader_handle,
+                                               indy_u32_t    count,
+
+                                               void           (*cb)(indy_handle_t xcommand_handle,
+                                                                    indy_error_t  err,
+                                                                    const char*   txns_json)
+                                               );
+
+    /// Closes the ledger transactions reader.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// reader_handle: reader handle (created by open_ledger_txn_reader).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_close_ledger_txn_reader(indy_handle_t command_handle,
+                                                     indy_handle_t reader_handle,
+
+                                                     void           (*cb)(indy_handle_t xcommand_handle,
+                                                                          indy_error_t  err)
+                                                     );
+
 #ifdef __cplusplus
 }
 #endif
@@ -52,6 +52,9 @@ typedef enum
     // IO Error
     CommonIOError = 114,
 
+    // Caller passed invalid value as param 13 (null, invalid json and etc..)
+    CommonInvalidParam13 = 115,
+
     // Wallet errors
     // Caller passed invalid wallet handle
     WalletInvalidHandle = 200,
@@ -121,12 +124,27 @@ typedef enum
     // Attempt to create claim definition with duplicated did schema pair
     AnoncredsClaimDefAlreadyExistsError = 407,
 
+    // Attempt to create claim for expired claim offer
+    AnoncredsClaimOfferExpiredError = 408,
+
+    // Attempt to create claim for unknown, withdrawn or already fulfilled claim offer
+    AnoncredsClaimOfferNotFoundError = 409,
+
     // Crypto errors
     // Unknown format of DID entity keys
     UnknownCryptoTypeError = 500,
 
+    // Attempt to delete key that is still used by DID
+    CryptoKeyUsedByDidError = 501,
+
     // Attempt to create duplicate did
-    DidAlreadyExistsError = 600
+    DidAlreadyExistsError = 600,
+
+    // Requested DID isn't found on the ledger
+    DidNotFoundError = 601,
+
+    // Attempt to set master seed of the wallet that already has one
+    DidMasterSeedAlreadySetError = 602
 
 } indy_error_t;
 
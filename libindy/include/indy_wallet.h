@@ -22,6 +22,10 @@ extern "C" {
     /// delete: WalletType delete operation handler
     /// free: Handler that allows to de-allocate strings allocated in caller code
     ///
+    /// Wallet type registered without remove handler can't delete records, so deletion of keys,
+    /// claims and claim offers fails for its wallets. Use indy_register_wallet_type_with_remove
+    /// to provide remove handler.
+    ///
     /// #Returns
     /// Error code
     
@@ -63,6 +67,69 @@ extern "C" {
                                                   void           (*fn)(indy_handle_t xcommand_handle, indy_error_t err)
                                                   );
 
+    /// Registers custom wallet implementation with records remove handler.
+    ///
+    /// The same as indy_register_wallet_type, but wallets of registered type support deletion
+    /// of records (keys, claims and claim offers).
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// xtype: Wallet type name.
+    /// create: WalletType create operation handler
+    /// open: WalletType open operation handler
+    /// set: Wallet set operation handler
+    /// get: Wallet get operation handler
+    /// get_not_expired: Wallet get_not_expired operation handler
+    /// list: Wallet list operation handler
+    /// remove: Wallet remove operation handler (must return WalletNotFoundError for unknown key)
+    /// close: Wallet close operation handler
+    /// delete: WalletType delete operation handler
+    /// free: Handler that allows to de-allocate strings allocated in caller code
+    ///
+    /// #Returns
+    /// Error code
+    
+
+    extern indy_error_t indy_register_wallet_type_with_remove(indy_handle_t  command_handle,
+                                                              const char*    xtype,
+                                                              indy_error_t (*createFn)(const char* name,
+                                                                                         const char* config,
+                                                                                         const char* credentials),
+
+                                                              indy_error_t (*openFn)(const char* name,
+                                                                                       const char* config,
+                                                                                       const char* runtime_config,
+                                                                                       const char* credentials,
+                                                                                       indy_handle_t* handle),
+
+                                                              indy_error_t (*setFn)(indy_handle_t handle,
+                                                                                      const char* key,
+                                                                                      const char* value),
+
+                                                              indy_error_t (*getFn)(indy_handle_t handle,
+                                                                                      const char* key,
+                                                                                      const char ** const value_ptr),
+
+                                                              indy_error_t (*getNotExpiredFn)(indy_handle_t handle,
+                                                                                      const char* key,
+                                                                                      const char ** const value_ptr),
+
+                                                              indy_error_t (*listFn)(indy_handle_t handle,
+                                                                                      const char* key,
+                                                                                      const char ** const values_json_ptr),
+
+                                                              indy_error_t (*removeFn)(indy_handle_t handle,
+                                                                                      const char* key),
+
+                                                              indy_error_t (*closeFn)(indy_handle_t handle),
+                                                              indy_error_t (*deleteFn)(const char* name,
+                                                                                         const char* config,
+                                                                                         const char* credentials),
+
+                                                              indy_error_t (*freeFn)(indy_handle_t handle, const char* str),
+                                                              void           (*fn)(indy_handle_t xcommand_handle, indy_error_t err)
+                                                              );
+
     /// Creates a new secure wallet with the given unique name.
     ///
     /// #Params
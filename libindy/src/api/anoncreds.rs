@@ -13,6 +13,8 @@ use self::libc::c_char;
 
 /// Create keys (both primary and revocation) for the given schema and signature type (currently only CL signature type is supported).
 /// Store the keys together with signature type and schema in a secure wallet as a claim definition.
+/// Creates untagged claim definition, see indy_issuer_create_and_store_claim_def_with_tag to create more
+/// than one claim definition for the same schema. Created claim definition becomes active.
 ///
 /// #Params
 /// wallet_handle: wallet handler (created by open_wallet).
@@ -25,6 +27,7 @@ use self::libc::c_char;
 ///
 /// #Returns
 /// claim definition json containing information about signature type, schema and issuer's public key.
+/// Its data declares canonical attribute encoding of issued claims ("attr_encoding": "1").
 ///
 /// #Errors
 /// Common*
@@ -52,6 +55,7 @@ pub extern fn indy_issuer_create_and_store_claim_def(command_handle: i32,
             schema_json,
             signature_type,
             create_non_revoc,
+            None,
             Box::new(move |result| {
                 let (err, claim_def_json) = result_to_err_code_1!(result, String::new());
                 let claim_def_json = CStringUtils::string_to_cstring(claim_def_json);
@@ -62,15 +66,224 @@ pub extern fn indy_issuer_create_and_store_claim_def(command_handle: i32,
     result_to_err_code!(result)
 }
 
+/// Creates tagged claim definition (see indy_issuer_create_and_store_claim_def).
+/// The issuer may have several claim definitions for the same schema distinguished by tags
+/// (i.e. to rotate keys). Created claim definition becomes active: claim offers and revocation
+/// registries use it until another claim definition is activated (see indy_issuer_set_active_claim_def).
+/// Claims are issued with claim definition of the offer and are revoked by its tag
+/// (see indy_issuer_revoke_claim_with_tag).
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// issuer_did: a DID of the issuer signing claim_def transaction to the Ledger
+/// schema_json: schema as a json
+/// signature_type: signature type (optional). Currently only 'CL' is supported.
+/// create_non_revoc: whether to request non-revocation claim.
+/// tag: non-empty tag unique for the issuer and the schema.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// claim definition json with "tag" field.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_issuer_create_and_store_claim_def_with_tag(command_handle: i32,
+                                                              wallet_handle: i32,
+                                                              issuer_did: *const c_char,
+                                                              schema_json: *const c_char,
+                                                              signature_type: *const c_char,
+                                                              create_non_revoc: bool,
+                                                              tag: *const c_char,
+                                                              cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                                   claim_def_json: *const c_char
+                                                              )>) -> ErrorCode {
+    check_useful_c_str!(issuer_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(schema_json, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(signature_type, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(tag, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Issuer(IssuerCommand::CreateAndStoreClaimDefinition(
+            wallet_handle,
+            issuer_did,
+            schema_json,
+            signature_type,
+            create_non_revoc,
+            Some(tag),
+            Box::new(move |result| {
+                let (err, claim_def_json) = result_to_err_code_1!(result, String::new());
+                let claim_def_json = CStringUtils::string_to_cstring(claim_def_json);
+                cb(command_handle, err, claim_def_json.as_ptr())
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Makes the claim definition with the given tag active for the issuer and the schema.
+/// New claim offers and revocation registries use active claim definition.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// issuer_did: a DID of the issuer
+/// schema_json: schema as a json
+/// tag: (Optional) tag of claim definition, NULL for untagged claim definition.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// None
+///
+/// #Errors
+/// Common*
+/// Wallet* (WalletNotFoundError if claim definition doesn't exist)
+#[no_mangle]
+pub extern fn indy_issuer_set_active_claim_def(command_handle: i32,
+                                               wallet_handle: i32,
+                                               issuer_did: *const c_char,
+                                               schema_json: *const c_char,
+                                               tag: *const c_char,
+                                               cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_str!(issuer_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(schema_json, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(tag, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Issuer(IssuerCommand::SetActiveClaimDefinition(
+            wallet_handle,
+            issuer_did,
+            schema_json,
+            tag,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Lists issuer's claim definitions for the schema in order of creation (lineage).
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// issuer_did: a DID of the issuer
+/// schema_json: schema as a json
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// claim definitions json
+///     [{
+///         "tag": string (Optional) - null for untagged claim definition,
+///         "created_at": int (Optional) - seconds since epoch, null if created before lineage was tracked,
+///         "active": bool,
+///         "claim_def": <claim definition json>
+///     }]
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_list_claim_defs(command_handle: i32,
+                                          wallet_handle: i32,
+                                          issuer_did: *const c_char,
+                                          schema_json: *const c_char,
+                                          cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                               claim_defs_json: *const c_char
+                                          )>) -> ErrorCode {
+    check_useful_c_str!(issuer_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(schema_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Issuer(IssuerCommand::ListClaimDefinitions(
+            wallet_handle,
+            issuer_did,
+            schema_json,
+            Box::new(move |result| {
+                let (err, claim_defs_json) = result_to_err_code_1!(result, String::new());
+                let claim_defs_json = CStringUtils::string_to_cstring(claim_defs_json);
+                cb(command_handle, err, claim_defs_json.as_ptr())
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Compares attribute sets and versions of two schemas, usually two versions of the same schema.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// schema_json: schema as a json
+/// other_schema_json: schema as a json to compare with
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// schema diff json
+///     {
+///         "same_lineage": bool - both schemas have the same name and are created by the same DID,
+///         "version_cmp": int - -1, 0 or 1 if version of schema_json is lower, equal or greater than other one.
+///             Versions are compared by dot separated components, numeric components numerically,
+///         "added": [string] - attributes of other_schema_json missed in schema_json,
+///         "removed": [string] - attributes of schema_json missed in other_schema_json,
+///         "common": [string] - attributes of both schemas
+///     }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_issuer_compare_schemas(command_handle: i32,
+                                          schema_json: *const c_char,
+                                          other_schema_json: *const c_char,
+                                          cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                               schema_diff_json: *const c_char
+                                          )>) -> ErrorCode {
+    check_useful_c_str!(schema_json, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(other_schema_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Issuer(IssuerCommand::CompareSchemas(
+            schema_json,
+            other_schema_json,
+            Box::new(move |result| {
+                let (err, schema_diff_json) = result_to_err_code_1!(result, String::new());
+                let schema_diff_json = CStringUtils::string_to_cstring(schema_diff_json);
+                cb(command_handle, err, schema_diff_json.as_ptr())
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
 /// Create a new revocation registry for the given claim definition.
 /// Stores it in a secure wallet.
 ///
+/// Registry tails are generated into a blob written to the blob storage given by tails_writer_config_json
+/// (<indy home>/tails by default). Returned registry json doesn't contain tails, it references them
+/// by "tails_hash" (base58 sha256 of the blob) only. Tails must be published by issuer, provers and
+/// verifiers read them by the hash from their own storage (see tails_reader_config_json of
+/// indy_prover_store_claim, indy_prover_create_proof and indy_verifier_verify_proof).
+///
 /// #Params
 /// wallet_handle: wallet handler (created by open_wallet).
 /// command_handle: command handle to map callback to user context.
 /// issuer_did: a DID of the issuer signing revoc_reg transaction to the Ledger
 /// schema_json: schema as a json
 /// max_claim_num: maximum number of claims the new registry can process.
+/// tails_writer_config_json: (Optional) blob storage to write tails to
+///     {
+///         "type": string (Optional) - "default" or type registered with indy_register_blob_storage_type,
+///         "config": json (Optional) - config of the type, {"base_dir": string (Optional)} for "default"
+///     }
+///     Issuer reads tails from the same storage later.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
@@ -86,12 +299,14 @@ pub extern fn indy_issuer_create_and_store_revoc_reg(command_handle: i32,
                                                      issuer_did: *const c_char,
                                                      schema_json: *const c_char,
                                                      max_claim_num: u32,
+                                                     tails_writer_config_json: *const c_char,
                                                      cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
                                                                           revoc_reg_json: *const c_char
                                                      )>) -> ErrorCode {
     check_useful_c_str!(issuer_did, ErrorCode::CommonInvalidParam3);
     check_useful_c_str!(schema_json, ErrorCode::CommonInvalidParam4);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+    check_useful_opt_c_str!(tails_writer_config_json, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
 
     let result = CommandExecutor::instance()
         .send(Command::Anoncreds(
@@ -101,6 +316,7 @@ pub extern fn indy_issuer_create_and_store_revoc_reg(command_handle: i32,
                     issuer_did,
                     schema_json,
                     max_claim_num,
+                    tails_writer_config_json,
                     Box::new(move |result| {
                         let (err, revoc_reg_json) = result_to_err_code_1!(result, String::new());
                         let revoc_reg_json = CStringUtils::string_to_cstring(revoc_reg_json);
@@ -127,7 +343,9 @@ pub extern fn indy_issuer_create_and_store_revoc_reg(command_handle: i32,
 ///            "issuer_did": string,
 ///            "schema_key" : {name: string, version: string, did: string},
 ///            "nonce": string,
-///            "key_correctness_proof" : <key_correctness_proof>
+///            "key_correctness_proof" : <key_correctness_proof>,
+///            "expires_at": null,
+///            "claim_def_tag": (Optional) string - tag of the active claim definition
 ///        }
 ///
 /// #Errors
@@ -154,6 +372,68 @@ pub extern fn indy_issuer_create_claim_offer(command_handle: i32,
             schema_json,
             issuer_did,
             prover_did,
+            None,
+            Box::new(move |result| {
+                let (err, claim_offer_json) = result_to_err_code_1!(result, String::new());
+                let claim_offer_json = CStringUtils::string_to_cstring(claim_offer_json);
+                cb(command_handle, err, claim_offer_json.as_ptr())
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Create claim offer in Wallet that expires after given period.
+/// Claim requests for expired offer are rejected by prover and issuer.
+/// A new offer for the same prover, schema and issuer replaces outstanding one.
+/// Expired offers of the claim definition are removed from the wallet when a new offer is created.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// schema_json: schema as a json
+/// issuer_did: a DID of the issuer created Claim definition
+/// prover_did: a DID of the target user
+/// expires_in: period in seconds the offer can be used for claim request
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// claim offer json:
+///        {
+///            "issuer_did": string,
+///            "schema_key" : {name: string, version: string, did: string},
+///            "nonce": string,
+///            "key_correctness_proof" : <key_correctness_proof>,
+///            "expires_at": int - seconds since epoch,
+///            "claim_def_tag": (Optional) string - tag of the active claim definition
+///        }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_issuer_create_claim_offer_with_expiration(command_handle: i32,
+                                                             wallet_handle: i32,
+                                                             schema_json: *const c_char,
+                                                             issuer_did: *const c_char,
+                                                             prover_did: *const c_char,
+                                                             expires_in: u32,
+                                                             cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                                  claim_offer_json: *const c_char
+                                                             )>) -> ErrorCode {
+    check_useful_c_str!(schema_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(issuer_did, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(prover_did, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Issuer(IssuerCommand::CreateClaimOffer(
+            wallet_handle,
+            schema_json,
+            issuer_did,
+            prover_did,
+            Some(expires_in),
             Box::new(move |result| {
                 let (err, claim_offer_json) = result_to_err_code_1!(result, String::new());
                 let claim_offer_json = CStringUtils::string_to_cstring(claim_offer_json);
@@ -164,9 +444,58 @@ pub extern fn indy_issuer_create_claim_offer(command_handle: i32,
     result_to_err_code!(result)
 }
 
+/// Withdraws outstanding claim offer, so claim requests for it are rejected.
+/// AnoncredsClaimOfferNotFoundError is returned if there is no outstanding offer.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// issuer_did: a DID of the issuer created Claim definition
+/// schema_json: schema as a json
+/// claim_def_tag: (Optional) tag of claim definition the offer was created with ("claim_def_tag" of the offer),
+/// NULL for untagged claim definition.
+/// prover_did: a DID of the user the offer was created for
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_withdraw_claim_offer(command_handle: i32,
+                                               wallet_handle: i32,
+                                               issuer_did: *const c_char,
+                                               schema_json: *const c_char,
+                                               claim_def_tag: *const c_char,
+                                               prover_did: *const c_char,
+                                               cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_str!(issuer_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(schema_json, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(claim_def_tag, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(prover_did, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Issuer(IssuerCommand::WithdrawClaimOffer(
+            wallet_handle,
+            issuer_did,
+            schema_json,
+            claim_def_tag,
+            prover_did,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
 /// Signs a given claim values for the given user by a given key (claim def).
 /// The corresponding claim definition and revocation registry must be already created
 /// an stored into the wallet.
+/// Claim offer the request is created for is fulfilled by the claim, so it can't be used again
+/// (AnoncredsClaimOfferNotFoundError).
 ///
 /// #Params
 /// wallet_handle: wallet handler (created by open_wallet).
@@ -186,10 +515,16 @@ pub extern fn indy_issuer_create_claim_offer(command_handle: i32,
 /// claim_values_json: a claim containing attribute values for each of requested attribute names.
 ///     Example:
 ///     {
-///      "attr1" : ["value1", "value1_as_int"],
+///      "attr1" : ["value1"],
 ///      "attr2" : ["value2", "value2_as_int"]
 ///     }
-/// user_revoc_index: index of a new user in the revocation registry (optional, pass -1 if user_revoc_index is absentee; default one is used if not provided)
+///     For claim definitions with "attr_encoding" "1" in data (all created by this version) encoded values
+///     are computed from raw ones with canonical encoding (non-negative integers as is, other values
+///     as SHA-256 hash integer) and encoded values passed by the caller are ignored.
+///     For legacy claim definitions without "attr_encoding" encoded values must be passed by the caller.
+///     Revealed attributes of such claims are accepted by verifier only if encoded canonically.
+/// user_revoc_index: index of a new user in the revocation registry (optional, pass -1 if user_revoc_index is absentee;
+///     the lowest free index of the registry is allocated if not provided). Issued claim is kept in the wallet under its index.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
@@ -242,9 +577,74 @@ pub extern fn indy_issuer_create_claim(command_handle: i32,
     result_to_err_code!(result)
 }
 
+/// Signs many claims of the same claim definition in one pass.
+/// Claim definition and revocation registry are read from the wallet and stored
+/// back only once for the whole batch. Revocation indices are assigned automatically
+/// (the lowest indices not issued yet) if revocation registry exists.
+/// Claim offers are fulfilled as in indy_issuer_create_claim, so a request for an offer fulfilled before
+/// or earlier in the batch fails with AnoncredsClaimOfferNotFoundError.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// claim_reqs_json: list of claim requests with claim values, all requests
+/// must be for the same issuer_did and schema_key
+///     [{
+///         "claim_request": <claim_req_json as in indy_issuer_create_claim>,
+///         "claim_values": <claim_values_json as in indy_issuer_create_claim>
+///     }]
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Revocation registry delta json with all newly issued claims (empty string if there is no revocation registry)
+///     {
+///         "prevAccum": string, - accumulator before the batch
+///         "accum": string, - accumulator after the batch
+///         "issued": [int], - revocation indices of issued claims
+///         "revoked": [int] - always empty
+///     }
+/// Claims json with result for each claim request in the same order. A failed request doesn't abort the batch.
+///     [
+///         {"claim": <claim_json as in indy_issuer_create_claim>},
+///         {"error_code": int, "error": string}
+///     ]
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_create_claims(command_handle: i32,
+                                        wallet_handle: i32,
+                                        claim_reqs_json: *const c_char,
+                                        cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                             revoc_reg_update_json: *const c_char,
+                                                             claims_json: *const c_char
+                                        )>) -> ErrorCode {
+    check_useful_c_str!(claim_reqs_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Issuer(IssuerCommand::CreateClaims(
+            wallet_handle,
+            claim_reqs_json,
+            Box::new(move |result| {
+                let (err, revoc_reg_update_json, claims_json) = result_to_err_code_2!(result, String::new(), String::new());
+                let revoc_reg_update_json = CStringUtils::string_to_cstring(revoc_reg_update_json);
+                let claims_json = CStringUtils::string_to_cstring(claims_json);
+                cb(command_handle, err, revoc_reg_update_json.as_ptr(), claims_json.as_ptr())
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
 /// Revokes a user identified by a user_revoc_index in a given revoc-registry.
 /// The corresponding claim definition and revocation registry must be already
 /// created an stored into the wallet.
+/// Revoked index isn't allocated again, a claim revoked in error can be recovered with indy_issuer_recover_claim.
+/// Revokes claims issued with untagged claim definition, see indy_issuer_revoke_claim_with_tag
+/// to revoke claims issued with tagged one.
 ///
 /// #Params
 /// wallet_handle: wallet handler (created by open_wallet).
@@ -261,6 +661,9 @@ pub extern fn indy_issuer_create_claim(command_handle: i32,
 /// Annoncreds*
 /// Common*
 /// Wallet*
+///
+/// AnoncredsInvalidUserRevocIndex is returned if no claim was issued with user_revoc_index
+/// and AnoncredsClaimRevoked if the claim is already revoked.
 #[no_mangle]
 pub extern fn indy_issuer_revoke_claim(command_handle: i32,
                                        wallet_handle: i32,
@@ -281,6 +684,7 @@ pub extern fn indy_issuer_revoke_claim(command_handle: i32,
                     wallet_handle,
                     issuer_did,
                     schema_json,
+                    None,
                     user_revoc_index,
                     Box::new(move |result| {
                         let (err, revoc_reg_update_json) = result_to_err_code_1!(result, String::new());
@@ -292,32 +696,202 @@ pub extern fn indy_issuer_revoke_claim(command_handle: i32,
     result_to_err_code!(result)
 }
 
-/// Stores a claim offer from the given issuer in a secure storage.
+/// Revokes a claim issued with tagged claim definition (see indy_issuer_revoke_claim).
+/// Claims issued with claim definition stay revocable by its tag after another claim definition
+/// is activated.
 ///
 /// #Params
 /// wallet_handle: wallet handler (created by open_wallet).
 /// command_handle: command handle to map callback to user context.
-/// claim_offer_json: claim offer as a json containing information about the issuer and a claim:
-///        {
-///            "issuer_did": string,
-///            "schema_key" : {name: string, version: string, did: string},
-///            "nonce": string,
-///            "key_correctness_proof" : <key_correctness_proof>
-///        }
+/// issuer_did: a DID of the issuer signing claim_def transaction to the Ledger
+/// schema_json: schema as a json
+/// claim_def_tag: (Optional) tag of claim definition the claim was issued with ("claim_def_tag" of the claim),
+/// NULL for untagged claim definition.
+/// user_revoc_index: index of the user in the revocation registry
+/// cb: Callback that takes command result as parameter.
+///
 /// #Returns
-/// None.
+/// Revocation registry update json with a revoked claim
 ///
 /// #Errors
+/// Annoncreds*
 /// Common*
 /// Wallet*
 #[no_mangle]
-pub extern fn indy_prover_store_claim_offer(command_handle: i32,
-                                            wallet_handle: i32,
-                                            claim_offer_json: *const c_char,
-                                            cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode
-                                            )>) -> ErrorCode {
-    check_useful_c_str!(claim_offer_json, ErrorCode::CommonInvalidParam3);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+pub extern fn indy_issuer_revoke_claim_with_tag(command_handle: i32,
+                                                wallet_handle: i32,
+                                                issuer_did: *const c_char,
+                                                schema_json: *const c_char,
+                                                claim_def_tag: *const c_char,
+                                                user_revoc_index: u32,
+                                                cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                     revoc_reg_update_json: *const c_char,
+                                                )>) -> ErrorCode {
+    check_useful_c_str!(issuer_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(schema_json, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(claim_def_tag, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::RevokeClaim(
+                    wallet_handle,
+                    issuer_did,
+                    schema_json,
+                    claim_def_tag,
+                    user_revoc_index,
+                    Box::new(move |result| {
+                        let (err, revoc_reg_update_json) = result_to_err_code_1!(result, String::new());
+                        let revoc_reg_update_json = CStringUtils::string_to_cstring(revoc_reg_update_json);
+                        cb(command_handle, err, revoc_reg_update_json.as_ptr())
+                    })
+                ))));
+
+    result_to_err_code!(result)
+}
+
+/// Recovers (unrevokes) a claim of a user identified by a user_revoc_index in a given revoc-registry.
+/// The claim must be previously revoked with indy_issuer_revoke_claim. Tail of the index is added back
+/// to the registry accumulator, so holder's witness is updated by the published registry as usual.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// issuer_did: a DID of the issuer signing claim_def transaction to the Ledger
+/// schema_json: schema as a json
+/// claim_def_tag: (Optional) tag of claim definition the claim was issued with ("claim_def_tag" of the claim),
+/// NULL for untagged claim definition.
+/// user_revoc_index: index of the user in the revocation registry
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Revocation registry update json with a recovered claim
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+///
+/// AnoncredsInvalidUserRevocIndex is returned if no claim was issued with user_revoc_index
+/// or the claim isn't revoked.
+#[no_mangle]
+pub extern fn indy_issuer_recover_claim(command_handle: i32,
+                                        wallet_handle: i32,
+                                        issuer_did: *const c_char,
+                                        schema_json: *const c_char,
+                                        claim_def_tag: *const c_char,
+                                        user_revoc_index: u32,
+                                        cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                             revoc_reg_update_json: *const c_char,
+                                        )>) -> ErrorCode {
+    check_useful_c_str!(issuer_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(schema_json, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(claim_def_tag, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::RecoverClaim(
+                    wallet_handle,
+                    issuer_did,
+                    schema_json,
+                    claim_def_tag,
+                    user_revoc_index,
+                    Box::new(move |result| {
+                        let (err, revoc_reg_update_json) = result_to_err_code_1!(result, String::new());
+                        let revoc_reg_update_json = CStringUtils::string_to_cstring(revoc_reg_update_json);
+                        cb(command_handle, err, revoc_reg_update_json.as_ptr())
+                    })
+                ))));
+
+    result_to_err_code!(result)
+}
+
+/// Returns revocation indices used in a given revoc-registry.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// issuer_did: a DID of the issuer signing claim_def transaction to the Ledger
+/// schema_json: schema as a json
+/// claim_def_tag: (Optional) tag of claim definition the registry was created for,
+/// NULL for untagged claim definition.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// revoc_reg_indices_json: issued and revoked indices of the registry
+///     {
+///         "max_claim_num": int,
+///         "issued": [int], // indices of issued claims (including revoked ones)
+///         "revoked": [int] // indices of revoked claims
+///     }
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_get_revoc_reg_indices(command_handle: i32,
+                                                wallet_handle: i32,
+                                                issuer_did: *const c_char,
+                                                schema_json: *const c_char,
+                                                claim_def_tag: *const c_char,
+                                                cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                     revoc_reg_indices_json: *const c_char,
+                                                )>) -> ErrorCode {
+    check_useful_c_str!(issuer_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(schema_json, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(claim_def_tag, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::GetRevocationRegistryIndices(
+                    wallet_handle,
+                    issuer_did,
+                    schema_json,
+                    claim_def_tag,
+                    Box::new(move |result| {
+                        let (err, revoc_reg_indices_json) = result_to_err_code_1!(result, String::new());
+                        let revoc_reg_indices_json = CStringUtils::string_to_cstring(revoc_reg_indices_json);
+                        cb(command_handle, err, revoc_reg_indices_json.as_ptr())
+                    })
+                ))));
+
+    result_to_err_code!(result)
+}
+
+/// Stores a claim offer from the given issuer in a secure storage.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// claim_offer_json: claim offer as a json containing information about the issuer and a claim:
+///        {
+///            "issuer_did": string,
+///            "schema_key" : {name: string, version: string, did: string},
+///            "nonce": string,
+///            "key_correctness_proof" : <key_correctness_proof>,
+///            "expires_at": (Optional) int - seconds since epoch,
+///            "claim_def_tag": (Optional) string
+///        }
+/// #Returns
+/// None.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_store_claim_offer(command_handle: i32,
+                                            wallet_handle: i32,
+                                            claim_offer_json: *const c_char,
+                                            cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode
+                                            )>) -> ErrorCode {
+    check_useful_c_str!(claim_offer_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     let result = CommandExecutor::instance()
         .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::StoreClaimOffer(
@@ -387,6 +961,10 @@ pub extern fn indy_prover_get_claim_offers(command_handle: i32,
 /// Creates a master secret with a given name and stores it in the wallet.
 /// The name must be unique.
 ///
+/// Master secret can't be rotated in place as claims are bound to it on issuance.
+/// To rotate it create a new master secret and get claims issued for it again,
+/// claims bound to the old one are reported by indy_prover_get_claims ("master_secret_name").
+///
 /// #Params
 /// wallet_handle: wallet handler (created by open_wallet).
 /// command_handle: command handle to map callback to user context.
@@ -421,6 +999,133 @@ pub extern fn indy_prover_create_master_secret(command_handle: i32,
     result_to_err_code!(result)
 }
 
+/// Lists names of master secrets stored in the wallet.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+///
+/// #Returns
+/// master secret names json
+///     [string, ...]
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_list_master_secrets(command_handle: i32,
+                                              wallet_handle: i32,
+                                              cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                   master_secret_names_json: *const c_char
+                                              )>) -> ErrorCode {
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::ListMasterSecrets(
+            wallet_handle,
+            Box::new(move |result| {
+                let (err, master_secret_names_json) = result_to_err_code_1!(result, String::new());
+                let master_secret_names_json = CStringUtils::string_to_cstring(master_secret_names_json);
+                cb(command_handle, err, master_secret_names_json.as_ptr())
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Exports master secret encrypted under the passphrase,
+/// so it can be imported to another wallet by indy_prover_import_master_secret.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// master_secret_name: name of the master secret to export
+/// passphrase: passphrase to derive encryption key from
+///
+/// #Returns
+/// exported master secret json
+///     {
+///         "salt": string, - base58 encoded salt of passphrase key derivation
+///         "nonce": string, - base58 encoded encryption nonce
+///         "ciphertext": string - base58 encoded encrypted master secret
+///     }
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_export_master_secret(command_handle: i32,
+                                               wallet_handle: i32,
+                                               master_secret_name: *const c_char,
+                                               passphrase: *const c_char,
+                                               cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                    exported_master_secret_json: *const c_char
+                                               )>) -> ErrorCode {
+    check_useful_c_str!(master_secret_name, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(passphrase, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::ExportMasterSecret(
+            wallet_handle,
+            master_secret_name,
+            passphrase,
+            Box::new(move |result| {
+                let (err, exported_master_secret_json) = result_to_err_code_1!(result, String::new());
+                let exported_master_secret_json = CStringUtils::string_to_cstring(exported_master_secret_json);
+                cb(command_handle, err, exported_master_secret_json.as_ptr())
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Imports master secret exported by indy_prover_export_master_secret
+/// and stores it in the wallet with a given name. The name must be unique.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// master_secret_name: name to store the imported master secret with
+/// exported_master_secret_json: exported master secret json
+/// passphrase: passphrase used for export
+///
+/// #Returns
+/// None.
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_import_master_secret(command_handle: i32,
+                                               wallet_handle: i32,
+                                               master_secret_name: *const c_char,
+                                               exported_master_secret_json: *const c_char,
+                                               passphrase: *const c_char,
+                                               cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode
+                                               )>) -> ErrorCode {
+    check_useful_c_str!(master_secret_name, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(exported_master_secret_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(passphrase, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::ImportMasterSecret(
+            wallet_handle,
+            master_secret_name,
+            exported_master_secret_json,
+            passphrase,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
 
 /// Creates a clam request json for the given claim offer and stores it in a secure wallet.
 /// The claim offer contains the information about Issuer (DID, schema_seq_no),
@@ -438,7 +1143,9 @@ pub extern fn indy_prover_create_master_secret(command_handle: i32,
 ///            "issuer_did": string,
 ///            "schema_key" : {name: string, version: string, did: string},
 ///            "nonce": string,
-///            "key_correctness_proof" : <key_correctness_proof>
+///            "key_correctness_proof" : <key_correctness_proof>,
+///            "expires_at": (Optional) int - seconds since epoch, expired offer is rejected,
+///            "claim_def_tag": (Optional) string - must be equal to "tag" of claim_def_json
 ///        }
 /// claim_def_json: claim definition json associated with issuer_did and schema_seq_no in the claim_offer
 /// master_secret_name: the name of the master secret stored in the wallet
@@ -458,137 +1165,657 @@ pub extern fn indy_prover_create_master_secret(command_handle: i32,
 /// #Errors
 /// Annoncreds*
 /// Common*
-/// Wallet*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_create_and_store_claim_req(command_handle: i32,
+                                                     wallet_handle: i32,
+                                                     prover_did: *const c_char,
+                                                     claim_offer_json: *const c_char,
+                                                     claim_def_json: *const c_char,
+                                                     master_secret_name: *const c_char,
+                                                     cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                          claim_req_json: *const c_char
+                                                     )>) -> ErrorCode {
+    check_useful_c_str!(prover_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(claim_offer_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(claim_def_json, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(master_secret_name, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CreateAndStoreClaimRequest(
+            wallet_handle,
+            prover_did,
+            claim_offer_json,
+            claim_def_json,
+            master_secret_name,
+            Box::new(move |result| {
+                let (err, claim_req_json) = result_to_err_code_1!(result, String::new());
+                let claim_req_json = CStringUtils::string_to_cstring(claim_req_json);
+                cb(command_handle, err, claim_req_json.as_ptr())
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Updates the claim by a master secret and stores in a secure wallet.
+/// The claim contains the information about
+/// schema_key, issuer_did, revoc_reg_seq_no (see issuer_create_claim).
+/// Seq_no is a sequence number of the corresponding transaction in the ledger.
+/// The method loads a blinded secret for this key from the wallet,
+/// updates the claim and stores it in a wallet.
+/// Stored claim offer for this claim is removed from the wallet.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// claims_json: claim json:
+///     {
+///         "values": <see claim_values_json above>,
+///         "signature": <signature>,
+///         "revoc_reg_seq_no": int,
+///         "issuer_did", string,
+///         "schema_key" : {name: string, version: string, did: string},
+///         "signature_correctness_proof": <signature_correctness_proof>
+///     }
+/// rev_reg_json: revocation registry json
+/// tails_reader_config_json: (Optional) blob storage to read tails of revocation registries from by "tails_hash"
+///     {"type": string (Optional), "config": json (Optional)} (see indy_issuer_create_and_store_revoc_reg),
+///     read tails are checked against the hash.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// None
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_store_claim(command_handle: i32,
+                                      wallet_handle: i32,
+                                      claims_json: *const c_char,
+                                      rev_reg_json: *const c_char,
+                                      tails_reader_config_json: *const c_char,
+                                      cb: Option<extern fn(
+                                          xcommand_handle: i32, err: ErrorCode
+                                      )>) -> ErrorCode {
+    check_useful_c_str!(claims_json, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_c_str!(rev_reg_json, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(tails_reader_config_json, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::StoreClaim(
+            wallet_handle,
+            claims_json,
+            rev_reg_json,
+            tails_reader_config_json,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Deletes a claim stored in the wallet by indy_prover_store_claim.
+/// Deleted claim is no longer returned by indy_prover_get_claims or
+/// indy_prover_get_claims_for_proof_req.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// referent: referent of the claim to delete (as returned by indy_prover_get_claims).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// None
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_delete_claim(command_handle: i32,
+                                       wallet_handle: i32,
+                                       referent: *const c_char,
+                                       cb: Option<extern fn(
+                                           xcommand_handle: i32, err: ErrorCode
+                                       )>) -> ErrorCode {
+    check_useful_c_str!(referent, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::DeleteClaim(
+            wallet_handle,
+            referent,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Sets prover's own metadata (label) for a stored claim.
+/// Metadata is returned by indy_prover_get_claims as "metadata" field.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// referent: referent of the claim (as returned by indy_prover_get_claims).
+/// metadata: arbitrary string. NULL clears previously set metadata.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// None
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_set_claim_metadata(command_handle: i32,
+                                             wallet_handle: i32,
+                                             referent: *const c_char,
+                                             metadata: *const c_char,
+                                             cb: Option<extern fn(
+                                                 xcommand_handle: i32, err: ErrorCode
+                                             )>) -> ErrorCode {
+    check_useful_c_str!(referent, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_c_str!(metadata, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::SetClaimMetadata(
+            wallet_handle,
+            referent,
+            metadata,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Gets prover's own metadata (label) of a stored claim.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// referent: referent of the claim (as returned by indy_prover_get_claims).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// metadata: string set by indy_prover_set_claim_metadata
+///
+/// #Errors
+/// Common*
+/// Wallet* (WalletNotFoundError if claim or metadata doesn't exist)
+#[no_mangle]
+pub extern fn indy_prover_get_claim_metadata(command_handle: i32,
+                                             wallet_handle: i32,
+                                             referent: *const c_char,
+                                             cb: Option<extern fn(
+                                                 xcommand_handle: i32, err: ErrorCode,
+                                                 metadata: *const c_char
+                                             )>) -> ErrorCode {
+    check_useful_c_str!(referent, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::GetClaimMetadata(
+            wallet_handle,
+            referent,
+            Box::new(move |result| {
+                let (err, metadata) = result_to_err_code_1!(result, String::new());
+                let metadata = CStringUtils::string_to_cstring(metadata);
+                cb(command_handle, err, metadata.as_ptr())
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+
+/// Gets human readable claims according to the filter.
+/// If filter is NULL, then all claims are returned.
+/// Claims can be filtered by Issuer, claim_def and/or Schema.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// filter_json: filter for claims
+///     {
+///         "issuer_did": string (Optional),
+///         "schema_key" : {name: string (Optional), version: string (Optional), did: string (Optional)} (Optional)
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// claims json
+///     [{
+///         "referent": <string>,
+///         "attrs": [{"attr_name" : "attr_raw_value"}],
+///         "schema_key" : {name: string, version: string, did: string},
+///         "issuer_did": string,
+///         "revoc_reg_seq_no": int,
+///         "schema_seq_no": int (Optional),
+///         "master_secret_name": string (Optional) - master secret the claim is bound to,
+///         "metadata": string (Optional) - label set with indy_prover_set_claim_metadata,
+///         "stored_at": int (Optional) - time the claim was stored (seconds since epoch)
+///     }]
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_get_claims(command_handle: i32,
+                                     wallet_handle: i32,
+                                     filter_json: *const c_char,
+                                     cb: Option<extern fn(
+                                         xcommand_handle: i32, err: ErrorCode,
+                                         claims_json: *const c_char
+                                     )>) -> ErrorCode {
+    check_useful_c_str!(filter_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::GetClaims(
+            wallet_handle,
+            filter_json,
+            Box::new(move |result| {
+                let (err, claims_json) = result_to_err_code_1!(result, String::new());
+                let claims_json = CStringUtils::string_to_cstring(claims_json);
+                cb(command_handle, err, claims_json.as_ptr())
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Gets human readable claims matching the given proof request.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// proof_request_json: proof request json
+///     {
+///         "name": string,
+///         "version": string,
+///         "nonce": string,
+///         "requested_attr1_referent": <attr_info>,
+///         "requested_attr2_referent": <attr_info>,
+///         "requested_attr3_referent": <attr_info>,
+///         "requested_predicate_1_referent": <predicate_info>,
+///         "requested_predicate_2_referent": <predicate_info>,
+///         "non_revoked": <non_revoked_interval> (Optional) - default interval for all attributes and predicates
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// where attr_info:
+///     {
+///         "name": attribute name, (case insensitive and ignore spaces)
+///         "restrictions": [<restriction>, ...]  (Optional) - if specified, claim must satisfy one of the given restrictions.
+///         "non_revoked": <non_revoked_interval> (Optional)
+///     }
+/// restriction: either filter (all specified fields must match)
+///     {
+///         "schema_key" : {name: string (Optional), version: string (Optional), did: string (Optional),
+///                         min_version: string (Optional) - schema version must be greater or equal} (Optional)
+///         "issuer_did": string (Optional)
+///         "schema_seq_no": int (Optional)
+///         "claim_def_id": string (Optional) - "<issuer_did>:3:CL:<schema_seq_no>" or "<issuer_did>:3:CL:<schema_seq_no>:<tag>"
+///         "claim_def_tag": string (Optional) - tag of claim definition the claim is issued with
+///         "rev_reg_seq_no": int (Optional)
+///         "attr::<attribute name>::value": string (Optional) - raw value of claim attribute
+///     }
+///     or composition of restrictions: {"$and": [<restriction>, ...]} or {"$or": [<restriction>, ...]}
+///     Unknown filter fields are rejected.
+/// non_revoked_interval: claim must be proved non-revoked against revocation registry state
+///     valid at some moment (seconds since epoch) of the interval
+///     {
+///         "from": int (Optional),
+///         "to": int (Optional)
+///     }
+/// predicate_info:
+///     {
+///         "attr_name": attribute name, (case insensitive and ignore spaces)
+///         "p_type": predicate type (">=", ">", "<=" or "<", CL proofs support only ">=" and ">")
+///         "value": requested value of attribute (64-bit integer, CL proofs are limited to 32-bit bounds)
+///         "restrictions": [<restriction>, ...]  (Optional) - if specified, claim must satisfy one of the given restrictions.
+///         "non_revoked": <non_revoked_interval> (Optional)
+///     }
+/// #Returns
+/// json with claims for the given pool request.
+/// Claim consists of referent, human-readable attributes (key-value map), schema_key, issuer_did and revoc_reg_seq_no.
+///     {
+///         "requested_attr1_referent": [claim1, claim2],
+///         "requested_attr2_referent": [],
+///         "requested_attr3_referent": [claim3],
+///         "requested_predicate_1_referent": [claim1, claim3],
+///         "requested_predicate_2_referent": [claim2],
+///     }, where claim is
+///     {
+///         "referent": <string>,
+///         "attrs": [{"attr_name" : "attr_raw_value"}],
+///         "schema_key" : {name: string, version: string, did: string},
+///         "issuer_did": string,
+///         "revoc_reg_seq_no": int,
+///         "schema_seq_no": int (Optional),
+///         "master_secret_name": string (Optional) - master secret the claim is bound to
+///     }
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_get_claims_for_proof_req(command_handle: i32,
+                                                   wallet_handle: i32,
+                                                   proof_request_json: *const c_char,
+                                                   cb: Option<extern fn(
+                                                       xcommand_handle: i32, err: ErrorCode,
+                                                       claims_json: *const c_char
+                                                   )>) -> ErrorCode {
+    check_useful_c_str!(proof_request_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::GetClaimsForProofReq(
+            wallet_handle,
+            proof_request_json,
+            Box::new(move |result| {
+                let (err, claims_json) = result_to_err_code_1!(result, String::new());
+                let claims_json = CStringUtils::string_to_cstring(claims_json);
+                cb(command_handle, err, claims_json.as_ptr())
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Automatically selects claims to create a proof for the given proof request.
+/// Unlike indy_prover_get_claims_for_proof_req, the single claim is picked for each requested
+/// attribute and predicate, so the result can be passed to indy_prover_create_proof as is.
+///
+/// Selection rules:
+/// - all selected claims are bound to the same master secret;
+/// - the fewest distinct claims are used, so one claim satisfies as many items as possible;
+/// - among equal selections newer claims (see "stored_at" of indy_prover_get_claims) are preferred;
+/// - if non-revoked interval is requested, only revocable claims whose revocation registry
+///   stored in the wallet is valid in the interval (from "timestamp" up to "valid_to") are used;
+/// - attributes without restrictions that no claim satisfies are self-attested
+///   if the value is given in self_attested_values_json.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// proof_request_json: proof request json (see indy_prover_get_claims_for_proof_req)
+/// self_attested_values_json: (Optional) values to self-attest keyed by attribute referent
+///     or attribute name (case insensitive and ignore spaces)
+///     {
+///         "attr1_referent": string,
+///         "<attribute name>": string
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// claims selection json
+///     {
+///         "requested_claims": <requested claims json for indy_prover_create_proof>
+///             {
+///                 "self_attested_attributes": {"requested_attr_referent": string},
+///                 "requested_attrs": {"requested_attr_referent": [claim_referent, true]},
+///                 "requested_predicates": {"requested_predicate_referent": claim_referent}
+///             },
+///         "master_secret_name": string (Optional) - master secret selected claims are bound to,
+///         "unsatisfied": {"requested_attr_or_predicate_referent": string} - reason why referent isn't satisfied
+///     }
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_select_claims_for_proof_req(command_handle: i32,
+                                                      wallet_handle: i32,
+                                                      proof_request_json: *const c_char,
+                                                      self_attested_values_json: *const c_char,
+                                                      cb: Option<extern fn(
+                                                          xcommand_handle: i32, err: ErrorCode,
+                                                          claims_selection_json: *const c_char
+                                                      )>) -> ErrorCode {
+    check_useful_c_str!(proof_request_json, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_c_str!(self_attested_values_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::SelectClaimsForProofReq(
+            wallet_handle,
+            proof_request_json,
+            self_attested_values_json,
+            Box::new(move |result| {
+                let (err, claims_selection_json) = result_to_err_code_1!(result, String::new());
+                let claims_selection_json = CStringUtils::string_to_cstring(claims_selection_json);
+                cb(command_handle, err, claims_selection_json.as_ptr())
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Opens search for claims according to the filter.
+/// Unlike indy_prover_get_claims, claims are not loaded at once but read
+/// from the wallet by pages when indy_prover_fetch_claims is called.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// filter_json: filter for claims, <restriction> as described in indy_prover_get_claims_for_proof_req
+///     {
+///         "issuer_did": string (Optional),
+///         "schema_key" : {name: string (Optional), version: string (Optional), did: string (Optional)} (Optional)
+///         "schema_seq_no": int (Optional)
+///         "claim_def_id": string (Optional)
+///         "claim_def_tag": string (Optional)
+///         "rev_reg_seq_no": int (Optional)
+///         "attr::<attribute name>::value": string (Optional)
+///     }
+///     or {"$and": [<restriction>, ...]} or {"$or": [<restriction>, ...]}
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// search_handle: search handle to use in indy_prover_fetch_claims and indy_prover_close_claims_search
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_search_claims(command_handle: i32,
+                                        wallet_handle: i32,
+                                        filter_json: *const c_char,
+                                        cb: Option<extern fn(
+                                            xcommand_handle: i32, err: ErrorCode,
+                                            search_handle: i32
+                                        )>) -> ErrorCode {
+    check_useful_c_str!(filter_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::SearchClaims(
+            wallet_handle,
+            filter_json,
+            Box::new(move |result| {
+                let (err, search_handle) = result_to_err_code_1!(result, 0);
+                cb(command_handle, err, search_handle)
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Fetches next claims of the search opened by indy_prover_search_claims.
+///
+/// #Params
+/// search_handle: search handle (created by indy_prover_search_claims).
+/// count: maximum number of claims to return.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// claims json, fewer than count claims means that search is completed
+///     [{
+///         "referent": <string>,
+///         "attrs": [{"attr_name" : "attr_raw_value"}],
+///         "schema_key" : {name: string, version: string, did: string},
+///         "issuer_did": string,
+///         "revoc_reg_seq_no": int,
+///         "schema_seq_no": int (Optional),
+///         "master_secret_name": string (Optional) - master secret the claim is bound to
+///     }]
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_fetch_claims(command_handle: i32,
+                                       search_handle: i32,
+                                       count: u32,
+                                       cb: Option<extern fn(
+                                           xcommand_handle: i32, err: ErrorCode,
+                                           claims_json: *const c_char
+                                       )>) -> ErrorCode {
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::FetchClaims(
+            search_handle,
+            count as usize,
+            Box::new(move |result| {
+                let (err, claims_json) = result_to_err_code_1!(result, String::new());
+                let claims_json = CStringUtils::string_to_cstring(claims_json);
+                cb(command_handle, err, claims_json.as_ptr())
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Closes search opened by indy_prover_search_claims.
+///
+/// #Params
+/// search_handle: search handle (created by indy_prover_search_claims).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
 #[no_mangle]
-pub extern fn indy_prover_create_and_store_claim_req(command_handle: i32,
-                                                     wallet_handle: i32,
-                                                     prover_did: *const c_char,
-                                                     claim_offer_json: *const c_char,
-                                                     claim_def_json: *const c_char,
-                                                     master_secret_name: *const c_char,
-                                                     cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
-                                                                          claim_req_json: *const c_char
-                                                     )>) -> ErrorCode {
-    check_useful_c_str!(prover_did, ErrorCode::CommonInvalidParam3);
-    check_useful_c_str!(claim_offer_json, ErrorCode::CommonInvalidParam4);
-    check_useful_c_str!(claim_def_json, ErrorCode::CommonInvalidParam5);
-    check_useful_c_str!(master_secret_name, ErrorCode::CommonInvalidParam6);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+pub extern fn indy_prover_close_claims_search(command_handle: i32,
+                                              search_handle: i32,
+                                              cb: Option<extern fn(
+                                                  xcommand_handle: i32, err: ErrorCode
+                                              )>) -> ErrorCode {
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CreateAndStoreClaimRequest(
-            wallet_handle,
-            prover_did,
-            claim_offer_json,
-            claim_def_json,
-            master_secret_name,
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CloseClaimsSearch(
+            search_handle,
             Box::new(move |result| {
-                let (err, claim_req_json) = result_to_err_code_1!(result, String::new());
-                let claim_req_json = CStringUtils::string_to_cstring(claim_req_json);
-                cb(command_handle, err, claim_req_json.as_ptr())
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
             })
         ))));
 
     result_to_err_code!(result)
 }
 
-/// Updates the claim by a master secret and stores in a secure wallet.
-/// The claim contains the information about
-/// schema_key, issuer_did, revoc_reg_seq_no (see issuer_create_claim).
-/// Seq_no is a sequence number of the corresponding transaction in the ledger.
-/// The method loads a blinded secret for this key from the wallet,
-/// updates the claim and stores it in a wallet.
+/// Opens search for claims matching the given proof request.
+/// Claims for each attribute and predicate referent are fetched separately
+/// by indy_prover_fetch_claims_for_proof_req.
 ///
 /// #Params
 /// wallet_handle: wallet handler (created by open_wallet).
-/// command_handle: command handle to map callback to user context.
-/// claims_json: claim json:
-///     {
-///         "values": <see claim_values_json above>,
-///         "signature": <signature>,
-///         "revoc_reg_seq_no": int,
-///         "issuer_did", string,
-///         "schema_key" : {name: string, version: string, did: string},
-///         "signature_correctness_proof": <signature_correctness_proof>
-///     }
-/// rev_reg_json: revocation registry json
+/// proof_request_json: proof request json as described in indy_prover_get_claims_for_proof_req
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
-/// None
+/// search_handle: search handle to use in indy_prover_fetch_claims_for_proof_req
+/// and indy_prover_close_claims_search_for_proof_req
 ///
 /// #Errors
 /// Annoncreds*
 /// Common*
 /// Wallet*
 #[no_mangle]
-pub extern fn indy_prover_store_claim(command_handle: i32,
-                                      wallet_handle: i32,
-                                      claims_json: *const c_char,
-                                      rev_reg_json: *const c_char,
-                                      cb: Option<extern fn(
-                                          xcommand_handle: i32, err: ErrorCode
-                                      )>) -> ErrorCode {
-    check_useful_c_str!(claims_json, ErrorCode::CommonInvalidParam3);
-    check_useful_opt_c_str!(rev_reg_json, ErrorCode::CommonInvalidParam4);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+pub extern fn indy_prover_search_claims_for_proof_req(command_handle: i32,
+                                                      wallet_handle: i32,
+                                                      proof_request_json: *const c_char,
+                                                      cb: Option<extern fn(
+                                                          xcommand_handle: i32, err: ErrorCode,
+                                                          search_handle: i32
+                                                      )>) -> ErrorCode {
+    check_useful_c_str!(proof_request_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::StoreClaim(
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::SearchClaimsForProofReq(
             wallet_handle,
-            claims_json,
-            rev_reg_json,
+            proof_request_json,
             Box::new(move |result| {
-                let err = result_to_err_code!(result);
-                cb(command_handle, err)
+                let (err, search_handle) = result_to_err_code_1!(result, 0);
+                cb(command_handle, err, search_handle)
             })
         ))));
 
     result_to_err_code!(result)
 }
 
-
-/// Gets human readable claims according to the filter.
-/// If filter is NULL, then all claims are returned.
-/// Claims can be filtered by Issuer, claim_def and/or Schema.
+/// Fetches next claims for the attribute or predicate referent of the proof request
+/// of the search opened by indy_prover_search_claims_for_proof_req.
 ///
 /// #Params
-/// wallet_handle: wallet handler (created by open_wallet).
-/// filter_json: filter for claims
-///     {
-///         "issuer_did": string (Optional),
-///         "schema_key" : {name: string (Optional), version: string (Optional), did: string (Optional)} (Optional)
-///     }
+/// search_handle: search handle (created by indy_prover_search_claims_for_proof_req).
+/// item_referent: referent of requested attribute or predicate of the proof request.
+/// count: maximum number of claims to return.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
-/// claims json
+/// claims json, fewer than count claims means that search for the referent is completed
 ///     [{
 ///         "referent": <string>,
 ///         "attrs": [{"attr_name" : "attr_raw_value"}],
 ///         "schema_key" : {name: string, version: string, did: string},
 ///         "issuer_did": string,
 ///         "revoc_reg_seq_no": int,
+///         "schema_seq_no": int (Optional),
+///         "master_secret_name": string (Optional) - master secret the claim is bound to
 ///     }]
+///
 /// #Errors
 /// Annoncreds*
 /// Common*
 /// Wallet*
 #[no_mangle]
-pub extern fn indy_prover_get_claims(command_handle: i32,
-                                     wallet_handle: i32,
-                                     filter_json: *const c_char,
-                                     cb: Option<extern fn(
-                                         xcommand_handle: i32, err: ErrorCode,
-                                         claims_json: *const c_char
-                                     )>) -> ErrorCode {
-    check_useful_c_str!(filter_json, ErrorCode::CommonInvalidParam3);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+pub extern fn indy_prover_fetch_claims_for_proof_req(command_handle: i32,
+                                                     search_handle: i32,
+                                                     item_referent: *const c_char,
+                                                     count: u32,
+                                                     cb: Option<extern fn(
+                                                         xcommand_handle: i32, err: ErrorCode,
+                                                         claims_json: *const c_char
+                                                     )>) -> ErrorCode {
+    check_useful_c_str!(item_referent, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::GetClaims(
-            wallet_handle,
-            filter_json,
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::FetchClaimsForProofReq(
+            search_handle,
+            item_referent,
+            count as usize,
             Box::new(move |result| {
                 let (err, claims_json) = result_to_err_code_1!(result, String::new());
                 let claims_json = CStringUtils::string_to_cstring(claims_json);
@@ -599,88 +1826,29 @@ pub extern fn indy_prover_get_claims(command_handle: i32,
     result_to_err_code!(result)
 }
 
-/// Gets human readable claims matching the given proof request.
+/// Closes search opened by indy_prover_search_claims_for_proof_req.
 ///
 /// #Params
-/// wallet_handle: wallet handler (created by open_wallet).
-/// proof_request_json: proof request json
-///     {
-///         "name": string,
-///         "version": string,
-///         "nonce": string,
-///         "requested_attr1_referent": <attr_info>,
-///         "requested_attr2_referent": <attr_info>,
-///         "requested_attr3_referent": <attr_info>,
-///         "requested_predicate_1_referent": <predicate_info>,
-///         "requested_predicate_2_referent": <predicate_info>,
-///     }
+/// search_handle: search handle (created by indy_prover_search_claims_for_proof_req).
 /// cb: Callback that takes command result as parameter.
 ///
-/// where attr_info:
-///     {
-///         "name": attribute name, (case insensitive and ignore spaces)
-///         "restrictions": [
-///             {
-///                 "schema_key" : {name: string (Optional), version: string (Optional), did: string (Optional)} (Optional)
-///                 "issuer_did": string (Optional)
-///             }
-///         ]  (Optional) - if specified, claim must be created for one of the given
-///                         schema_key/issuer_did pairs, or just schema_key, or just issuer_did.
-///     }
-/// predicate_info:
-///     {
-///         "attr_name": attribute name, (case insensitive and ignore spaces)
-///         "p_type": predicate type (Currently >= only)
-///         "value": requested value of attribute
-///         "restrictions": [
-///             {
-///                 "schema_key" : {name: string (Optional), version: string (Optional), did: string (Optional)} (Optional)
-///                 "issuer_did": string (Optional)
-///             }
-///         ]  (Optional) - if specified, claim must be created for one of the given
-///                         schema_key/issuer_did pairs, or just schema_key, or just issuer_did.
-///     }
-/// #Returns
-/// json with claims for the given pool request.
-/// Claim consists of referent, human-readable attributes (key-value map), schema_key, issuer_did and revoc_reg_seq_no.
-///     {
-///         "requested_attr1_referent": [claim1, claim2],
-///         "requested_attr2_referent": [],
-///         "requested_attr3_referent": [claim3],
-///         "requested_predicate_1_referent": [claim1, claim3],
-///         "requested_predicate_2_referent": [claim2],
-///     }, where claim is
-///     {
-///         "referent": <string>,
-///         "attrs": [{"attr_name" : "attr_raw_value"}],
-///         "schema_key" : {name: string, version: string, did: string},
-///         "issuer_did": string,
-///         "revoc_reg_seq_no": int
-///     }
-///
 /// #Errors
 /// Annoncreds*
 /// Common*
-/// Wallet*
 #[no_mangle]
-pub extern fn indy_prover_get_claims_for_proof_req(command_handle: i32,
-                                                   wallet_handle: i32,
-                                                   proof_request_json: *const c_char,
-                                                   cb: Option<extern fn(
-                                                       xcommand_handle: i32, err: ErrorCode,
-                                                       claims_json: *const c_char
-                                                   )>) -> ErrorCode {
-    check_useful_c_str!(proof_request_json, ErrorCode::CommonInvalidParam3);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+pub extern fn indy_prover_close_claims_search_for_proof_req(command_handle: i32,
+                                                            search_handle: i32,
+                                                            cb: Option<extern fn(
+                                                                xcommand_handle: i32, err: ErrorCode
+                                                            )>) -> ErrorCode {
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::GetClaimsForProofReq(
-            wallet_handle,
-            proof_request_json,
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CloseClaimsSearchForProofReq(
+            search_handle,
             Box::new(move |result| {
-                let (err, claims_json) = result_to_err_code_1!(result, String::new());
-                let claims_json = CStringUtils::string_to_cstring(claims_json);
-                cb(command_handle, err, claims_json.as_ptr())
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
             })
         ))));
 
@@ -706,6 +1874,7 @@ pub extern fn indy_prover_get_claims_for_proof_req(command_handle: i32,
 ///         "requested_attr3_referent": <attr_info>,
 ///         "requested_predicate_1_referent": <predicate_info>,
 ///         "requested_predicate_2_referent": <predicate_info>,
+///         "non_revoked": <non_revoked_interval> (Optional) - default interval for all attributes and predicates
 ///     }
 /// requested_claims_json: either a claim or self-attested attribute for each requested attribute
 ///     {
@@ -736,29 +1905,24 @@ pub extern fn indy_prover_get_claims_for_proof_req(command_handle: i32,
 ///         "claim2_referent_in_wallet": <revoc_reg2>,
 ///         "claim3_referent_in_wallet": <revoc_reg3>,
 ///     }
+/// tails_reader_config_json: (Optional) blob storage to read tails of revocation registries from by "tails_hash"
+///     {"type": string (Optional), "config": json (Optional)} (see indy_issuer_create_and_store_revoc_reg),
+///     read tails are checked against the hash.
 /// cb: Callback that takes command result as parameter.
 ///
 /// where attr_info:
 ///     {
 ///         "name": attribute name, (case insensitive and ignore spaces)
-///         "restrictions": [
-///             {
-///                 "schema_key": {name (Optional), version (Optional), did (Optional)}, (Optional)
-///                 "issuer_did": string (Optional)
-///             }
-///         ]  (Optional)
+///         "restrictions": [<restriction>, ...]  (Optional) - see indy_prover_get_claims_for_proof_req
+///         "non_revoked": <non_revoked_interval> (Optional) - see indy_prover_get_claims_for_proof_req
 ///     }
 /// predicate_info:
 ///     {
 ///         "attr_name": attribute name, (case insensitive and ignore spaces)
-///         "p_type": predicate type (Currently >= only)
-///         "value": requested value of attribute
-///         "restrictions": [
-///             {
-///                 "schema_key": {name (Optional), version (Optional), did (Optional)}, (Optional)
-///                 "issuer_did": string (Optional)
-///             }
-///         ]  (Optional)
+///         "p_type": predicate type (">=", ">", "<=" or "<", CL proofs support only ">=" and ">")
+///         "value": requested value of attribute (64-bit integer, CL proofs are limited to 32-bit bounds)
+///         "restrictions": [<restriction>, ...]  (Optional) - see indy_prover_get_claims_for_proof_req
+///         "non_revoked": <non_revoked_interval> (Optional) - see indy_prover_get_claims_for_proof_req
 ///     }
 ///
 /// #Returns
@@ -784,7 +1948,7 @@ pub extern fn indy_prover_get_claims_for_proof_req(command_handle: i32,
 ///             },
 ///             "aggregated_proof": <aggregated_proof>
 ///         }
-///         "identifiers": {"claim_proof1_referent":{issuer_did, rev_reg_seq_no, schema_key: {name, version, did}}}
+///         "identifiers": {"claim_proof1_referent":{issuer_did, rev_reg_seq_no, schema_key: {name, version, did}, timestamp}}
 ///     }
 ///
 /// #Errors
@@ -800,6 +1964,7 @@ pub extern fn indy_prover_create_proof(command_handle: i32,
                                        master_secret_name: *const c_char,
                                        claim_defs_json: *const c_char,
                                        revoc_regs_json: *const c_char,
+                                       tails_reader_config_json: *const c_char,
                                        cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
                                                             proof_json: *const c_char)>) -> ErrorCode {
     check_useful_c_str!(proof_req_json, ErrorCode::CommonInvalidParam3);
@@ -808,7 +1973,8 @@ pub extern fn indy_prover_create_proof(command_handle: i32,
     check_useful_c_str!(master_secret_name, ErrorCode::CommonInvalidParam6);
     check_useful_c_str!(claim_defs_json, ErrorCode::CommonInvalidParam7);
     check_useful_c_str!(revoc_regs_json, ErrorCode::CommonInvalidParam8);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+    check_useful_opt_c_str!(tails_reader_config_json, ErrorCode::CommonInvalidParam9);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam10);
 
     let result = CommandExecutor::instance()
         .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CreateProof(
@@ -819,6 +1985,7 @@ pub extern fn indy_prover_create_proof(command_handle: i32,
             master_secret_name,
             claim_defs_json,
             revoc_regs_json,
+            tails_reader_config_json,
             Box::new(move |result| {
                 let (err, proof_json) = result_to_err_code_1!(result, String::new());
                 let proof_json = CStringUtils::string_to_cstring(proof_json);
@@ -843,6 +2010,7 @@ pub extern fn indy_prover_create_proof(command_handle: i32,
 ///         "requested_attr3_referent": <attr_info>,
 ///         "requested_predicate_1_referent": <predicate_info>,
 ///         "requested_predicate_2_referent": <predicate_info>,
+///         "non_revoked": <non_revoked_interval> (Optional) - default interval for all attributes and predicates
 ///     }
 /// proof_json: proof json
 /// For each requested attribute either a proof (with optionally revealed attribute value) or
@@ -866,7 +2034,7 @@ pub extern fn indy_prover_create_proof(command_handle: i32,
 ///             },
 ///             "aggregated_proof": <aggregated_proof>
 ///         }
-///         "identifiers": {"claim_proof1_referent":{issuer_did, rev_reg_seq_no, schema_key: {name, version, did}}}
+///         "identifiers": {"claim_proof1_referent":{issuer_did, rev_reg_seq_no, schema_key: {name, version, did}, timestamp}}
 ///     }
 /// schemas_jsons: all schema jsons participating in the proof
 ///         {
@@ -886,10 +2054,22 @@ pub extern fn indy_prover_create_proof(command_handle: i32,
 ///             "claim_proof2_referent": <revoc_reg>,
 ///             "claim_proof3_referent": <revoc_reg>
 ///         }
+///     where "timestamp" of <revoc_reg> must be ledger time the registry state was written (txnTime of
+///     GET_REVOC_REG reply checked with state proof) and optional "valid_to" the time the state was requested for,
+///     so the state is known to be valid from "timestamp" up to "valid_to". "timestamp" of proof identifiers
+///     is set by prover and isn't used for revocation checks.
+/// tails_reader_config_json: (Optional) blob storage to read tails of revocation registries from by "tails_hash"
+///     {"type": string (Optional), "config": json (Optional)} (see indy_issuer_create_and_store_revoc_reg),
+///     read tails are checked against the hash.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
-/// valid: true - if signature is valid, false - otherwise
+/// valid: true - if signature is valid, encoded values of revealed attributes are proved, their raw values correspond
+///        to encoded ones (by encoding declared in "attr_encoding" of claim definition, canonical encoding is
+///        required for legacy claim definitions without it, so their claims with custom encoded values can't be revealed)
+///        and proved claims satisfy restrictions of proof request (checked against identifiers, claim definitions,
+///        schemas and raw values of revealed attributes) and were proved non-revoked
+///        against revocation registry states valid in requested intervals, false - otherwise
 ///
 /// #Errors
 /// Annoncreds*
@@ -902,6 +2082,7 @@ pub extern fn indy_verifier_verify_proof(command_handle: i32,
                                          schemas_json: *const c_char,
                                          claim_defs_jsons: *const c_char,
                                          revoc_regs_json: *const c_char,
+                                         tails_reader_config_json: *const c_char,
                                          cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
                                                               valid: bool)>) -> ErrorCode {
     check_useful_c_str!(proof_request_json, ErrorCode::CommonInvalidParam2);
@@ -909,7 +2090,8 @@ pub extern fn indy_verifier_verify_proof(command_handle: i32,
     check_useful_c_str!(schemas_json, ErrorCode::CommonInvalidParam4);
     check_useful_c_str!(claim_defs_jsons, ErrorCode::CommonInvalidParam5);
     check_useful_c_str!(revoc_regs_json, ErrorCode::CommonInvalidParam6);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+    check_useful_opt_c_str!(tails_reader_config_json, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
 
     let result = CommandExecutor::instance()
         .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProof(
@@ -918,6 +2100,7 @@ pub extern fn indy_verifier_verify_proof(command_handle: i32,
             schemas_json,
             claim_defs_jsons,
             revoc_regs_json,
+            tails_reader_config_json,
             Box::new(move |result| {
                 let (err, valid) = result_to_err_code_1!(result, false);
                 cb(command_handle, err, valid)
@@ -926,3 +2109,219 @@ pub extern fn indy_verifier_verify_proof(command_handle: i32,
 
     result_to_err_code!(result)
 }
+
+/// Verifies a proof (of multiple claim) resolving schemas, claim definitions and revocation registries from the ledger.
+/// Schemas and claim definitions are taken from the ledger for identifiers contained in the proof,
+/// replies are accepted only with valid state proof or from enough nodes to reach consensus.
+/// As state proof covers the reply keys, received claim definitions must also reference
+/// the requested issuer and schema seq no.
+/// Ledger keeps untagged claim definitions only, so proofs of claims issued with tagged claim definition
+/// ("claim_def_tag" of the identifier) are rejected with CommonInvalidStructure.
+/// For claim definitions with revocation keys the revocation registry definition
+/// "<issuer_did>:4:<issuer_did>:3:CL:<schema_seq_no>:CL_ACCUM:default" and its state (GET_REVOC_REG_DELTA)
+/// at the identifier "timestamp" (or the current time if it's missed) are taken from the ledger.
+/// Tails are read by "tailsHash" of the definition from tails_reader_config_json blob storage.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// submitter_did: Id of Identity stored in secured Wallet.
+/// proof_request_json: initial proof request as sent by the verifier (see indy_verifier_verify_proof)
+/// proof_json: proof for a proof request (see indy_verifier_verify_proof)
+/// tails_reader_config_json: (Optional) blob storage to read tails from (see indy_verifier_verify_proof)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// valid: true - if proof is valid (see indy_verifier_verify_proof), false - otherwise
+/// resolved_json: schemas, claim definitions and revocation registries (without tails) resolved from the ledger
+///     {
+///         "schemas": {"claim_proof1_referent": <schema>, ...},
+///         "claim_defs": {"claim_proof1_referent": <claim_def>, ...},
+///         "revoc_regs": {"claim_proof1_referent": <revoc_reg>, ...}
+///     }
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Ledger*
+/// Pool*
+#[no_mangle]
+pub extern fn indy_verifier_verify_proof_with_ledger(command_handle: i32,
+                                                     pool_handle: i32,
+                                                     submitter_did: *const c_char,
+                                                     proof_request_json: *const c_char,
+                                                     proof_json: *const c_char,
+                                                     tails_reader_config_json: *const c_char,
+                                                     cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                          valid: bool,
+                                                                          resolved_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(proof_request_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(proof_json, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_c_str!(tails_reader_config_json, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProofWithLedger(
+            pool_handle,
+            submitter_did,
+            proof_request_json,
+            proof_json,
+            tails_reader_config_json,
+            Box::new(move |result| {
+                let (err, valid, resolved_json) = result_to_err_code_2!(result, false, String::new());
+                let resolved_json = CStringUtils::string_to_cstring(resolved_json);
+                cb(command_handle, err, valid, resolved_json.as_ptr())
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Generates a fresh nonce for a proof request.
+/// Nonce is a random 80 bit number, so every proof request must use a new one.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// nonce: decimal string representation of the nonce
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+#[no_mangle]
+pub extern fn indy_verifier_generate_nonce(command_handle: i32,
+                                           cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                nonce: *const c_char)>) -> ErrorCode {
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam2);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::GenerateNonce(
+            Box::new(move |result| {
+                let (err, nonce) = result_to_err_code_1!(result, String::new());
+                let nonce = CStringUtils::string_to_cstring(nonce);
+                cb(command_handle, err, nonce.as_ptr())
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Builds a proof request with a fresh nonce.
+/// Referents of requested attributes and predicates are assigned by their position in the lists:
+/// "attr1_referent", "attr2_referent", ... and "predicate1_referent", "predicate2_referent", ...
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// name: name of the proof request.
+/// version: version of the proof request.
+/// requested_attrs_json: list of requested attributes
+///     [<attr_info>, ...]
+///     where attr_info: {"name": string, "restrictions": (Optional) [<restriction>, ...],
+///                       "non_revoked": (Optional) <non_revoked_interval>}
+/// requested_predicates_json: list of requested predicates
+///     [<predicate_info>, ...]
+///     where predicate_info: {"attr_name": string, "p_type": ">=" | ">" | "<=" | "<", "value": int,
+///                            "restrictions": (Optional) [<restriction>, ...],
+///                            "non_revoked": (Optional) <non_revoked_interval>}
+/// non_revoked_json: (Optional) default non-revoked interval for all attributes and predicates
+///     {"from": (Optional) int, "to": (Optional) int}
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// proof_request_json: proof request (see indy_verifier_verify_proof)
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+#[no_mangle]
+pub extern fn indy_verifier_build_proof_request(command_handle: i32,
+                                                name: *const c_char,
+                                                version: *const c_char,
+                                                requested_attrs_json: *const c_char,
+                                                requested_predicates_json: *const c_char,
+                                                non_revoked_json: *const c_char,
+                                                cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                     proof_request_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(name, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(version, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(requested_attrs_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(requested_predicates_json, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_c_str!(non_revoked_json, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::BuildProofRequest(
+            name,
+            version,
+            requested_attrs_json,
+            requested_predicates_json,
+            non_revoked_json,
+            Box::new(move |result| {
+                let (err, proof_request_json) = result_to_err_code_1!(result, String::new());
+                let proof_request_json = CStringUtils::string_to_cstring(proof_request_json);
+                cb(command_handle, err, proof_request_json.as_ptr())
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
+
+/// Describes a proof in terms of its proof request.
+/// Proof isn't verified, so it must be checked with indy_verifier_verify_proof before values are trusted.
+/// In particular "raw" values of revealed attributes are reported as supplied by the prover:
+/// they are bound to the proof only by indy_verifier_verify_proof checking them against encoded values.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// proof_request_json: initial proof request as sent by the verifier (see indy_verifier_verify_proof)
+/// proof_json: proof for a proof request (see indy_verifier_verify_proof)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// inspection_json: requested values grouped by the way they were proved
+///     {
+///         "name": string,
+///         "version": string,
+///         "revealed_attrs": {
+///             "attr1_referent": {"name": string, "raw": string, "issuer_did": string, "schema_key": {name, version, did}},
+///         },
+///         "unrevealed_attrs": {
+///             "attr2_referent": {"name": string, "issuer_did": string, "schema_key": {name, version, did}},
+///         },
+///         "self_attested_attrs": {
+///             "attr3_referent": {"name": string, "value": string},
+///         },
+///         "predicates": {
+///             "predicate1_referent": {"attr_name": string, "p_type": string, "value": int,
+///                                     "issuer_did": string, "schema_key": {name, version, did}},
+///         }
+///     }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_verifier_inspect_proof(command_handle: i32,
+                                          proof_request_json: *const c_char,
+                                          proof_json: *const c_char,
+                                          cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                               inspection_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(proof_request_json, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(proof_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::InspectProof(
+            proof_request_json,
+            proof_json,
+            Box::new(move |result| {
+                let (err, inspection_json) = result_to_err_code_1!(result, String::new());
+                let inspection_json = CStringUtils::string_to_cstring(inspection_json);
+                cb(command_handle, err, inspection_json.as_ptr())
+            })
+        ))));
+
+    result_to_err_code!(result)
+}
@@ -0,0 +1,76 @@
+extern crate libc;
+
+use api::ErrorCode;
+use errors::ToErrorCode;
+use commands::{Command, CommandExecutor};
+use commands::blob_storage::BlobStorageCommand;
+use utils::cstring::CStringUtils;
+
+use self::libc::c_char;
+
+/// Registers custom blob storage implementation.
+///
+/// Blob storage keeps revocation registry tails. Blobs are content-addressed: they are written
+/// and read by base58 encoded sha256 hash of their data, libindy checks read data against the hash.
+/// Type and config of storage are passed to anoncreds functions as tails writer/reader config
+/// {"type": <xtype>, "config": <json passed to open_writer/open_reader>}.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// xtype: Blob storage type name.
+/// open_writer: opens writer for new blob, returns its handle
+/// append: appends chunk of data to the blob of writer
+/// finalize: persists the blob of writer so it can be read by given hash
+/// open_reader: opens reader of the blob with given hash, returns its handle
+/// read: reads next chunk of the blob into buffer of buf_len bytes, sets read_len to number
+///     of read bytes (0 at the end of the blob)
+/// close: closes writer or reader handle, data of not finalized writer must be discarded
+///
+/// #Returns
+/// Error code
+#[no_mangle]
+pub extern fn indy_register_blob_storage_type(command_handle: i32,
+                                              xtype: *const c_char,
+                                              open_writer: Option<extern fn(config: *const c_char,
+                                                                            handle: *mut i32) -> ErrorCode>,
+                                              append: Option<extern fn(handle: i32,
+                                                                       bytes: *const u8,
+                                                                       bytes_len: u32) -> ErrorCode>,
+                                              finalize: Option<extern fn(handle: i32,
+                                                                         hash: *const c_char) -> ErrorCode>,
+                                              open_reader: Option<extern fn(config: *const c_char,
+                                                                            hash: *const c_char,
+                                                                            handle: *mut i32) -> ErrorCode>,
+                                              read: Option<extern fn(handle: i32,
+                                                                     buf: *mut u8,
+                                                                     buf_len: u32,
+                                                                     read_len: *mut u32) -> ErrorCode>,
+                                              close: Option<extern fn(handle: i32) -> ErrorCode>,
+                                              cb: Option<extern fn(xcommand_handle: i32,
+                                                                   err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_str!(xtype, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(open_writer, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(append, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(finalize, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(open_reader, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(read, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(close, ErrorCode::CommonInvalidParam8);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    let result = CommandExecutor::instance()
+        .send(Command::BlobStorage(BlobStorageCommand::RegisterBlobStorageType(
+            xtype,
+            open_writer,
+            append,
+            finalize,
+            open_reader,
+            read,
+            close,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
@@ -8,6 +8,7 @@ use utils::cstring::CStringUtils;
 use utils::byte_array::vec_to_pointer;
 
 use self::libc::c_char;
+use std::ptr;
 
 
 /// Creates keys pair and stores in the wallet.
@@ -18,7 +19,9 @@ use self::libc::c_char;
 /// key_json: Key information as json. Example:
 /// {
 ///     "seed": string, // Optional (if not set random one will be used); Seed information that allows deterministic key creation.
-///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently only 'ed25519' value is supported for this field.
+///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); 'ed25519' and 'secp256k1' (if built with secp256k1_libsecp256k1 feature) values are supported.
+///     "derivation_path": string, // Optional; Path of the key derived from the wallet master seed (see indy_set_master_seed), for example "m/1'/0'".
+///                                // Only hardened indices and 'ed25519' crypto type are supported. Can't be used together with seed.
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -459,4 +462,890 @@ pub  extern fn indy_crypto_anon_decrypt(command_handle: i32,
         )));
 
     result_to_err_code!(result)
-}
\ No newline at end of file
+}
+
+/// Packs a message for one or several recipients.
+///
+/// The message is encrypted once with a random content key and the content key is encrypted
+/// separately for every recipient. The protected header is authenticated with the message
+/// as associated data. If sender verkey is passed, the content key together with sha256 digest of
+/// protected header, iv and ciphertext is encrypted by authenticated-encryption scheme
+/// (see indy_crypto_auth_crypt) and the sender verkey is sealed for every recipient, so every recipient
+/// can authenticate the sender and the content (other recipients know the content key, but can't
+/// alter the content on behalf of the sender). Otherwise the content key is encrypted
+/// by anonymous-encryption scheme (see indy_crypto_anon_crypt).
+///
+/// Packed message is utf-8 encoded json (all binary values are base64 encoded):
+/// {
+///     "protected": base64 of {"enc": "xsalsa20poly1305", "typ": "JWM/1.0", "alg": "Authcrypt" or "Anoncrypt"},
+///     "recipients": [
+///         {
+///             "encrypted_key": encrypted content key (followed by content digest for Authcrypt),
+///             "header": {
+///                 "kid": recipient verkey,
+///                 "sender": sender verkey sealed for recipient (only for Authcrypt),
+///                 "iv": nonce of content key encryption (only for Authcrypt)
+///             }
+///         }
+///     ],
+///     "iv": nonce of message encryption,
+///     "ciphertext": encrypted message
+/// }
+///
+/// Note to use DID keys with this function you can call indy_key_for_did to get key id (verkey)
+/// for specific DID.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// msg_data: a pointer to first byte of message that to be packed
+/// msg_len: a message length
+/// receiver_keys: json array of recipients verkeys: ["verkey1", "verkey2"]
+/// sender_vk: (optional) id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+///            If not set, the message is packed anonymously.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// packed message
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_pack_msg(command_handle: i32,
+                                    wallet_handle: i32,
+                                    msg_data: *const u8,
+                                    msg_len: u32,
+                                    receiver_keys: *const c_char,
+                                    sender_vk: *const c_char,
+                                    cb: Option<extern fn(command_handle_: i32,
+                                                         err: ErrorCode,
+                                                         packed_msg: *const u8,
+                                                         packed_len: u32)>) -> ErrorCode {
+    check_useful_c_byte_array!(msg_data, msg_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(receiver_keys, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_c_str!(sender_vk, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::PackMessage(
+            wallet_handle,
+            msg_data,
+            receiver_keys,
+            sender_vk,
+            Box::new(move |result| {
+                let (err, packed_msg) = result_to_err_code_1!(result, Vec::new());
+                let (packed_msg_raw, packed_msg_len) = vec_to_pointer(&packed_msg);
+                cb(command_handle, err, packed_msg_raw, packed_msg_len)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Unpacks a message packed by indy_crypto_pack_msg.
+///
+/// The wallet is searched for a key of one of the message recipients and this key is used
+/// to decrypt the message. For authenticated packing the sender verkey is returned.
+/// Messages with altered protected header or content (including content altered by other
+/// recipients of authenticated packing) are rejected with CommonInvalidStructure.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// packed_msg: a pointer to first byte of packed message
+/// packed_len: a packed message length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// unpacked message info json:
+/// {
+///     "recipient_verkey": verkey of my key used to unpack the message,
+///     "sender_verkey": verkey of the sender or null if the message was packed anonymously
+/// }
+/// and unpacked message
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_unpack_msg(command_handle: i32,
+                                      wallet_handle: i32,
+                                      packed_msg: *const u8,
+                                      packed_len: u32,
+                                      cb: Option<extern fn(command_handle_: i32,
+                                                           err: ErrorCode,
+                                                           unpacked_info_json: *const c_char,
+                                                           msg_data: *const u8,
+                                                           msg_len: u32)>) -> ErrorCode {
+    check_useful_c_byte_array!(packed_msg, packed_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::UnpackMessage(
+            wallet_handle,
+            packed_msg,
+            Box::new(move |result| {
+                let (err, unpacked_info_json, msg) = result_to_err_code_2!(result, String::new(), Vec::new());
+                let (msg_data, msg_len) = vec_to_pointer(&msg);
+                let unpacked_info_json = CStringUtils::string_to_cstring(unpacked_info_json);
+                cb(command_handle, err, unpacked_info_json.as_ptr(), msg_data, msg_len)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Starts streaming signing of a large message by key stored in the wallet.
+///
+/// Message is passed by parts with indy_crypto_sign_stream_update calls and signature
+/// is returned by indy_crypto_sign_stream_finish. Streamed signature is the signature of
+/// sha256 digest of the whole message prefixed with fixed context tag, so it must be verified
+/// with indy_crypto_verify_stream_* functions (not with indy_crypto_verify) and can't be taken
+/// for a signature made by indy_crypto_sign. Unfinished stream is closed by indy_crypto_close_stream.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// my_vk: id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// stream handle
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_sign_stream_init(command_handle: i32,
+                                            wallet_handle: i32,
+                                            my_vk: *const c_char,
+                                            cb: Option<extern fn(command_handle_: i32,
+                                                                 err: ErrorCode,
+                                                                 stream_handle: i32)>) -> ErrorCode {
+    check_useful_c_str!(my_vk, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::SignStreamInit(
+            wallet_handle,
+            my_vk,
+            Box::new(move |result| {
+                let (err, stream_handle) = result_to_err_code_1!(result, 0);
+                cb(command_handle, err, stream_handle)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Passes next part of the message to the signing stream.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle (created by indy_crypto_sign_stream_init).
+/// message_raw: a pointer to first byte of message part
+/// message_len: a message part length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub  extern fn indy_crypto_sign_stream_update(command_handle: i32,
+                                              stream_handle: i32,
+                                              message_raw: *const u8,
+                                              message_len: u32,
+                                              cb: Option<extern fn(command_handle_: i32,
+                                                                   err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_byte_array!(message_raw, message_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::SignStreamUpdate(
+            stream_handle,
+            message_raw,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Finishes the signing stream and returns the signature. Stream handle is closed.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle (created by indy_crypto_sign_stream_init).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// a signature
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_sign_stream_finish(command_handle: i32,
+                                              stream_handle: i32,
+                                              cb: Option<extern fn(command_handle_: i32,
+                                                                   err: ErrorCode,
+                                                                   signature_raw: *const u8,
+                                                                   signature_len: u32)>) -> ErrorCode {
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::SignStreamFinish(
+            stream_handle,
+            Box::new(move |result| {
+                let (err, signature) = result_to_err_code_1!(result, Vec::new());
+                let (signature_raw, signature_len) = vec_to_pointer(&signature);
+                cb(command_handle, err, signature_raw, signature_len)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Starts streaming verification of a signature created by indy_crypto_sign_stream_finish.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// their_vk: verkey to use
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// stream handle
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_verify_stream_init(command_handle: i32,
+                                              their_vk: *const c_char,
+                                              cb: Option<extern fn(command_handle_: i32,
+                                                                   err: ErrorCode,
+                                                                   stream_handle: i32)>) -> ErrorCode {
+    check_useful_c_str!(their_vk, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::VerifyStreamInit(
+            their_vk,
+            Box::new(move |result| {
+                let (err, stream_handle) = result_to_err_code_1!(result, 0);
+                cb(command_handle, err, stream_handle)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Passes next part of the message to the verification stream.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle (created by indy_crypto_verify_stream_init).
+/// message_raw: a pointer to first byte of message part
+/// message_len: a message part length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub  extern fn indy_crypto_verify_stream_update(command_handle: i32,
+                                                stream_handle: i32,
+                                                message_raw: *const u8,
+                                                message_len: u32,
+                                                cb: Option<extern fn(command_handle_: i32,
+                                                                     err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_byte_array!(message_raw, message_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::VerifyStreamUpdate(
+            stream_handle,
+            message_raw,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Finishes the verification stream and checks the signature. Stream handle is closed.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle (created by indy_crypto_verify_stream_init).
+/// signature_raw: a pointer to first byte of signature to be verified
+/// signature_len: a signature length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// valid: true - if signature is valid, false - otherwise
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_verify_stream_finish(command_handle: i32,
+                                                stream_handle: i32,
+                                                signature_raw: *const u8,
+                                                signature_len: u32,
+                                                cb: Option<extern fn(command_handle_: i32,
+                                                                     err: ErrorCode,
+                                                                     valid: bool)>) -> ErrorCode {
+    check_useful_c_byte_array!(signature_raw, signature_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::VerifyStreamFinish(
+            stream_handle,
+            signature_raw,
+            Box::new(move |result| {
+                let (err, valid) = result_to_err_code_1!(result, false);
+                cb(command_handle, err, valid)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Starts streaming encryption of a large message by authenticated-encryption scheme.
+///
+/// Message is passed by parts with indy_crypto_crypt_stream_update calls and the rest of
+/// encrypted stream is returned by indy_crypto_crypt_stream_finish. Encrypted stream is
+/// the concatenation of all returned parts and must be decrypted with
+/// indy_crypto_auth_decrypt_stream_* functions.
+///
+/// Encrypted stream consists of frames, every frame is body length (4 bytes, big-endian) || body.
+/// The first frame is the header: stream key (32 bytes) || nonce prefix (16 bytes) encrypted
+/// as indy_crypto_auth_crypt does. Next frames are chunks: xsalsa20-poly1305 encryption under
+/// stream key of tag (1 byte: 0 - chunk, 1 - final chunk) || data (up to 64 KiB) with
+/// nonce = nonce prefix || chunk number (8 bytes, big-endian, starts from 0). Chunk numbers in
+/// nonces protect against reordering and dropping of chunks, final tag protects against truncation.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// my_vk: id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+/// their_vk: id (verkey) of their key
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// stream handle
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_auth_crypt_stream_init(command_handle: i32,
+                                                  wallet_handle: i32,
+                                                  my_vk: *const c_char,
+                                                  their_vk: *const c_char,
+                                                  cb: Option<extern fn(command_handle_: i32,
+                                                                       err: ErrorCode,
+                                                                       stream_handle: i32)>) -> ErrorCode {
+    check_useful_c_str!(my_vk, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(their_vk, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::AuthenticatedEncryptStreamInit(
+            wallet_handle,
+            my_vk,
+            their_vk,
+            Box::new(move |result| {
+                let (err, stream_handle) = result_to_err_code_1!(result, 0);
+                cb(command_handle, err, stream_handle)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Starts streaming encryption of a large message by anonymous-encryption scheme.
+///
+/// Framing of encrypted stream is the same as for indy_crypto_auth_crypt_stream_init, but
+/// the header is encrypted as indy_crypto_anon_crypt does. Encrypted stream must be decrypted
+/// with indy_crypto_anon_decrypt_stream_* functions.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// their_vk: id (verkey) of their key
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// stream handle
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_anon_crypt_stream_init(command_handle: i32,
+                                                  their_vk: *const c_char,
+                                                  cb: Option<extern fn(command_handle_: i32,
+                                                                       err: ErrorCode,
+                                                                       stream_handle: i32)>) -> ErrorCode {
+    check_useful_c_str!(their_vk, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::AnonymousEncryptStreamInit(
+            their_vk,
+            Box::new(move |result| {
+                let (err, stream_handle) = result_to_err_code_1!(result, 0);
+                cb(command_handle, err, stream_handle)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Passes next part of the message to the encryption stream.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle (created by indy_crypto_auth_crypt_stream_init or indy_crypto_anon_crypt_stream_init).
+/// msg_data: a pointer to first byte of message part
+/// msg_len: a message part length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// next part of encrypted stream (can be empty)
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub  extern fn indy_crypto_crypt_stream_update(command_handle: i32,
+                                               stream_handle: i32,
+                                               msg_data: *const u8,
+                                               msg_len: u32,
+                                               cb: Option<extern fn(command_handle_: i32,
+                                                                    err: ErrorCode,
+                                                                    encrypted_msg: *const u8,
+                                                                    encrypted_len: u32)>) -> ErrorCode {
+    check_useful_c_byte_array!(msg_data, msg_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::EncryptStreamUpdate(
+            stream_handle,
+            msg_data,
+            Box::new(move |result| {
+                let (err, encrypted_msg) = result_to_err_code_1!(result, Vec::new());
+                let (encrypted_msg_raw, encrypted_msg_len) = vec_to_pointer(&encrypted_msg);
+                cb(command_handle, err, encrypted_msg_raw, encrypted_msg_len)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Finishes the encryption stream. Stream handle is closed.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle (created by indy_crypto_auth_crypt_stream_init or indy_crypto_anon_crypt_stream_init).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// the last part of encrypted stream
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub  extern fn indy_crypto_crypt_stream_finish(command_handle: i32,
+                                               stream_handle: i32,
+                                               cb: Option<extern fn(command_handle_: i32,
+                                                                    err: ErrorCode,
+                                                                    encrypted_msg: *const u8,
+                                                                    encrypted_len: u32)>) -> ErrorCode {
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::EncryptStreamFinish(
+            stream_handle,
+            Box::new(move |result| {
+                let (err, encrypted_msg) = result_to_err_code_1!(result, Vec::new());
+                let (encrypted_msg_raw, encrypted_msg_len) = vec_to_pointer(&encrypted_msg);
+                cb(command_handle, err, encrypted_msg_raw, encrypted_msg_len)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Starts streaming decryption of a stream encrypted by indy_crypto_auth_crypt_stream_* functions.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// my_vk: id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// stream handle
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_auth_decrypt_stream_init(command_handle: i32,
+                                                    wallet_handle: i32,
+                                                    my_vk: *const c_char,
+                                                    cb: Option<extern fn(command_handle_: i32,
+                                                                         err: ErrorCode,
+                                                                         stream_handle: i32)>) -> ErrorCode {
+    check_useful_c_str!(my_vk, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::AuthenticatedDecryptStreamInit(
+            wallet_handle,
+            my_vk,
+            Box::new(move |result| {
+                let (err, stream_handle) = result_to_err_code_1!(result, 0);
+                cb(command_handle, err, stream_handle)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Starts streaming decryption of a stream encrypted by indy_crypto_anon_crypt_stream_* functions.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// my_vk: id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// stream handle
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_anon_decrypt_stream_init(command_handle: i32,
+                                                    wallet_handle: i32,
+                                                    my_vk: *const c_char,
+                                                    cb: Option<extern fn(command_handle_: i32,
+                                                                         err: ErrorCode,
+                                                                         stream_handle: i32)>) -> ErrorCode {
+    check_useful_c_str!(my_vk, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::AnonymousDecryptStreamInit(
+            wallet_handle,
+            my_vk,
+            Box::new(move |result| {
+                let (err, stream_handle) = result_to_err_code_1!(result, 0);
+                cb(command_handle, err, stream_handle)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Passes next part of encrypted stream to the decryption stream.
+///
+/// Parts can be of any size, they don't need to match parts returned by encryption stream.
+/// Stream handle is closed if encrypted stream part is invalid.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle (created by indy_crypto_auth_decrypt_stream_init or indy_crypto_anon_decrypt_stream_init).
+/// encrypted_msg: a pointer to first byte of encrypted stream part
+/// encrypted_len: an encrypted stream part length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// next part of decrypted message (can be empty)
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_decrypt_stream_update(command_handle: i32,
+                                                 stream_handle: i32,
+                                                 encrypted_msg: *const u8,
+                                                 encrypted_len: u32,
+                                                 cb: Option<extern fn(command_handle_: i32,
+                                                                      err: ErrorCode,
+                                                                      msg_data: *const u8,
+                                                                      msg_len: u32)>) -> ErrorCode {
+    check_useful_c_byte_array!(encrypted_msg, encrypted_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::DecryptStreamUpdate(
+            stream_handle,
+            encrypted_msg,
+            Box::new(move |result| {
+                let (err, msg) = result_to_err_code_1!(result, Vec::new());
+                let (msg_data, msg_len) = vec_to_pointer(&msg);
+                cb(command_handle, err, msg_data, msg_len)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Finishes the decryption stream. Stream handle is closed.
+///
+/// Returns an error if the encrypted stream was truncated.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle (created by indy_crypto_auth_decrypt_stream_init or indy_crypto_anon_decrypt_stream_init).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// sender verkey for authenticated-encryption stream or null for anonymous-encryption stream
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub  extern fn indy_crypto_decrypt_stream_finish(command_handle: i32,
+                                                 stream_handle: i32,
+                                                 cb: Option<extern fn(command_handle_: i32,
+                                                                      err: ErrorCode,
+                                                                      sender_vk: *const c_char)>) -> ErrorCode {
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::DecryptStreamFinish(
+            stream_handle,
+            Box::new(move |result| {
+                let (err, sender_vk) = result_to_err_code_1!(result, None);
+                let sender_vk = sender_vk.map(CStringUtils::string_to_cstring);
+                cb(command_handle, err, sender_vk.as_ref().map(|vk| vk.as_ptr()).unwrap_or(ptr::null()))
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Closes signing, verification, encryption or decryption stream without finishing it,
+/// i.e. to abort processing of a message. Stream handle is closed.
+///
+/// Decryption stream is also closed by indy_crypto_decrypt_stream_update on invalid encrypted data.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle (created by indy_crypto_*_stream_init).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub  extern fn indy_crypto_close_stream(command_handle: i32,
+                                        stream_handle: i32,
+                                        cb: Option<extern fn(command_handle_: i32,
+                                                             err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::CloseStream(
+            stream_handle,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Lists all keys stored in the wallet.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - xcommand_handle: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - keys_json: List of keys as json array:
+///   [{
+///     "verkey": string, // Ver key of the key
+///     "metadata": Optional<string>, // The meta information stored with the key
+///     "derivation_path": Optional<string>, // Derivation path for keys derived from the master seed
+///   }]
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub  extern fn indy_list_keys(command_handle: i32,
+                              wallet_handle: i32,
+                              cb: Option<extern fn(xcommand_handle: i32,
+                                                   err: ErrorCode,
+                                                   keys_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::ListKeys(
+            wallet_handle,
+            Box::new(move |result| {
+                let (err, keys_json) = result_to_err_code_1!(result, String::new());
+                let keys_json = CStringUtils::string_to_cstring(keys_json);
+                cb(command_handle, err, keys_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Deletes the key and its meta information from the wallet.
+///
+/// Keys that are still used by some of my DIDs can't be deleted (CryptoKeyUsedByDidError).
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// verkey - The key (verkey, key id) to delete.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - xcommand_handle: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_delete_key(command_handle: i32,
+                               wallet_handle: i32,
+                               verkey: *const c_char,
+                               cb: Option<extern fn(xcommand_handle: i32,
+                                                    err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_str!(verkey, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::DeleteKey(
+            wallet_handle,
+            verkey,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Exports the verkey in one of the standard formats.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// verkey - The key (verkey, key id) to export.
+/// format - Export format. One of:
+///   "jwk" - JSON Web Key (RFC 8037), supported for ed25519 keys only
+///   "multibase" - base58btc multibase of multicodec prefixed key
+///   "hex" - lowercase hex of raw key bytes
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - xcommand_handle: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - exported: The key in requested format.
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_export_verkey(command_handle: i32,
+                                  verkey: *const c_char,
+                                  format: *const c_char,
+                                  cb: Option<extern fn(xcommand_handle: i32,
+                                                       err: ErrorCode,
+                                                       exported: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(verkey, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(format, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::ExportVerkey(
+            verkey,
+            format,
+            Box::new(move |result| {
+                let (err, exported) = result_to_err_code_1!(result, String::new());
+                let exported = CStringUtils::string_to_cstring(exported);
+                cb(command_handle, err, exported.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Imports an existing ed25519 secret key from JSON Web Key (RFC 8037) and stores it in the wallet.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// jwk: JSON Web Key:
+///   {
+///     "kty": "OKP",
+///     "crv": "Ed25519",
+///     "d": string, // base64url encoded 32 bytes secret key
+///     "x": Optional<string>, // base64url encoded public key. If present it must match the secret key
+///   }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - xcommand_handle: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - verkey: Ver key of imported key pair to use in other api calls.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_import_key_from_jwk(command_handle: i32,
+                                        wallet_handle: i32,
+                                        jwk: *const c_char,
+                                        cb: Option<extern fn(xcommand_handle: i32,
+                                                             err: ErrorCode,
+                                                             verkey: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(jwk, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::ImportKeyFromJwk(
+            wallet_handle,
+            jwk,
+            Box::new(move |result| {
+                let (err, verkey) = result_to_err_code_1!(result, String::new());
+                let verkey = CStringUtils::string_to_cstring(verkey);
+                cb(command_handle, err, verkey.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
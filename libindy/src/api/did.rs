@@ -27,8 +27,11 @@ use std::ptr;
 ///             if provided, then keys will be replaced - key rotation use case)
 ///     "seed": string, (optional; if not provide then a random one will be created)
 ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
-///               currently only 'ed25519' value is supported for this field)
+///               'ed25519' and 'secp256k1' (if built with secp256k1_libsecp256k1 feature) values are supported)
 ///     "cid": bool, (optional; if not set then false is used;)
+///     "derivation_path": string, (optional; path of the key derived from the wallet master seed
+///               (see indy_set_master_seed), for example "m/0'/1'"; only hardened indices and
+///               'ed25519' crypto type are supported; can't be used together with seed)
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -74,7 +77,7 @@ pub  extern fn indy_create_and_store_my_did(command_handle: i32,
 /// {
 ///     "seed": string, (optional; if not provide then a random one will be created)
 ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
-///               currently only 'ed25519' value is supported for this field)
+///               'ed25519' and 'secp256k1' (if built with secp256k1_libsecp256k1 feature) values are supported)
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -370,6 +373,82 @@ pub extern fn indy_get_endpoint_for_did(command_handle: i32,
     result_to_err_code!(result)
 }
 
+/// Resolves DID Document for the given DID.
+///
+/// DID Document is built from the ledger NYM transaction (verification key) and ATTRIB
+/// transactions (endpoint and other raw service attributes). Replies are checked with state
+/// proofs the same way as for GET_NYM and GET_ATTR requests. Resolved DID Document is cached
+/// in the wallet per requested attribs and cached value is returned while it is fresh according
+/// to "freshness_time" wallet config.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// pool_handle: Pool handle (created by open_pool).
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// submitter_did: Id of Identity used as identifier of GET_NYM and GET_ATTR requests.
+/// did - The DID to resolve.
+/// options_json: (optional) resolution options:
+///     {
+///         "no_cache": (optional, false by default) Skip wallet cache and resolve from the ledger,
+///         "attribs": (optional, ["endpoint"] by default) Raw ATTRIB names to represent as services.
+///             "endpoint" attrib is represented as IndyAgent service. Other attribs must contain
+///             service description {"<name>": {"type": string, "serviceEndpoint": string}}
+///             and are skipped otherwise.
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - xcommand_handle: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - did_doc_json: DID Document json:
+///     {
+///         "@context": "https://w3id.org/did/v1",
+///         "id": "did:sov:<did>",
+///         "publicKey": [{"id", "type", "owner", "publicKeyBase58"}],
+///         "authentication": [{"type", "publicKey"}],
+///         "service": [{"id", "type", "serviceEndpoint", "recipientKeys" (optional)}]
+///     }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+/// Crypto*
+/// Did*
+#[no_mangle]
+pub extern fn indy_resolve_did(command_handle: i32,
+                               pool_handle: i32,
+                               wallet_handle: i32,
+                               submitter_did: *const c_char,
+                               did: *const c_char,
+                               options_json: *const c_char,
+                               cb: Option<extern fn(xcommand_handle: i32,
+                                                    err: ErrorCode,
+                                                    did_doc_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(did, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_c_str!(options_json, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::ResolveDid(
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            did,
+            options_json,
+            Box::new(move |result| {
+                let (err, did_doc_json) = result_to_err_code_1!(result, String::new());
+                let did_doc_json = CStringUtils::string_to_cstring(did_doc_json);
+                cb(command_handle, err, did_doc_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
 /// Saves/replaces the meta information for the giving DID in the wallet.
 ///
 /// #Params
@@ -525,4 +604,95 @@ pub  extern fn indy_abbreviate_verkey(command_handle: i32,
         )));
 
     result_to_err_code!(result)
-}
\ No newline at end of file
+}
+/// Sets master seed of the wallet.
+///
+/// Keys and DIDs can be derived from the master seed by passing "derivation_path" to
+/// indy_create_key and indy_create_and_store_my_did. Derivation is hierarchical deterministic
+/// derivation of ed25519 keys described in SLIP-0010, so backup of the master seed is enough
+/// to restore all derived keys and DIDs (see indy_recover_from_master_seed).
+/// Master seed can be set only once for the wallet, DidMasterSeedAlreadySetError is returned
+/// if the wallet already has one.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// master_seed: master seed (from 16 to 64 bytes), in the same format as "seed" of did_json
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Did*
+#[no_mangle]
+pub  extern fn indy_set_master_seed(command_handle: i32,
+                                    wallet_handle: i32,
+                                    master_seed: *const c_char,
+                                    cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_str!(master_seed, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::SetMasterSeed(
+            wallet_handle,
+            master_seed,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Recovers DIDs and keys derived from the wallet master seed (see indy_set_master_seed).
+///
+/// Scans first "count" hardened children of the given paths, for example for "did_path" "m/0'"
+/// DIDs with paths "m/0'/0'", "m/0'/1'", ... are created. Recovered DIDs and keys are stored
+/// in the wallet with their derivation paths, existing ones are overwritten by the same values.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// recovery_json: recovery options as json:
+/// {
+///     "did_path": string, (optional; parent path of derived DIDs)
+///     "key_path": string, (optional; parent path of derived keys)
+///     "count": int, (optional; number of scanned children of every path, 20 by default, 1000 at most)
+/// }
+/// At least one of paths must be set.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// recovered DIDs and keys as json:
+/// {
+///     "dids": [{"did": string, "verkey": string, "derivation_path": string}],
+///     "keys": [{"verkey": string, "derivation_path": string}]
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_recover_from_master_seed(command_handle: i32,
+                                             wallet_handle: i32,
+                                             recovery_json: *const c_char,
+                                             cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                  recovered_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(recovery_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::RecoverFromMasterSeed(
+            wallet_handle,
+            recovery_json,
+            Box::new(move |result| {
+                let (err, recovered_json) = result_to_err_code_1!(result, String::new());
+                let recovered_json = CStringUtils::string_to_cstring(recovered_json);
+                cb(command_handle, err, recovered_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
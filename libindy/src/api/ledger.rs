@@ -97,6 +97,66 @@ pub extern fn indy_submit_request(command_handle: i32,
     result_to_err_code!(result)
 }
 
+/// Sends an action request to the nodes of the validator pool and collects one reply per node.
+///
+/// Unlike submit_request it doesn't wait for consensus (f + 1 equal replies): every addressed
+/// node is asked individually and its reply is returned as is. Every node has its own deadline:
+/// node that doesn't answer within the timeout is reported with timeout error instead of a reply
+/// and the result is returned as soon as every node either replied or timed out.
+/// Only POOL_RESTART and GET_VALIDATOR_INFO requests are supported.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// request_json: Request data json (must be signed, see sign_request).
+/// nodes: (Optional) json array of node names to send the request to:
+///     ["Node1", "Node2", ...]
+///     The request is sent to all nodes of the pool if not specified.
+///     Empty list is rejected.
+/// timeout: (Optional) time in seconds to wait for the reply of every node, -1 to use the default one (10 seconds).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Json map of node name to its reply:
+///     {
+///         "Node1": string - raw reply json of the node,
+///         "Node2": string - '{"node": "Node2", "error": "timeout"}' json for the node timed out,
+///         ...
+///     }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_submit_action(command_handle: i32,
+                                 pool_handle: i32,
+                                 request_json: *const c_char,
+                                 nodes: *const c_char,
+                                 timeout: i32,
+                                 cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                      request_result_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_c_str!(nodes, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    let timeout = if timeout != -1 { Some(timeout) } else { None };
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::SubmitAction(
+            pool_handle,
+            request_json,
+            nodes,
+            timeout,
+            Box::new(move |result| {
+                let (err, request_result_json) = result_to_err_code_1!(result, String::new());
+                let request_result_json = CStringUtils::string_to_cstring(request_result_json);
+                cb(command_handle, err, request_result_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
 /// Signs request message.
 ///
 /// Adds submitter information to passed request json, signs it with submitter
@@ -713,3 +773,757 @@ pub extern fn indy_build_pool_upgrade_request(command_handle: i32,
 
     result_to_err_code!(result)
 }
+
+/// Builds a POOL_RESTART request.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: Id of Identity stored in secured Wallet.
+/// action: Either start or cancel
+/// datetime: (Optional) restart time in ISO 8601 format, restart is performed immediately if not specified.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_pool_restart_request(command_handle: i32,
+                                              submitter_did: *const c_char,
+                                              action: *const c_char,
+                                              datetime: *const c_char,
+                                              cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                   request_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(action, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_c_str!(datetime, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildPoolRestartRequest(
+            submitter_did,
+            action,
+            datetime,
+            Box::new(move |result| {
+                let (err, request_json) = result_to_err_code_1!(result, String::new());
+                let request_json = CStringUtils::string_to_cstring(request_json);
+                cb(command_handle, err, request_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Builds a GET_VALIDATOR_INFO request.
+///
+/// The request must be signed and sent with submit_action to get the info of every node.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: Id of Identity stored in secured Wallet.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_get_validator_info_request(command_handle: i32,
+                                                    submitter_did: *const c_char,
+                                                    cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                         request_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildGetValidatorInfoRequest(
+            submitter_did,
+            Box::new(move |result| {
+                let (err, request_json) = result_to_err_code_1!(result, String::new());
+                let request_json = CStringUtils::string_to_cstring(request_json);
+                cb(command_handle, err, request_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Builds a REVOC_REG_DEF request. Request to add the definition of revocation registry
+/// to an exists claim definition.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: Id of Identity stored in secured Wallet.
+/// data: Revocation registry definition json:
+///     {
+///         "id": string - ID of the revocation registry definition,
+///         "revocDefType": string - revocation registry type (only CL_ACCUM is supported for now),
+///         "tag": string - unique descriptive ID of the registry,
+///         "credDefId": string - ID of the corresponding claim definition,
+///         "value": {
+///             "issuanceType": string - ISSUANCE_BY_DEFAULT or ISSUANCE_ON_DEMAND,
+///             "maxCredNum": number - maximum number of claims the registry can serve,
+///             "publicKeys": object - registry's public keys,
+///             "tailsHash": (optional) string - hash of the tails file,
+///             "tailsLocation": (optional) string - location of the tails file
+///         }
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_revoc_reg_def_request(command_handle: i32,
+                                               submitter_did: *const c_char,
+                                               data: *const c_char,
+                                               cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                    request_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(data, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildRevocRegDefRequest(
+            submitter_did,
+            data,
+            Box::new(move |result| {
+                let (err, request_json) = result_to_err_code_1!(result, String::new());
+                let request_json = CStringUtils::string_to_cstring(request_json);
+                cb(command_handle, err, request_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Builds a REVOC_REG_ENTRY request. Request to add the revocation registry entry record
+/// (accumulator value and delta of issued/revoked indices) to the ledger.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: Id of Identity stored in secured Wallet.
+/// revoc_reg_def_id: ID of the corresponding revocation registry definition.
+/// revoc_def_type: Revocation registry type (only CL_ACCUM is supported for now).
+/// value: Registry-specific data:
+///     {
+///         "prevAccum": (optional) string - previous accumulator value,
+///         "accum": string - current accumulator value,
+///         "issued": (optional) array<number> - indices issued since previous entry,
+///         "revoked": (optional) array<number> - indices revoked since previous entry
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_revoc_reg_entry_request(command_handle: i32,
+                                                 submitter_did: *const c_char,
+                                                 revoc_reg_def_id: *const c_char,
+                                                 revoc_def_type: *const c_char,
+                                                 value: *const c_char,
+                                                 cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                      request_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(revoc_reg_def_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(revoc_def_type, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(value, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildRevocRegEntryRequest(
+            submitter_did,
+            revoc_reg_def_id,
+            revoc_def_type,
+            value,
+            Box::new(move |result| {
+                let (err, request_json) = result_to_err_code_1!(result, String::new());
+                let request_json = CStringUtils::string_to_cstring(request_json);
+                cb(command_handle, err, request_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Builds a GET_REVOC_REG_DEF request. Request to get a revocation registry definition.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: Id of Identity stored in secured Wallet.
+/// id: ID of the revocation registry definition.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_get_revoc_reg_def_request(command_handle: i32,
+                                                   submitter_did: *const c_char,
+                                                   id: *const c_char,
+                                                   cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                        request_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildGetRevocRegDefRequest(
+            submitter_did,
+            id,
+            Box::new(move |result| {
+                let (err, request_json) = result_to_err_code_1!(result, String::new());
+                let request_json = CStringUtils::string_to_cstring(request_json);
+                cb(command_handle, err, request_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Builds a GET_REVOC_REG request. Request to get the accumulated state of the revocation registry
+/// by ID. The state is defined by the given timestamp.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: Id of Identity stored in secured Wallet.
+/// revoc_reg_def_id: ID of the corresponding revocation registry definition.
+/// timestamp: Requested time represented as a total number of seconds from Unix Epoch.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_get_revoc_reg_request(command_handle: i32,
+                                               submitter_did: *const c_char,
+                                               revoc_reg_def_id: *const c_char,
+                                               timestamp: i64,
+                                               cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                    request_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(revoc_reg_def_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildGetRevocRegRequest(
+            submitter_did,
+            revoc_reg_def_id,
+            timestamp,
+            Box::new(move |result| {
+                let (err, request_json) = result_to_err_code_1!(result, String::new());
+                let request_json = CStringUtils::string_to_cstring(request_json);
+                cb(command_handle, err, request_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Builds a GET_REVOC_REG_DELTA request. Request to get the delta of the accumulated state of
+/// the revocation registry. The delta is defined by "from" and "to" timestamp fields.
+/// If "from" is not specified, then the whole state till "to" will be returned.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: Id of Identity stored in secured Wallet.
+/// revoc_reg_def_id: ID of the corresponding revocation registry definition.
+/// from: Requested time represented as a total number of seconds from Unix Epoch (-1 to skip).
+/// to: Requested time represented as a total number of seconds from Unix Epoch.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_get_revoc_reg_delta_request(command_handle: i32,
+                                                     submitter_did: *const c_char,
+                                                     revoc_reg_def_id: *const c_char,
+                                                     from: i64,
+                                                     to: i64,
+                                                     cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                          request_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(revoc_reg_def_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    let from = if from != -1 { Some(from) } else { None };
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildGetRevocRegDeltaRequest(
+            submitter_did,
+            revoc_reg_def_id,
+            from,
+            to,
+            Box::new(move |result| {
+                let (err, request_json) = result_to_err_code_1!(result, String::new());
+                let request_json = CStringUtils::string_to_cstring(request_json);
+                cb(command_handle, err, request_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Parse a GET_REVOC_REG_DEF response to get the revocation registry definition.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_revoc_reg_def_response: response of GET_REVOC_REG_DEF request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Revocation registry definition id and revocation registry definition json (see indy_build_revoc_reg_def_request).
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_parse_get_revoc_reg_def_response(command_handle: i32,
+                                                    get_revoc_reg_def_response: *const c_char,
+                                                    cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                         revoc_reg_def_id: *const c_char,
+                                                                         revoc_reg_def_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(get_revoc_reg_def_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ParseGetRevocRegDefResponse(
+            get_revoc_reg_def_response,
+            Box::new(move |result| {
+                let (err, revoc_reg_def_id, revoc_reg_def_json) = result_to_err_code_2!(result, String::new(), String::new());
+                let revoc_reg_def_id = CStringUtils::string_to_cstring(revoc_reg_def_id);
+                let revoc_reg_def_json = CStringUtils::string_to_cstring(revoc_reg_def_json);
+                cb(command_handle, err, revoc_reg_def_id.as_ptr(), revoc_reg_def_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Parse a GET_REVOC_REG response to get the revocation registry state at the requested time.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_revoc_reg_response: response of GET_REVOC_REG request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Revocation registry definition id, revocation registry json ({"accum": string}) and
+/// the time of the registry entry as a total number of seconds from Unix Epoch.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_parse_get_revoc_reg_response(command_handle: i32,
+                                                get_revoc_reg_response: *const c_char,
+                                                cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                     revoc_reg_def_id: *const c_char,
+                                                                     revoc_reg_json: *const c_char,
+                                                                     timestamp: u64)>) -> ErrorCode {
+    check_useful_c_str!(get_revoc_reg_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ParseGetRevocRegResponse(
+            get_revoc_reg_response,
+            Box::new(move |result| {
+                let (err, revoc_reg_def_id, revoc_reg_json, timestamp) = result_to_err_code_3!(result, String::new(), String::new(), 0);
+                let revoc_reg_def_id = CStringUtils::string_to_cstring(revoc_reg_def_id);
+                let revoc_reg_json = CStringUtils::string_to_cstring(revoc_reg_json);
+                cb(command_handle, err, revoc_reg_def_id.as_ptr(), revoc_reg_json.as_ptr(), timestamp)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Parse a GET_REVOC_REG_DELTA response to get the revocation registry delta at the requested time.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_revoc_reg_delta_response: response of GET_REVOC_REG_DELTA request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Revocation registry definition id, revocation registry delta json:
+///     {
+///         "prevAccum": (optional) string - accumulator value at "from" time,
+///         "accum": string - accumulator value at "to" time,
+///         "issued": array<number> - indices issued in the interval,
+///         "revoked": array<number> - indices revoked in the interval
+///     }
+/// and the time of the last registry entry as a total number of seconds from Unix Epoch.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_parse_get_revoc_reg_delta_response(command_handle: i32,
+                                                      get_revoc_reg_delta_response: *const c_char,
+                                                      cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                           revoc_reg_def_id: *const c_char,
+                                                                           revoc_reg_delta_json: *const c_char,
+                                                                           timestamp: u64)>) -> ErrorCode {
+    check_useful_c_str!(get_revoc_reg_delta_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ParseGetRevocRegDeltaResponse(
+            get_revoc_reg_delta_response,
+            Box::new(move |result| {
+                let (err, revoc_reg_def_id, revoc_reg_delta_json, timestamp) = result_to_err_code_3!(result, String::new(), String::new(), 0);
+                let revoc_reg_def_id = CStringUtils::string_to_cstring(revoc_reg_def_id);
+                let revoc_reg_delta_json = CStringUtils::string_to_cstring(revoc_reg_delta_json);
+                cb(command_handle, err, revoc_reg_def_id.as_ptr(), revoc_reg_delta_json.as_ptr(), timestamp)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Gets GET_SCHEMA reply using the wallet cache of ledger objects.
+///
+/// Reply is fetched from the ledger only if there is no fresh enough cached reply for the schema
+/// in the wallet. Cached value freshness is checked the same way as for their DIDs: by "freshness_time"
+/// wallet config and additionally by "min_fresh" option. Successful replies are stored in the cache.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: Id of Identity stored in secured Wallet.
+/// dest: Schema issuer did.
+/// data: name, version
+/// options_json: (optional) cache options:
+///     {
+///         "no_cache": (optional, false by default) Skip usage of the wallet cache,
+///         "no_update": (optional, false by default) Use only cached data, do not fetch the ledger,
+///         "no_store": (optional, false by default) Skip storing of the fetched data in the wallet cache,
+///         "min_fresh": (optional, wallet "freshness_time" by default) Return cached data only if it
+///                      was fetched not more than min_fresh seconds ago
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// GET_SCHEMA reply json.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_get_schema(command_handle: i32,
+                              pool_handle: i32,
+                              wallet_handle: i32,
+                              submitter_did: *const c_char,
+                              dest: *const c_char,
+                              data: *const c_char,
+                              options_json: *const c_char,
+                              cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                   schema_reply_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(dest, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(data, ErrorCode::CommonInvalidParam6);
+    check_useful_opt_c_str!(options_json, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::GetSchema(
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            dest,
+            data,
+            options_json,
+            Box::new(move |result| {
+                let (err, schema_reply_json) = result_to_err_code_1!(result, String::new());
+                let schema_reply_json = CStringUtils::string_to_cstring(schema_reply_json);
+                cb(command_handle, err, schema_reply_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Gets GET_CLAIM_DEF reply using the wallet cache of ledger objects.
+///
+/// See indy_get_schema for the cache behaviour.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: Id of Identity stored in secured Wallet.
+/// xref: Seq. number of schema
+/// signature_type: signature type (only CL supported now)
+/// origin: issuer did
+/// options_json: (optional) cache options:
+///     {
+///         "no_cache": (optional, false by default) Skip usage of the wallet cache,
+///         "no_update": (optional, false by default) Use only cached data, do not fetch the ledger,
+///         "no_store": (optional, false by default) Skip storing of the fetched data in the wallet cache,
+///         "min_fresh": (optional, wallet "freshness_time" by default) Return cached data only if it
+///                      was fetched not more than min_fresh seconds ago
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// GET_CLAIM_DEF reply json.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_get_claim_def(command_handle: i32,
+                                 pool_handle: i32,
+                                 wallet_handle: i32,
+                                 submitter_did: *const c_char,
+                                 xref: i32,
+                                 signature_type: *const c_char,
+                                 origin: *const c_char,
+                                 options_json: *const c_char,
+                                 cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                      claim_def_reply_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(signature_type, ErrorCode::CommonInvalidParam6);
+    check_useful_c_str!(origin, ErrorCode::CommonInvalidParam7);
+    check_useful_opt_c_str!(options_json, ErrorCode::CommonInvalidParam8);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::GetClaimDef(
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            xref,
+            signature_type,
+            origin,
+            options_json,
+            Box::new(move |result| {
+                let (err, claim_def_reply_json) = result_to_err_code_1!(result, String::new());
+                let claim_def_reply_json = CStringUtils::string_to_cstring(claim_def_reply_json);
+                cb(command_handle, err, claim_def_reply_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Purges the wallet cache of schemas fetched from the ledger.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// options_json: (optional) purge options:
+///     {
+///         "max_age": (optional, all entries by default) Purge only entries fetched more than max_age seconds ago
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_purge_schema_cache(command_handle: i32,
+                                      wallet_handle: i32,
+                                      options_json: *const c_char,
+                                      cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode)>) -> ErrorCode {
+    check_useful_opt_c_str!(options_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::PurgeSchemaCache(
+            wallet_handle,
+            options_json,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Purges the wallet cache of claim definitions fetched from the ledger.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// options_json: (optional) purge options:
+///     {
+///         "max_age": (optional, all entries by default) Purge only entries fetched more than max_age seconds ago
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_purge_claim_def_cache(command_handle: i32,
+                                         wallet_handle: i32,
+                                         options_json: *const c_char,
+                                         cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode)>) -> ErrorCode {
+    check_useful_opt_c_str!(options_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::PurgeClaimDefCache(
+            wallet_handle,
+            options_json,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Opens a reader over a range of ledger transactions.
+///
+/// Transactions are fetched with GET_TXN requests and every transaction is verified
+/// against the ledger root hash using the merkle audit path returned by the nodes.
+/// The root hash must be covered by the pool BLS multi-signature (or agreed by f+1 nodes)
+/// and every new root must be proven consistent with the previously verified root.
+/// Transactions without valid proofs are rejected.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// submitter_did: Id of Identity stored in secured Wallet.
+/// ledger_id: ledger to read: 0 - pool ledger, 1 - domain ledger, 2 - config ledger.
+/// from: sequence number of the first transaction to read (starting from 1).
+/// to: sequence number of the last transaction to read, -1 to read until the end of the ledger.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Handle of the reader to use in fetch_ledger_txns.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_open_ledger_txn_reader(command_handle: i32,
+                                          pool_handle: i32,
+                                          submitter_did: *const c_char,
+                                          ledger_id: i32,
+                                          from: i32,
+                                          to: i32,
+                                          cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                               reader_handle: i32)>) -> ErrorCode {
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    let to = if to != -1 { Some(to) } else { None };
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::OpenLedgerTxnReader(
+            pool_handle,
+            submitter_did,
+            ledger_id,
+            from,
+            to,
+            Box::new(move |result| {
+                let (err, reader_handle) = result_to_err_code_1!(result, 0);
+                cb(command_handle, err, reader_handle)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Fetches next verified transactions from the ledger transactions reader.
+///
+/// Transactions are requested from the pool in batches and returned in order of their sequence numbers.
+/// Empty array is returned when there are no more transactions in the requested range.
+/// Fetch is atomic: if any transaction can't be fetched or verified, none are returned and
+/// the reader stays at the position the fetch started from, so the fetch can be retried.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// reader_handle: reader handle (created by open_ledger_txn_reader).
+/// count: maximum number of transactions to fetch.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Transactions json:
+///     [{
+///         "seqNo": int - sequence number of the transaction,
+///         "ledgerId": int - ledger of the transaction,
+///         "txn": object - transaction,
+///         "rootHash": string - base58 ledger root hash the transaction was verified against,
+///         "ledgerSize": int - size of the ledger for the root hash
+///     }]
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_fetch_ledger_txns(command_handle: i32,
+                                     reader_handle: i32,
+                                     count: u32,
+                                     cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                          txns_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::FetchLedgerTxns(
+            reader_handle,
+            count as usize,
+            Box::new(move |result| {
+                let (err, txns_json) = result_to_err_code_1!(result, String::new());
+                let txns_json = CStringUtils::string_to_cstring(txns_json);
+                cb(command_handle, err, txns_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Closes the ledger transactions reader.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// reader_handle: reader handle (created by open_ledger_txn_reader).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_close_ledger_txn_reader(command_handle: i32,
+                                           reader_handle: i32,
+                                           cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::CloseLedgerTxnReader(
+            reader_handle,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
@@ -1,6 +1,7 @@
 extern crate libc;
 
 pub mod anoncreds;
+pub mod blob_storage;
 pub mod crypto;
 pub mod ledger;
 pub mod pairwise;
@@ -61,6 +62,9 @@ pub enum ErrorCode
     // IO Error
     CommonIOError = 114,
 
+    // Caller passed invalid value as param 13 (null, invalid json and etc..)
+    CommonInvalidParam13 = 115,
+
     // Wallet errors
     // Caller passed invalid wallet handle
     WalletInvalidHandle = 200,
@@ -127,10 +131,25 @@ pub enum ErrorCode
     // Attempt to create claim definition with duplicated did schema pair
     AnoncredsClaimDefAlreadyExistsError = 407,
 
+    // Attempt to create claim for expired claim offer
+    AnoncredsClaimOfferExpiredError = 408,
+
+    // Attempt to create claim for unknown, withdrawn or already fulfilled claim offer
+    AnoncredsClaimOfferNotFoundError = 409,
+
     // Crypto errors
     // Unknown format of DID entity keys
     UnknownCryptoTypeError = 500,
 
+    // Attempt to delete key that is still used by DID
+    CryptoKeyUsedByDidError = 501,
+
     // Attempt to create duplicate did
     DidAlreadyExistsError = 600,
+
+    // Requested DID isn't found on the ledger
+    DidNotFoundError = 601,
+
+    // Attempt to set master seed of the wallet that already has one
+    DidMasterSeedAlreadySetError = 602,
 }
@@ -25,6 +25,10 @@ use self::libc::c_char;
 /// delete: WalletType delete operation handler
 /// free: Handler that allows to de-allocate strings allocated in caller code
 ///
+/// Wallet type registered without remove handler can't delete records, so deletion of keys,
+/// claims and claim offers fails for its wallets. Use indy_register_wallet_type_with_remove
+/// to provide remove handler.
+///
 /// #Returns
 /// Error code
 #[no_mangle]
@@ -79,6 +83,96 @@ pub extern fn indy_register_wallet_type(command_handle: i32,
             get,
             get_not_expired,
             list,
+            None,
+            close,
+            delete,
+            free,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Registers custom wallet implementation with records remove handler.
+///
+/// The same as indy_register_wallet_type, but wallets of registered type support deletion
+/// of records (keys, claims and claim offers).
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// xtype: Wallet type name.
+/// create: WalletType create operation handler
+/// open: WalletType open operation handler
+/// set: Wallet set operation handler
+/// get: Wallet get operation handler
+/// get_not_expired: Wallet get_not_expired operation handler
+/// list: Wallet list operation handler(must to return data in the following format: {"values":[{"key":"", "value":""}, {"key":"", "value":""}]}
+/// remove: Wallet remove operation handler (must return WalletNotFoundError for unknown key)
+/// close: Wallet close operation handler
+/// delete: WalletType delete operation handler
+/// free: Handler that allows to de-allocate strings allocated in caller code
+///
+/// #Returns
+/// Error code
+#[no_mangle]
+pub extern fn indy_register_wallet_type_with_remove(command_handle: i32,
+                                                    xtype: *const c_char,
+                                                    create: Option<extern fn(name: *const c_char,
+                                                                             config: *const c_char,
+                                                                             credentials: *const c_char) -> ErrorCode>,
+                                                    open: Option<extern fn(name: *const c_char,
+                                                                           config: *const c_char,
+                                                                           runtime_config: *const c_char,
+                                                                           credentials: *const c_char,
+                                                                           handle: *mut i32) -> ErrorCode>,
+                                                    set: Option<extern fn(handle: i32,
+                                                                          key: *const c_char,
+                                                                          value: *const c_char) -> ErrorCode>,
+                                                    get: Option<extern fn(handle: i32,
+                                                                          key: *const c_char,
+                                                                          value_ptr: *mut *const c_char) -> ErrorCode>,
+                                                    get_not_expired: Option<extern fn(handle: i32,
+                                                                                      key: *const c_char,
+                                                                                      value_ptr: *mut *const c_char) -> ErrorCode>,
+                                                    list: Option<extern fn(handle: i32,
+                                                                           key_prefix: *const c_char,
+                                                                           values_json_ptr: *mut *const c_char) -> ErrorCode>,
+                                                    remove: Option<extern fn(handle: i32,
+                                                                             key: *const c_char) -> ErrorCode>,
+                                                    close: Option<extern fn(handle: i32) -> ErrorCode>,
+                                                    delete: Option<extern fn(name: *const c_char,
+                                                                             config: *const c_char,
+                                                                             credentials: *const c_char) -> ErrorCode>,
+                                                    free: Option<extern fn(wallet_handle: i32,
+                                                                           value: *const c_char) -> ErrorCode>,
+                                                    cb: Option<extern fn(xcommand_handle: i32,
+                                                                         err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_str!(xtype, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(create, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(open, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(set, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(get, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(get_not_expired, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(list, ErrorCode::CommonInvalidParam8);
+    check_useful_c_callback!(remove, ErrorCode::CommonInvalidParam9);
+    check_useful_c_callback!(close, ErrorCode::CommonInvalidParam10);
+    check_useful_c_callback!(delete, ErrorCode::CommonInvalidParam11);
+    check_useful_c_callback!(free, ErrorCode::CommonInvalidParam12);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam13);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Wallet(WalletCommand::RegisterWalletType(
+            xtype,
+            create,
+            open,
+            set,
+            get,
+            get_not_expired,
+            list,
+            Some(remove),
             close,
             delete,
             free,
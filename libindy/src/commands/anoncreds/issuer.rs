@@ -1,20 +1,27 @@
 extern crate serde_json;
 extern crate indy_crypto;
+extern crate time;
 
 use errors::indy::IndyError;
 use errors::anoncreds::AnoncredsError;
 use errors::common::CommonError;
+use errors::wallet::WalletError;
+use errors::ToErrorCode;
 
 use services::anoncreds::AnoncredsService;
+use services::blob_storage::{BlobStorageService, parse_blob_storage_config};
 use services::pool::PoolService;
 use services::wallet::WalletService;
 use services::anoncreds::types::*;
-use services::anoncreds::helpers::get_composite_id;
+use services::anoncreds::helpers::{get_claim_def_composite_id, get_composite_id};
+use services::anoncreds::encoding::encode_claim_values;
+use super::{revocation_registry_from_json, revocation_registry_to_json};
 use std::rc::Rc;
 use std::collections::HashMap;
 use utils::crypto::base58::Base58;
 use self::indy_crypto::cl::*;
 use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
+use self::serde_json::Value;
 
 pub enum IssuerCommand {
     CreateAndStoreClaimDefinition(
@@ -23,45 +30,91 @@ pub enum IssuerCommand {
         String, // schema json
         Option<String>, // signature type
         bool,
+        Option<String>, // tag
+        Box<Fn(Result<String, IndyError>) + Send>),
+    SetActiveClaimDefinition(
+        i32, // wallet handle
+        String, // issuer did
+        String, // schema json
+        Option<String>, // tag
+        Box<Fn(Result<(), IndyError>) + Send>),
+    ListClaimDefinitions(
+        i32, // wallet handle
+        String, // issuer did
+        String, // schema json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    CompareSchemas(
+        String, // schema json
+        String, // other schema json
         Box<Fn(Result<String, IndyError>) + Send>),
     CreateAndStoreRevocationRegistry(
         i32, // wallet handle
         String, // schema json
         String, // issuer did
         u32, // max claim num
+        Option<String>, // tails writer config json
         Box<Fn(Result<String, IndyError>) + Send>),
     CreateClaimOffer(
         i32, // wallet handle
         String, // issuer did
         String, // schema json
         String, // prover did
+        Option<u32>, // expiration period in seconds
         Box<Fn(Result<String, IndyError>) + Send>),
+    WithdrawClaimOffer(
+        i32, // wallet handle
+        String, // issuer did
+        String, // schema json
+        Option<String>, // claim def tag
+        String, // prover did
+        Box<Fn(Result<(), IndyError>) + Send>),
     CreateClaim(
         i32, // wallet handle
         String, // claim req json
         String, // claim json
         Option<u32>, // user revoc index
         Box<Fn(Result<(String, String), IndyError>) + Send>),
+    CreateClaims(
+        i32, // wallet handle
+        String, // claim reqs json
+        Box<Fn(Result<(String, String), IndyError>) + Send>),
     RevokeClaim(
         i32, // wallet handle
         String, // issuer did
         String, // schema json
+        Option<String>, // claim def tag
+        u32, // user revoc index
+        Box<Fn(Result<String, IndyError>) + Send>),
+    RecoverClaim(
+        i32, // wallet handle
+        String, // issuer did
+        String, // schema json
+        Option<String>, // claim def tag
         u32, // user revoc index
         Box<Fn(Result<String, IndyError>) + Send>),
+    GetRevocationRegistryIndices(
+        i32, // wallet handle
+        String, // issuer did
+        String, // schema json
+        Option<String>, // claim def tag
+        Box<Fn(Result<String, IndyError>) + Send>),
 }
 
 pub struct IssuerCommandExecutor {
     pub anoncreds_service: Rc<AnoncredsService>,
+    pub blob_storage_service: Rc<BlobStorageService>,
     pub pool_service: Rc<PoolService>,
     pub wallet_service: Rc<WalletService>
 }
 
 impl IssuerCommandExecutor {
     pub fn new(anoncreds_service: Rc<AnoncredsService>,
+               blob_storage_service: Rc<BlobStorageService>,
                pool_service: Rc<PoolService>,
                wallet_service: Rc<WalletService>) -> IssuerCommandExecutor {
         IssuerCommandExecutor {
             anoncreds_service,
+            blob_storage_service,
             pool_service,
             wallet_service,
         }
@@ -69,26 +122,60 @@ impl IssuerCommandExecutor {
 
     pub fn execute(&self, command: IssuerCommand) {
         match command {
-            IssuerCommand::CreateAndStoreClaimDefinition(wallet_handle, issuer_did, schema_json, signature_type, create_non_revoc, cb) => {
+            IssuerCommand::CreateAndStoreClaimDefinition(wallet_handle, issuer_did, schema_json, signature_type, create_non_revoc, tag, cb) => {
                 info!(target: "issuer_command_executor", "CreateAndStoreClaimDef command received");
                 cb(self.create_and_store_claim_definition(wallet_handle, &issuer_did, &schema_json,
-                                                          signature_type.as_ref().map(String::as_str), create_non_revoc));
+                                                          signature_type.as_ref().map(String::as_str), create_non_revoc,
+                                                          tag.as_ref().map(String::as_str)));
+            }
+            IssuerCommand::SetActiveClaimDefinition(wallet_handle, issuer_did, schema_json, tag, cb) => {
+                info!(target: "issuer_command_executor", "SetActiveClaimDefinition command received");
+                cb(self.set_active_claim_definition(wallet_handle, &issuer_did, &schema_json, tag.as_ref().map(String::as_str)));
+            }
+            IssuerCommand::ListClaimDefinitions(wallet_handle, issuer_did, schema_json, cb) => {
+                info!(target: "issuer_command_executor", "ListClaimDefinitions command received");
+                cb(self.list_claim_definitions(wallet_handle, &issuer_did, &schema_json));
             }
-            IssuerCommand::CreateAndStoreRevocationRegistry(wallet_handle, issuer_did, schema_json, max_claim_num, cb) => {
+            IssuerCommand::CompareSchemas(schema_json, other_schema_json, cb) => {
+                info!(target: "issuer_command_executor", "CompareSchemas command received");
+                cb(self.compare_schemas(&schema_json, &other_schema_json));
+            }
+            IssuerCommand::CreateAndStoreRevocationRegistry(wallet_handle, issuer_did, schema_json, max_claim_num, tails_writer_config_json, cb) => {
                 info!(target: "issuer_command_executor", "CreateAndStoreRevocationRegistryRegistry command received");
-                cb(self.create_and_store_revocation_registry(wallet_handle, &issuer_did, &schema_json, max_claim_num));
+                cb(self.create_and_store_revocation_registry(wallet_handle, &issuer_did, &schema_json, max_claim_num,
+                                                             tails_writer_config_json.as_ref().map(String::as_str)));
             }
             IssuerCommand::CreateClaim(wallet_handle, claim_req_json, claim_json, user_revoc_index, cb) => {
                 info!(target: "issuer_command_executor", "CreateClaim command received");
                 cb(self.new_claim(wallet_handle, &claim_req_json, &claim_json, user_revoc_index));
             }
-            IssuerCommand::CreateClaimOffer(wallet_handle, schema_json, issuer_did, prover_did, cb) => {
+            IssuerCommand::CreateClaims(wallet_handle, claim_reqs_json, cb) => {
+                info!(target: "issuer_command_executor", "CreateClaims command received");
+                cb(self.new_claims(wallet_handle, &claim_reqs_json));
+            }
+            IssuerCommand::CreateClaimOffer(wallet_handle, schema_json, issuer_did, prover_did, expires_in, cb) => {
                 info!(target: "issuer_command_executor", "CreateClaimOffer command received");
-                cb(self.create_claim_offer(wallet_handle, &schema_json, &issuer_did, &prover_did));
+                cb(self.create_claim_offer(wallet_handle, &schema_json, &issuer_did, &prover_did, expires_in));
             }
-            IssuerCommand::RevokeClaim(wallet_handle, issuer_did, schema_json, user_revoc_index, cb) => {
+            IssuerCommand::WithdrawClaimOffer(wallet_handle, issuer_did, schema_json, claim_def_tag, prover_did, cb) => {
+                info!(target: "issuer_command_executor", "WithdrawClaimOffer command received");
+                cb(self.withdraw_claim_offer(wallet_handle, &issuer_did, &schema_json,
+                                             claim_def_tag.as_ref().map(String::as_str), &prover_did));
+            }
+            IssuerCommand::RevokeClaim(wallet_handle, issuer_did, schema_json, claim_def_tag, user_revoc_index, cb) => {
                 info!(target: "issuer_command_executor", "RevokeClaim command received");
-                cb(self.revoke_claim(wallet_handle, &issuer_did, &schema_json, user_revoc_index));
+                cb(self.revoke_claim(wallet_handle, &issuer_did, &schema_json,
+                                     claim_def_tag.as_ref().map(String::as_str), user_revoc_index));
+            }
+            IssuerCommand::RecoverClaim(wallet_handle, issuer_did, schema_json, claim_def_tag, user_revoc_index, cb) => {
+                info!(target: "issuer_command_executor", "RecoverClaim command received");
+                cb(self.recover_claim(wallet_handle, &issuer_did, &schema_json,
+                                      claim_def_tag.as_ref().map(String::as_str), user_revoc_index));
+            }
+            IssuerCommand::GetRevocationRegistryIndices(wallet_handle, issuer_did, schema_json, claim_def_tag, cb) => {
+                info!(target: "issuer_command_executor", "GetRevocationRegistryIndices command received");
+                cb(self.get_revocation_registry_indices(wallet_handle, &issuer_did, &schema_json,
+                                                        claim_def_tag.as_ref().map(String::as_str)));
             }
         };
     }
@@ -98,9 +185,15 @@ impl IssuerCommandExecutor {
                                          issuer_did: &str,
                                          schema_json: &str,
                                          signature_type: Option<&str>,
-                                         create_non_revoc: bool) -> Result<String, IndyError> {
+                                         create_non_revoc: bool,
+                                         tag: Option<&str>) -> Result<String, IndyError> {
         info!("create_and_store_claim_definition >>> wallet_handle: {:?}, issuer_did: {:?}, schema_json: {:?}, \
-                       signature_type: {:?}, create_non_revoc: {:?}", wallet_handle, issuer_did, schema_json, signature_type, create_non_revoc);
+                       signature_type: {:?}, create_non_revoc: {:?}, tag: {:?}",
+              wallet_handle, issuer_did, schema_json, signature_type, create_non_revoc, tag);
+
+        if tag == Some("") {
+            return Err(IndyError::CommonError(CommonError::InvalidStructure(format!("Claim definition tag is empty"))));
+        }
 
         Base58::decode(&issuer_did)
             .map_err(|err| CommonError::InvalidStructure(format!("Invalid issuer did: {:?}", err)))?;
@@ -110,14 +203,15 @@ impl IssuerCommandExecutor {
 
         let schema_key = SchemaKey { name: schema.data.name.clone(), version: schema.data.version.clone(), did: schema.dest.clone() };
 
-        let id = get_composite_id(issuer_did, &schema_key);
+        let id = get_claim_def_composite_id(issuer_did, &schema_key, tag);
 
         if self.wallet_service.get(wallet_handle, &format!("claim_definition::{}", id)).is_ok() {
             return Err(IndyError::AnoncredsError(AnoncredsError::ClaimDefAlreadyExists(format!("Claim definition for key: {:?} already exists", id))));
         };
 
-        let (claim_definition, private_key, key_correctness_proof) =
+        let (mut claim_definition, private_key, key_correctness_proof) =
             self.anoncreds_service.issuer.new_claim_definition(issuer_did, &schema, signature_type, create_non_revoc)?;
+        claim_definition.tag = tag.map(String::from);
 
         let claim_definition_json = claim_definition.to_json()
             .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claim definition: {:?}", err)))?;
@@ -133,18 +227,113 @@ impl IssuerCommandExecutor {
         self.wallet_service.set(wallet_handle, &format!("claim_definition_private_key::{}", id), &private_key_json)?;
         self.wallet_service.set(wallet_handle, &format!("key_correctness_proof::{}", id), &key_correctness_proof_json)?;
 
+        // The latest claim definition is used for new offers and revocation until another one is activated
+        let schema_id = get_composite_id(issuer_did, &schema_key);
+
+        let mut lineage = self._get_claim_definition_lineage(wallet_handle, &schema_id)?;
+        lineage.push(ClaimDefinitionLineageEntry { tag: tag.map(String::from), created_at: Some(time::get_time().sec) });
+        self._store_claim_definition_lineage(wallet_handle, &schema_id, &lineage)?;
+        self._set_active_claim_definition_tag(wallet_handle, &schema_id, tag)?;
+
         info!("create_and_store_claim_definition <<< claim_definition_json: {:?}", claim_definition_json);
 
         Ok(claim_definition_json)
     }
 
+    fn set_active_claim_definition(&self,
+                                   wallet_handle: i32,
+                                   issuer_did: &str,
+                                   schema_json: &str,
+                                   tag: Option<&str>) -> Result<(), IndyError> {
+        info!("set_active_claim_definition >>> wallet_handle: {:?}, issuer_did: {:?}, schema_json: {:?}, tag: {:?}",
+              wallet_handle, issuer_did, schema_json, tag);
+
+        let schema: Schema = Schema::from_json(schema_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid schema json: {}", err.to_string())))?;
+        let schema_key = SchemaKey { name: schema.data.name.clone(), version: schema.data.version.clone(), did: schema.dest.clone() };
+
+        self.wallet_service.get(wallet_handle, &format!("claim_definition::{}", get_claim_def_composite_id(issuer_did, &schema_key, tag)))?;
+
+        self._set_active_claim_definition_tag(wallet_handle, &get_composite_id(issuer_did, &schema_key), tag)?;
+
+        info!("set_active_claim_definition <<<");
+
+        Ok(())
+    }
+
+    fn list_claim_definitions(&self,
+                              wallet_handle: i32,
+                              issuer_did: &str,
+                              schema_json: &str) -> Result<String, IndyError> {
+        info!("list_claim_definitions >>> wallet_handle: {:?}, issuer_did: {:?}, schema_json: {:?}",
+              wallet_handle, issuer_did, schema_json);
+
+        let schema: Schema = Schema::from_json(schema_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid schema json: {}", err.to_string())))?;
+        let schema_key = SchemaKey { name: schema.data.name.clone(), version: schema.data.version.clone(), did: schema.dest.clone() };
+        let schema_id = get_composite_id(issuer_did, &schema_key);
+
+        let mut lineage = self._get_claim_definition_lineage(wallet_handle, &schema_id)?;
+
+        // Untagged claim definition could be created before lineage was tracked
+        if !lineage.iter().any(|entry| entry.tag.is_none()) &&
+            self.wallet_service.get(wallet_handle, &format!("claim_definition::{}", schema_id)).is_ok() {
+            lineage.insert(0, ClaimDefinitionLineageEntry { tag: None, created_at: None });
+        }
+
+        let (active_tag, _) = self._get_active_claim_definition_id(wallet_handle, issuer_did, &schema_key)?;
+
+        let mut claim_defs: Vec<Value> = Vec::new();
+
+        for entry in lineage {
+            let id = get_claim_def_composite_id(issuer_did, &schema_key, entry.tag.as_ref().map(String::as_str));
+
+            let claim_def_json = self.wallet_service.get(wallet_handle, &format!("claim_definition::{}", id))?;
+            let claim_def: Value = serde_json::from_str(&claim_def_json)
+                .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize claim definition: {:?}", err)))?;
+
+            claim_defs.push(json!({
+                "tag": entry.tag,
+                "created_at": entry.created_at,
+                "active": entry.tag == active_tag,
+                "claim_def": claim_def
+            }));
+        }
+
+        let claim_defs_json = serde_json::to_string(&claim_defs)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claim definitions: {:?}", err)))?;
+
+        info!("list_claim_definitions <<< claim_defs_json: {:?}", claim_defs_json);
+
+        Ok(claim_defs_json)
+    }
+
+    fn compare_schemas(&self, schema_json: &str, other_schema_json: &str) -> Result<String, IndyError> {
+        info!("compare_schemas >>> schema_json: {:?}, other_schema_json: {:?}", schema_json, other_schema_json);
+
+        let schema: Schema = Schema::from_json(schema_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid schema json: {}", err.to_string())))?;
+        let other_schema: Schema = Schema::from_json(other_schema_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid schema json: {}", err.to_string())))?;
+
+        let schema_diff_json = schema.diff(&other_schema).to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize schema diff: {:?}", err)))?;
+
+        info!("compare_schemas <<< schema_diff_json: {:?}", schema_diff_json);
+
+        Ok(schema_diff_json)
+    }
+
     fn create_and_store_revocation_registry(&self,
                                             wallet_handle: i32,
                                             issuer_did: &str,
                                             schema_json: &str,
-                                            max_claim_num: u32) -> Result<String, IndyError> {
-        info!("create_and_store_revocation_registry >>> wallet_handle: {:?}, issuer_did: {:?}, schema_json: {:?}, max_claim_num: {:?}",
-              wallet_handle, issuer_did, schema_json, max_claim_num);
+                                            max_claim_num: u32,
+                                            tails_writer_config_json: Option<&str>) -> Result<String, IndyError> {
+        info!("create_and_store_revocation_registry >>> wallet_handle: {:?}, issuer_did: {:?}, schema_json: {:?}, max_claim_num: {:?}, tails_writer_config_json: {:?}",
+              wallet_handle, issuer_did, schema_json, max_claim_num, tails_writer_config_json);
+
+        let tails_config = parse_blob_storage_config(tails_writer_config_json)?;
 
         Base58::decode(&issuer_did)
             .map_err(|err| CommonError::InvalidStructure(format!("Invalid issuer did: {:?}", err)))?;
@@ -153,26 +342,33 @@ impl IssuerCommandExecutor {
             .map_err(|err| CommonError::InvalidStructure(format!("Invalid schema json: {}", err.to_string())))?;
         let schema_key = SchemaKey { name: schema.data.name.clone(), version: schema.data.version.clone(), did: schema.dest.clone() };
 
-        let id = get_composite_id(issuer_did, &schema_key);
+        let (_, id) = self._get_active_claim_definition_id(wallet_handle, issuer_did, &schema_key)?;
 
         let claim_def_json = self.wallet_service.get(wallet_handle, &format!("claim_definition::{}", &id))?;
         let claim_def: ClaimDefinition = ClaimDefinition::from_json(&claim_def_json)
             .map_err(|err| CommonError::InvalidState(format!("Cannon deserialize claim definition: {:?}", err)))?;
 
-        let (revocation_registry, revocation_registry_private) =
+        let (mut revocation_registry, revocation_registry_private) =
             self.anoncreds_service.issuer.new_revocation_registry(&claim_def.data, max_claim_num, issuer_did, schema.seq_no)?;
 
-        let revocation_registry_json = revocation_registry.to_json()
-            .map_err(|err| CommonError::InvalidState(format!("Cannon serialize revocation registry: {:?}", err)))?;
+        // Tails are moved to blob storage here, registry references them by hash
+        let revocation_registry_json = revocation_registry_to_json(&self.blob_storage_service, &tails_config, &mut revocation_registry)?;
 
         let revocation_registry_private_json = revocation_registry_private.to_json()
             .map_err(|err| CommonError::InvalidState(format!("Cannon serialize revocation registry private: {:?}", err)))?;
 
+        let revocation_registry_indices_json = RevocationRegistryIndices::new(max_claim_num).to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize revocation registry indices: {:?}", err)))?;
+
         // TODO: store revocation registry using unique identifier(https://jira.hyperledger.org/browse/IS-514).
         self.wallet_service.set(wallet_handle, &format!("revocation_registry::{}", id), &revocation_registry_json)?;
         self.wallet_service.set(wallet_handle, &format!("revocation_registry_private::{}", id), &revocation_registry_private_json)?;
+        self.wallet_service.set(wallet_handle, &format!("revocation_registry_indices::{}", id), &revocation_registry_indices_json)?;
+        // Issuer reads tails from the storage it has written them to
+        if let Some(tails_writer_config_json) = tails_writer_config_json {
+            self.wallet_service.set(wallet_handle, &format!("revocation_registry_tails_config::{}", id), tails_writer_config_json)?;
+        }
 
-        // TODO: decide about tails storing
         info!("create_and_store_revocation_registry <<< revocation_registry_json: {:?}", revocation_registry_json);
 
         Ok(revocation_registry_json)
@@ -182,9 +378,10 @@ impl IssuerCommandExecutor {
                           wallet_handle: i32,
                           schema_json: &str,
                           issuer_did: &str,
-                          prover_did: &str) -> Result<String, IndyError> {
-        info!("create_claim_offer >>> wallet_handle: {:?}, issuer_did: {:?}, schema_json: {:?}, prover_did: {:?}",
-              wallet_handle, issuer_did, schema_json, prover_did);
+                          prover_did: &str,
+                          expires_in: Option<u32>) -> Result<String, IndyError> {
+        info!("create_claim_offer >>> wallet_handle: {:?}, issuer_did: {:?}, schema_json: {:?}, prover_did: {:?}, expires_in: {:?}",
+              wallet_handle, issuer_did, schema_json, prover_did, expires_in);
 
         Base58::decode(&issuer_did)
             .map_err(|err| CommonError::InvalidStructure(format!("Invalid issuer did: {:?}", err)))?;
@@ -196,7 +393,7 @@ impl IssuerCommandExecutor {
             .map_err(|err| CommonError::InvalidStructure(format!("Invalid schema json: {}", err.to_string())))?;
         let schema_key = SchemaKey { name: schema.data.name.clone(), version: schema.data.version.clone(), did: schema.dest.clone() };
 
-        let id = get_composite_id(issuer_did, &schema_key);
+        let (claim_def_tag, id) = self._get_active_claim_definition_id(wallet_handle, issuer_did, &schema_key)?;
 
         self.wallet_service.get(wallet_handle, &format!("claim_definition::{}", id))?;
 
@@ -210,23 +407,53 @@ impl IssuerCommandExecutor {
         let nonce_json = nonce.to_json()
             .map_err(|err| CommonError::InvalidState(format!("Cannon serialize claim offer: {:?}", err)))?;
 
+        let expires_at = expires_in.map(|expires_in| time::get_time().sec + expires_in as i64);
+
         let claim_offer = ClaimOffer {
             issuer_did: issuer_did.to_string(),
             schema_key,
             key_correctness_proof,
-            nonce
+            nonce,
+            expires_at,
+            claim_def_tag
         };
 
         let claim_offer_json = claim_offer.to_json()
             .map_err(|err| CommonError::InvalidState(format!("Cannon serialize claim offer: {:?}", err)))?;
 
+        let expires_at_json = serde_json::to_string(&expires_at)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claim offer expiration: {:?}", err)))?;
+
+        self._purge_expired_claim_offers(wallet_handle, &id)?;
+
+        // A new offer replaces outstanding one for the same prover, so its expiration is overwritten as well
         self.wallet_service.set(wallet_handle, &format!("nonce::{}::{}", id, prover_did), &nonce_json)?;
+        self.wallet_service.set(wallet_handle, &format!("claim_offer_expiration::{}::{}", id, prover_did), &expires_at_json)?;
 
         info!("create_claim_offer <<< claim_offer_json: {:?}", claim_offer_json);
 
         Ok(claim_offer_json)
     }
 
+    fn withdraw_claim_offer(&self,
+                            wallet_handle: i32,
+                            issuer_did: &str,
+                            schema_json: &str,
+                            claim_def_tag: Option<&str>,
+                            prover_did: &str) -> Result<(), IndyError> {
+        info!("withdraw_claim_offer >>> wallet_handle: {:?}, issuer_did: {:?}, schema_json: {:?}, claim_def_tag: {:?}, prover_did: {:?}",
+              wallet_handle, issuer_did, schema_json, claim_def_tag, prover_did);
+
+        let id = IssuerCommandExecutor::_get_schema_claim_definition_id(issuer_did, schema_json, claim_def_tag)?;
+
+        self._get_claim_offer_nonce(wallet_handle, &id, prover_did)?;
+        self._remove_claim_offer(wallet_handle, &id, prover_did)?;
+
+        info!("withdraw_claim_offer <<<");
+
+        Ok(())
+    }
+
     fn new_claim(&self,
                  wallet_handle: i32,
                  claim_req_json: &str,
@@ -238,107 +465,547 @@ impl IssuerCommandExecutor {
         let claim_request: ClaimRequest = ClaimRequest::from_json(claim_req_json)
             .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize claim request: {:?}", err)))?;
 
-        let id = get_composite_id(&claim_request.issuer_did, &claim_request.schema_key);
+        let id = get_claim_def_composite_id(&claim_request.issuer_did, &claim_request.schema_key,
+                                            claim_request.claim_def_tag.as_ref().map(String::as_str));
 
-        let claim_def_json = self.wallet_service.get(wallet_handle, &format!("claim_definition::{}", id))?;
-        let claim_def: ClaimDefinition = ClaimDefinition::from_json(&claim_def_json)
-            .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize claim definition: {:?}", err)))?;
+        let (claim_def, private_key) = self._get_claim_definition_with_private_key(wallet_handle, &id)?;
+        let (mut rev_reg_pub, rev_reg_priv) = self._get_revocation_registry_with_private(wallet_handle, &id)?;
+        let mut rev_reg_indices = match rev_reg_pub {
+            Some(ref rev_reg_pub) => Some(self._get_revocation_registry_indices(wallet_handle, &id, rev_reg_pub)?),
+            None => None
+        };
 
-        let private_key_json = self.wallet_service.get(wallet_handle, &format!("claim_definition_private_key::{}", id))?;
-        let private_key = IssuerPrivateKey::from_json(&private_key_json)
-            .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize claim definition private key: {:?}", err)))?;
+        let claim_values: HashMap<String, Vec<String>> = serde_json::from_str(claim_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Cannon deserialize claim values: {:?}", err)))?;
 
-        let mut rev_reg_pub = match self.wallet_service.get(wallet_handle, &format!("revocation_registry::{}", id)) {
-            Ok(rev_reg_pub_json) =>
-                Some(RevocationRegistry::from_json(&rev_reg_pub_json)
-                    .map_err(|err| CommonError::InvalidState(format!("Cannon deserialize revocation registry: {:?}", err)))?),
-            Err(_) => None
+        let rev_idx = match rev_reg_indices {
+            Some(ref mut rev_reg_indices) => Some(IssuerCommandExecutor::_allocate_revocation_index(&id, rev_reg_indices, rev_idx)?),
+            None => rev_idx
         };
 
-        let rev_reg_priv = match self.wallet_service.get(wallet_handle, &format!("revocation_registry_private::{}", id)) {
-            Ok(rev_reg_priv_json) =>
-                Some(RevocationRegistryPrivate::from_json(&rev_reg_priv_json)
-                    .map_err(|err| CommonError::InvalidState(format!("Cannon deserialize revocation registry private: {:?}", err)))?),
-            Err(_) => None
+        let claim = self._new_claim(wallet_handle,
+                                    &claim_def,
+                                    &private_key,
+                                    rev_reg_pub.as_mut(),
+                                    rev_reg_priv.as_ref(),
+                                    claim_request,
+                                    &claim_values,
+                                    rev_idx)?;
+
+        let claim_json = claim.to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claim: {:?}", err)))?;
+
+        if let (Some(rev_reg_indices), Some(rev_idx)) = (rev_reg_indices.as_mut(), rev_idx) {
+            self._record_revocation_index(wallet_handle, &id, rev_reg_indices, rev_idx, &claim_json)?;
+        }
+
+        let revocation_registry_json = self._store_revocation_registry(wallet_handle, &id, rev_reg_pub, rev_reg_indices)?;
+
+        info!("new_claim <<< revocation_registry_json: {:?}, claim_json: {:?}", revocation_registry_json, claim_json);
+
+        Ok((revocation_registry_json, claim_json))
+    }
+
+    fn new_claims(&self,
+                  wallet_handle: i32,
+                  claim_reqs_json: &str) -> Result<(String, String), IndyError> {
+        info!("new_claims >>> wallet_handle: {:?}, claim_reqs_json: {:?}", wallet_handle, claim_reqs_json);
+
+        let claim_requests: Vec<BatchClaimRequest> = serde_json::from_str(claim_reqs_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize claim requests: {:?}", err)))?;
+
+        let id = match claim_requests.first() {
+            Some(first) => get_claim_def_composite_id(&first.claim_request.issuer_did, &first.claim_request.schema_key,
+                                                      first.claim_request.claim_def_tag.as_ref().map(String::as_str)),
+            None => return Err(IndyError::CommonError(CommonError::InvalidStructure(format!("Empty list of claim requests"))))
+        };
+
+        // Claim definition and revocation registry are loaded and stored once for the whole batch
+        let (claim_def, private_key) = self._get_claim_definition_with_private_key(wallet_handle, &id)?;
+        let (mut rev_reg_pub, rev_reg_priv) = self._get_revocation_registry_with_private(wallet_handle, &id)?;
+        let mut rev_reg_indices = match rev_reg_pub {
+            Some(ref rev_reg_pub) => Some(self._get_revocation_registry_indices(wallet_handle, &id, rev_reg_pub)?),
+            None => None
+        };
+
+        let prev_accum = match rev_reg_pub {
+            Some(ref rev_reg_pub) => Some(rev_reg_pub.accumulator()?),
+            None => None
         };
 
-        let nonce_json = self.wallet_service.get(wallet_handle, &format!("nonce::{}::{}", id, claim_request.prover_did))?;
+        let mut issued: Vec<u32> = Vec::new();
+        let mut results: Vec<Value> = Vec::new();
+
+        for batch_claim_request in claim_requests {
+            let res = self._new_batch_claim(wallet_handle,
+                                            &id,
+                                            &claim_def,
+                                            &private_key,
+                                            rev_reg_pub.as_mut(),
+                                            rev_reg_priv.as_ref(),
+                                            rev_reg_indices.as_mut(),
+                                            batch_claim_request);
+
+            let result = match res {
+                Ok((claim, rev_idx)) => {
+                    issued.extend(rev_idx);
+
+                    let claim = serde_json::to_value(&claim)
+                        .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claim: {:?}", err)))?;
+                    json!({"claim": claim})
+                }
+                Err(err) => json!({"error_code": err.to_error_code() as i32, "error": err.to_string()})
+            };
+
+            results.push(result);
+        }
+
+        let revocation_registry_delta_json = match (prev_accum, rev_reg_pub.as_ref()) {
+            (Some(prev_accum), Some(rev_reg_pub)) => {
+                let delta = RevocationRegistryDelta {
+                    prev_accum,
+                    accum: rev_reg_pub.accumulator()?,
+                    issued,
+                    revoked: Vec::new()
+                };
+
+                delta.to_json()
+                    .map_err(|err| CommonError::InvalidState(format!("Cannot serialize revocation registry delta: {:?}", err)))?
+            }
+            _ => String::new()
+        };
+
+        self._store_revocation_registry(wallet_handle, &id, rev_reg_pub, rev_reg_indices)?;
+
+        let results_json = serde_json::to_string(&results)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claims: {:?}", err)))?;
+
+        info!("new_claims <<< revocation_registry_delta_json: {:?}, results_json: {:?}", revocation_registry_delta_json, results_json);
+
+        Ok((revocation_registry_delta_json, results_json))
+    }
+
+    fn _new_batch_claim(&self,
+                        wallet_handle: i32,
+                        id: &str,
+                        claim_def: &ClaimDefinition,
+                        private_key: &IssuerPrivateKey,
+                        rev_reg_pub: Option<&mut RevocationRegistry>,
+                        rev_reg_priv: Option<&RevocationRegistryPrivate>,
+                        mut rev_reg_indices: Option<&mut RevocationRegistryIndices>,
+                        batch_claim_request: BatchClaimRequest) -> Result<(Claim, Option<u32>), IndyError> {
+        let claim_request = batch_claim_request.claim_request;
+
+        if get_claim_def_composite_id(&claim_request.issuer_did, &claim_request.schema_key,
+                                      claim_request.claim_def_tag.as_ref().map(String::as_str)) != id {
+            return Err(IndyError::CommonError(CommonError::InvalidStructure(
+                format!("Claim request doesn't correspond to claim definition of the batch: {}", id))));
+        }
+
+        let rev_idx = match rev_reg_indices {
+            Some(ref mut rev_reg_indices) => Some(IssuerCommandExecutor::_allocate_revocation_index(id, rev_reg_indices, None)?),
+            None => None
+        };
+
+        let claim = self._new_claim(wallet_handle,
+                                    claim_def,
+                                    private_key,
+                                    rev_reg_pub,
+                                    rev_reg_priv,
+                                    claim_request,
+                                    &batch_claim_request.claim_values,
+                                    rev_idx)?;
+
+        if let (Some(rev_reg_indices), Some(rev_idx)) = (rev_reg_indices, rev_idx) {
+            let claim_json = claim.to_json()
+                .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claim: {:?}", err)))?;
+
+            self._record_revocation_index(wallet_handle, id, rev_reg_indices, rev_idx, &claim_json)?;
+        }
+
+        Ok((claim, rev_idx))
+    }
+
+    /// Returns requested revocation index if it is free or the lowest free one if nothing is requested.
+    fn _allocate_revocation_index(id: &str,
+                                  rev_reg_indices: &mut RevocationRegistryIndices,
+                                  rev_idx: Option<u32>) -> Result<u32, IndyError> {
+        match rev_idx {
+            Some(rev_idx) if rev_reg_indices.is_free(rev_idx) => Ok(rev_idx),
+            Some(rev_idx) => Err(IndyError::AnoncredsError(AnoncredsError::InvalidUserRevocIndex(
+                format!("Revocation index {} is already used or out of range in registry: {}", rev_idx, id)))),
+            None => rev_reg_indices.next_free()
+                .ok_or(IndyError::AnoncredsError(AnoncredsError::RevocationRegistryFull(format!("Revocation registry is full: {}", id))))
+        }
+    }
+
+    /// Marks revocation index as issued and keeps issued claim under this index.
+    fn _record_revocation_index(&self,
+                                wallet_handle: i32,
+                                id: &str,
+                                rev_reg_indices: &mut RevocationRegistryIndices,
+                                rev_idx: u32,
+                                claim_json: &str) -> Result<(), IndyError> {
+        self.wallet_service.set(wallet_handle, &format!("revocation_registry_claim::{}::{}", id, rev_idx), claim_json)?;
+        rev_reg_indices.issued.insert(rev_idx);
+        Ok(())
+    }
+
+    fn _new_claim(&self,
+                  wallet_handle: i32,
+                  claim_def: &ClaimDefinition,
+                  private_key: &IssuerPrivateKey,
+                  rev_reg_pub: Option<&mut RevocationRegistry>,
+                  rev_reg_priv: Option<&RevocationRegistryPrivate>,
+                  claim_request: ClaimRequest,
+                  claim_values: &HashMap<String, Vec<String>>,
+                  rev_idx: Option<u32>) -> Result<Claim, IndyError> {
+        let id = get_claim_def_composite_id(&claim_request.issuer_did, &claim_request.schema_key,
+                                            claim_request.claim_def_tag.as_ref().map(String::as_str));
+
+        // Nonce is removed when the offer is withdrawn or fulfilled, so claim request for unknown offer is rejected here
+        let nonce_json = self._get_claim_offer_nonce(wallet_handle, &id, &claim_request.prover_did)?;
+        self._check_claim_offer_expiration(wallet_handle, &id, &claim_request.prover_did)?;
+
         let nonce = Nonce::from_json(&nonce_json)
             .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize nonce: {:?}", err)))?;
 
-        let claim_values: HashMap<String, Vec<String>> = serde_json::from_str(claim_json)
-            .map_err(|err| CommonError::InvalidStructure(format!("Cannon deserialize claim values: {:?}", err)))?;
+        let claim_values = encode_claim_values(claim_def.data.attr_encoding.as_ref().map(String::as_str), claim_values)?;
 
         let (claim_signature, signature_correctness_proof) = self.anoncreds_service.issuer.new_claim(&claim_def.data,
-                                                                                                     &private_key,
-                                                                                                     rev_reg_pub.as_mut(),
-                                                                                                     rev_reg_priv.as_ref(),
+                                                                                                     private_key,
+                                                                                                     rev_reg_pub,
+                                                                                                     rev_reg_priv,
                                                                                                      &nonce,
                                                                                                      &claim_request,
                                                                                                      &claim_values,
                                                                                                      rev_idx)?;
 
-        let revocation_registry_json = match rev_reg_pub {
-            Some(rev_reg) => {
-                let rev_reg_json = rev_reg.to_json()
-                    .map_err(|err| CommonError::InvalidState(format!("Cannon serialize revocation registry: {:?}", err)))?;
-
-                let id = get_composite_id(&claim_request.issuer_did, &claim_request.schema_key);
-                self.wallet_service.set(wallet_handle, &format!("revocation_registry::{}", &id), &rev_reg_json)?;
+        // Claim offer is redeemed only once
+        self._remove_claim_offer(wallet_handle, &id, &claim_request.prover_did)?;
 
-                rev_reg_json
-            }
-            None => String::new()
-        };
-
-        let claim = Claim {
+        Ok(Claim {
             values: claim_values,
             signature: claim_signature,
             signature_correctness_proof,
             schema_key: claim_request.schema_key,
             issuer_did: claim_request.issuer_did,
-            rev_reg_seq_no: None // TODO: How Issuer gets rev_reg_seq_no
+            rev_reg_seq_no: None, // TODO: How Issuer gets rev_reg_seq_no
+            claim_def_tag: claim_request.claim_def_tag
+        })
+    }
+
+    fn _check_claim_offer_expiration(&self, wallet_handle: i32, id: &str, prover_did: &str) -> Result<(), IndyError> {
+        // Offers created before expiration was introduced have no expiration record
+        let expires_at_json = match self.wallet_service.get(wallet_handle, &format!("claim_offer_expiration::{}::{}", id, prover_did)) {
+            Ok(expires_at_json) => expires_at_json,
+            Err(WalletError::NotFound(_)) => return Ok(()),
+            Err(err) => return Err(IndyError::from(err))
         };
 
-        let claim_json = claim.to_json()
-            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claim: {:?}", err)))?;
+        let expires_at: Option<i64> = serde_json::from_str(&expires_at_json)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize claim offer expiration: {:?}", err)))?;
 
-        info!("new_claim <<< revocation_registry_json: {:?}, claim_json: {:?}", revocation_registry_json, claim_json);
+        match expires_at {
+            Some(expires_at) if time::get_time().sec > expires_at => {
+                self._remove_claim_offer(wallet_handle, id, prover_did)?;
 
-        Ok((revocation_registry_json, claim_json))
+                Err(IndyError::AnoncredsError(AnoncredsError::ClaimOfferExpired(
+                    format!("Claim offer {} for prover {} expired at {}", id, prover_did, expires_at))))
+            }
+            _ => Ok(())
+        }
+    }
+
+    fn _get_claim_offer_nonce(&self, wallet_handle: i32, id: &str, prover_did: &str) -> Result<String, IndyError> {
+        match self.wallet_service.get(wallet_handle, &format!("nonce::{}::{}", id, prover_did)) {
+            Ok(nonce_json) => Ok(nonce_json),
+            Err(WalletError::NotFound(_)) => Err(IndyError::AnoncredsError(AnoncredsError::ClaimOfferNotFound(
+                format!("There is no outstanding claim offer {} for prover {}", id, prover_did)))),
+            Err(err) => Err(IndyError::from(err))
+        }
+    }
+
+    fn _remove_claim_offer(&self, wallet_handle: i32, id: &str, prover_did: &str) -> Result<(), IndyError> {
+        for key in [format!("nonce::{}::{}", id, prover_did), format!("claim_offer_expiration::{}::{}", id, prover_did)].iter() {
+            match self.wallet_service.remove(wallet_handle, key) {
+                Ok(()) | Err(WalletError::NotFound(_)) => {}
+                Err(err) => return Err(IndyError::from(err))
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes offers of the claim definition that expired and so can't be fulfilled anymore.
+    fn _purge_expired_claim_offers(&self, wallet_handle: i32, id: &str) -> Result<(), IndyError> {
+        let prefix = format!("claim_offer_expiration::{}::", id);
+        let now = time::get_time().sec;
+
+        for (key, expires_at_json) in self.wallet_service.list(wallet_handle, &prefix)? {
+            // Listed prefix is a LIKE pattern, so it can match other keys as well
+            if !key.starts_with(&prefix) {
+                continue;
+            }
+
+            let expires_at: Option<i64> = serde_json::from_str(&expires_at_json)
+                .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize claim offer expiration: {:?}", err)))?;
+
+            match expires_at {
+                Some(expires_at) if now > expires_at => self._remove_claim_offer(wallet_handle, id, &key[prefix.len()..])?,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn _get_claim_definition_with_private_key(&self, wallet_handle: i32, id: &str) -> Result<(ClaimDefinition, IssuerPrivateKey), IndyError> {
+        let claim_def_json = self.wallet_service.get(wallet_handle, &format!("claim_definition::{}", id))?;
+        let claim_def: ClaimDefinition = ClaimDefinition::from_json(&claim_def_json)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize claim definition: {:?}", err)))?;
+
+        let private_key_json = self.wallet_service.get(wallet_handle, &format!("claim_definition_private_key::{}", id))?;
+        let private_key = IssuerPrivateKey::from_json(&private_key_json)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize claim definition private key: {:?}", err)))?;
+
+        Ok((claim_def, private_key))
+    }
+
+    fn _get_revocation_registry_with_private(&self, wallet_handle: i32, id: &str)
+                                             -> Result<(Option<RevocationRegistry>, Option<RevocationRegistryPrivate>), IndyError> {
+        let rev_reg_pub = match self.wallet_service.get(wallet_handle, &format!("revocation_registry::{}", id)) {
+            Ok(rev_reg_pub_json) =>
+                Some(revocation_registry_from_json(&self.blob_storage_service,
+                                                   &self._get_tails_config(wallet_handle, id)?,
+                                                   &rev_reg_pub_json)?),
+            Err(_) => None
+        };
+
+        let rev_reg_priv = match self.wallet_service.get(wallet_handle, &format!("revocation_registry_private::{}", id)) {
+            Ok(rev_reg_priv_json) =>
+                Some(RevocationRegistryPrivate::from_json(&rev_reg_priv_json)
+                    .map_err(|err| CommonError::InvalidState(format!("Cannon deserialize revocation registry private: {:?}", err)))?),
+            Err(_) => None
+        };
+
+        Ok((rev_reg_pub, rev_reg_priv))
+    }
+
+    /// Indices of registries created before indices were tracked are restored from the registry
+    /// and stored with the next registry update.
+    fn _get_revocation_registry_indices(&self, wallet_handle: i32, id: &str, rev_reg: &RevocationRegistry)
+                                        -> Result<RevocationRegistryIndices, IndyError> {
+        match self.wallet_service.get(wallet_handle, &format!("revocation_registry_indices::{}", id)) {
+            Ok(rev_reg_indices_json) =>
+                Ok(RevocationRegistryIndices::from_json(&rev_reg_indices_json)
+                    .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize revocation registry indices: {:?}", err)))?),
+            Err(WalletError::NotFound(_)) => {
+                warn!("Revocation indices of registry {} aren't tracked, restoring them from the registry", id);
+                Ok(RevocationRegistryIndices::from_registry(rev_reg)?)
+            }
+            Err(err) => Err(IndyError::from(err))
+        }
+    }
+
+    /// Stores updated revocation registry and its indices. Returns revocation registry json
+    /// or empty string if there is no revocation registry.
+    fn _store_revocation_registry(&self,
+                                  wallet_handle: i32,
+                                  id: &str,
+                                  rev_reg_pub: Option<RevocationRegistry>,
+                                  rev_reg_indices: Option<RevocationRegistryIndices>) -> Result<String, IndyError> {
+        if let Some(rev_reg_indices) = rev_reg_indices {
+            let rev_reg_indices_json = rev_reg_indices.to_json()
+                .map_err(|err| CommonError::InvalidState(format!("Cannot serialize revocation registry indices: {:?}", err)))?;
+
+            self.wallet_service.set(wallet_handle, &format!("revocation_registry_indices::{}", id), &rev_reg_indices_json)?;
+        }
+
+        match rev_reg_pub {
+            Some(mut rev_reg) => {
+                let rev_reg_json = revocation_registry_to_json(&self.blob_storage_service,
+                                                               &self._get_tails_config(wallet_handle, id)?,
+                                                               &mut rev_reg)?;
+
+                self.wallet_service.set(wallet_handle, &format!("revocation_registry::{}", id), &rev_reg_json)?;
+
+                Ok(rev_reg_json)
+            }
+            None => Ok(String::new())
+        }
     }
 
     fn revoke_claim(&self,
                     wallet_handle: i32,
                     issuer_did: &str,
                     schema_json: &str,
+                    claim_def_tag: Option<&str>,
                     user_revoc_index: u32) -> Result<String, IndyError> {
-        info!("revoke_claim >>> wallet_handle: {:?}, issuer_did: {:?}, schema_json: {:?}, user_revoc_index: {:?}",
-              wallet_handle, issuer_did, schema_json, user_revoc_index);
+        info!("revoke_claim >>> wallet_handle: {:?}, issuer_did: {:?}, schema_json: {:?}, claim_def_tag: {:?}, user_revoc_index: {:?}",
+              wallet_handle, issuer_did, schema_json, claim_def_tag, user_revoc_index);
+
+        let id = IssuerCommandExecutor::_get_schema_claim_definition_id(issuer_did, schema_json, claim_def_tag)?;
+
+        let (mut revocation_registry, mut revocation_registry_indices) =
+            self._get_revocation_registry_for_update(wallet_handle, &id)?;
+
+        if !revocation_registry_indices.issued.contains(&user_revoc_index) {
+            return Err(IndyError::AnoncredsError(AnoncredsError::InvalidUserRevocIndex(
+                format!("Revocation index {} isn't issued in registry: {}", user_revoc_index, id))));
+        }
+
+        if revocation_registry_indices.revoked.contains(&user_revoc_index) {
+            return Err(IndyError::AnoncredsError(AnoncredsError::ClaimRevoked(
+                format!("Claim with revocation index {} is already revoked in registry: {}", user_revoc_index, id))));
+        }
+
+        revocation_registry_indices.revoked.insert(user_revoc_index);
+
+        self.anoncreds_service.issuer.revoke(&mut revocation_registry, user_revoc_index)?;
+
+        let revocation_registry_updated_json =
+            self._store_revocation_registry(wallet_handle, &id, Some(revocation_registry), Some(revocation_registry_indices))?;
+
+        info!("revoke_claim <<< revocation_registry_updated_json: {:?}", revocation_registry_updated_json);
+
+        Ok(revocation_registry_updated_json)
+    }
+
+    fn recover_claim(&self,
+                     wallet_handle: i32,
+                     issuer_did: &str,
+                     schema_json: &str,
+                     claim_def_tag: Option<&str>,
+                     user_revoc_index: u32) -> Result<String, IndyError> {
+        info!("recover_claim >>> wallet_handle: {:?}, issuer_did: {:?}, schema_json: {:?}, claim_def_tag: {:?}, user_revoc_index: {:?}",
+              wallet_handle, issuer_did, schema_json, claim_def_tag, user_revoc_index);
+
+        let id = IssuerCommandExecutor::_get_schema_claim_definition_id(issuer_did, schema_json, claim_def_tag)?;
+
+        let (mut revocation_registry, mut revocation_registry_indices) =
+            self._get_revocation_registry_for_update(wallet_handle, &id)?;
+
+        if !revocation_registry_indices.issued.contains(&user_revoc_index) {
+            return Err(IndyError::AnoncredsError(AnoncredsError::InvalidUserRevocIndex(
+                format!("Revocation index {} isn't issued in registry: {}", user_revoc_index, id))));
+        }
+
+        if !revocation_registry_indices.revoked.remove(&user_revoc_index) {
+            return Err(IndyError::AnoncredsError(AnoncredsError::InvalidUserRevocIndex(
+                format!("Claim with revocation index {} isn't revoked in registry: {}", user_revoc_index, id))));
+        }
+
+        self.anoncreds_service.issuer.recover(&mut revocation_registry, user_revoc_index)?;
+
+        let revocation_registry_updated_json =
+            self._store_revocation_registry(wallet_handle, &id, Some(revocation_registry), Some(revocation_registry_indices))?;
+
+        info!("recover_claim <<< revocation_registry_updated_json: {:?}", revocation_registry_updated_json);
+
+        Ok(revocation_registry_updated_json)
+    }
+
+    fn get_revocation_registry_indices(&self,
+                                       wallet_handle: i32,
+                                       issuer_did: &str,
+                                       schema_json: &str,
+                                       claim_def_tag: Option<&str>) -> Result<String, IndyError> {
+        info!("get_revocation_registry_indices >>> wallet_handle: {:?}, issuer_did: {:?}, schema_json: {:?}, claim_def_tag: {:?}",
+              wallet_handle, issuer_did, schema_json, claim_def_tag);
+
+        let id = IssuerCommandExecutor::_get_schema_claim_definition_id(issuer_did, schema_json, claim_def_tag)?;
+
+        let revocation_registry_indices_json = match self.wallet_service.get(wallet_handle, &format!("revocation_registry_indices::{}", id)) {
+            Ok(revocation_registry_indices_json) => revocation_registry_indices_json,
+            Err(WalletError::NotFound(_)) => {
+                let (_, revocation_registry_indices) = self._get_revocation_registry_for_update(wallet_handle, &id)?;
+
+                revocation_registry_indices.to_json()
+                    .map_err(|err| CommonError::InvalidState(format!("Cannot serialize revocation registry indices: {:?}", err)))?
+            }
+            Err(err) => return Err(IndyError::from(err))
+        };
+
+        info!("get_revocation_registry_indices <<< revocation_registry_indices_json: {:?}", revocation_registry_indices_json);
 
+        Ok(revocation_registry_indices_json)
+    }
+
+    /// Claims issued with a claim definition are managed by its tag even after another one is activated.
+    fn _get_schema_claim_definition_id(issuer_did: &str, schema_json: &str, claim_def_tag: Option<&str>) -> Result<String, IndyError> {
         let schema: Schema = Schema::from_json(schema_json)
             .map_err(|err| CommonError::InvalidStructure(format!("Invalid schema json: {}", err.to_string())))?;
         let schema_key = SchemaKey { name: schema.data.name.clone(), version: schema.data.version.clone(), did: schema.dest.clone() };
 
-        let id = get_composite_id(issuer_did, &schema_key);
+        Ok(get_claim_def_composite_id(issuer_did, &schema_key, claim_def_tag))
+    }
 
-        let revocation_registry_json = self.wallet_service.get(wallet_handle, &format!("revocation_registry::{}", id))?;
-        let mut revocation_registry = RevocationRegistry::from_json(&revocation_registry_json)
-            .map_err(|err| CommonError::InvalidState(format!("Cannon deserialize revocation registry: {:?}", err)))?;
+    /// Returns tag and wallet id of the claim definition currently used for the schema.
+    /// Claim definitions created before tags were introduced have no active tag record and are untagged.
+    fn _get_active_claim_definition_id(&self, wallet_handle: i32, issuer_did: &str, schema_key: &SchemaKey)
+                                       -> Result<(Option<String>, String), IndyError> {
+        let schema_id = get_composite_id(issuer_did, schema_key);
+
+        let tag: Option<String> = match self.wallet_service.get(wallet_handle, &format!("claim_definition_active_tag::{}", schema_id)) {
+            Ok(tag_json) => serde_json::from_str(&tag_json)
+                .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize active claim definition tag: {:?}", err)))?,
+            Err(WalletError::NotFound(_)) => None,
+            Err(err) => return Err(IndyError::from(err))
+        };
 
-        let revocation_registry_private_json = self.wallet_service.get(wallet_handle, &format!("revocation_registry_private::{}", id))?;
-        let revocation_registry_private = RevocationRegistryPrivate::from_json(&revocation_registry_private_json)
-            .map_err(|err| CommonError::InvalidState(format!("Cannon deserialize  revocation registry private: {:?}", err)))?;
+        let id = get_claim_def_composite_id(issuer_did, schema_key, tag.as_ref().map(String::as_str));
 
-        self.anoncreds_service.issuer.revoke(&mut revocation_registry, user_revoc_index)?;
+        Ok((tag, id))
+    }
 
-        let revocation_registry_updated_json = revocation_registry.to_json()
-            .map_err(|err| CommonError::InvalidState(format!("Cannon serialize revocation registry: {:?}", err)))?;
+    fn _set_active_claim_definition_tag(&self, wallet_handle: i32, schema_id: &str, tag: Option<&str>) -> Result<(), IndyError> {
+        let tag_json = serde_json::to_string(&tag)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize active claim definition tag: {:?}", err)))?;
 
-        self.wallet_service.set(wallet_handle, &format!("revocation_registry::{}", id), &revocation_registry_updated_json)?;
+        self.wallet_service.set(wallet_handle, &format!("claim_definition_active_tag::{}", schema_id), &tag_json)?;
 
-        info!("revoke_claim <<< revocation_registry_updated_json: {:?}", revocation_registry_updated_json);
+        Ok(())
+    }
 
-        Ok(revocation_registry_updated_json)
+    fn _get_claim_definition_lineage(&self, wallet_handle: i32, schema_id: &str) -> Result<Vec<ClaimDefinitionLineageEntry>, IndyError> {
+        match self.wallet_service.get(wallet_handle, &format!("claim_definition_lineage::{}", schema_id)) {
+            Ok(lineage_json) => Ok(serde_json::from_str(&lineage_json)
+                .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize claim definition lineage: {:?}", err)))?),
+            Err(WalletError::NotFound(_)) => Ok(Vec::new()),
+            Err(err) => Err(IndyError::from(err))
+        }
+    }
+
+    fn _store_claim_definition_lineage(&self, wallet_handle: i32, schema_id: &str, lineage: &Vec<ClaimDefinitionLineageEntry>) -> Result<(), IndyError> {
+        let lineage_json = serde_json::to_string(lineage)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claim definition lineage: {:?}", err)))?;
+
+        self.wallet_service.set(wallet_handle, &format!("claim_definition_lineage::{}", schema_id), &lineage_json)?;
+
+        Ok(())
+    }
+
+    /// Returns config of blob storage tails of revocation registry were written to.
+    /// Registries created without config use default storage.
+    fn _get_tails_config(&self, wallet_handle: i32, id: &str) -> Result<(String, String), IndyError> {
+        match self.wallet_service.get(wallet_handle, &format!("revocation_registry_tails_config::{}", id)) {
+            Ok(tails_config_json) => Ok(parse_blob_storage_config(Some(&tails_config_json))?),
+            Err(WalletError::NotFound(_)) => Ok(parse_blob_storage_config(None)?),
+            Err(err) => Err(IndyError::from(err))
+        }
+    }
+
+    /// Loads revocation registry and its indices, both private and public parts of registry must exist.
+    fn _get_revocation_registry_for_update(&self, wallet_handle: i32, id: &str)
+                                           -> Result<(RevocationRegistry, RevocationRegistryIndices), IndyError> {
+        let revocation_registry_json = self.wallet_service.get(wallet_handle, &format!("revocation_registry::{}", id))?;
+        let revocation_registry = revocation_registry_from_json(&self.blob_storage_service,
+                                                                &self._get_tails_config(wallet_handle, id)?,
+                                                                &revocation_registry_json)?;
+
+        self.wallet_service.get(wallet_handle, &format!("revocation_registry_private::{}", id))?;
+
+        let revocation_registry_indices = self._get_revocation_registry_indices(wallet_handle, id, &revocation_registry)?;
+
+        Ok((revocation_registry, revocation_registry_indices))
     }
 }
@@ -1,3 +1,5 @@
+extern crate serde_json;
+
 pub mod issuer;
 pub mod prover;
 pub mod verifier;
@@ -6,10 +8,16 @@ use commands::anoncreds::issuer::{IssuerCommand, IssuerCommandExecutor};
 use commands::anoncreds::prover::{ProverCommand, ProverCommandExecutor};
 use commands::anoncreds::verifier::{VerifierCommand, VerifierCommandExecutor};
 
+use errors::common::CommonError;
 use services::anoncreds::AnoncredsService;
+use services::anoncreds::types::RevocationRegistry;
+use services::blob_storage::BlobStorageService;
+use services::ledger::LedgerService;
 use services::pool::PoolService;
 use services::wallet::WalletService;
 
+use self::serde_json::Value;
+use std::collections::HashMap;
 use std::rc::Rc;
 
 pub enum AnoncredsCommand {
@@ -26,15 +34,17 @@ pub struct AnoncredsCommandExecutor {
 
 impl AnoncredsCommandExecutor {
     pub fn new(anoncreds_service: Rc<AnoncredsService>,
+               blob_storage_service: Rc<BlobStorageService>,
                pool_service: Rc<PoolService>,
-               wallet_service: Rc<WalletService>) -> AnoncredsCommandExecutor {
+               wallet_service: Rc<WalletService>,
+               ledger_service: Rc<LedgerService>) -> AnoncredsCommandExecutor {
         AnoncredsCommandExecutor {
             issuer_command_cxecutor: IssuerCommandExecutor::new(
-                anoncreds_service.clone(), pool_service.clone(), wallet_service.clone()),
+                anoncreds_service.clone(), blob_storage_service.clone(), pool_service.clone(), wallet_service.clone()),
             prover_command_cxecutor: ProverCommandExecutor::new(
-                anoncreds_service.clone(), wallet_service.clone()),
+                anoncreds_service.clone(), blob_storage_service.clone(), wallet_service.clone()),
             verifier_command_cxecutor: VerifierCommandExecutor::new(
-                anoncreds_service.clone()),
+                anoncreds_service.clone(), blob_storage_service.clone(), ledger_service.clone()),
         }
     }
 
@@ -54,4 +64,92 @@ impl AnoncredsCommandExecutor {
             }
         };
     }
-}
\ No newline at end of file
+}
+
+/// Serializes revocation registry with tails moved to blob storage. Tails don't change after
+/// registry creation, so they are written once and then referenced by their hash only.
+/// Readers find tails by the hash in their own storage, so no issuer-local location is published.
+pub fn revocation_registry_to_json(blob_storage_service: &BlobStorageService,
+                                   tails_writer_config: &(String, String),
+                                   rev_reg: &mut RevocationRegistry) -> Result<String, CommonError> {
+    let mut rev_reg_value = serde_json::to_value(&*rev_reg)
+        .map_err(|err| CommonError::InvalidState(format!("Cannot serialize revocation registry: {:?}", err)))?;
+
+    let tails = _revocation_registry_data(&mut rev_reg_value)?
+        .remove("tails")
+        .ok_or(CommonError::InvalidState(format!("Revocation registry tails not found")))?;
+
+    if rev_reg.tails_hash.is_none() {
+        let (ref xtype, ref config) = *tails_writer_config;
+
+        let mut writer = blob_storage_service.open_writer(xtype, config)?;
+        serde_json::to_writer(&mut writer, &tails)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot write revocation registry tails: {:?}", err)))?;
+        let hash = writer.finalize()?;
+
+        if let Some(rev_reg_map) = rev_reg_value.as_object_mut() {
+            rev_reg_map.insert("tails_hash".to_string(), Value::String(hash.clone()));
+        }
+
+        rev_reg.tails_hash = Some(hash);
+    }
+
+    serde_json::to_string(&rev_reg_value)
+        .map_err(|err| CommonError::InvalidState(format!("Cannot serialize revocation registry: {:?}", err)))
+}
+
+/// Deserializes revocation registry reading its tails by hash from blob storage of the reader
+/// if they aren't inlined. Read tails are checked against the hash.
+pub fn revocation_registry_from_json(blob_storage_service: &BlobStorageService,
+                                     tails_reader_config: &(String, String),
+                                     rev_reg_json: &str) -> Result<RevocationRegistry, CommonError> {
+    let mut rev_reg_value: Value = serde_json::from_str(rev_reg_json)
+        .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize revocation registry: {:?}", err)))?;
+
+    if !_revocation_registry_data(&mut rev_reg_value)?.contains_key("tails") {
+        let tails = {
+            let hash = rev_reg_value["tails_hash"].as_str()
+                .ok_or(CommonError::InvalidStructure(format!("Revocation registry tails hash not found")))?;
+
+            let (ref xtype, ref config) = *tails_reader_config;
+
+            let mut reader = blob_storage_service.open_reader(xtype, config, hash)?;
+            let tails: Value = serde_json::from_reader(&mut reader)
+                .map_err(|err| CommonError::InvalidState(format!("Cannot read revocation registry tails: {:?}", err)))?;
+            reader.finalize()?;
+
+            tails
+        };
+
+        _revocation_registry_data(&mut rev_reg_value)?.insert("tails".to_string(), tails);
+    }
+
+    serde_json::from_value(rev_reg_value)
+        .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize revocation registry: {:?}", err)))
+}
+
+/// Deserializes `{referent: revocation registry}` map reading tails of each registry from blob storage.
+pub fn revocation_registries_from_json(blob_storage_service: &BlobStorageService,
+                                       tails_reader_config: &(String, String),
+                                       rev_regs_json: &str) -> Result<HashMap<String, RevocationRegistry>, CommonError> {
+    let rev_regs: HashMap<String, Value> = serde_json::from_str(rev_regs_json)
+        .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize list of revocation registries: {:?}", err)))?;
+
+    let mut res: HashMap<String, RevocationRegistry> = HashMap::new();
+
+    for (referent, rev_reg) in rev_regs {
+        let rev_reg_json = serde_json::to_string(&rev_reg)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize revocation registry: {:?}", err)))?;
+
+        res.insert(referent, revocation_registry_from_json(blob_storage_service, tails_reader_config, &rev_reg_json)?);
+    }
+
+    Ok(res)
+}
+
+fn _revocation_registry_data(rev_reg_value: &mut Value) -> Result<&mut serde_json::Map<String, Value>, CommonError> {
+    rev_reg_value.as_object_mut()
+        .and_then(|rev_reg| rev_reg.get_mut("data"))
+        .and_then(|data| data.as_object_mut())
+        .ok_or(CommonError::InvalidStructure(format!("Revocation registry data not found")))
+}
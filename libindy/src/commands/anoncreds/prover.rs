@@ -1,17 +1,25 @@
 extern crate serde_json;
+extern crate time;
 extern crate uuid;
 extern crate indy_crypto;
 
 use errors::common::CommonError;
 use errors::indy::IndyError;
 use errors::anoncreds::AnoncredsError;
+use errors::wallet::WalletError;
 use services::anoncreds::AnoncredsService;
+use services::blob_storage::{BlobStorageService, parse_blob_storage_config};
 use services::wallet::WalletService;
+use std::cell::RefCell;
 use std::rc::Rc;
-use services::anoncreds::helpers::get_composite_id;
+use services::anoncreds::helpers::{claim_satisfy_restriction, compare_versions, get_claim_def_composite_id};
 use services::anoncreds::types::*;
-use std::collections::{HashMap, HashSet};
+use super::{revocation_registry_from_json, revocation_registries_from_json};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet, VecDeque};
+use self::serde_json::Value;
 use utils::crypto::base58::Base58;
+use utils::sequence::SequenceUtils;
 use self::indy_crypto::cl::*;
 use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
 use self::uuid::Uuid;
@@ -29,6 +37,20 @@ pub enum ProverCommand {
         i32, // wallet handle
         String, // master secret name
         Box<Fn(Result<(), IndyError>) + Send>),
+    ListMasterSecrets(
+        i32, // wallet handle
+        Box<Fn(Result<String, IndyError>) + Send>),
+    ExportMasterSecret(
+        i32, // wallet handle
+        String, // master secret name
+        String, // passphrase
+        Box<Fn(Result<String, IndyError>) + Send>),
+    ImportMasterSecret(
+        i32, // wallet handle
+        String, // master secret name
+        String, // exported master secret json
+        String, // passphrase
+        Box<Fn(Result<(), IndyError>) + Send>),
     CreateAndStoreClaimRequest(
         i32, // wallet handle
         String, // prover did
@@ -40,7 +62,21 @@ pub enum ProverCommand {
         i32, // wallet handle
         String, // claims json
         Option<String>, // revocation registry json
+        Option<String>, // tails reader config json
         Box<Fn(Result<(), IndyError>) + Send>),
+    DeleteClaim(
+        i32, // wallet handle
+        String, // claim referent
+        Box<Fn(Result<(), IndyError>) + Send>),
+    SetClaimMetadata(
+        i32, // wallet handle
+        String, // claim referent
+        Option<String>, // metadata
+        Box<Fn(Result<(), IndyError>) + Send>),
+    GetClaimMetadata(
+        i32, // wallet handle
+        String, // claim referent
+        Box<Fn(Result<String, IndyError>) + Send>),
     GetClaims(
         i32, // wallet handle
         String, // filter json
@@ -49,6 +85,34 @@ pub enum ProverCommand {
         i32, // wallet handle
         String, // proof request json
         Box<Fn(Result<String, IndyError>) + Send>),
+    SelectClaimsForProofReq(
+        i32, // wallet handle
+        String, // proof request json
+        Option<String>, // self attested values json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    SearchClaims(
+        i32, // wallet handle
+        String, // filter json
+        Box<Fn(Result<i32, IndyError>) + Send>),
+    FetchClaims(
+        i32, // search handle
+        usize, // count
+        Box<Fn(Result<String, IndyError>) + Send>),
+    CloseClaimsSearch(
+        i32, // search handle
+        Box<Fn(Result<(), IndyError>) + Send>),
+    SearchClaimsForProofReq(
+        i32, // wallet handle
+        String, // proof request json
+        Box<Fn(Result<i32, IndyError>) + Send>),
+    FetchClaimsForProofReq(
+        i32, // search handle
+        String, // item referent
+        usize, // count
+        Box<Fn(Result<String, IndyError>) + Send>),
+    CloseClaimsSearchForProofReq(
+        i32, // search handle
+        Box<Fn(Result<(), IndyError>) + Send>),
     CreateProof(
         i32, // wallet handle
         String, // proof request json
@@ -57,20 +121,52 @@ pub enum ProverCommand {
         String, // master secret name
         String, // claim defs json
         String, // revoc regs json
+        Option<String>, // tails reader config json
         Box<Fn(Result<String, IndyError>) + Send>),
 }
 
+// Number of wallet records read at once while searching claims
+const CLAIMS_SEARCH_PAGE_SIZE: usize = 100;
+
+/// Position of claims search. Read but not yet fetched claims are kept in `pending`,
+/// as wallets without key range queries return all remaining claims in one page.
+#[derive(Default)]
+struct ClaimsCursor {
+    from_key: Option<String>,
+    pending: VecDeque<(String, String)>,
+    is_done: bool
+}
+
+struct ClaimsSearch {
+    wallet_handle: i32,
+    filter: Restriction,
+    cursor: ClaimsCursor
+}
+
+struct ClaimsForProofReqSearch {
+    wallet_handle: i32,
+    proof_req: ProofRequest,
+    cursors: HashMap<String, ClaimsCursor>
+}
+
 pub struct ProverCommandExecutor {
     anoncreds_service: Rc<AnoncredsService>,
-    wallet_service: Rc<WalletService>
+    blob_storage_service: Rc<BlobStorageService>,
+    wallet_service: Rc<WalletService>,
+    claims_searches: RefCell<HashMap<i32, ClaimsSearch>>,
+    claims_for_proof_req_searches: RefCell<HashMap<i32, ClaimsForProofReqSearch>>
 }
 
 impl ProverCommandExecutor {
     pub fn new(anoncreds_service: Rc<AnoncredsService>,
+               blob_storage_service: Rc<BlobStorageService>,
                wallet_service: Rc<WalletService>) -> ProverCommandExecutor {
         ProverCommandExecutor {
             anoncreds_service,
+            blob_storage_service,
             wallet_service,
+            claims_searches: RefCell::new(HashMap::new()),
+            claims_for_proof_req_searches: RefCell::new(HashMap::new()),
         }
     }
 
@@ -88,15 +184,40 @@ impl ProverCommandExecutor {
                 info!(target: "prover_command_executor", "CreateMasterSecret command received");
                 cb(self.create_master_secret(wallet_handle, &master_secret_name));
             }
+            ProverCommand::ListMasterSecrets(wallet_handle, cb) => {
+                info!(target: "prover_command_executor", "ListMasterSecrets command received");
+                cb(self.list_master_secrets(wallet_handle));
+            }
+            ProverCommand::ExportMasterSecret(wallet_handle, master_secret_name, passphrase, cb) => {
+                info!(target: "prover_command_executor", "ExportMasterSecret command received");
+                cb(self.export_master_secret(wallet_handle, &master_secret_name, &passphrase));
+            }
+            ProverCommand::ImportMasterSecret(wallet_handle, master_secret_name, exported_master_secret_json, passphrase, cb) => {
+                info!(target: "prover_command_executor", "ImportMasterSecret command received");
+                cb(self.import_master_secret(wallet_handle, &master_secret_name, &exported_master_secret_json, &passphrase));
+            }
             ProverCommand::CreateAndStoreClaimRequest(wallet_handle, prover_did, claim_offer_json,
                                                       claim_def_json, master_secret_name, cb) => {
                 info!(target: "prover_command_executor", "CreateAndStoreClaimRequest command received");
                 cb(self.create_and_store_claim_request(wallet_handle, &prover_did, &claim_offer_json,
                                                        &claim_def_json, &master_secret_name));
             }
-            ProverCommand::StoreClaim(wallet_handle, claims_json, rev_reg_json, cb) => {
+            ProverCommand::StoreClaim(wallet_handle, claims_json, rev_reg_json, tails_reader_config_json, cb) => {
                 info!(target: "prover_command_executor", "StoreClaim command received");
-                cb(self.store_claim(wallet_handle, &claims_json, rev_reg_json.as_ref().map(String::as_str)));
+                cb(self.store_claim(wallet_handle, &claims_json, rev_reg_json.as_ref().map(String::as_str),
+                                    tails_reader_config_json.as_ref().map(String::as_str)));
+            }
+            ProverCommand::DeleteClaim(wallet_handle, referent, cb) => {
+                info!(target: "prover_command_executor", "DeleteClaim command received");
+                cb(self.delete_claim(wallet_handle, &referent));
+            }
+            ProverCommand::SetClaimMetadata(wallet_handle, referent, metadata, cb) => {
+                info!(target: "prover_command_executor", "SetClaimMetadata command received");
+                cb(self.set_claim_metadata(wallet_handle, &referent, metadata.as_ref().map(String::as_str)));
+            }
+            ProverCommand::GetClaimMetadata(wallet_handle, referent, cb) => {
+                info!(target: "prover_command_executor", "GetClaimMetadata command received");
+                cb(self.get_claim_metadata(wallet_handle, &referent));
             }
             ProverCommand::GetClaims(wallet_handle, filter_json, cb) => {
                 info!(target: "prover_command_executor", "GetClaims command received");
@@ -106,11 +227,40 @@ impl ProverCommandExecutor {
                 info!(target: "prover_command_executor", "GetClaimsForProofReq command received");
                 cb(self.get_claims_for_proof_req(wallet_handle, &proof_req_json));
             }
+            ProverCommand::SelectClaimsForProofReq(wallet_handle, proof_req_json, self_attested_values_json, cb) => {
+                info!(target: "prover_command_executor", "SelectClaimsForProofReq command received");
+                cb(self.select_claims_for_proof_req(wallet_handle, &proof_req_json, self_attested_values_json.as_ref().map(String::as_str)));
+            }
+            ProverCommand::SearchClaims(wallet_handle, filter_json, cb) => {
+                info!(target: "prover_command_executor", "SearchClaims command received");
+                cb(self.search_claims(wallet_handle, &filter_json));
+            }
+            ProverCommand::FetchClaims(search_handle, count, cb) => {
+                info!(target: "prover_command_executor", "FetchClaims command received");
+                cb(self.fetch_claims(search_handle, count));
+            }
+            ProverCommand::CloseClaimsSearch(search_handle, cb) => {
+                info!(target: "prover_command_executor", "CloseClaimsSearch command received");
+                cb(self.close_claims_search(search_handle));
+            }
+            ProverCommand::SearchClaimsForProofReq(wallet_handle, proof_req_json, cb) => {
+                info!(target: "prover_command_executor", "SearchClaimsForProofReq command received");
+                cb(self.search_claims_for_proof_req(wallet_handle, &proof_req_json));
+            }
+            ProverCommand::FetchClaimsForProofReq(search_handle, item_referent, count, cb) => {
+                info!(target: "prover_command_executor", "FetchClaimsForProofReq command received");
+                cb(self.fetch_claims_for_proof_req(search_handle, &item_referent, count));
+            }
+            ProverCommand::CloseClaimsSearchForProofReq(search_handle, cb) => {
+                info!(target: "prover_command_executor", "CloseClaimsSearchForProofReq command received");
+                cb(self.close_claims_search_for_proof_req(search_handle));
+            }
             ProverCommand::CreateProof(wallet_handle, proof_req_json, requested_claims_json, schemas_jsons,
-                                       master_secret_name, claim_def_jsons, revoc_regs_jsons, cb) => {
+                                       master_secret_name, claim_def_jsons, revoc_regs_jsons, tails_reader_config_json, cb) => {
                 info!(target: "prover_command_executor", "CreateProof command received");
                 cb(self.create_proof(wallet_handle, &proof_req_json, &requested_claims_json, &schemas_jsons,
-                                     &master_secret_name, &claim_def_jsons, &revoc_regs_jsons));
+                                     &master_secret_name, &claim_def_jsons, &revoc_regs_jsons,
+                                     tails_reader_config_json.as_ref().map(String::as_str)));
             }
         };
     }
@@ -124,7 +274,8 @@ impl ProverCommandExecutor {
         Base58::decode(&claim_offer.issuer_did)
             .map_err(|err| CommonError::InvalidStructure(format!("Invalid issuer did: {:?}", err)))?;
 
-        let id = get_composite_id(&claim_offer.issuer_did, &claim_offer.schema_key);
+        let id = get_claim_def_composite_id(&claim_offer.issuer_did, &claim_offer.schema_key,
+                                            claim_offer.claim_def_tag.as_ref().map(String::as_str));
         self.wallet_service.set(wallet_handle, &format!("claim_offer::{}", &id), &claim_offer_json)?;
 
         info!("store_claim_offer <<<");
@@ -160,6 +311,9 @@ impl ProverCommandExecutor {
                 if let Some(ref version) = schema_key.version {
                     condition = condition && claim_offer.schema_key.version == version.clone();
                 }
+                if let Some(ref min_version) = schema_key.min_version {
+                    condition = condition && compare_versions(&claim_offer.schema_key.version, min_version) != Ordering::Less;
+                }
                 if let Some(ref did) = schema_key.did {
                     condition = condition && claim_offer.schema_key.did == did.clone();
                 }
@@ -194,6 +348,67 @@ impl ProverCommandExecutor {
         Ok(())
     }
 
+    fn list_master_secrets(&self, wallet_handle: i32) -> Result<String, IndyError> {
+        info!("list_master_secrets >>> wallet_handle: {:?}", wallet_handle);
+
+        let master_secret_names: Vec<String> = self.wallet_service.list(wallet_handle, "master_secret::")?
+            .into_iter()
+            .map(|(key, _)| key["master_secret::".len()..].to_string())
+            .collect();
+
+        let master_secret_names_json = serde_json::to_string(&master_secret_names)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize list of master secrets: {:?}", err)))?;
+
+        info!("list_master_secrets <<< master_secret_names_json: {:?}", master_secret_names_json);
+
+        Ok(master_secret_names_json)
+    }
+
+    fn export_master_secret(&self, wallet_handle: i32, master_secret_name: &str, passphrase: &str) -> Result<String, IndyError> {
+        info!("export_master_secret >>> wallet_handle: {:?}, master_secret_name: {:?}", wallet_handle, master_secret_name);
+
+        let master_secret_json = self.wallet_service.get(wallet_handle, &format!("master_secret::{}", master_secret_name))?;
+        let master_secret = MasterSecret::from_json(&master_secret_json)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize master secret: {:?}", err)))?;
+
+        let exported_master_secret = self.anoncreds_service.prover.export_master_secret(&master_secret, passphrase)?;
+
+        let exported_master_secret_json = exported_master_secret.to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize exported master secret: {:?}", err)))?;
+
+        info!("export_master_secret <<< exported_master_secret_json: {:?}", exported_master_secret_json);
+
+        Ok(exported_master_secret_json)
+    }
+
+    fn import_master_secret(&self,
+                            wallet_handle: i32,
+                            master_secret_name: &str,
+                            exported_master_secret_json: &str,
+                            passphrase: &str) -> Result<(), IndyError> {
+        info!("import_master_secret >>> wallet_handle: {:?}, master_secret_name: {:?}, exported_master_secret_json: {:?}",
+              wallet_handle, master_secret_name, exported_master_secret_json);
+
+        if let Ok(_) = self.wallet_service.get(wallet_handle, &format!("master_secret::{}", master_secret_name)) {
+            return Err(IndyError::AnoncredsError(
+                AnoncredsError::MasterSecretDuplicateNameError(format!("Master Secret already exists {}", master_secret_name))));
+        };
+
+        let exported_master_secret = ExportedMasterSecret::from_json(exported_master_secret_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize exported master secret: {:?}", err)))?;
+
+        let master_secret = self.anoncreds_service.prover.import_master_secret(&exported_master_secret, passphrase)?;
+
+        let master_secret_json = master_secret.to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize master secret: {:?}", err)))?;
+
+        self.wallet_service.set(wallet_handle, &format!("master_secret::{}", master_secret_name), &master_secret_json)?;
+
+        info!("import_master_secret <<<");
+
+        Ok(())
+    }
+
     fn create_and_store_claim_request(&self,
                                       wallet_handle: i32,
                                       prover_did: &str,
@@ -221,6 +436,16 @@ impl ProverCommandExecutor {
                 format!("ClaimOffer issuer_did {:?} does not correspond to ClaimDef issuer_did {:?}", claim_offer.issuer_did, claim_def.issuer_did))));
         }
 
+        if claim_def.tag != claim_offer.claim_def_tag {
+            return Err(IndyError::CommonError(CommonError::InvalidStructure(
+                format!("ClaimOffer claim_def_tag {:?} does not correspond to ClaimDef tag {:?}", claim_offer.claim_def_tag, claim_def.tag))));
+        }
+
+        if claim_offer.is_expired() {
+            return Err(IndyError::AnoncredsError(AnoncredsError::ClaimOfferExpired(
+                format!("ClaimOffer expired at {:?}", claim_offer.expires_at))));
+        }
+
         let (claim_request, master_secret_blinding_data) =
             self.anoncreds_service.prover.new_claim_request(&claim_def.data, &master_secret, &claim_offer, prover_did)?;
 
@@ -236,7 +461,8 @@ impl ProverCommandExecutor {
         let claim_request_metadata_json = claim_request_metadata.to_json()
             .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claim request metadata {:?}", err)))?;
 
-        let id = get_composite_id(&claim_offer.issuer_did, &claim_offer.schema_key);
+        let id = get_claim_def_composite_id(&claim_offer.issuer_did, &claim_offer.schema_key,
+                                            claim_offer.claim_def_tag.as_ref().map(String::as_str));
         self.wallet_service.set(wallet_handle, &format!("claim_request_metadata::{}", id), &claim_request_metadata_json)?;
 
         let claim_request_json = claim_request.to_json()
@@ -249,19 +475,24 @@ impl ProverCommandExecutor {
         Ok(claim_request_json)
     }
 
-    fn store_claim(&self, wallet_handle: i32, claim_json: &str, rev_reg_json: Option<&str>) -> Result<(), IndyError> {
-        info!("store_claim >>> wallet_handle: {:?}, claim_json: {:?}, rev_reg_json: {:?}", wallet_handle, claim_json, rev_reg_json);
+    fn store_claim(&self,
+                   wallet_handle: i32,
+                   claim_json: &str,
+                   rev_reg_json: Option<&str>,
+                   tails_reader_config_json: Option<&str>) -> Result<(), IndyError> {
+        info!("store_claim >>> wallet_handle: {:?}, claim_json: {:?}, rev_reg_json: {:?}, tails_reader_config_json: {:?}",
+              wallet_handle, claim_json, rev_reg_json, tails_reader_config_json);
+
+        let tails_config = parse_blob_storage_config(tails_reader_config_json)?;
 
         let mut claim: Claim = Claim::from_json(&claim_json)
             .map_err(|err| CommonError::InvalidStructure(format!("Cannon deserialize claim: {:?}", err)))?;
 
-        let id = get_composite_id(&claim.issuer_did, &claim.schema_key);
+        let id = get_claim_def_composite_id(&claim.issuer_did, &claim.schema_key, claim.claim_def_tag.as_ref().map(String::as_str));
 
         let rev_reg_pub = match rev_reg_json {
             Some(rev_reg_pub_json) =>
-                Some(RevocationRegistry::from_json(&rev_reg_pub_json)
-                    .map_err(|err| CommonError::InvalidState(format!("Cannon deserialize revocation registry: {:?}", err)))?
-                    .data),
+                Some(revocation_registry_from_json(&self.blob_storage_service, &tails_config, &rev_reg_pub_json)?.data),
             None => None
         };
 
@@ -286,18 +517,130 @@ impl ProverCommandExecutor {
         let claim_json = claim.to_json()
             .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claim: {:?}", err)))?;
 
-        let referent = Uuid::new_v4().to_string();
-        self.wallet_service.set(wallet_handle, &format!("claim::{}", &referent), &claim_json)?;
+        let referent = format!("claim::{}", Uuid::new_v4().to_string());
+        self.wallet_service.set(wallet_handle, &referent, &claim_json)?;
+        // Claims can be used only with master secret they were issued for
+        self._set_claim_record_info(wallet_handle, &referent, &ClaimRecordInfo {
+            master_secret_name: Some(claim_request_metadata.master_secret_name),
+            metadata: None,
+            stored_at: Some(time::get_time().sec)
+        })?;
 
         if let Some(rev_reg_json) = rev_reg_json {
             self.wallet_service.set(wallet_handle, &format!("revocation_registry::{}", id), &rev_reg_json)?;
         }
 
+        // Claim offer is fulfilled, so it isn't returned by get_claim_offers anymore.
+        // Claim is already stored here, so failed removal doesn't fail the command.
+        match self.wallet_service.remove(wallet_handle, &format!("claim_offer::{}", id)) {
+            Ok(()) | Err(WalletError::NotFound(_)) => {}
+            Err(err) => warn!("store_claim: can't remove fulfilled claim offer {}: {:?}", id, err)
+        }
+
         info!("store_claim <<<");
 
         Ok(())
     }
 
+    fn delete_claim(&self, wallet_handle: i32, referent: &str) -> Result<(), IndyError> {
+        info!("delete_claim >>> wallet_handle: {:?}, referent: {:?}", wallet_handle, referent);
+
+        self._check_claim_exists(wallet_handle, referent)?;
+
+        self.wallet_service.remove(wallet_handle, referent)?;
+
+        for key in [format!("claim_info::{}", referent),
+            format!("claim_master_secret::{}", referent),
+            format!("claim_metadata::{}", referent),
+            format!("claim_stored_at::{}", referent)].iter() {
+            match self.wallet_service.remove(wallet_handle, key) {
+                Ok(()) | Err(WalletError::NotFound(_)) => {}
+                Err(err) => return Err(IndyError::from(err))
+            }
+        }
+
+        info!("delete_claim <<<");
+
+        Ok(())
+    }
+
+    fn set_claim_metadata(&self, wallet_handle: i32, referent: &str, metadata: Option<&str>) -> Result<(), IndyError> {
+        info!("set_claim_metadata >>> wallet_handle: {:?}, referent: {:?}, metadata: {:?}", wallet_handle, referent, metadata);
+
+        self._check_claim_exists(wallet_handle, referent)?;
+
+        let mut record_info = self._get_claim_record_info(wallet_handle, referent)?;
+        record_info.metadata = metadata.map(String::from);
+        self._set_claim_record_info(wallet_handle, referent, &record_info)?;
+
+        info!("set_claim_metadata <<<");
+
+        Ok(())
+    }
+
+    fn get_claim_metadata(&self, wallet_handle: i32, referent: &str) -> Result<String, IndyError> {
+        info!("get_claim_metadata >>> wallet_handle: {:?}, referent: {:?}", wallet_handle, referent);
+
+        self._check_claim_exists(wallet_handle, referent)?;
+
+        let metadata = self._get_claim_record_info(wallet_handle, referent)?.metadata
+            .ok_or(WalletError::NotFound(format!("Metadata of claim {} isn't set", referent)))?;
+
+        info!("get_claim_metadata <<< metadata: {:?}", metadata);
+
+        Ok(metadata)
+    }
+
+    /// Claims stored by older versions keep each field in separate record.
+    fn _get_claim_record_info(&self, wallet_handle: i32, referent: &str) -> Result<ClaimRecordInfo, IndyError> {
+        match self.wallet_service.get(wallet_handle, &format!("claim_info::{}", referent)) {
+            Ok(record_info_json) => return Ok(ClaimRecordInfo::from_json(&record_info_json)
+                .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize claim info: {:?}", err)))?),
+            Err(WalletError::NotFound(_)) => {}
+            Err(err) => return Err(IndyError::from(err))
+        };
+
+        let stored_at = match self._get_optional(wallet_handle, &format!("claim_stored_at::{}", referent))? {
+            Some(stored_at) => Some(stored_at.parse::<i64>()
+                .map_err(|err| CommonError::InvalidState(format!("Invalid claim store time: {:?}", err)))?),
+            None => None
+        };
+
+        Ok(ClaimRecordInfo {
+            master_secret_name: self._get_optional(wallet_handle, &format!("claim_master_secret::{}", referent))?,
+            metadata: self._get_optional(wallet_handle, &format!("claim_metadata::{}", referent))?,
+            stored_at
+        })
+    }
+
+    fn _set_claim_record_info(&self, wallet_handle: i32, referent: &str, record_info: &ClaimRecordInfo) -> Result<(), IndyError> {
+        let record_info_json = record_info.to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claim info: {:?}", err)))?;
+
+        self.wallet_service.set(wallet_handle, &format!("claim_info::{}", referent), &record_info_json)?;
+
+        Ok(())
+    }
+
+    fn _get_optional(&self, wallet_handle: i32, key: &str) -> Result<Option<String>, IndyError> {
+        match self.wallet_service.get(wallet_handle, key) {
+            Ok(value) => Ok(Some(value)),
+            Err(WalletError::NotFound(_)) => Ok(None),
+            Err(err) => Err(IndyError::from(err))
+        }
+    }
+
+    fn _check_claim_exists(&self, wallet_handle: i32, referent: &str) -> Result<(), IndyError> {
+        // Referent is used as wallet key, so it must not point to other records
+        if !referent.starts_with("claim::") {
+            return Err(IndyError::CommonError(CommonError::InvalidStructure(format!("Invalid claim referent: {}", referent))));
+        }
+
+        self.wallet_service.get(wallet_handle, referent)?;
+
+        Ok(())
+    }
+
     fn get_claims(&self,
                   wallet_handle: i32,
                   filter_json: &str) -> Result<String, IndyError> {
@@ -324,31 +667,242 @@ impl ProverCommandExecutor {
         let claims: Vec<(String, String)> = self.wallet_service.list(wallet_handle, &format!("claim::"))?;
 
         let mut claims_info: Vec<ClaimInfo> = Vec::new();
+        let mut schema_seq_nos: HashMap<String, Option<i32>> = HashMap::new();
 
-        for &(ref referent, ref claim) in claims.iter() {
-            let claim: Claim = Claim::from_json(claim)
-                .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize claim: {:?}", err)))?;
+        for &(ref referent, ref claim_json) in claims.iter() {
+            claims_info.push(self._get_claim_info(wallet_handle, referent, claim_json, &mut schema_seq_nos)?);
+        }
 
-            let mut claim_values: HashMap<String, String> = HashMap::new();
-            for (attr, values) in claim.values {
-                claim_values.insert(attr.clone(), values[0].clone());
-            }
+        info!("get_claims_info <<< claims_info: {:?}", claims_info);
+
+        Ok(claims_info)
+    }
 
-            claims_info.push(
-                ClaimInfo {
-                    referent: referent.clone(),
-                    attrs: claim_values,
-                    schema_key: claim.schema_key.clone(),
-                    issuer_did: claim.issuer_did.clone(),
-                    revoc_reg_seq_no: claim.rev_reg_seq_no.clone()
-                });
+    /// Claims usually share few claim definitions, so their schema sequence numbers are cached
+    /// in `schema_seq_nos` by claim definition id instead of read for every claim.
+    fn _get_claim_info(&self,
+                       wallet_handle: i32,
+                       referent: &str,
+                       claim_json: &str,
+                       schema_seq_nos: &mut HashMap<String, Option<i32>>) -> Result<ClaimInfo, IndyError> {
+        let claim: Claim = Claim::from_json(claim_json)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize claim: {:?}", err)))?;
+
+        let mut claim_values: HashMap<String, String> = HashMap::new();
+        for (attr, values) in claim.values {
+            claim_values.insert(attr.clone(), values[0].clone());
         }
 
-        info!("get_claims_info <<< claims_info: {:?}", claims_info);
+        let id = get_claim_def_composite_id(&claim.issuer_did, &claim.schema_key, claim.claim_def_tag.as_ref().map(String::as_str));
+
+        if !schema_seq_nos.contains_key(&id) {
+            let schema_seq_no = match self._get_optional(wallet_handle, &format!("claim_definition::{}", id))? {
+                Some(claim_def_json) => Some(ClaimDefinition::from_json(&claim_def_json)
+                    .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize claim definition: {:?}", err)))?
+                    .schema_seq_no),
+                None => None
+            };
+            schema_seq_nos.insert(id.clone(), schema_seq_no);
+        }
+        let schema_seq_no = schema_seq_nos[&id];
+
+        let record_info = self._get_claim_record_info(wallet_handle, referent)?;
+
+        Ok(ClaimInfo {
+            referent: referent.to_string(),
+            attrs: claim_values,
+            schema_key: claim.schema_key,
+            issuer_did: claim.issuer_did,
+            revoc_reg_seq_no: claim.rev_reg_seq_no,
+            schema_seq_no,
+            master_secret_name: record_info.master_secret_name,
+            metadata: record_info.metadata,
+            stored_at: record_info.stored_at,
+            claim_def_tag: claim.claim_def_tag
+        })
+    }
+
+    /// Reads claims page by page from `cursor` until `count` claims satisfying `predicate`
+    /// are found or all claims are read. `cursor` is moved past the read claims,
+    /// so the next call continues from there.
+    fn _fetch_claims_info<F>(&self,
+                             wallet_handle: i32,
+                             cursor: &mut ClaimsCursor,
+                             count: usize,
+                             predicate: F) -> Result<Vec<ClaimInfo>, IndyError>
+        where F: Fn(&ClaimInfo) -> Result<bool, IndyError> {
+        let mut claims_info: Vec<ClaimInfo> = Vec::new();
+        let mut schema_seq_nos: HashMap<String, Option<i32>> = HashMap::new();
+
+        while claims_info.len() < count {
+            if cursor.pending.is_empty() {
+                if cursor.is_done {
+                    break;
+                }
+
+                let claims = self.wallet_service.list_page(wallet_handle,
+                                                           "claim::",
+                                                           cursor.from_key.as_ref().map(String::as_str),
+                                                           CLAIMS_SEARCH_PAGE_SIZE)?;
+                cursor.is_done = claims.len() < CLAIMS_SEARCH_PAGE_SIZE;
+
+                if let Some(&(ref key, _)) = claims.last() {
+                    cursor.from_key = Some(key.clone());
+                }
+
+                cursor.pending.extend(claims);
+                continue;
+            }
+
+            let claim_info = {
+                let &(ref referent, ref claim_json) = cursor.pending.front().unwrap();
+                self._get_claim_info(wallet_handle, referent, claim_json, &mut schema_seq_nos)?
+            };
+            cursor.pending.pop_front();
+
+            if predicate(&claim_info)? {
+                claims_info.push(claim_info);
+            }
+        }
 
         Ok(claims_info)
     }
 
+    fn search_claims(&self,
+                     wallet_handle: i32,
+                     filter_json: &str) -> Result<i32, IndyError> {
+        info!("search_claims >>> wallet_handle: {:?}, filter_json: {:?}", wallet_handle, filter_json);
+
+        let filter: Restriction = serde_json::from_str(filter_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize filter: {:?}", err)))?;
+
+        // Fail fast on invalid wallet handle instead of on first fetch
+        self.wallet_service.get_pool_name(wallet_handle)?;
+
+        let search_handle = SequenceUtils::get_next_id();
+
+        self.claims_searches.borrow_mut().insert(search_handle, ClaimsSearch {
+            wallet_handle,
+            filter,
+            cursor: ClaimsCursor::default()
+        });
+
+        info!("search_claims <<< search_handle: {:?}", search_handle);
+
+        Ok(search_handle)
+    }
+
+    fn fetch_claims(&self,
+                    search_handle: i32,
+                    count: usize) -> Result<String, IndyError> {
+        info!("fetch_claims >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+        let mut searches = self.claims_searches.borrow_mut();
+
+        let search = searches.get_mut(&search_handle)
+            .ok_or(CommonError::InvalidParam2(format!("Unknown claims search handle: {}", search_handle)))?;
+
+        let filter = &search.filter;
+
+        let claims_info = self._fetch_claims_info(search.wallet_handle, &mut search.cursor, count,
+                                                  |claim_info| Ok(claim_satisfy_restriction(claim_info, filter)))?;
+
+        let claims_info_json = serde_json::to_string(&claims_info)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claims info: {:?}", err)))?;
+
+        info!("fetch_claims <<< claims_info_json: {:?}", claims_info_json);
+
+        Ok(claims_info_json)
+    }
+
+    fn close_claims_search(&self, search_handle: i32) -> Result<(), IndyError> {
+        info!("close_claims_search >>> search_handle: {:?}", search_handle);
+
+        self.claims_searches.borrow_mut().remove(&search_handle)
+            .ok_or(CommonError::InvalidParam2(format!("Unknown claims search handle: {}", search_handle)))?;
+
+        info!("close_claims_search <<<");
+
+        Ok(())
+    }
+
+    fn search_claims_for_proof_req(&self,
+                                   wallet_handle: i32,
+                                   proof_req_json: &str) -> Result<i32, IndyError> {
+        info!("search_claims_for_proof_req >>> wallet_handle: {:?}, proof_req_json: {:?}", wallet_handle, proof_req_json);
+
+        let proof_req: ProofRequest = ProofRequest::from_json(proof_req_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize proof request: {:?}", err)))?;
+
+        self.wallet_service.get_pool_name(wallet_handle)?;
+
+        let cursors: HashMap<String, ClaimsCursor> = proof_req.requested_attrs.keys()
+            .chain(proof_req.requested_predicates.keys())
+            .map(|referent| (referent.clone(), ClaimsCursor::default()))
+            .collect();
+
+        let search_handle = SequenceUtils::get_next_id();
+
+        self.claims_for_proof_req_searches.borrow_mut().insert(search_handle, ClaimsForProofReqSearch {
+            wallet_handle,
+            proof_req,
+            cursors
+        });
+
+        info!("search_claims_for_proof_req <<< search_handle: {:?}", search_handle);
+
+        Ok(search_handle)
+    }
+
+    fn fetch_claims_for_proof_req(&self,
+                                  search_handle: i32,
+                                  item_referent: &str,
+                                  count: usize) -> Result<String, IndyError> {
+        info!("fetch_claims_for_proof_req >>> search_handle: {:?}, item_referent: {:?}, count: {:?}", search_handle, item_referent, count);
+
+        let mut searches = self.claims_for_proof_req_searches.borrow_mut();
+
+        let search = searches.get_mut(&search_handle)
+            .ok_or(CommonError::InvalidParam2(format!("Unknown claims for proof request search handle: {}", search_handle)))?;
+
+        let wallet_handle = search.wallet_handle;
+        let proof_req = &search.proof_req;
+
+        let cursor = search.cursors.get_mut(item_referent)
+            .ok_or(CommonError::InvalidParam3(format!("Proof request doesn't contain item referent: {}", item_referent)))?;
+
+        let prover = &self.anoncreds_service.prover;
+
+        let claims_info = match (proof_req.requested_attrs.get(item_referent), proof_req.requested_predicates.get(item_referent)) {
+            (Some(requested_attr), _) =>
+                self._fetch_claims_info(wallet_handle, cursor, count,
+                                        |claim_info| Ok(prover.claim_satisfy_requested_attr(claim_info, requested_attr)))?,
+            (None, Some(requested_predicate)) =>
+                self._fetch_claims_info(wallet_handle, cursor, count,
+                                        |claim_info| Ok(prover.claim_satisfy_requested_predicate(claim_info, requested_predicate)?))?,
+            (None, None) => return Err(IndyError::CommonError(CommonError::InvalidParam3(
+                format!("Proof request doesn't contain item referent: {}", item_referent))))
+        };
+
+        let claims_info_json = serde_json::to_string(&claims_info)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claims info: {:?}", err)))?;
+
+        info!("fetch_claims_for_proof_req <<< claims_info_json: {:?}", claims_info_json);
+
+        Ok(claims_info_json)
+    }
+
+    fn close_claims_search_for_proof_req(&self, search_handle: i32) -> Result<(), IndyError> {
+        info!("close_claims_search_for_proof_req >>> search_handle: {:?}", search_handle);
+
+        self.claims_for_proof_req_searches.borrow_mut().remove(&search_handle)
+            .ok_or(CommonError::InvalidParam2(format!("Unknown claims for proof request search handle: {}", search_handle)))?;
+
+        info!("close_claims_search_for_proof_req <<<");
+
+        Ok(())
+    }
+
     fn get_claims_for_proof_req(&self,
                                 wallet_handle: i32,
                                 proof_req_json: &str, ) -> Result<String, IndyError> {
@@ -368,6 +922,74 @@ impl ProverCommandExecutor {
         Ok(claims_for_proof_request_json)
     }
 
+    fn select_claims_for_proof_req(&self,
+                                   wallet_handle: i32,
+                                   proof_req_json: &str,
+                                   self_attested_values_json: Option<&str>) -> Result<String, IndyError> {
+        info!("select_claims_for_proof_req >>> wallet_handle: {:?}, proof_req_json: {:?}, self_attested_values_json: {:?}",
+              wallet_handle, proof_req_json, self_attested_values_json);
+
+        let proof_req: ProofRequest = ProofRequest::from_json(proof_req_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize proof request: {:?}", err)))?;
+
+        let self_attested_values: HashMap<String, String> = match self_attested_values_json {
+            Some(self_attested_values_json) => serde_json::from_str(self_attested_values_json)
+                .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize self attested values: {:?}", err)))?,
+            None => HashMap::new()
+        };
+
+        let claims_info: Vec<ClaimInfo> = self.get_claims_info(wallet_handle)?;
+
+        // Revocable claim can be proved non-revoked only in validity period of the registry state prover has
+        // and only if its index is still in the accumulator of that state
+        let mut rev_reg_periods: HashMap<String, (i64, i64)> = HashMap::new();
+        let mut revoked_claims: HashSet<String> = HashSet::new();
+        for claim_info in claims_info.iter().filter(|claim_info| claim_info.revoc_reg_seq_no.is_some()) {
+            let id = get_claim_def_composite_id(&claim_info.issuer_did, &claim_info.schema_key,
+                                                claim_info.claim_def_tag.as_ref().map(String::as_str));
+
+            let rev_reg_json = match self.wallet_service.get(wallet_handle, &format!("revocation_registry::{}", id)) {
+                Ok(rev_reg_json) => rev_reg_json,
+                Err(WalletError::NotFound(_)) => continue,
+                Err(err) => return Err(IndyError::from(err))
+            };
+
+            let rev_reg: Value = serde_json::from_str(&rev_reg_json)
+                .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize revocation registry: {:?}", err)))?;
+
+            if let Some(timestamp) = rev_reg["timestamp"].as_i64() {
+                let valid_to = ::std::cmp::max(timestamp, rev_reg["valid_to"].as_i64().unwrap_or(timestamp));
+                rev_reg_periods.insert(claim_info.referent.clone(), (timestamp, valid_to));
+            }
+
+            let claim_json = self.wallet_service.get(wallet_handle, &claim_info.referent)?;
+            let claim: Value = serde_json::from_str(&claim_json)
+                .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize claim: {:?}", err)))?;
+
+            let is_revoked = match (claim["signature"]["r_claim"]["i"].as_u64(), rev_reg["data"]["acc"]["v"].as_array()) {
+                (Some(index), Some(issued)) => !issued.iter().any(|issued_index| issued_index.as_u64() == Some(index)),
+                _ => false
+            };
+
+            if is_revoked {
+                revoked_claims.insert(claim_info.referent.clone());
+            }
+        }
+
+        let claims_selection = self.anoncreds_service.prover.select_claims_for_proof_req(&proof_req,
+                                                                                          &claims_info,
+                                                                                          &rev_reg_periods,
+                                                                                          &revoked_claims,
+                                                                                          &self_attested_values)?;
+
+        let claims_selection_json = claims_selection.to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claims selection: {:?}", err)))?;
+
+        info!("select_claims_for_proof_req <<< claims_selection_json: {:?}", claims_selection_json);
+
+        Ok(claims_selection_json)
+    }
+
     fn create_proof(&self,
                     wallet_handle: i32,
                     proof_req_json: &str,
@@ -375,7 +997,10 @@ impl ProverCommandExecutor {
                     schemas_jsons: &str,
                     master_secret_name: &str,
                     claim_def_jsons: &str,
-                    revoc_regs_jsons: &str) -> Result<String, IndyError> {
+                    revoc_regs_jsons: &str,
+                    tails_reader_config_json: Option<&str>) -> Result<String, IndyError> {
+        let tails_config = parse_blob_storage_config(tails_reader_config_json)?;
+
         let proof_req: ProofRequest = ProofRequest::from_json(proof_req_json)
             .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize proof request: {:?}", err)))?;
 
@@ -385,8 +1010,8 @@ impl ProverCommandExecutor {
         let claim_defs: HashMap<String, ClaimDefinition> = serde_json::from_str(claim_def_jsons)
             .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize list of claim definitions: {:?}", err)))?;
 
-        let revoc_regs: HashMap<String, RevocationRegistry> = serde_json::from_str(revoc_regs_jsons)
-            .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize list of revocation registries: {:?}", err)))?;
+        // Witnesses are computed from registry tails, so they are loaded from blob storage on demand
+        let revoc_regs = revocation_registries_from_json(&self.blob_storage_service, &tails_config, revoc_regs_jsons)?;
 
         let requested_claims: RequestedClaims = RequestedClaims::from_json(requested_claims_json)
             .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize requested claims: {:?}", err)))?;
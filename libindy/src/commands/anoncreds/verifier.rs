@@ -1,14 +1,28 @@
 extern crate serde_json;
 extern crate indy_crypto;
+extern crate time;
 
+use errors::anoncreds::AnoncredsError;
 use errors::common::CommonError;
 use errors::indy::IndyError;
 
+use commands::{Command, CommandExecutor};
+use commands::anoncreds::AnoncredsCommand;
+use commands::ledger::LedgerCommand;
 use services::anoncreds::AnoncredsService;
 use services::anoncreds::types::*;
-use std::collections::{HashMap, HashSet};
+use services::blob_storage::{BlobStorageService, parse_blob_storage_config};
+use services::ledger::LedgerService;
+use services::ledger::constants::{CL_ACCUM, ISSUANCE_BY_DEFAULT};
+use services::ledger::types::{RevocRegDefData, RevocRegDelta};
+use super::{revocation_registry_from_json, revocation_registries_from_json};
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::rc::Rc;
-use self::indy_crypto::utils::json::JsonDecodable;
+use utils::sequence::SequenceUtils;
+use self::indy_crypto::cl::{new_nonce, Nonce};
+use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
+use self::serde_json::{Map, Value};
 
 pub enum VerifierCommand {
     VerifyProof(
@@ -17,17 +31,77 @@ pub enum VerifierCommand {
         String, // schemas json
         String, // claim defs jsons
         String, // revoc regs json
-        Box<Fn(Result<bool, IndyError>) + Send>)
+        Option<String>, // tails reader config json
+        Box<Fn(Result<bool, IndyError>) + Send>),
+    VerifyProofWithLedger(
+        i32, // pool handle
+        String, // submitter did
+        String, // proof request json
+        String, // proof json
+        Option<String>, // tails reader config json
+        Box<Fn(Result<(bool, String), IndyError>) + Send>),
+    GenerateNonce(
+        Box<Fn(Result<String, IndyError>) + Send>),
+    BuildProofRequest(
+        String, // name
+        String, // version
+        String, // requested attrs json
+        String, // requested predicates json
+        Option<String>, // non revoked interval json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    InspectProof(
+        String, // proof request json
+        String, // proof json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    // Internal commands
+    GetLedgerObjectAck(
+        i32, // verification id
+        Result<String, IndyError>, // GET_SCHEMA, GET_CLAIM_DEF, GET_REVOC_REG_DEF or GET_REVOC_REG_DELTA reply
+    )
+}
+
+/// Tag of revocation registry definition published for claim definition on the ledger,
+/// a single revocation registry is kept per claim definition.
+const LEDGER_REVOC_REG_DEF_TAG: &'static str = "default";
+
+enum LedgerObjectKey {
+    Schema(SchemaKey),
+    ClaimDef(String /* issuer did */, SchemaKey, Option<String> /* claim def tag */),
+    RevocRegDef(String /* issuer did */, SchemaKey, Option<String> /* claim def tag */),
+    RevocReg(String /* issuer did */, SchemaKey, Option<String> /* claim def tag */, i64 /* requested time */)
+}
+
+struct LedgerProofVerification {
+    pool_handle: i32,
+    submitter_did: String,
+    proof_req: ProofRequest,
+    proof: FullProof,
+    tails_config: (String, String),
+    revoc_reg_times: HashMap<String, i64>,
+    pending_objects: Vec<LedgerObjectKey>,
+    schemas: HashMap<SchemaKey, Schema>,
+    claim_defs: HashMap<(String, SchemaKey, Option<String>), ClaimDefinition>,
+    revoc_reg_defs: HashMap<(String, SchemaKey, Option<String>), RevocRegDefData>,
+    revoc_regs: HashMap<(String, SchemaKey, Option<String>, i64), RevocationRegistry>,
+    cb: Box<Fn(Result<(bool, String), IndyError>) + Send>
 }
 
 pub struct VerifierCommandExecutor {
     anoncreds_service: Rc<AnoncredsService>,
+    blob_storage_service: Rc<BlobStorageService>,
+    ledger_service: Rc<LedgerService>,
+    pending_verifications: RefCell<HashMap<i32, LedgerProofVerification>>,
 }
 
 impl VerifierCommandExecutor {
-    pub fn new(anoncreds_service: Rc<AnoncredsService>) -> VerifierCommandExecutor {
+    pub fn new(anoncreds_service: Rc<AnoncredsService>,
+               blob_storage_service: Rc<BlobStorageService>,
+               ledger_service: Rc<LedgerService>) -> VerifierCommandExecutor {
         VerifierCommandExecutor {
             anoncreds_service,
+            blob_storage_service,
+            ledger_service,
+            pending_verifications: RefCell::new(HashMap::new()),
         }
     }
 
@@ -35,10 +109,34 @@ impl VerifierCommandExecutor {
         match command {
             VerifierCommand::VerifyProof(proof_request_json,
                                          proof_json, schemas_json,
-                                         claim_defs_jsons, revoc_regs_json, cb) => {
+                                         claim_defs_jsons, revoc_regs_json, tails_reader_config_json, cb) => {
                 info!(target: "verifier_command_executor", "VerifyProof command received");
                 self.verify_proof(&proof_request_json, &proof_json, &schemas_json,
-                                  &claim_defs_jsons, &revoc_regs_json, cb);
+                                  &claim_defs_jsons, &revoc_regs_json,
+                                  tails_reader_config_json.as_ref().map(String::as_str), cb);
+            }
+            VerifierCommand::VerifyProofWithLedger(pool_handle, submitter_did, proof_request_json, proof_json,
+                                                   tails_reader_config_json, cb) => {
+                info!(target: "verifier_command_executor", "VerifyProofWithLedger command received");
+                self.verify_proof_with_ledger(pool_handle, &submitter_did, &proof_request_json, &proof_json,
+                                              tails_reader_config_json.as_ref().map(String::as_str), cb);
+            }
+            VerifierCommand::GenerateNonce(cb) => {
+                info!(target: "verifier_command_executor", "GenerateNonce command received");
+                cb(self.generate_nonce());
+            }
+            VerifierCommand::BuildProofRequest(name, version, requested_attrs_json, requested_predicates_json, non_revoked_json, cb) => {
+                info!(target: "verifier_command_executor", "BuildProofRequest command received");
+                cb(self.build_proof_request(&name, &version, &requested_attrs_json, &requested_predicates_json,
+                                            non_revoked_json.as_ref().map(String::as_str)));
+            }
+            VerifierCommand::InspectProof(proof_request_json, proof_json, cb) => {
+                info!(target: "verifier_command_executor", "InspectProof command received");
+                cb(self.inspect_proof(&proof_request_json, &proof_json));
+            }
+            VerifierCommand::GetLedgerObjectAck(verification_id, result) => {
+                info!(target: "verifier_command_executor", "GetLedgerObjectAck command received");
+                self.get_ledger_object_ack(verification_id, result);
             }
         };
     }
@@ -49,8 +147,10 @@ impl VerifierCommandExecutor {
                     schemas_json: &str,
                     claim_defs_jsons: &str,
                     revoc_regs_json: &str,
+                    tails_reader_config_json: Option<&str>,
                     cb: Box<Fn(Result<bool, IndyError>) + Send>) {
-        let result = self._verify_proof(proof_request_json, proof_json, schemas_json, claim_defs_jsons, revoc_regs_json);
+        let result = self._verify_proof(proof_request_json, proof_json, schemas_json, claim_defs_jsons, revoc_regs_json,
+                                        tails_reader_config_json);
         cb(result)
     }
 
@@ -59,9 +159,13 @@ impl VerifierCommandExecutor {
                      proof_json: &str,
                      schemas_json: &str,
                      claim_defs_jsons: &str,
-                     revoc_regs_json: &str) -> Result<bool, IndyError> {
+                     revoc_regs_json: &str,
+                     tails_reader_config_json: Option<&str>) -> Result<bool, IndyError> {
         info!("verify_proof >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, claim_defs_jsons: {:?}, \
-               revoc_regs_json: {:?}", proof_request_json, proof_json, schemas_json, claim_defs_jsons, revoc_regs_json);
+               revoc_regs_json: {:?}, tails_reader_config_json: {:?}",
+              proof_request_json, proof_json, schemas_json, claim_defs_jsons, revoc_regs_json, tails_reader_config_json);
+
+        let tails_config = parse_blob_storage_config(tails_reader_config_json)?;
 
         let proof_req: ProofRequest = ProofRequest::from_json(proof_request_json)
             .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize proof request: {:?}", err)))?;
@@ -72,12 +176,24 @@ impl VerifierCommandExecutor {
         let claim_defs: HashMap<String, ClaimDefinition> = serde_json::from_str(claim_defs_jsons)
             .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize list of claim definitions: {:?}", err)))?;
 
-        let revoc_regs: HashMap<String, RevocationRegistry> = serde_json::from_str(revoc_regs_json)
-            .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize list of revocation registries: {:?}", err)))?;
+        let revoc_regs = revocation_registries_from_json(&self.blob_storage_service, &tails_config, revoc_regs_json)?;
 
         let proof_claims: FullProof = FullProof::from_json(&proof_json)
             .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize proof: {:?}", err)))?;
 
+        let result = self._verify_full_proof(&proof_req, &proof_claims, &schemas, &claim_defs, &revoc_regs)?;
+
+        info!("verify_proof <<< result: {:?}", result);
+
+        Ok(result)
+    }
+
+    fn _verify_full_proof(&self,
+                          proof_req: &ProofRequest,
+                          proof_claims: &FullProof,
+                          schemas: &HashMap<String, Schema>,
+                          claim_defs: &HashMap<String, ClaimDefinition>,
+                          revoc_regs: &HashMap<String, RevocationRegistry>) -> Result<bool, IndyError> {
         if schemas.keys().collect::<HashSet<&String>>() != claim_defs.keys().collect::<HashSet<&String>>() {
             return Err(IndyError::CommonError(CommonError::InvalidStructure(
                 format!("Claim definition keys {:?} do not correspond to schema received {:?}", schemas.keys(), claim_defs.keys()))));
@@ -143,14 +259,510 @@ impl VerifierCommandExecutor {
                 format!("Requested predicates {:?} do not correspond to received {:?}", requested_predicates, received_predicates))));
         }
 
-        let result = self.anoncreds_service.verifier.verify(&proof_claims,
-                                                            &proof_req,
-                                                            &claim_defs,
-                                                            &revoc_regs,
-                                                            &schemas)?;
-
-        info!("verify_proof <<< result: {:?}", result);
+        let result = self.anoncreds_service.verifier.verify(proof_claims,
+                                                            proof_req,
+                                                            claim_defs,
+                                                            revoc_regs,
+                                                            schemas)?;
 
         Ok(result)
     }
+
+    fn generate_nonce(&self) -> Result<String, IndyError> {
+        info!("generate_nonce >>>");
+
+        let nonce = self._new_nonce()?;
+
+        let nonce_json = nonce.to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize nonce: {:?}", err)))?;
+
+        let nonce: String = serde_json::from_str(&nonce_json)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize nonce: {:?}", err)))?;
+
+        info!("generate_nonce <<< nonce: {:?}", nonce);
+
+        Ok(nonce)
+    }
+
+    fn build_proof_request(&self,
+                           name: &str,
+                           version: &str,
+                           requested_attrs_json: &str,
+                           requested_predicates_json: &str,
+                           non_revoked_json: Option<&str>) -> Result<String, IndyError> {
+        info!("build_proof_request >>> name: {:?}, version: {:?}, requested_attrs_json: {:?}, requested_predicates_json: {:?}, \
+               non_revoked_json: {:?}", name, version, requested_attrs_json, requested_predicates_json, non_revoked_json);
+
+        let requested_attrs: Vec<AttributeInfo> = serde_json::from_str(requested_attrs_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize requested attributes: {:?}", err)))?;
+
+        let requested_predicates: Vec<PredicateInfo> = serde_json::from_str(requested_predicates_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize requested predicates: {:?}", err)))?;
+
+        let non_revoked: Option<NonRevokedInterval> = match non_revoked_json {
+            Some(non_revoked_json) => Some(serde_json::from_str(non_revoked_json)
+                .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize non-revoked interval: {:?}", err)))?),
+            None => None
+        };
+
+        let proof_req = ProofRequest {
+            nonce: self._new_nonce()?,
+            name: name.to_string(),
+            version: version.to_string(),
+            requested_attrs: requested_attrs.into_iter()
+                .enumerate()
+                .map(|(i, attr_info)| (format!("attr{}_referent", i + 1), attr_info))
+                .collect(),
+            requested_predicates: requested_predicates.into_iter()
+                .enumerate()
+                .map(|(i, predicate_info)| (format!("predicate{}_referent", i + 1), predicate_info))
+                .collect(),
+            non_revoked
+        };
+
+        proof_req.validate()?;
+
+        let proof_req_json = proof_req.to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize proof request: {:?}", err)))?;
+
+        info!("build_proof_request <<< proof_req_json: {:?}", proof_req_json);
+
+        Ok(proof_req_json)
+    }
+
+    fn _new_nonce(&self) -> Result<Nonce, IndyError> {
+        new_nonce()
+            .map_err(|err| IndyError::AnoncredsError(AnoncredsError::from(err)))
+    }
+
+    fn inspect_proof(&self,
+                     proof_request_json: &str,
+                     proof_json: &str) -> Result<String, IndyError> {
+        info!("inspect_proof >>> proof_request_json: {:?}, proof_json: {:?}", proof_request_json, proof_json);
+
+        let proof_req: ProofRequest = ProofRequest::from_json(proof_request_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize proof request: {:?}", err)))?;
+
+        let proof: FullProof = FullProof::from_json(proof_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize proof: {:?}", err)))?;
+
+        let mut revealed_attrs = Map::new();
+        for (referent, &(ref claim_referent, ref raw, _)) in proof.requested_proof.revealed_attrs.iter() {
+            let attr_info = self._get_requested_attr(&proof_req, referent)?;
+            let identifier = self._get_identifier(&proof, claim_referent)?;
+
+            revealed_attrs.insert(referent.clone(), json!({
+                "name": attr_info.name,
+                "raw": raw,
+                "issuer_did": identifier.issuer_did,
+                "schema_key": identifier.schema_key
+            }));
+        }
+
+        let mut unrevealed_attrs = Map::new();
+        for (referent, claim_referent) in proof.requested_proof.unrevealed_attrs.iter() {
+            let attr_info = self._get_requested_attr(&proof_req, referent)?;
+            let identifier = self._get_identifier(&proof, claim_referent)?;
+
+            unrevealed_attrs.insert(referent.clone(), json!({
+                "name": attr_info.name,
+                "issuer_did": identifier.issuer_did,
+                "schema_key": identifier.schema_key
+            }));
+        }
+
+        let mut self_attested_attrs = Map::new();
+        for (referent, value) in proof.requested_proof.self_attested_attrs.iter() {
+            let attr_info = self._get_requested_attr(&proof_req, referent)?;
+
+            self_attested_attrs.insert(referent.clone(), json!({
+                "name": attr_info.name,
+                "value": value
+            }));
+        }
+
+        let mut predicates = Map::new();
+        for (referent, claim_referent) in proof.requested_proof.predicates.iter() {
+            let predicate_info = proof_req.requested_predicates.get(referent)
+                .ok_or(CommonError::InvalidStructure(format!("Predicate {} isn't requested by proof request", referent)))?;
+            let identifier = self._get_identifier(&proof, claim_referent)?;
+
+            predicates.insert(referent.clone(), json!({
+                "attr_name": predicate_info.attr_name,
+                "p_type": predicate_info.p_type,
+                "value": predicate_info.value,
+                "issuer_did": identifier.issuer_did,
+                "schema_key": identifier.schema_key
+            }));
+        }
+
+        let inspection_json = json!({
+            "name": proof_req.name,
+            "version": proof_req.version,
+            "revealed_attrs": Value::Object(revealed_attrs),
+            "unrevealed_attrs": Value::Object(unrevealed_attrs),
+            "self_attested_attrs": Value::Object(self_attested_attrs),
+            "predicates": Value::Object(predicates)
+        }).to_string();
+
+        info!("inspect_proof <<< inspection_json: {:?}", inspection_json);
+
+        Ok(inspection_json)
+    }
+
+    fn _get_requested_attr<'a>(&self, proof_req: &'a ProofRequest, referent: &str) -> Result<&'a AttributeInfo, CommonError> {
+        proof_req.requested_attrs.get(referent)
+            .ok_or(CommonError::InvalidStructure(format!("Attribute {} isn't requested by proof request", referent)))
+    }
+
+    fn _get_identifier<'a>(&self, proof: &'a FullProof, claim_referent: &str) -> Result<&'a Identifier, CommonError> {
+        proof.identifiers.get(claim_referent)
+            .ok_or(CommonError::InvalidStructure(format!("Identifier isn't found for claim: {}", claim_referent)))
+    }
+
+    fn verify_proof_with_ledger(&self,
+                                pool_handle: i32,
+                                submitter_did: &str,
+                                proof_request_json: &str,
+                                proof_json: &str,
+                                tails_reader_config_json: Option<&str>,
+                                cb: Box<Fn(Result<(bool, String), IndyError>) + Send>) {
+        info!("verify_proof_with_ledger >>> pool_handle: {:?}, submitter_did: {:?}, proof_request_json: {:?}, proof_json: {:?}, \
+               tails_reader_config_json: {:?}",
+              pool_handle, submitter_did, proof_request_json, proof_json, tails_reader_config_json);
+
+        let tails_config = try_cb!(parse_blob_storage_config(tails_reader_config_json), cb);
+
+        let proof_req: ProofRequest = try_cb!(ProofRequest::from_json(proof_request_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize proof request: {:?}", err))), cb);
+
+        let proof: FullProof = try_cb!(FullProof::from_json(proof_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize proof: {:?}", err))), cb);
+
+        // Registry state is requested for the time of the state prover used, it's checked against
+        // requested non-revoked intervals by txnTime of the reply, not by the time itself
+        let now = time::now_utc().to_timespec().sec;
+        let revoc_reg_times: HashMap<String, i64> = proof.identifiers.iter()
+            .map(|(referent, identifier)| (referent.clone(), identifier.timestamp.unwrap_or(now)))
+            .collect();
+
+        // Schemas are requested first as claim definitions on the ledger reference schema seq no,
+        // revocation registries are requested after claim definitions that have revocation keys
+        let mut schema_keys: Vec<SchemaKey> = Vec::new();
+        let mut claim_def_keys: Vec<(String, SchemaKey, Option<String>)> = Vec::new();
+
+        for identifier in proof.identifiers.values() {
+            if !schema_keys.contains(&identifier.schema_key) {
+                schema_keys.push(identifier.schema_key.clone());
+            }
+
+            let claim_def_key = (identifier.issuer_did.clone(), identifier.schema_key.clone(), identifier.claim_def_tag.clone());
+            if !claim_def_keys.contains(&claim_def_key) {
+                claim_def_keys.push(claim_def_key);
+            }
+        }
+
+        let mut pending_objects: Vec<LedgerObjectKey> = schema_keys.into_iter()
+            .map(LedgerObjectKey::Schema)
+            .chain(claim_def_keys.into_iter().map(|(issuer_did, schema_key, tag)| LedgerObjectKey::ClaimDef(issuer_did, schema_key, tag)))
+            .collect();
+        pending_objects.reverse();
+
+        let verification_id = SequenceUtils::get_next_id();
+
+        self._continue_proof_verification(verification_id, LedgerProofVerification {
+            pool_handle,
+            submitter_did: submitter_did.to_string(),
+            proof_req,
+            proof,
+            tails_config,
+            revoc_reg_times,
+            pending_objects,
+            schemas: HashMap::new(),
+            claim_defs: HashMap::new(),
+            revoc_reg_defs: HashMap::new(),
+            revoc_regs: HashMap::new(),
+            cb
+        });
+    }
+
+    fn get_ledger_object_ack(&self, verification_id: i32, result: Result<String, IndyError>) {
+        let mut verification = match self.pending_verifications.borrow_mut().remove(&verification_id) {
+            Some(verification) => verification,
+            None => return error!("No pending proof verification for id: {}", verification_id)
+        };
+
+        let object_key = match verification.pending_objects.pop() {
+            Some(object_key) => object_key,
+            None => return error!("No pending ledger object for proof verification with id: {}", verification_id)
+        };
+
+        let res = result.and_then(|reply| {
+            match object_key {
+                LedgerObjectKey::Schema(schema_key) => {
+                    let schema_json = self.ledger_service.parse_get_schema_response(&reply)?;
+                    let schema = Schema::from_json(&schema_json)
+                        .map_err(|err| CommonError::InvalidStructure(format!("Invalid schema received from ledger: {:?}", err)))?;
+
+                    if schema.data.name != schema_key.name || schema.data.version != schema_key.version || schema.dest != schema_key.did {
+                        return Err(IndyError::CommonError(CommonError::InvalidStructure(
+                            format!("Schema received from ledger doesn't correspond to requested {:?}", schema_key))));
+                    }
+
+                    verification.schemas.insert(schema_key, schema);
+                }
+                LedgerObjectKey::ClaimDef(issuer_did, schema_key, tag) => {
+                    let claim_def_json = self.ledger_service.parse_get_claim_def_response(&reply)?;
+                    let claim_def = ClaimDefinition::from_json(&claim_def_json)
+                        .map_err(|err| CommonError::InvalidStructure(format!("Invalid claim definition received from ledger: {:?}", err)))?;
+
+                    let schema_seq_no = verification.schemas.get(&schema_key)
+                        .map(|schema| schema.seq_no)
+                        .ok_or(CommonError::InvalidState(format!("Schema isn't resolved for claim definition: {:?}", schema_key)))?;
+
+                    // State proof binds data to origin, signature type and ref taken from the reply itself,
+                    // so they must be checked against the request. Ledger keeps claim definitions untagged,
+                    // so claims issued with tagged ones can't be verified against it.
+                    if claim_def.issuer_did != issuer_did || claim_def.schema_seq_no != schema_seq_no
+                        || claim_def.signature_type != SignatureTypes::CL || claim_def.tag != tag {
+                        return Err(IndyError::CommonError(CommonError::InvalidStructure(
+                            format!("Claim definition received from ledger doesn't correspond to issuer {}, schema {} and tag {:?}",
+                                    issuer_did, schema_seq_no, tag))));
+                    }
+
+                    if claim_def.data.revocation.is_some() {
+                        let mut times: Vec<i64> = verification.proof.identifiers.iter()
+                            .filter(|&(_, identifier)| identifier.issuer_did == issuer_did
+                                && identifier.schema_key == schema_key && identifier.claim_def_tag == tag)
+                            .filter_map(|(referent, _)| verification.revoc_reg_times.get(referent).cloned())
+                            .collect();
+                        times.sort();
+                        times.dedup();
+
+                        for time in times.into_iter().rev() {
+                            verification.pending_objects.push(LedgerObjectKey::RevocReg(issuer_did.clone(), schema_key.clone(), tag.clone(), time));
+                        }
+                        verification.pending_objects.push(LedgerObjectKey::RevocRegDef(issuer_did.clone(), schema_key.clone(), tag.clone()));
+                    }
+
+                    verification.claim_defs.insert((issuer_did, schema_key, tag), claim_def);
+                }
+                LedgerObjectKey::RevocRegDef(issuer_did, schema_key, tag) => {
+                    let (id, revoc_reg_def_json) = self.ledger_service.parse_get_revoc_reg_def_response(&reply)?;
+                    let revoc_reg_def = RevocRegDefData::from_json(&revoc_reg_def_json)
+                        .map_err(|err| CommonError::InvalidStructure(format!("Invalid revocation registry definition received from ledger: {:?}", err)))?;
+
+                    let schema_seq_no = VerifierCommandExecutor::_get_schema_seq_no(&verification.schemas, &schema_key)?;
+                    let expected_id = VerifierCommandExecutor::_get_ledger_revoc_reg_def_id(&issuer_did, schema_seq_no);
+
+                    if id != expected_id || revoc_reg_def.id != expected_id || revoc_reg_def.revoc_def_type != CL_ACCUM
+                        || revoc_reg_def.cred_def_id != VerifierCommandExecutor::_get_ledger_claim_def_id(&issuer_did, schema_seq_no) {
+                        return Err(IndyError::CommonError(CommonError::InvalidStructure(
+                            format!("Revocation registry definition received from ledger doesn't correspond to requested {}", expected_id))));
+                    }
+
+                    verification.revoc_reg_defs.insert((issuer_did, schema_key, tag), revoc_reg_def);
+                }
+                LedgerObjectKey::RevocReg(issuer_did, schema_key, tag, time) => {
+                    let (id, revoc_reg_delta_json, txn_time) = self.ledger_service.parse_get_revoc_reg_delta_response(&reply)?;
+                    let revoc_reg_delta = RevocRegDelta::from_json(&revoc_reg_delta_json)
+                        .map_err(|err| CommonError::InvalidStructure(format!("Invalid revocation registry delta received from ledger: {:?}", err)))?;
+
+                    let schema_seq_no = VerifierCommandExecutor::_get_schema_seq_no(&verification.schemas, &schema_key)?;
+                    let expected_id = VerifierCommandExecutor::_get_ledger_revoc_reg_def_id(&issuer_did, schema_seq_no);
+
+                    if id != expected_id || txn_time as i64 > time {
+                        return Err(IndyError::CommonError(CommonError::InvalidStructure(
+                            format!("Revocation registry received from ledger doesn't correspond to requested {} at {}", expected_id, time))));
+                    }
+
+                    let revoc_reg = {
+                        let revoc_reg_def = verification.revoc_reg_defs.get(&(issuer_did.clone(), schema_key.clone(), tag.clone()))
+                            .ok_or(CommonError::InvalidState(format!("Revocation registry definition isn't resolved: {}", expected_id)))?;
+
+                        self._build_ledger_revocation_registry(&issuer_did, schema_seq_no, revoc_reg_def, &revoc_reg_delta,
+                                                               txn_time as i64, time, &verification.tails_config)?
+                    };
+
+                    verification.revoc_regs.insert((issuer_did, schema_key, tag, time), revoc_reg);
+                }
+            }
+            Ok(())
+        });
+
+        match res {
+            Ok(()) => self._continue_proof_verification(verification_id, verification),
+            Err(err) => (verification.cb)(Err(err))
+        }
+    }
+
+    fn _continue_proof_verification(&self, verification_id: i32, verification: LedgerProofVerification) {
+        let request = match verification.pending_objects.last() {
+            Some(&LedgerObjectKey::Schema(ref schema_key)) => {
+                let data = json!({"name": schema_key.name, "version": schema_key.version}).to_string();
+                self.ledger_service.build_get_schema_request(&verification.submitter_did, &schema_key.did, &data)
+                    .map_err(IndyError::from)
+            }
+            Some(&LedgerObjectKey::ClaimDef(ref issuer_did, ref schema_key, _)) => {
+                match verification.schemas.get(schema_key) {
+                    Some(schema) =>
+                        self.ledger_service.build_get_claim_def_request(&verification.submitter_did, schema.seq_no, "CL", issuer_did)
+                            .map_err(IndyError::from),
+                    None => Err(IndyError::CommonError(CommonError::InvalidState(
+                        format!("Schema isn't resolved for claim definition: {:?}", schema_key))))
+                }
+            }
+            Some(&LedgerObjectKey::RevocRegDef(ref issuer_did, ref schema_key, _)) => {
+                VerifierCommandExecutor::_get_schema_seq_no(&verification.schemas, schema_key)
+                    .and_then(|schema_seq_no| {
+                        let id = VerifierCommandExecutor::_get_ledger_revoc_reg_def_id(issuer_did, schema_seq_no);
+                        self.ledger_service.build_get_revoc_reg_def_request(&verification.submitter_did, &id)
+                            .map_err(IndyError::from)
+                    })
+            }
+            Some(&LedgerObjectKey::RevocReg(ref issuer_did, ref schema_key, _, time)) => {
+                VerifierCommandExecutor::_get_schema_seq_no(&verification.schemas, schema_key)
+                    .and_then(|schema_seq_no| {
+                        let id = VerifierCommandExecutor::_get_ledger_revoc_reg_def_id(issuer_did, schema_seq_no);
+                        self.ledger_service.build_get_revoc_reg_delta_request(&verification.submitter_did, &id, None, time)
+                            .map_err(IndyError::from)
+                    })
+            }
+            None => {
+                let res = self._complete_proof_verification(&verification);
+                return (verification.cb)(res);
+            }
+        };
+
+        let request = match request {
+            Ok(request) => request,
+            Err(err) => return (verification.cb)(Err(err))
+        };
+
+        let pool_handle = verification.pool_handle;
+        self.pending_verifications.borrow_mut().insert(verification_id, verification);
+
+        CommandExecutor::instance()
+            .send(Command::Ledger(LedgerCommand::SubmitRequest(
+                pool_handle,
+                request,
+                Box::new(move |result| {
+                    CommandExecutor::instance()
+                        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::GetLedgerObjectAck(
+                            verification_id,
+                            result
+                        )))).unwrap();
+                })
+            ))).unwrap();
+    }
+
+    fn _complete_proof_verification(&self, verification: &LedgerProofVerification) -> Result<(bool, String), IndyError> {
+        let mut schemas: HashMap<String, Schema> = HashMap::new();
+        let mut claim_defs: HashMap<String, ClaimDefinition> = HashMap::new();
+        let mut revoc_regs: HashMap<String, RevocationRegistry> = HashMap::new();
+
+        for (referent, identifier) in verification.proof.identifiers.iter() {
+            let schema = verification.schemas.get(&identifier.schema_key)
+                .ok_or(CommonError::InvalidState(format!("Schema isn't resolved for referent: {}", referent)))?;
+
+            let claim_def = verification.claim_defs.get(&(identifier.issuer_did.clone(), identifier.schema_key.clone(), identifier.claim_def_tag.clone()))
+                .ok_or(CommonError::InvalidState(format!("Claim definition isn't resolved for referent: {}", referent)))?;
+
+            if claim_def.data.revocation.is_some() {
+                let time = verification.revoc_reg_times.get(referent).cloned()
+                    .ok_or(CommonError::InvalidState(format!("Revocation registry time isn't set for referent: {}", referent)))?;
+
+                let revoc_reg = verification.revoc_regs.get(&(identifier.issuer_did.clone(), identifier.schema_key.clone(), identifier.claim_def_tag.clone(), time))
+                    .ok_or(CommonError::InvalidState(format!("Revocation registry isn't resolved for referent: {}", referent)))?;
+
+                revoc_regs.insert(referent.clone(), revoc_reg.clone());
+            }
+
+            schemas.insert(referent.clone(), schema.clone());
+            claim_defs.insert(referent.clone(), claim_def.clone()?);
+        }
+
+        let valid = self._verify_full_proof(&verification.proof_req, &verification.proof, &schemas, &claim_defs, &revoc_regs)?;
+
+        // Tails are known by hash, so resolved registries are returned without them
+        let mut resolved_revoc_regs: Map<String, Value> = Map::new();
+        for (referent, revoc_reg) in revoc_regs {
+            let mut revoc_reg = serde_json::to_value(&revoc_reg)
+                .map_err(|err| CommonError::InvalidState(format!("Cannot serialize revocation registry: {:?}", err)))?;
+
+            if let Some(data) = revoc_reg["data"].as_object_mut() {
+                data.remove("tails");
+            }
+
+            resolved_revoc_regs.insert(referent, revoc_reg);
+        }
+
+        let resolved_json = json!({"schemas": schemas, "claim_defs": claim_defs, "revoc_regs": resolved_revoc_regs}).to_string();
+
+        info!("verify_proof_with_ledger <<< valid: {:?}, resolved_json: {:?}", valid, resolved_json);
+
+        Ok((valid, resolved_json))
+    }
+
+    // Claim definition ids on the ledger are "<issuer_did>:3:CL:<schema_seq_no>"
+    fn _get_ledger_claim_def_id(issuer_did: &str, schema_seq_no: i32) -> String {
+        format!("{}:3:CL:{}", issuer_did, schema_seq_no)
+    }
+
+    // Revocation registry definition ids on the ledger are "<issuer_did>:4:<claim_def_id>:CL_ACCUM:<tag>"
+    fn _get_ledger_revoc_reg_def_id(issuer_did: &str, schema_seq_no: i32) -> String {
+        format!("{}:4:{}:{}:{}", issuer_did, VerifierCommandExecutor::_get_ledger_claim_def_id(issuer_did, schema_seq_no),
+                CL_ACCUM, LEDGER_REVOC_REG_DEF_TAG)
+    }
+
+    fn _get_schema_seq_no(schemas: &HashMap<SchemaKey, Schema>, schema_key: &SchemaKey) -> Result<i32, IndyError> {
+        schemas.get(schema_key)
+            .map(|schema| schema.seq_no)
+            .ok_or(IndyError::CommonError(CommonError::InvalidState(format!("Schema isn't resolved: {:?}", schema_key))))
+    }
+
+    // Ledger keeps accumulator, its public key and issued and revoked indices, while CL registry keeps
+    // indices used in accumulator. Tails are read by hash from blob storage of the verifier.
+    fn _build_ledger_revocation_registry(&self,
+                                         issuer_did: &str,
+                                         schema_seq_no: i32,
+                                         revoc_reg_def: &RevocRegDefData,
+                                         revoc_reg_delta: &RevocRegDelta,
+                                         timestamp: i64,
+                                         valid_to: i64,
+                                         tails_config: &(String, String)) -> Result<RevocationRegistry, IndyError> {
+        let accum_key = revoc_reg_def.value.public_keys.get("accumKey")
+            .ok_or(CommonError::InvalidStructure(format!("Accumulator key not found in revocation registry definition: {}", revoc_reg_def.id)))?;
+
+        let tails_hash = revoc_reg_def.value.tails_hash.as_ref()
+            .ok_or(CommonError::InvalidStructure(format!("Tails hash not found in revocation registry definition: {}", revoc_reg_def.id)))?;
+
+        let max_cred_num = revoc_reg_def.value.max_cred_num;
+
+        let mut used_indices: BTreeSet<u32> = if revoc_reg_def.value.issuance_type == ISSUANCE_BY_DEFAULT {
+            (1..max_cred_num + 1).collect()
+        } else {
+            BTreeSet::new()
+        };
+
+        used_indices.extend(revoc_reg_delta.issued.iter().cloned());
+
+        for idx in revoc_reg_delta.revoked.iter() {
+            used_indices.remove(idx);
+        }
+
+        let revoc_reg_json = json!({
+            "issuer_did": issuer_did,
+            "schema_seq_no": schema_seq_no,
+            "data": {
+                "key": accum_key,
+                "acc": {"acc": revoc_reg_delta.accum, "v": used_indices, "max_claim_num": max_cred_num}
+            },
+            "timestamp": timestamp,
+            "valid_to": valid_to,
+            "tails_hash": tails_hash
+        }).to_string();
+
+        Ok(revocation_registry_from_json(&self.blob_storage_service, tails_config, &revoc_reg_json)?)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,78 @@
+extern crate libc;
+
+use api::ErrorCode;
+use errors::indy::IndyError;
+use services::blob_storage::{BlobStorageService, PluggedBlobStorageType};
+
+use self::libc::c_char;
+
+use std::rc::Rc;
+
+pub enum BlobStorageCommand {
+    RegisterBlobStorageType(String, // xtype
+                            extern fn(config: *const c_char,
+                                      handle: *mut i32) -> ErrorCode, // open_writer
+                            extern fn(handle: i32,
+                                      bytes: *const u8,
+                                      bytes_len: u32) -> ErrorCode, // append
+                            extern fn(handle: i32,
+                                      hash: *const c_char) -> ErrorCode, // finalize
+                            extern fn(config: *const c_char,
+                                      hash: *const c_char,
+                                      handle: *mut i32) -> ErrorCode, // open_reader
+                            extern fn(handle: i32,
+                                      buf: *mut u8,
+                                      buf_len: u32,
+                                      read_len: *mut u32) -> ErrorCode, // read
+                            extern fn(handle: i32) -> ErrorCode, // close
+                            Box<Fn(Result<(), IndyError>) + Send>)
+}
+
+pub struct BlobStorageCommandExecutor {
+    blob_storage_service: Rc<BlobStorageService>
+}
+
+impl BlobStorageCommandExecutor {
+    pub fn new(blob_storage_service: Rc<BlobStorageService>) -> BlobStorageCommandExecutor {
+        BlobStorageCommandExecutor {
+            blob_storage_service
+        }
+    }
+
+    pub fn execute(&self, command: BlobStorageCommand) {
+        match command {
+            BlobStorageCommand::RegisterBlobStorageType(xtype, open_writer, append, finalize, open_reader, read, close, cb) => {
+                info!(target: "blob_storage_command_executor", "RegisterBlobStorageType command received");
+                cb(self.register_type(&xtype, open_writer, append, finalize, open_reader, read, close));
+            }
+        };
+    }
+
+    fn register_type(&self,
+                     xtype: &str,
+                     open_writer: extern fn(config: *const c_char,
+                                            handle: *mut i32) -> ErrorCode,
+                     append: extern fn(handle: i32,
+                                       bytes: *const u8,
+                                       bytes_len: u32) -> ErrorCode,
+                     finalize: extern fn(handle: i32,
+                                         hash: *const c_char) -> ErrorCode,
+                     open_reader: extern fn(config: *const c_char,
+                                            hash: *const c_char,
+                                            handle: *mut i32) -> ErrorCode,
+                     read: extern fn(handle: i32,
+                                     buf: *mut u8,
+                                     buf_len: u32,
+                                     read_len: *mut u32) -> ErrorCode,
+                     close: extern fn(handle: i32) -> ErrorCode) -> Result<(), IndyError> {
+        info!("register_type >>> xtype: {:?}", xtype);
+
+        self.blob_storage_service.register_type(
+            xtype,
+            Box::new(PluggedBlobStorageType::new(open_writer, append, finalize, open_reader, read, close)))?;
+
+        info!("register_type <<<");
+
+        Ok(())
+    }
+}
@@ -2,16 +2,30 @@ extern crate indy_crypto;
 
 use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
 use errors::common::CommonError;
+use errors::crypto::CryptoError;
 use errors::indy::IndyError;
-use services::crypto::types::{KeyInfo, Key, ComboBox};
+use errors::wallet::WalletError;
+use services::crypto::types::{KeyInfo, Key, Did, ComboBox, PackedMessage, PackedProtectedHeader, PackedRecipient, PackedRecipientHeader, UnpackedMessageInfo};
 use services::wallet::WalletService;
 use services::crypto::CryptoService;
+use services::crypto::hd::DerivationPath;
+use services::crypto::stream::{DecryptionStream, DigestStream, EncryptionStream};
+use utils::crypto::hash::Hash;
+use utils::sequence::SequenceUtils;
 
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::error::Error;
 use std::rc::Rc;
 use std::str;
 
 use base64;
+use serde_json;
+
+const PACKED_MESSAGE_ENC: &'static str = "xsalsa20poly1305";
+const PACKED_MESSAGE_TYP: &'static str = "JWM/1.0";
+const PACKED_MESSAGE_AUTHCRYPT: &'static str = "Authcrypt";
+const PACKED_MESSAGE_ANONCRYPT: &'static str = "Anoncrypt";
 
 pub enum CryptoCommand {
     CreateKey(
@@ -27,6 +41,21 @@ pub enum CryptoCommand {
         i32, // wallet handle
         String, // verkey
         Box<Fn(Result<String, IndyError>) + Send>),
+    ListKeys(
+        i32, // wallet handle
+        Box<Fn(Result<String, IndyError>) + Send>),
+    DeleteKey(
+        i32, // wallet handle
+        String, // verkey
+        Box<Fn(Result<(), IndyError>) + Send>),
+    ExportVerkey(
+        String, // verkey
+        String, // format
+        Box<Fn(Result<String, IndyError>) + Send>),
+    ImportKeyFromJwk(
+        i32, // wallet handle
+        String, // jwk
+        Box<Fn(Result<String, IndyError>) + Send>),
     CryptoSign(
         i32, // wallet handle
         String, // my vk
@@ -56,12 +85,93 @@ pub enum CryptoCommand {
         i32, // wallet handle
         String, // my vk
         Vec<u8>, // msg
-        Box<Fn(Result<Vec<u8>, IndyError>) + Send>)
+        Box<Fn(Result<Vec<u8>, IndyError>) + Send>),
+    PackMessage(
+        i32, // wallet handle
+        Vec<u8>, // msg
+        String, // receiver keys json
+        Option<String>, // sender vk
+        Box<Fn(Result<Vec<u8>, IndyError>) + Send>),
+    UnpackMessage(
+        i32, // wallet handle
+        Vec<u8>, // packed msg
+        Box<Fn(Result<(String, Vec<u8>), IndyError>) + Send>),
+    SignStreamInit(
+        i32, // wallet handle
+        String, // my vk
+        Box<Fn(Result<i32, IndyError>) + Send>),
+    SignStreamUpdate(
+        i32, // stream handle
+        Vec<u8>, // msg chunk
+        Box<Fn(Result<(), IndyError>) + Send>),
+    SignStreamFinish(
+        i32, // stream handle
+        Box<Fn(Result<Vec<u8>, IndyError>) + Send>),
+    VerifyStreamInit(
+        String, // their vk
+        Box<Fn(Result<i32, IndyError>) + Send>),
+    VerifyStreamUpdate(
+        i32, // stream handle
+        Vec<u8>, // msg chunk
+        Box<Fn(Result<(), IndyError>) + Send>),
+    VerifyStreamFinish(
+        i32, // stream handle
+        Vec<u8>, // signature
+        Box<Fn(Result<bool, IndyError>) + Send>),
+    AuthenticatedEncryptStreamInit(
+        i32, // wallet handle
+        String, // my vk
+        String, // their vk
+        Box<Fn(Result<i32, IndyError>) + Send>),
+    AnonymousEncryptStreamInit(
+        String, // their vk
+        Box<Fn(Result<i32, IndyError>) + Send>),
+    EncryptStreamUpdate(
+        i32, // stream handle
+        Vec<u8>, // msg chunk
+        Box<Fn(Result<Vec<u8>, IndyError>) + Send>),
+    EncryptStreamFinish(
+        i32, // stream handle
+        Box<Fn(Result<Vec<u8>, IndyError>) + Send>),
+    AuthenticatedDecryptStreamInit(
+        i32, // wallet handle
+        String, // my vk
+        Box<Fn(Result<i32, IndyError>) + Send>),
+    AnonymousDecryptStreamInit(
+        i32, // wallet handle
+        String, // my vk
+        Box<Fn(Result<i32, IndyError>) + Send>),
+    DecryptStreamUpdate(
+        i32, // stream handle
+        Vec<u8>, // encrypted chunk
+        Box<Fn(Result<Vec<u8>, IndyError>) + Send>),
+    DecryptStreamFinish(
+        i32, // stream handle
+        Box<Fn(Result<Option<String>, IndyError>) + Send>),
+    CloseStream(
+        i32, // stream handle
+        Box<Fn(Result<(), IndyError>) + Send>)
+}
+
+struct DecryptStream {
+    my_key: Key,
+    authenticated: bool,
+    sender_vk: Option<String>,
+    stream: DecryptionStream
+}
+
+enum CryptoStream {
+    Sign(Key, DigestStream),
+    Verify(String, DigestStream),
+    Encrypt(EncryptionStream),
+    Decrypt(DecryptStream)
 }
 
 pub struct CryptoCommandExecutor {
     wallet_service: Rc<WalletService>,
     crypto_service: Rc<CryptoService>,
+
+    streams: RefCell<HashMap<i32, CryptoStream>>,
 }
 
 impl CryptoCommandExecutor {
@@ -71,6 +181,7 @@ impl CryptoCommandExecutor {
         CryptoCommandExecutor {
             wallet_service,
             crypto_service,
+            streams: RefCell::new(HashMap::new()),
         }
     }
 
@@ -88,6 +199,22 @@ impl CryptoCommandExecutor {
                 info!("GetKeyMetadata command received");
                 cb(self.get_key_metadata(wallet_handle, verkey));
             }
+            CryptoCommand::ListKeys(wallet_handle, cb) => {
+                info!("ListKeys command received");
+                cb(self.list_keys(wallet_handle));
+            }
+            CryptoCommand::DeleteKey(wallet_handle, verkey, cb) => {
+                info!("DeleteKey command received");
+                cb(self.delete_key(wallet_handle, &verkey));
+            }
+            CryptoCommand::ExportVerkey(verkey, format, cb) => {
+                info!("ExportVerkey command received");
+                cb(self.export_verkey(&verkey, &format));
+            }
+            CryptoCommand::ImportKeyFromJwk(wallet_handle, jwk, cb) => {
+                info!("ImportKeyFromJwk command received");
+                cb(self.import_key_from_jwk(wallet_handle, &jwk));
+            }
             CryptoCommand::CryptoSign(wallet_handle, my_vk, msg, cb) => {
                 info!("CryptoSign command received");
                 cb(self.crypto_sign(wallet_handle, &my_vk, &msg));
@@ -112,21 +239,108 @@ impl CryptoCommandExecutor {
                 info!("AnonymousDecrypt command received");
                 cb(self.anonymous_decrypt(wallet_handle, my_vk, encrypted_msg));
             }
+            CryptoCommand::PackMessage(wallet_handle, msg, receiver_keys_json, sender_vk, cb) => {
+                info!("PackMessage command received");
+                cb(self.pack_msg(wallet_handle, msg, receiver_keys_json, sender_vk));
+            }
+            CryptoCommand::UnpackMessage(wallet_handle, packed_msg, cb) => {
+                info!("UnpackMessage command received");
+                cb(self.unpack_msg(wallet_handle, packed_msg));
+            }
+            CryptoCommand::SignStreamInit(wallet_handle, my_vk, cb) => {
+                info!("SignStreamInit command received");
+                cb(self.sign_stream_init(wallet_handle, &my_vk));
+            }
+            CryptoCommand::SignStreamUpdate(stream_handle, msg, cb) => {
+                info!("SignStreamUpdate command received");
+                cb(self.sign_stream_update(stream_handle, &msg));
+            }
+            CryptoCommand::SignStreamFinish(stream_handle, cb) => {
+                info!("SignStreamFinish command received");
+                cb(self.sign_stream_finish(stream_handle));
+            }
+            CryptoCommand::VerifyStreamInit(their_vk, cb) => {
+                info!("VerifyStreamInit command received");
+                cb(self.verify_stream_init(&their_vk));
+            }
+            CryptoCommand::VerifyStreamUpdate(stream_handle, msg, cb) => {
+                info!("VerifyStreamUpdate command received");
+                cb(self.verify_stream_update(stream_handle, &msg));
+            }
+            CryptoCommand::VerifyStreamFinish(stream_handle, signature, cb) => {
+                info!("VerifyStreamFinish command received");
+                cb(self.verify_stream_finish(stream_handle, &signature));
+            }
+            CryptoCommand::AuthenticatedEncryptStreamInit(wallet_handle, my_vk, their_vk, cb) => {
+                info!("AuthenticatedEncryptStreamInit command received");
+                cb(self.authenticated_encrypt_stream_init(wallet_handle, &my_vk, &their_vk));
+            }
+            CryptoCommand::AnonymousEncryptStreamInit(their_vk, cb) => {
+                info!("AnonymousEncryptStreamInit command received");
+                cb(self.anonymous_encrypt_stream_init(&their_vk));
+            }
+            CryptoCommand::EncryptStreamUpdate(stream_handle, msg, cb) => {
+                info!("EncryptStreamUpdate command received");
+                cb(self.encrypt_stream_update(stream_handle, &msg));
+            }
+            CryptoCommand::EncryptStreamFinish(stream_handle, cb) => {
+                info!("EncryptStreamFinish command received");
+                cb(self.encrypt_stream_finish(stream_handle));
+            }
+            CryptoCommand::AuthenticatedDecryptStreamInit(wallet_handle, my_vk, cb) => {
+                info!("AuthenticatedDecryptStreamInit command received");
+                cb(self.decrypt_stream_init(wallet_handle, &my_vk, true));
+            }
+            CryptoCommand::AnonymousDecryptStreamInit(wallet_handle, my_vk, cb) => {
+                info!("AnonymousDecryptStreamInit command received");
+                cb(self.decrypt_stream_init(wallet_handle, &my_vk, false));
+            }
+            CryptoCommand::DecryptStreamUpdate(stream_handle, encrypted_msg, cb) => {
+                info!("DecryptStreamUpdate command received");
+                cb(self.decrypt_stream_update(stream_handle, &encrypted_msg));
+            }
+            CryptoCommand::DecryptStreamFinish(stream_handle, cb) => {
+                info!("DecryptStreamFinish command received");
+                cb(self.decrypt_stream_finish(stream_handle));
+            }
+            CryptoCommand::CloseStream(stream_handle, cb) => {
+                info!("CloseStream command received");
+                cb(self.close_stream(stream_handle));
+            }
         };
     }
 
     fn create_key(&self, wallet_handle: i32, key_info_json: String) -> Result<String, IndyError> {
         info!("create_key >>> wallet_handle: {:?}, key_info_json: {:?}", wallet_handle, key_info_json);
 
-        let key_info = KeyInfo::from_json(&key_info_json)
+        let mut key_info = KeyInfo::from_json(&key_info_json)
             .map_err(map_err_trace!())
             .map_err(|err|
                 CommonError::InvalidStructure(
                     format!("Invalid KeyInfo json: {}", err.description())))?;
 
+        let derivation_path = match key_info.derivation_path {
+            Some(ref path) => Some(DerivationPath::parse(path)?),
+            None => None
+        };
+
+        if let Some(ref path) = derivation_path {
+            if key_info.seed.is_some() {
+                return Err(IndyError::CommonError(CommonError::InvalidStructure(
+                    format!("KeyInfo can't contain both seed and derivation_path"))));
+            }
+
+            let master_seed = self._wallet_get_master_seed(wallet_handle)?;
+            key_info.seed = Some(self.crypto_service.derive_seed(&master_seed, key_info.crypto_type.as_ref().map(String::as_str), path)?);
+        }
+
         let key = self.crypto_service.create_key(&key_info)?;
         self._wallet_set_key(wallet_handle, &key)?;
 
+        if let Some(ref path) = derivation_path {
+            self._wallet_set_key_derivation_path(wallet_handle, &key.verkey, path)?;
+        }
+
         let res = key.verkey;
 
         info!("create_key <<< res: {:?}", res);
@@ -178,12 +392,7 @@ impl CryptoCommandExecutor {
 
         let my_key = CryptoCommandExecutor::_wallet_get_key(&self, wallet_handle, &my_vk)?;
 
-        let msg = self.crypto_service.create_combo_box(&my_key, &their_vk, msg.as_slice())?;
-
-        let msg = msg.to_msg_pack()
-            .map_err(|e| CommonError::InvalidState(format!("Can't serialize ComboBox: {:?}", e)))?;
-
-        let res = self.crypto_service.encrypt_sealed(&their_vk, &msg)?;
+        let res = self._authenticated_encrypt(&my_key, &their_vk, &msg)?;
 
         info!("authenticated_encrypt <<< res: {:?}", res);
 
@@ -200,20 +409,7 @@ impl CryptoCommandExecutor {
 
         let my_key = CryptoCommandExecutor::_wallet_get_key(&self, wallet_handle, &my_vk)?;
 
-        let decrypted_msg = self.crypto_service.decrypt_sealed(&my_key, &msg)?;
-
-        let parsed_msg = ComboBox::from_msg_pack(decrypted_msg.as_slice())
-            .map_err(|err| CommonError::InvalidStructure(format!("Can't deserialize ComboBox: {:?}", err)))?;
-
-        let doc: Vec<u8> = base64::decode(&parsed_msg.msg)
-            .map_err(|err| CommonError::InvalidStructure(format!("Can't decode internal msg filed from base64 {}", err)))?;
-
-        let nonce: Vec<u8> = base64::decode(&parsed_msg.nonce)
-            .map_err(|err| CommonError::InvalidStructure(format!("Can't decode nonce from base64 {}", err)))?;
-
-        let decrypted_msg = self.crypto_service.decrypt(&my_key, &parsed_msg.sender, &doc, &nonce)?;
-
-        let res = (parsed_msg.sender.clone(), decrypted_msg);
+        let res = self._authenticated_decrypt(&my_key, &msg)?;
 
         info!("authenticated_decrypt <<< res: {:?}", res);
 
@@ -251,6 +447,407 @@ impl CryptoCommandExecutor {
         Ok(res)
     }
 
+    fn pack_msg(&self,
+                wallet_handle: i32,
+                msg: Vec<u8>,
+                receiver_keys_json: String,
+                sender_vk: Option<String>) -> Result<Vec<u8>, IndyError> {
+        info!("pack_msg >>> wallet_handle: {:?}, msg: {:?}, receiver_keys_json: {:?}, sender_vk: {:?}",
+              wallet_handle, msg, receiver_keys_json, sender_vk);
+
+        let receiver_keys: Vec<String> = serde_json::from_str(&receiver_keys_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid receiver keys json: {}", err.description())))?;
+
+        if receiver_keys.is_empty() {
+            return Err(IndyError::CommonError(CommonError::InvalidStructure(format!("Receiver keys list is empty"))));
+        }
+
+        for receiver_key in receiver_keys.iter() {
+            self.crypto_service.validate_key(receiver_key)?;
+        }
+
+        let sender_key = match sender_vk {
+            Some(ref sender_vk) => {
+                self.crypto_service.validate_key(sender_vk)?;
+                Some(self._wallet_get_key(wallet_handle, sender_vk)?)
+            }
+            None => None
+        };
+
+        let protected = PackedProtectedHeader {
+            enc: PACKED_MESSAGE_ENC.to_string(),
+            typ: PACKED_MESSAGE_TYP.to_string(),
+            alg: (if sender_key.is_some() { PACKED_MESSAGE_AUTHCRYPT } else { PACKED_MESSAGE_ANONCRYPT }).to_string()
+        };
+
+        let protected = protected.to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Can't serialize protected header: {:?}", err)))?;
+        let protected = base64::encode(protected.as_bytes());
+
+        // Protected header is authenticated with the content as associated data
+        let (cek, iv, ciphertext) = self.crypto_service.encrypt_content(&msg, protected.as_bytes())?;
+
+        let mut recipients: Vec<PackedRecipient> = Vec::new();
+
+        for receiver_key in receiver_keys {
+            let (encrypted_key, sender, iv) = match sender_key {
+                Some(ref sender_key) => {
+                    // Every recipient knows content key, so content is authenticated by the sender
+                    // for each recipient separately, otherwise one recipient could alter it for others
+                    let mut key_and_digest = cek.clone();
+                    key_and_digest.extend_from_slice(&CryptoCommandExecutor::_packed_content_digest(&protected, &iv, &ciphertext)?);
+
+                    let (encrypted_key, nonce) = self.crypto_service.encrypt(sender_key, &receiver_key, &key_and_digest)?;
+                    let sender = self.crypto_service.encrypt_sealed(&receiver_key, sender_key.verkey.as_bytes())?;
+                    (encrypted_key, Some(base64::encode(&sender)), Some(base64::encode(&nonce)))
+                }
+                None => (self.crypto_service.encrypt_sealed(&receiver_key, &cek)?, None, None)
+            };
+
+            recipients.push(PackedRecipient {
+                encrypted_key: base64::encode(&encrypted_key),
+                header: PackedRecipientHeader { kid: receiver_key, sender, iv }
+            });
+        }
+
+        let packed_msg = PackedMessage {
+            protected,
+            recipients,
+            iv: base64::encode(&iv),
+            ciphertext: base64::encode(&ciphertext)
+        };
+
+        let res = packed_msg.to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Can't serialize packed message: {:?}", err)))?
+            .into_bytes();
+
+        info!("pack_msg <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn unpack_msg(&self,
+                  wallet_handle: i32,
+                  packed_msg: Vec<u8>) -> Result<(String, Vec<u8>), IndyError> {
+        info!("unpack_msg >>> wallet_handle: {:?}, packed_msg: {:?}", wallet_handle, packed_msg);
+
+        let packed_msg: PackedMessage = serde_json::from_slice(&packed_msg)
+            .map_err(|err| CommonError::InvalidStructure(format!("Can't deserialize packed message: {}", err.description())))?;
+
+        let protected = base64::decode(&packed_msg.protected)
+            .map_err(|err| CommonError::InvalidStructure(format!("Can't decode protected header from base64 {}", err)))?;
+        let protected = str::from_utf8(&protected)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid protected header: {}", err)))?;
+        let protected = PackedProtectedHeader::from_json(protected)
+            .map_err(|err| CommonError::InvalidStructure(format!("Can't deserialize protected header: {}", err.description())))?;
+
+        if protected.enc != PACKED_MESSAGE_ENC {
+            return Err(IndyError::CommonError(CommonError::InvalidStructure(format!("Unsupported content encryption: {}", protected.enc))));
+        }
+
+        let mut recipient: Option<(&PackedRecipient, Key)> = None;
+
+        for packed_recipient in packed_msg.recipients.iter() {
+            match self._wallet_get_key(wallet_handle, &packed_recipient.header.kid) {
+                Ok(key) => {
+                    recipient = Some((packed_recipient, key));
+                    break;
+                }
+                Err(IndyError::WalletError(WalletError::NotFound(_))) => {}
+                Err(err) => return Err(err)
+            }
+        }
+
+        let (recipient, my_key) = recipient
+            .ok_or(IndyError::WalletError(WalletError::NotFound(format!("None of the recipient keys is found in the wallet"))))?;
+
+        let encrypted_key = base64::decode(&recipient.encrypted_key)
+            .map_err(|err| CommonError::InvalidStructure(format!("Can't decode encrypted key from base64 {}", err)))?;
+
+        let iv = base64::decode(&packed_msg.iv)
+            .map_err(|err| CommonError::InvalidStructure(format!("Can't decode iv from base64 {}", err)))?;
+        let ciphertext = base64::decode(&packed_msg.ciphertext)
+            .map_err(|err| CommonError::InvalidStructure(format!("Can't decode ciphertext from base64 {}", err)))?;
+
+        let (cek, sender_verkey) = match protected.alg.as_str() {
+            PACKED_MESSAGE_AUTHCRYPT => {
+                let (sender, nonce) = match (recipient.header.sender.as_ref(), recipient.header.iv.as_ref()) {
+                    (Some(sender), Some(nonce)) => (sender, nonce),
+                    _ => return Err(IndyError::CommonError(CommonError::InvalidStructure(format!("Recipient header doesn't contain sender or iv"))))
+                };
+
+                let sender = base64::decode(sender)
+                    .map_err(|err| CommonError::InvalidStructure(format!("Can't decode sender from base64 {}", err)))?;
+                let nonce = base64::decode(nonce)
+                    .map_err(|err| CommonError::InvalidStructure(format!("Can't decode iv from base64 {}", err)))?;
+
+                let sender_verkey = self.crypto_service.decrypt_sealed(&my_key, &sender)?;
+                let sender_verkey = String::from_utf8(sender_verkey)
+                    .map_err(|err| CommonError::InvalidStructure(format!("Invalid sender verkey: {}", err)))?;
+
+                self.crypto_service.validate_key(&sender_verkey)?;
+
+                let mut key_and_digest = self.crypto_service.decrypt(&my_key, &sender_verkey, &encrypted_key, &nonce)?;
+
+                let digest = CryptoCommandExecutor::_packed_content_digest(&packed_msg.protected, &iv, &ciphertext)?;
+
+                if key_and_digest.len() <= digest.len() || key_and_digest[key_and_digest.len() - digest.len()..] != digest[..] {
+                    return Err(IndyError::CommonError(CommonError::InvalidStructure(format!("Content isn't authenticated by the sender"))));
+                }
+
+                let cek_len = key_and_digest.len() - digest.len();
+                key_and_digest.truncate(cek_len);
+
+                (key_and_digest, Some(sender_verkey))
+            }
+            PACKED_MESSAGE_ANONCRYPT => (self.crypto_service.decrypt_sealed(&my_key, &encrypted_key)?, None),
+            alg => return Err(IndyError::CommonError(CommonError::InvalidStructure(format!("Unsupported packing algorithm: {}", alg))))
+        };
+
+        let msg = self.crypto_service.decrypt_content(&cek, &iv, &ciphertext, packed_msg.protected.as_bytes())?;
+
+        let info = UnpackedMessageInfo { recipient_verkey: my_key.verkey.clone(), sender_verkey };
+
+        let info = info.to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Can't serialize unpacked message info: {:?}", err)))?;
+
+        let res = (info, msg);
+
+        info!("unpack_msg <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn sign_stream_init(&self, wallet_handle: i32, my_vk: &str) -> Result<i32, IndyError> {
+        info!("sign_stream_init >>> wallet_handle: {:?}, my_vk: {:?}", wallet_handle, my_vk);
+
+        self.crypto_service.validate_key(my_vk)?;
+
+        let my_key = self._wallet_get_key(wallet_handle, my_vk)?;
+
+        let res = self._add_stream(CryptoStream::Sign(my_key, DigestStream::new()));
+
+        info!("sign_stream_init <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn sign_stream_update(&self, stream_handle: i32, msg: &[u8]) -> Result<(), IndyError> {
+        info!("sign_stream_update >>> stream_handle: {:?}, msg len: {:?}", stream_handle, msg.len());
+
+        match self.streams.borrow_mut().get_mut(&stream_handle) {
+            Some(&mut CryptoStream::Sign(_, ref mut digest)) => digest.update(msg),
+            _ => return Err(CryptoCommandExecutor::_unknown_stream(stream_handle))
+        }
+
+        info!("sign_stream_update <<<");
+
+        Ok(())
+    }
+
+    fn sign_stream_finish(&self, stream_handle: i32) -> Result<Vec<u8>, IndyError> {
+        info!("sign_stream_finish >>> stream_handle: {:?}", stream_handle);
+
+        let (my_key, digest) = match self._remove_stream(stream_handle)? {
+            CryptoStream::Sign(my_key, digest) => (my_key, digest),
+            stream => return Err(self._return_stream(stream_handle, stream))
+        };
+
+        let res = self.crypto_service.sign(&my_key, &digest.finish())?;
+
+        info!("sign_stream_finish <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn verify_stream_init(&self, their_vk: &str) -> Result<i32, IndyError> {
+        info!("verify_stream_init >>> their_vk: {:?}", their_vk);
+
+        self.crypto_service.validate_key(their_vk)?;
+
+        let res = self._add_stream(CryptoStream::Verify(their_vk.to_string(), DigestStream::new()));
+
+        info!("verify_stream_init <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn verify_stream_update(&self, stream_handle: i32, msg: &[u8]) -> Result<(), IndyError> {
+        info!("verify_stream_update >>> stream_handle: {:?}, msg len: {:?}", stream_handle, msg.len());
+
+        match self.streams.borrow_mut().get_mut(&stream_handle) {
+            Some(&mut CryptoStream::Verify(_, ref mut digest)) => digest.update(msg),
+            _ => return Err(CryptoCommandExecutor::_unknown_stream(stream_handle))
+        }
+
+        info!("verify_stream_update <<<");
+
+        Ok(())
+    }
+
+    fn verify_stream_finish(&self, stream_handle: i32, signature: &[u8]) -> Result<bool, IndyError> {
+        info!("verify_stream_finish >>> stream_handle: {:?}, signature: {:?}", stream_handle, signature);
+
+        let (their_vk, digest) = match self._remove_stream(stream_handle)? {
+            CryptoStream::Verify(their_vk, digest) => (their_vk, digest),
+            stream => return Err(self._return_stream(stream_handle, stream))
+        };
+
+        let res = self.crypto_service.verify(&their_vk, &digest.finish(), signature)?;
+
+        info!("verify_stream_finish <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn authenticated_encrypt_stream_init(&self, wallet_handle: i32, my_vk: &str, their_vk: &str) -> Result<i32, IndyError> {
+        info!("authenticated_encrypt_stream_init >>> wallet_handle: {:?}, my_vk: {:?}, their_vk: {:?}", wallet_handle, my_vk, their_vk);
+
+        self.crypto_service.validate_key(my_vk)?;
+        self.crypto_service.validate_key(their_vk)?;
+
+        let my_key = self._wallet_get_key(wallet_handle, my_vk)?;
+
+        let key_material = EncryptionStream::gen_key_material();
+        let header = self._authenticated_encrypt(&my_key, their_vk, &key_material)?;
+
+        let res = self._add_stream(CryptoStream::Encrypt(EncryptionStream::new(&key_material, &header)?));
+
+        info!("authenticated_encrypt_stream_init <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn anonymous_encrypt_stream_init(&self, their_vk: &str) -> Result<i32, IndyError> {
+        info!("anonymous_encrypt_stream_init >>> their_vk: {:?}", their_vk);
+
+        self.crypto_service.validate_key(their_vk)?;
+
+        let key_material = EncryptionStream::gen_key_material();
+        let header = self.crypto_service.encrypt_sealed(their_vk, &key_material)?;
+
+        let res = self._add_stream(CryptoStream::Encrypt(EncryptionStream::new(&key_material, &header)?));
+
+        info!("anonymous_encrypt_stream_init <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn encrypt_stream_update(&self, stream_handle: i32, msg: &[u8]) -> Result<Vec<u8>, IndyError> {
+        info!("encrypt_stream_update >>> stream_handle: {:?}, msg len: {:?}", stream_handle, msg.len());
+
+        let res = match self.streams.borrow_mut().get_mut(&stream_handle) {
+            Some(&mut CryptoStream::Encrypt(ref mut stream)) => stream.update(msg),
+            _ => return Err(CryptoCommandExecutor::_unknown_stream(stream_handle))
+        };
+
+        info!("encrypt_stream_update <<< res len: {:?}", res.len());
+
+        Ok(res)
+    }
+
+    fn encrypt_stream_finish(&self, stream_handle: i32) -> Result<Vec<u8>, IndyError> {
+        info!("encrypt_stream_finish >>> stream_handle: {:?}", stream_handle);
+
+        let res = match self._remove_stream(stream_handle)? {
+            CryptoStream::Encrypt(stream) => stream.finish(),
+            stream => return Err(self._return_stream(stream_handle, stream))
+        };
+
+        info!("encrypt_stream_finish <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn decrypt_stream_init(&self, wallet_handle: i32, my_vk: &str, authenticated: bool) -> Result<i32, IndyError> {
+        info!("decrypt_stream_init >>> wallet_handle: {:?}, my_vk: {:?}, authenticated: {:?}", wallet_handle, my_vk, authenticated);
+
+        self.crypto_service.validate_key(my_vk)?;
+
+        let my_key = self._wallet_get_key(wallet_handle, my_vk)?;
+
+        let res = self._add_stream(CryptoStream::Decrypt(DecryptStream {
+            my_key,
+            authenticated,
+            sender_vk: None,
+            stream: DecryptionStream::new()
+        }));
+
+        info!("decrypt_stream_init <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn decrypt_stream_update(&self, stream_handle: i32, encrypted_msg: &[u8]) -> Result<Vec<u8>, IndyError> {
+        info!("decrypt_stream_update >>> stream_handle: {:?}, encrypted_msg len: {:?}", stream_handle, encrypted_msg.len());
+
+        let res = match self.streams.borrow_mut().get_mut(&stream_handle) {
+            Some(&mut CryptoStream::Decrypt(ref mut decrypt_stream)) => self._decrypt_stream_update(decrypt_stream, encrypted_msg),
+            _ => return Err(CryptoCommandExecutor::_unknown_stream(stream_handle))
+        };
+
+        // Stream can't be continued after invalid data
+        if res.is_err() {
+            self.streams.borrow_mut().remove(&stream_handle);
+        }
+
+        let res = res?;
+
+        info!("decrypt_stream_update <<< res len: {:?}", res.len());
+
+        Ok(res)
+    }
+
+    fn _decrypt_stream_update(&self, decrypt_stream: &mut DecryptStream, encrypted_msg: &[u8]) -> Result<Vec<u8>, IndyError> {
+        decrypt_stream.stream.push(encrypted_msg)?;
+
+        if !decrypt_stream.stream.has_key() {
+            let header = match decrypt_stream.stream.take_header()? {
+                Some(header) => header,
+                None => return Ok(Vec::new())
+            };
+
+            let key_material = if decrypt_stream.authenticated {
+                let (sender_vk, key_material) = self._authenticated_decrypt(&decrypt_stream.my_key, &header)?;
+                decrypt_stream.sender_vk = Some(sender_vk);
+                key_material
+            } else {
+                self.crypto_service.decrypt_sealed(&decrypt_stream.my_key, &header)?
+            };
+
+            decrypt_stream.stream.set_key_material(&key_material)?;
+        }
+
+        Ok(decrypt_stream.stream.decrypt_chunks()?)
+    }
+
+    fn decrypt_stream_finish(&self, stream_handle: i32) -> Result<Option<String>, IndyError> {
+        info!("decrypt_stream_finish >>> stream_handle: {:?}", stream_handle);
+
+        let decrypt_stream = match self._remove_stream(stream_handle)? {
+            CryptoStream::Decrypt(decrypt_stream) => decrypt_stream,
+            stream => return Err(self._return_stream(stream_handle, stream))
+        };
+
+        decrypt_stream.stream.finish()?;
+
+        let res = decrypt_stream.sender_vk;
+
+        info!("decrypt_stream_finish <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn close_stream(&self, stream_handle: i32) -> Result<(), IndyError> {
+        info!("close_stream >>> stream_handle: {:?}", stream_handle);
+
+        self._remove_stream(stream_handle)?;
+
+        info!("close_stream <<<");
+
+        Ok(())
+    }
+
     fn set_key_metadata(&self, wallet_handle: i32, verkey: String, metadata: String) -> Result<(), IndyError> {
         info!("set_key_metadata >>> wallet_handle: {:?}, verkey: {:?}, metadata: {:?}", wallet_handle, verkey, metadata);
 
@@ -275,6 +872,147 @@ impl CryptoCommandExecutor {
         Ok(res)
     }
 
+    fn list_keys(&self, wallet_handle: i32) -> Result<String, IndyError> {
+        info!("list_keys >>> wallet_handle: {:?}", wallet_handle);
+
+        let mut keys: Vec<serde_json::Value> = Vec::new();
+
+        for (id, key_json) in self.wallet_service.list(wallet_handle, "key::")? {
+            // Skip key::<verkey>::metadata and other records attached to the key
+            if id["key::".len()..].contains("::") {
+                continue;
+            }
+
+            let key = Key::from_json(&key_json)
+                .map_err(map_err_trace!())
+                .map_err(|err|
+                    CommonError::InvalidState(
+                        format!("Can't deserialize Key: {}", err.description())))?;
+
+            let metadata = self._wallet_get_key_record(wallet_handle, &key.verkey, "metadata")?;
+            let derivation_path = self._wallet_get_key_record(wallet_handle, &key.verkey, "derivation_path")?;
+
+            keys.push(json!({
+                "verkey": key.verkey,
+                "metadata": metadata,
+                "derivation_path": derivation_path,
+            }));
+        }
+
+        let res = serde_json::to_string(&keys)
+            .map_err(|err| CommonError::InvalidState(format!("Can't serialize keys list: {}", err)))?;
+
+        info!("list_keys <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn delete_key(&self, wallet_handle: i32, verkey: &str) -> Result<(), IndyError> {
+        info!("delete_key >>> wallet_handle: {:?}, verkey: {:?}", wallet_handle, verkey);
+
+        self.crypto_service.validate_key(verkey)?;
+
+        self._wallet_get_key(wallet_handle, verkey)?;
+
+        for prefix in ["my_did::", "my_temporary_did::"].iter() {
+            for (_, did_json) in self.wallet_service.list(wallet_handle, prefix)? {
+                let did = Did::from_json(&did_json)
+                    .map_err(map_err_trace!())
+                    .map_err(|err|
+                        CommonError::InvalidState(
+                            format!("Can't deserialize Did: {}", err.description())))?;
+
+                if did.verkey == verkey {
+                    return Err(IndyError::CryptoError(CryptoError::KeyUsedByDidError(
+                        format!("Key {} is used by DID: {}", verkey, did.did))));
+                }
+            }
+        }
+
+        self.wallet_service.remove(wallet_handle, &format!("key::{}", verkey))?;
+
+        for suffix in ["metadata", "derivation_path"].iter() {
+            match self.wallet_service.remove(wallet_handle, &format!("key::{}::{}", verkey, suffix)) {
+                Ok(()) | Err(WalletError::NotFound(_)) => {}
+                Err(err) => return Err(IndyError::WalletError(err))
+            }
+        }
+
+        info!("delete_key <<<");
+
+        Ok(())
+    }
+
+    fn export_verkey(&self, verkey: &str, format: &str) -> Result<String, IndyError> {
+        info!("export_verkey >>> verkey: {:?}, format: {:?}", verkey, format);
+
+        let res = self.crypto_service.export_verkey(verkey, format)?;
+
+        info!("export_verkey <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn import_key_from_jwk(&self, wallet_handle: i32, jwk: &str) -> Result<String, IndyError> {
+        info!("import_key_from_jwk >>> wallet_handle: {:?}", wallet_handle);
+
+        let key = self.crypto_service.import_jwk(jwk)?;
+        self._wallet_set_key(wallet_handle, &key)?;
+
+        let res = key.verkey;
+
+        info!("import_key_from_jwk <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _authenticated_encrypt(&self, my_key: &Key, their_vk: &str, msg: &[u8]) -> Result<Vec<u8>, IndyError> {
+        let msg = self.crypto_service.create_combo_box(my_key, their_vk, msg)?;
+
+        let msg = msg.to_msg_pack()
+            .map_err(|e| CommonError::InvalidState(format!("Can't serialize ComboBox: {:?}", e)))?;
+
+        Ok(self.crypto_service.encrypt_sealed(their_vk, &msg)?)
+    }
+
+    fn _authenticated_decrypt(&self, my_key: &Key, msg: &[u8]) -> Result<(String, Vec<u8>), IndyError> {
+        let decrypted_msg = self.crypto_service.decrypt_sealed(my_key, msg)?;
+
+        let parsed_msg = ComboBox::from_msg_pack(decrypted_msg.as_slice())
+            .map_err(|err| CommonError::InvalidStructure(format!("Can't deserialize ComboBox: {:?}", err)))?;
+
+        let doc: Vec<u8> = base64::decode(&parsed_msg.msg)
+            .map_err(|err| CommonError::InvalidStructure(format!("Can't decode internal msg filed from base64 {}", err)))?;
+
+        let nonce: Vec<u8> = base64::decode(&parsed_msg.nonce)
+            .map_err(|err| CommonError::InvalidStructure(format!("Can't decode nonce from base64 {}", err)))?;
+
+        let decrypted_msg = self.crypto_service.decrypt(my_key, &parsed_msg.sender, &doc, &nonce)?;
+
+        Ok((parsed_msg.sender, decrypted_msg))
+    }
+
+    fn _add_stream(&self, stream: CryptoStream) -> i32 {
+        let stream_handle = SequenceUtils::get_next_id();
+        self.streams.borrow_mut().insert(stream_handle, stream);
+        stream_handle
+    }
+
+    fn _remove_stream(&self, stream_handle: i32) -> Result<CryptoStream, IndyError> {
+        self.streams.borrow_mut().remove(&stream_handle)
+            .ok_or(CryptoCommandExecutor::_unknown_stream(stream_handle))
+    }
+
+    // Puts back the stream finished with wrong function
+    fn _return_stream(&self, stream_handle: i32, stream: CryptoStream) -> IndyError {
+        self.streams.borrow_mut().insert(stream_handle, stream);
+        CryptoCommandExecutor::_unknown_stream(stream_handle)
+    }
+
+    fn _unknown_stream(stream_handle: i32) -> IndyError {
+        IndyError::CommonError(CommonError::InvalidParam2(format!("Unknown crypto stream handle: {}", stream_handle)))
+    }
+
     fn _wallet_set_key(&self, wallet_handle: i32, key: &Key) -> Result<(), IndyError> {
         info!("_wallet_set_key >>> wallet_handle: {:?}, key: {:?}", wallet_handle, key);
 
@@ -291,6 +1029,15 @@ impl CryptoCommandExecutor {
         Ok(res)
     }
 
+    // Digest of packed content the sender authenticates for every recipient along with content key
+    fn _packed_content_digest(protected: &str, iv: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, CommonError> {
+        let mut hasher = Hash::new_context()?;
+        hasher.update(protected.as_bytes())?;
+        hasher.update(iv)?;
+        hasher.update(ciphertext)?;
+        Ok(hasher.finish2()?.to_vec())
+    }
+
     fn _wallet_get_key(&self, wallet_handle: i32, key: &str) -> Result<Key, IndyError> {
         info!("_wallet_get_key >>> wallet_handle: {:?}, key: {:?}", wallet_handle, key);
 
@@ -317,6 +1064,15 @@ impl CryptoCommandExecutor {
         Ok(res)
     }
 
+    // Returns None if record isn't attached to the key
+    fn _wallet_get_key_record(&self, wallet_handle: i32, verkey: &str, suffix: &str) -> Result<Option<String>, IndyError> {
+        match self.wallet_service.get(wallet_handle, &format!("key::{}::{}", verkey, suffix)) {
+            Ok(res) => Ok(Some(res)),
+            Err(WalletError::NotFound(_)) => Ok(None),
+            Err(err) => Err(IndyError::WalletError(err))
+        }
+    }
+
     fn _wallet_get_key_metadata(&self, wallet_handle: i32, verkey: &str) -> Result<String, IndyError> {
         info!("_wallet_get_key_metadata >>> wallet_handle: {:?}, verkey: {:?}", wallet_handle, verkey);
 
@@ -326,4 +1082,24 @@ impl CryptoCommandExecutor {
 
         Ok(res)
     }
+
+    fn _wallet_set_key_derivation_path(&self, wallet_handle: i32, verkey: &str, path: &DerivationPath) -> Result<(), IndyError> {
+        info!("_wallet_set_key_derivation_path >>> wallet_handle: {:?}, verkey: {:?}, path: {:?}", wallet_handle, verkey, path);
+
+        let res = self.wallet_service.set(wallet_handle, &format!("key::{}::derivation_path", verkey), &path.to_string())?;
+
+        info!("_wallet_set_key_derivation_path <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _wallet_get_master_seed(&self, wallet_handle: i32) -> Result<String, IndyError> {
+        info!("_wallet_get_master_seed >>> wallet_handle: {:?}", wallet_handle);
+
+        let res = self.wallet_service.get(wallet_handle, "master_seed")?;
+
+        info!("_wallet_get_master_seed <<<");
+
+        Ok(res)
+    }
 }
\ No newline at end of file
@@ -5,11 +5,13 @@ use errors::common::CommonError;
 use errors::did::DidError;
 use errors::wallet::WalletError;
 use errors::indy::IndyError;
-use services::crypto::types::{KeyInfo, MyDidInfo, TheirDidInfo, Did, Key};
-use services::ledger::types::{Reply, GetNymResultData, GetNymReplyResult, GetAttribReplyResult, Endpoint, AttribData};
+use services::crypto::types::{KeyInfo, MyDidInfo, TheirDidInfo, Did, Key, RecoveryOptions};
+use services::ledger::types::{Reply, GetNymResultData, GetNymReplyResult, GetAttribReplyResult, Endpoint, AttribData,
+                              DidDocument, DidResolutionOptions};
 use services::pool::PoolService;
 use services::wallet::WalletService;
 use services::crypto::CryptoService;
+use services::crypto::hd::DerivationPath;
 use services::ledger::LedgerService;
 
 use std::error::Error;
@@ -22,9 +24,13 @@ use commands::{Command, CommandExecutor};
 use std::collections::HashMap;
 use utils::sequence::SequenceUtils;
 use utils::crypto::base58::Base58;
+use serde_json::Value;
 
 use super::utils::check_wallet_and_pool_handles_consistency;
 
+const DEFAULT_RECOVERY_COUNT: u32 = 20;
+const MAX_RECOVERY_COUNT: u32 = 1000;
+
 pub enum DidCommand {
     CreateAndStoreMyDid(
         i32, // wallet handle
@@ -83,6 +89,21 @@ pub enum DidCommand {
         String, // did
         String, // verkey
         Box<Fn(Result<String, IndyError>) + Send>),
+    ResolveDid(
+        i32, // pool handle
+        i32, // wallet handle
+        String, // submitter did
+        String, // did
+        Option<String>, // options json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    SetMasterSeed(
+        i32, // wallet handle
+        String, // master seed
+        Box<Fn(Result<(), IndyError>) + Send>),
+    RecoverFromMasterSeed(
+        i32, // wallet handle
+        String, // recovery options json
+        Box<Fn(Result<String, IndyError>) + Send>),
     // Internal commands
     GetNymAck(
         i32, // wallet_handle
@@ -94,9 +115,31 @@ pub enum DidCommand {
         i32, // wallet_handle
         Result<String, IndyError>, // GetAttrib Result
         i32, // deferred cmd id
+    ),
+    // Internal commands
+    ResolveDidNymAck(
+        i32, // resolution id
+        Result<String, IndyError>, // GetNym Result
+    ),
+    // Internal commands
+    ResolveDidAttribAck(
+        i32, // resolution id
+        Result<String, IndyError>, // GetAttrib Result
     )
 }
 
+struct DidResolution {
+    wallet_handle: i32,
+    pool_handle: i32,
+    submitter_did: String,
+    did: String,
+    cache_key: String,
+    nym_data: Option<GetNymResultData>,
+    attribs: Vec<(String, String)>,
+    pending_attribs: Vec<String>,
+    cb: Box<Fn(Result<String, IndyError>) + Send>
+}
+
 macro_rules! ensure_their_did {
     ($self_:ident, $wallet_handle:ident, $pool_handle:ident, $their_did:ident, $deferred_cmd:expr, $cb:ident) => (match $self_._wallet_get_their_did($wallet_handle, &$their_did) {
           Ok(val) => val,
@@ -118,6 +161,7 @@ pub struct DidCommandExecutor {
     crypto_service: Rc<CryptoService>,
     ledger_service: Rc<LedgerService>,
     deferred_commands: RefCell<HashMap<i32, DidCommand>>,
+    pending_resolutions: RefCell<HashMap<i32, DidResolution>>,
 }
 
 impl DidCommandExecutor {
@@ -131,6 +175,7 @@ impl DidCommandExecutor {
             crypto_service,
             ledger_service,
             deferred_commands: RefCell::new(HashMap::new()),
+            pending_resolutions: RefCell::new(HashMap::new()),
         }
     }
 
@@ -188,6 +233,18 @@ impl DidCommandExecutor {
                 info!("AbbreviateVerkey command received");
                 cb(self.abbreviate_verkey(did, verkey));
             }
+            DidCommand::ResolveDid(pool_handle, wallet_handle, submitter_did, did, options_json, cb) => {
+                info!("ResolveDid command received");
+                self.resolve_did(pool_handle, wallet_handle, submitter_did, did, options_json, cb);
+            }
+            DidCommand::SetMasterSeed(wallet_handle, master_seed, cb) => {
+                info!("SetMasterSeed command received");
+                cb(self.set_master_seed(wallet_handle, &master_seed));
+            }
+            DidCommand::RecoverFromMasterSeed(wallet_handle, recovery_options_json, cb) => {
+                info!("RecoverFromMasterSeed command received");
+                cb(self.recover_from_master_seed(wallet_handle, &recovery_options_json));
+            }
             DidCommand::GetNymAck(wallet_handle, result, deferred_cmd_id) => {
                 info!("GetNymAck command received");
                 self.get_nym_ack(wallet_handle, result, deferred_cmd_id);
@@ -196,11 +253,19 @@ impl DidCommandExecutor {
                 info!("GetAttribAck command received");
                 self.get_attrib_ack(wallet_handle, result, deferred_cmd_id);
             }
+            DidCommand::ResolveDidNymAck(resolution_id, result) => {
+                info!("ResolveDidNymAck command received");
+                self.resolve_did_nym_ack(resolution_id, result);
+            }
+            DidCommand::ResolveDidAttribAck(resolution_id, result) => {
+                info!("ResolveDidAttribAck command received");
+                self.resolve_did_attrib_ack(resolution_id, result);
+            }
         };
     }
 
     fn create_and_store_my_did(&self, wallet_handle: i32, my_did_info_json: &str) -> Result<(String, String), IndyError> {
-        let my_did_info = MyDidInfo::from_json(&my_did_info_json)
+        let mut my_did_info = MyDidInfo::from_json(&my_did_info_json)
             .map_err(map_err_trace!())
             .map_err(|err|
                 CommonError::InvalidStructure(
@@ -212,15 +277,122 @@ impl DidCommandExecutor {
             };
         }
 
+        let derivation_path = match my_did_info.derivation_path {
+            Some(ref path) => Some(DerivationPath::parse(path)?),
+            None => None
+        };
+
+        if let Some(ref path) = derivation_path {
+            if my_did_info.seed.is_some() {
+                return Err(IndyError::CommonError(CommonError::InvalidStructure(
+                    format!("MyDidInfo can't contain both seed and derivation_path"))));
+            }
+
+            let master_seed = self._wallet_get_master_seed(wallet_handle)?;
+            my_did_info.seed = Some(self.crypto_service.derive_seed(&master_seed, my_did_info.crypto_type.as_ref().map(String::as_str), path)?);
+        }
+
         let (my_did, key) = self.crypto_service.create_my_did(&my_did_info)?;
 
         self._wallet_set_my_did(wallet_handle, &my_did)?;
         self._wallet_set_key(wallet_handle, &key)?;
 
+        if let Some(ref path) = derivation_path {
+            self._wallet_set_key_derivation_path(wallet_handle, &key.verkey, path)?;
+        }
+
         let res = (my_did.did, my_did.verkey);
         Ok(res)
     }
 
+    fn set_master_seed(&self, wallet_handle: i32, master_seed: &str) -> Result<(), IndyError> {
+        self.crypto_service.validate_master_seed(master_seed)?;
+
+        match self._wallet_get_master_seed(wallet_handle) {
+            Ok(_) => return Err(IndyError::DidError(DidError::MasterSeedAlreadySetError(format!("Master seed is already set")))),
+            Err(IndyError::WalletError(WalletError::NotFound(_))) => {}
+            Err(err) => return Err(err)
+        };
+
+        self.wallet_service.set(wallet_handle, "master_seed", master_seed)?;
+        Ok(())
+    }
+
+    fn recover_from_master_seed(&self, wallet_handle: i32, recovery_options_json: &str) -> Result<String, IndyError> {
+        let recovery_options = RecoveryOptions::from_json(recovery_options_json)
+            .map_err(map_err_trace!())
+            .map_err(|err|
+                CommonError::InvalidStructure(
+                    format!("Invalid RecoveryOptions json: {}", err.description())))?;
+
+        if recovery_options.did_path.is_none() && recovery_options.key_path.is_none() {
+            return Err(IndyError::CommonError(CommonError::InvalidStructure(
+                format!("RecoveryOptions must contain did_path or key_path"))));
+        }
+
+        let count = recovery_options.count.unwrap_or(DEFAULT_RECOVERY_COUNT);
+
+        if count > MAX_RECOVERY_COUNT {
+            return Err(IndyError::CommonError(CommonError::InvalidStructure(
+                format!("RecoveryOptions count must be not greater than {}", MAX_RECOVERY_COUNT))));
+        }
+
+        let master_seed = self._wallet_get_master_seed(wallet_handle)?;
+
+        let mut dids: Vec<Value> = Vec::new();
+
+        if let Some(ref did_path) = recovery_options.did_path {
+            let did_path = DerivationPath::parse(did_path)?;
+
+            for index in 0..count {
+                let path = did_path.child(index)?;
+
+                let mut my_did_info = MyDidInfo::new(None, None, None, None);
+                my_did_info.seed = Some(self.crypto_service.derive_seed(&master_seed, None, &path)?);
+
+                let (my_did, key) = self.crypto_service.create_my_did(&my_did_info)?;
+
+                self._wallet_set_my_did(wallet_handle, &my_did)?;
+                self._wallet_set_key(wallet_handle, &key)?;
+                self._wallet_set_key_derivation_path(wallet_handle, &key.verkey, &path)?;
+
+                dids.push(json!({
+                    "did": my_did.did,
+                    "verkey": my_did.verkey,
+                    "derivation_path": path.to_string(),
+                }));
+            }
+        }
+
+        let mut keys: Vec<Value> = Vec::new();
+
+        if let Some(ref key_path) = recovery_options.key_path {
+            let key_path = DerivationPath::parse(key_path)?;
+
+            for index in 0..count {
+                let path = key_path.child(index)?;
+
+                let seed = self.crypto_service.derive_seed(&master_seed, None, &path)?;
+                let key = self.crypto_service.create_key(&KeyInfo::new(Some(seed), None))?;
+
+                self._wallet_set_key(wallet_handle, &key)?;
+                self._wallet_set_key_derivation_path(wallet_handle, &key.verkey, &path)?;
+
+                keys.push(json!({
+                    "verkey": key.verkey,
+                    "derivation_path": path.to_string(),
+                }));
+            }
+        }
+
+        let res = json!({
+            "dids": dids,
+            "keys": keys,
+        }).to_string();
+
+        Ok(res)
+    }
+
     fn replace_keys_start(&self,
                           wallet_handle: i32,
                           key_info_json: &str,
@@ -480,6 +652,189 @@ impl DidCommandExecutor {
         Ok(())
     }
 
+    fn resolve_did(&self,
+                   pool_handle: i32,
+                   wallet_handle: i32,
+                   submitter_did: String,
+                   did: String,
+                   options_json: Option<String>,
+                   cb: Box<Fn(Result<String, IndyError>) + Send>) {
+        try_cb!(self.crypto_service.validate_did(&submitter_did), cb);
+        try_cb!(self.crypto_service.validate_did(&did), cb);
+
+        let options: DidResolutionOptions = match options_json {
+            Some(ref options_json) => try_cb!(DidResolutionOptions::from_json(options_json)
+                .map_err(map_err_trace!())
+                .map_err(|err|
+                    CommonError::InvalidStructure(
+                        format!("Invalid DidResolutionOptions json: {}", err.description()))), cb),
+            None => DidResolutionOptions::default()
+        };
+
+        let cache_key = DidCommandExecutor::_did_doc_cache_key(&did, &options.attribs);
+
+        if !options.no_cache {
+            match self.wallet_service.get_not_expired(wallet_handle, &cache_key) {
+                Ok(did_doc_json) => return cb(Ok(did_doc_json)),
+                Err(WalletError::NotFound(_)) => {}
+                Err(err) => return cb(Err(IndyError::from(err)))
+            }
+        }
+
+        check_wallet_and_pool_handles_consistency!(self.wallet_service, self.pool_service,
+                                                   wallet_handle, pool_handle, cb);
+
+        let get_nym_request = try_cb!(self.ledger_service.build_get_nym_request(&submitter_did, &did), cb);
+
+        let mut pending_attribs = options.attribs;
+        pending_attribs.reverse();
+
+        let resolution_id = SequenceUtils::get_next_id();
+        self.pending_resolutions.borrow_mut().insert(resolution_id, DidResolution {
+            wallet_handle,
+            pool_handle,
+            submitter_did,
+            did,
+            cache_key,
+            nym_data: None,
+            attribs: Vec::new(),
+            pending_attribs,
+            cb
+        });
+
+        CommandExecutor::instance()
+            .send(Command::Ledger(LedgerCommand::SubmitRequest(
+                pool_handle,
+                get_nym_request,
+                Box::new(move |result| {
+                    CommandExecutor::instance()
+                        .send(Command::Did(DidCommand::ResolveDidNymAck(
+                            resolution_id,
+                            result
+                        ))).unwrap();
+                })
+            ))).unwrap();
+    }
+
+    fn resolve_did_nym_ack(&self, resolution_id: i32, get_nym_reply_result: Result<String, IndyError>) {
+        let mut resolution = match self.pending_resolutions.borrow_mut().remove(&resolution_id) {
+            Some(resolution) => resolution,
+            None => return error!("No pending did resolution for id: {}", resolution_id)
+        };
+
+        match self._parse_resolved_nym(&resolution.did, get_nym_reply_result) {
+            Ok(nym_data) => resolution.nym_data = Some(nym_data),
+            Err(err) => return (resolution.cb)(Err(err))
+        }
+
+        self._continue_did_resolution(resolution_id, resolution);
+    }
+
+    fn resolve_did_attrib_ack(&self, resolution_id: i32, get_attrib_reply_result: Result<String, IndyError>) {
+        let mut resolution = match self.pending_resolutions.borrow_mut().remove(&resolution_id) {
+            Some(resolution) => resolution,
+            None => return error!("No pending did resolution for id: {}", resolution_id)
+        };
+
+        let name = match resolution.pending_attribs.pop() {
+            Some(name) => name,
+            None => return error!("No pending attrib for did resolution with id: {}", resolution_id)
+        };
+
+        match self._parse_resolved_attrib(get_attrib_reply_result) {
+            Ok(Some(data)) => resolution.attribs.push((name, data)),
+            Ok(None) => {}
+            Err(err) => return (resolution.cb)(Err(err))
+        }
+
+        self._continue_did_resolution(resolution_id, resolution);
+    }
+
+    fn _continue_did_resolution(&self, resolution_id: i32, resolution: DidResolution) {
+        let next_attrib = resolution.pending_attribs.last().cloned();
+
+        match next_attrib {
+            Some(name) => {
+                let get_attrib_request =
+                    match self.ledger_service.build_get_attrib_request(&resolution.submitter_did, &resolution.did, Some(&name), None, None) {
+                        Ok(request) => request,
+                        Err(err) => return (resolution.cb)(Err(IndyError::from(err)))
+                    };
+
+                let pool_handle = resolution.pool_handle;
+                self.pending_resolutions.borrow_mut().insert(resolution_id, resolution);
+
+                CommandExecutor::instance()
+                    .send(Command::Ledger(LedgerCommand::SubmitRequest(
+                        pool_handle,
+                        get_attrib_request,
+                        Box::new(move |result| {
+                            CommandExecutor::instance()
+                                .send(Command::Did(DidCommand::ResolveDidAttribAck(
+                                    resolution_id,
+                                    result
+                                ))).unwrap();
+                        })
+                    ))).unwrap();
+            }
+            None => {
+                let res = self._complete_did_resolution(&resolution);
+                (resolution.cb)(res)
+            }
+        }
+    }
+
+    fn _complete_did_resolution(&self, resolution: &DidResolution) -> Result<String, IndyError> {
+        let nym_data = resolution.nym_data.as_ref()
+            .ok_or(CommonError::InvalidState(format!("Did resolution is completed without nym data")))?;
+
+        let did_doc = self.ledger_service.build_did_document(&resolution.did, nym_data, &resolution.attribs)?;
+
+        let did_doc_json = DidDocument::to_json(&did_doc)
+            .map_err(map_err_trace!())
+            .map_err(|err|
+                CommonError::InvalidState(
+                    format!("Can't serialize DidDocument: {}", err.description())))?;
+
+        self.wallet_service.set(resolution.wallet_handle, &resolution.cache_key, &did_doc_json)?;
+        Ok(did_doc_json)
+    }
+
+    // DID Document depends on requested attribs, so documents resolved for different attribs are cached separately
+    fn _did_doc_cache_key(did: &str, attribs: &Vec<String>) -> String {
+        let mut attribs = attribs.clone();
+        attribs.sort();
+        attribs.dedup();
+
+        format!("did::{}::ddo::{}", did, attribs.join(","))
+    }
+
+    fn _parse_resolved_nym(&self, did: &str, get_nym_reply_result: Result<String, IndyError>) -> Result<GetNymResultData, IndyError> {
+        let get_nym_reply = get_nym_reply_result?;
+
+        let get_nym_response: Value = ::serde_json::from_str(&get_nym_reply)
+            .map_err(map_err_trace!())
+            .map_err(|_| CommonError::InvalidState(format!("Invalid GetNymReplyResult json")))?;
+
+        let data = get_nym_response["result"]["data"].as_str()
+            .ok_or(DidError::NotFoundError(format!("Did {} isn't found on the ledger", did)))?;
+
+        let res = GetNymResultData::from_json(data)
+            .map_err(map_err_trace!())
+            .map_err(|_| CommonError::InvalidState(format!("Invalid GetNymResultData json")))?;
+        Ok(res)
+    }
+
+    fn _parse_resolved_attrib(&self, get_attrib_reply_result: Result<String, IndyError>) -> Result<Option<String>, IndyError> {
+        let get_attrib_reply = get_attrib_reply_result?;
+
+        let get_attrib_response: Value = ::serde_json::from_str(&get_attrib_reply)
+            .map_err(map_err_trace!())
+            .map_err(|_| CommonError::InvalidState(format!("Invalid GetAttribReplyResult json")))?;
+
+        Ok(get_attrib_response["result"]["data"].as_str().map(String::from))
+    }
+
     fn _defer_command(&self, cmd: DidCommand) -> i32 {
         let deferred_cmd_id = SequenceUtils::get_next_id();
         self.deferred_commands.borrow_mut().insert(deferred_cmd_id, cmd);
@@ -518,6 +873,9 @@ impl DidCommandExecutor {
             DidCommand::GetEndpointForDid(_, _, _, cb) => {
                 return cb(Err(err));
             }
+            DidCommand::ResolveDid(_, _, _, _, _, cb) => {
+                return cb(Err(err));
+            }
             _ => {}
         }
     }
@@ -710,4 +1068,14 @@ impl DidCommandExecutor {
         let res = self.wallet_service.get(wallet_handle, &format!("did::{}::metadata", did))?;
         Ok(res)
     }
+
+    fn _wallet_set_key_derivation_path(&self, wallet_handle: i32, verkey: &str, path: &DerivationPath) -> Result<(), IndyError> {
+        self.wallet_service.set(wallet_handle, &format!("key::{}::derivation_path", verkey), &path.to_string())?;
+        Ok(())
+    }
+
+    fn _wallet_get_master_seed(&self, wallet_handle: i32) -> Result<String, IndyError> {
+        let res = self.wallet_service.get(wallet_handle, "master_seed")?;
+        Ok(res)
+    }
 }
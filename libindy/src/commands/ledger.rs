@@ -7,14 +7,18 @@ use errors::common::CommonError;
 use errors::pool::PoolError;
 use errors::crypto::CryptoError;
 use errors::indy::IndyError;
+use errors::wallet::WalletError;
 
 use services::pool::PoolService;
 use services::crypto::CryptoService;
 use services::crypto::types::{Did, Key};
 use services::wallet::WalletService;
 use services::ledger::LedgerService;
+use services::ledger::constants::{SCHEMA_CACHE_PREFIX, CLAIM_DEF_CACHE_PREFIX, POOL_LEDGER_ID, DOMAIN_LEDGER_ID, CONFIG_LEDGER_ID};
+use services::ledger::types::{LedgerCacheOptions, LedgerCachePurgeOptions, LedgerTxn};
 
 
+use commands::{Command, CommandExecutor};
 use super::utils::check_wallet_and_pool_handles_consistency;
 
 use std::cell::RefCell;
@@ -23,6 +27,7 @@ use std::error::Error;
 use std::rc::Rc;
 
 use utils::crypto::base58::Base58;
+use utils::sequence::SequenceUtils;
 
 use utils::crypto::signature_serializer::serialize_signature;
 use self::indy_crypto::utils::json::JsonDecodable;
@@ -42,6 +47,12 @@ pub enum LedgerCommand {
         i32, // cmd_id
         Result<String, PoolError>, // result json or error
     ),
+    SubmitAction(
+        i32, // pool handle
+        String, // request json
+        Option<String>, // nodes
+        Option<i32>, // timeout
+        Box<Fn(Result<String, IndyError>) + Send>),
     SignRequest(
         i32, // wallet handle
         String, // submitter did
@@ -122,7 +133,120 @@ pub enum LedgerCommand {
         Option<String>, // justification
         bool, // reinstall
         bool, // force
-        Box<Fn(Result<String, IndyError>) + Send>)
+        Box<Fn(Result<String, IndyError>) + Send>),
+    BuildPoolRestartRequest(
+        String, // submitter did
+        String, // action
+        Option<String>, // datetime
+        Box<Fn(Result<String, IndyError>) + Send>),
+    BuildGetValidatorInfoRequest(
+        String, // submitter did
+        Box<Fn(Result<String, IndyError>) + Send>),
+    BuildRevocRegDefRequest(
+        String, // submitter did
+        String, // data
+        Box<Fn(Result<String, IndyError>) + Send>),
+    BuildRevocRegEntryRequest(
+        String, // submitter did
+        String, // revoc reg def id
+        String, // revoc def type
+        String, // value
+        Box<Fn(Result<String, IndyError>) + Send>),
+    BuildGetRevocRegDefRequest(
+        String, // submitter did
+        String, // id
+        Box<Fn(Result<String, IndyError>) + Send>),
+    BuildGetRevocRegRequest(
+        String, // submitter did
+        String, // revoc reg def id
+        i64, // timestamp
+        Box<Fn(Result<String, IndyError>) + Send>),
+    BuildGetRevocRegDeltaRequest(
+        String, // submitter did
+        String, // revoc reg def id
+        Option<i64>, // from
+        i64, // to
+        Box<Fn(Result<String, IndyError>) + Send>),
+    ParseGetRevocRegDefResponse(
+        String, // get revoc reg def response
+        Box<Fn(Result<(String, String), IndyError>) + Send>),
+    ParseGetRevocRegResponse(
+        String, // get revoc reg response
+        Box<Fn(Result<(String, String, u64), IndyError>) + Send>),
+    ParseGetRevocRegDeltaResponse(
+        String, // get revoc reg delta response
+        Box<Fn(Result<(String, String, u64), IndyError>) + Send>),
+    GetSchema(
+        i32, // pool handle
+        i32, // wallet handle
+        String, // submitter did
+        String, // dest
+        String, // data
+        Option<String>, // options json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    GetClaimDef(
+        i32, // pool handle
+        i32, // wallet handle
+        String, // submitter did
+        i32, // xref
+        String, // signature_type
+        String, // origin
+        Option<String>, // options json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    PurgeSchemaCache(
+        i32, // wallet handle
+        Option<String>, // options json
+        Box<Fn(Result<(), IndyError>) + Send>),
+    PurgeClaimDefCache(
+        i32, // wallet handle
+        Option<String>, // options json
+        Box<Fn(Result<(), IndyError>) + Send>),
+    OpenLedgerTxnReader(
+        i32, // pool handle
+        String, // submitter did
+        i32, // ledger id
+        i32, // from
+        Option<i32>, // to
+        Box<Fn(Result<i32, IndyError>) + Send>),
+    FetchLedgerTxns(
+        i32, // reader handle
+        usize, // count
+        Box<Fn(Result<String, IndyError>) + Send>),
+    CloseLedgerTxnReader(
+        i32, // reader handle
+        Box<Fn(Result<(), IndyError>) + Send>),
+    // Internal commands
+    FetchLedgerTxnsAck(
+        i32, // fetch id
+        i32, // seq no
+        Result<String, IndyError>, // GetTxn result
+    ),
+}
+
+const MAX_LEDGER_TXNS_BATCH_SIZE: usize = 10;
+
+struct LedgerTxnReader {
+    pool_handle: i32,
+    submitter_did: String,
+    ledger_id: i32,
+    next_seq_no: i32,
+    to: Option<i32>,
+    // Last verified ledger root, following roots must be consistent with it
+    root: Option<(String, usize)>,
+    finished: bool,
+    fetching: bool,
+}
+
+struct LedgerTxnFetch {
+    reader_handle: i32,
+    // Reader position the fetch started from, failed fetch rolls the reader back to it
+    start_seq_no: i32,
+    start_root: Option<(String, usize)>,
+    count: usize,
+    txns: Vec<LedgerTxn>,
+    batch: Vec<i32>,
+    replies: HashMap<i32, Result<String, IndyError>>,
+    cb: Box<Fn(Result<String, IndyError>) + Send>,
 }
 
 pub struct LedgerCommandExecutor {
@@ -132,6 +256,8 @@ pub struct LedgerCommandExecutor {
     ledger_service: Rc<LedgerService>,
 
     send_callbacks: RefCell<HashMap<i32, Box<Fn(Result<String, IndyError>)>>>,
+    txn_readers: RefCell<HashMap<i32, LedgerTxnReader>>,
+    pending_txn_fetches: RefCell<HashMap<i32, LedgerTxnFetch>>,
 }
 
 impl LedgerCommandExecutor {
@@ -145,6 +271,8 @@ impl LedgerCommandExecutor {
             wallet_service,
             ledger_service,
             send_callbacks: RefCell::new(HashMap::new()),
+            txn_readers: RefCell::new(HashMap::new()),
+            pending_txn_fetches: RefCell::new(HashMap::new()),
         }
     }
 
@@ -164,6 +292,10 @@ impl LedgerCommandExecutor {
                     .expect("Expect callback to process ack command")
                     (result.map_err(IndyError::from));
             }
+            LedgerCommand::SubmitAction(handle, request_json, nodes, timeout, cb) => {
+                info!(target: "ledger_command_executor", "SubmitAction command received");
+                self.submit_action(handle, &request_json, nodes.as_ref().map(String::as_str), timeout, cb);
+            }
             LedgerCommand::SignRequest(wallet_handle, submitter_did, request_json, cb) => {
                 info!(target: "ledger_command_executor", "SignRequest command received");
                 self.sign_request(wallet_handle, &submitter_did, &request_json, cb);
@@ -232,6 +364,80 @@ impl LedgerCommandExecutor {
                                                    justification.as_ref().map(String::as_str),
                                                    reinstall, force));
             }
+            LedgerCommand::BuildPoolRestartRequest(submitter_did, action, datetime, cb) => {
+                info!(target: "ledger_command_executor", "BuildPoolRestartRequest command received");
+                cb(self.build_pool_restart_request(&submitter_did, &action, datetime.as_ref().map(String::as_str)));
+            }
+            LedgerCommand::BuildGetValidatorInfoRequest(submitter_did, cb) => {
+                info!(target: "ledger_command_executor", "BuildGetValidatorInfoRequest command received");
+                cb(self.build_get_validator_info_request(&submitter_did));
+            }
+            LedgerCommand::BuildRevocRegDefRequest(submitter_did, data, cb) => {
+                info!(target: "ledger_command_executor", "BuildRevocRegDefRequest command received");
+                cb(self.build_revoc_reg_def_request(&submitter_did, &data));
+            }
+            LedgerCommand::BuildRevocRegEntryRequest(submitter_did, revoc_reg_def_id, revoc_def_type, value, cb) => {
+                info!(target: "ledger_command_executor", "BuildRevocRegEntryRequest command received");
+                cb(self.build_revoc_reg_entry_request(&submitter_did, &revoc_reg_def_id, &revoc_def_type, &value));
+            }
+            LedgerCommand::BuildGetRevocRegDefRequest(submitter_did, id, cb) => {
+                info!(target: "ledger_command_executor", "BuildGetRevocRegDefRequest command received");
+                cb(self.build_get_revoc_reg_def_request(&submitter_did, &id));
+            }
+            LedgerCommand::BuildGetRevocRegRequest(submitter_did, revoc_reg_def_id, timestamp, cb) => {
+                info!(target: "ledger_command_executor", "BuildGetRevocRegRequest command received");
+                cb(self.build_get_revoc_reg_request(&submitter_did, &revoc_reg_def_id, timestamp));
+            }
+            LedgerCommand::BuildGetRevocRegDeltaRequest(submitter_did, revoc_reg_def_id, from, to, cb) => {
+                info!(target: "ledger_command_executor", "BuildGetRevocRegDeltaRequest command received");
+                cb(self.build_get_revoc_reg_delta_request(&submitter_did, &revoc_reg_def_id, from, to));
+            }
+            LedgerCommand::ParseGetRevocRegDefResponse(get_revoc_reg_def_response, cb) => {
+                info!(target: "ledger_command_executor", "ParseGetRevocRegDefResponse command received");
+                cb(self.ledger_service.parse_get_revoc_reg_def_response(&get_revoc_reg_def_response).map_err(IndyError::from));
+            }
+            LedgerCommand::ParseGetRevocRegResponse(get_revoc_reg_response, cb) => {
+                info!(target: "ledger_command_executor", "ParseGetRevocRegResponse command received");
+                cb(self.ledger_service.parse_get_revoc_reg_response(&get_revoc_reg_response).map_err(IndyError::from));
+            }
+            LedgerCommand::ParseGetRevocRegDeltaResponse(get_revoc_reg_delta_response, cb) => {
+                info!(target: "ledger_command_executor", "ParseGetRevocRegDeltaResponse command received");
+                cb(self.ledger_service.parse_get_revoc_reg_delta_response(&get_revoc_reg_delta_response).map_err(IndyError::from));
+            }
+            LedgerCommand::GetSchema(pool_handle, wallet_handle, submitter_did, dest, data, options_json, cb) => {
+                info!(target: "ledger_command_executor", "GetSchema command received");
+                self.get_schema(pool_handle, wallet_handle, &submitter_did, &dest, &data,
+                                options_json.as_ref().map(String::as_str), cb);
+            }
+            LedgerCommand::GetClaimDef(pool_handle, wallet_handle, submitter_did, xref, signature_type, origin, options_json, cb) => {
+                info!(target: "ledger_command_executor", "GetClaimDef command received");
+                self.get_claim_def(pool_handle, wallet_handle, &submitter_did, xref, &signature_type, &origin,
+                                   options_json.as_ref().map(String::as_str), cb);
+            }
+            LedgerCommand::PurgeSchemaCache(wallet_handle, options_json, cb) => {
+                info!(target: "ledger_command_executor", "PurgeSchemaCache command received");
+                cb(self.purge_cache(wallet_handle, SCHEMA_CACHE_PREFIX, options_json.as_ref().map(String::as_str)));
+            }
+            LedgerCommand::PurgeClaimDefCache(wallet_handle, options_json, cb) => {
+                info!(target: "ledger_command_executor", "PurgeClaimDefCache command received");
+                cb(self.purge_cache(wallet_handle, CLAIM_DEF_CACHE_PREFIX, options_json.as_ref().map(String::as_str)));
+            }
+            LedgerCommand::OpenLedgerTxnReader(pool_handle, submitter_did, ledger_id, from, to, cb) => {
+                info!(target: "ledger_command_executor", "OpenLedgerTxnReader command received");
+                cb(self.open_ledger_txn_reader(pool_handle, &submitter_did, ledger_id, from, to));
+            }
+            LedgerCommand::FetchLedgerTxns(reader_handle, count, cb) => {
+                info!(target: "ledger_command_executor", "FetchLedgerTxns command received");
+                self.fetch_ledger_txns(reader_handle, count, cb);
+            }
+            LedgerCommand::CloseLedgerTxnReader(reader_handle, cb) => {
+                info!(target: "ledger_command_executor", "CloseLedgerTxnReader command received");
+                cb(self.close_ledger_txn_reader(reader_handle));
+            }
+            LedgerCommand::FetchLedgerTxnsAck(fetch_id, seq_no, result) => {
+                info!(target: "ledger_command_executor", "FetchLedgerTxnsAck command received");
+                self.fetch_ledger_txns_ack(fetch_id, seq_no, result);
+            }
         };
     }
 
@@ -287,6 +493,13 @@ impl LedgerCommandExecutor {
                       handle: i32,
                       request_json: &str,
                       cb: Box<Fn(Result<String, IndyError>) + Send>) {
+        self._submit_request(handle, request_json, cb)
+    }
+
+    fn _submit_request(&self,
+                       handle: i32,
+                       request_json: &str,
+                       cb: Box<Fn(Result<String, IndyError>)>) {
         let x: Result<i32, PoolError> = self.pool_service.send_tx(handle, request_json);
         match x {
             Ok(cmd_id) => { self.send_callbacks.borrow_mut().insert(cmd_id, cb); }
@@ -294,6 +507,23 @@ impl LedgerCommandExecutor {
         };
     }
 
+    fn submit_action(&self,
+                     handle: i32,
+                     request_json: &str,
+                     nodes: Option<&str>,
+                     timeout: Option<i32>,
+                     cb: Box<Fn(Result<String, IndyError>) + Send>) {
+        if let Err(err) = self.ledger_service.validate_action(request_json) {
+            return cb(Err(IndyError::CommonError(err)));
+        }
+
+        let x: Result<i32, PoolError> = self.pool_service.send_action(handle, request_json, nodes, timeout);
+        match x {
+            Ok(cmd_id) => { self.send_callbacks.borrow_mut().insert(cmd_id, cb); }
+            Err(err) => { cb(Err(IndyError::PoolError(err))); }
+        };
+    }
+
     fn sign_request(&self,
                     wallet_handle: i32,
                     submitter_did: &str,
@@ -499,7 +729,9 @@ impl LedgerCommandExecutor {
         self.crypto_service.validate_did(submitter_did)?;
 
         let res = self.ledger_service.build_get_txn_request(submitter_did,
-                                                            data)?;
+                                                            None,
+                                                            data,
+                                                            None)?;
 
         info!("build_get_txn_request <<< res: {:?}", res);
 
@@ -546,4 +778,521 @@ impl LedgerCommandExecutor {
 
         Ok(res)
     }
+
+    fn build_pool_restart_request(&self,
+                                  submitter_did: &str,
+                                  action: &str,
+                                  datetime: Option<&str>) -> Result<String, IndyError> {
+        info!("build_pool_restart_request >>> submitter_did: {:?}, action: {:?}, datetime: {:?}",
+              submitter_did, action, datetime);
+
+        self.crypto_service.validate_did(submitter_did)?;
+
+        let res = self.ledger_service.build_pool_restart(submitter_did, action, datetime)?;
+
+        info!("build_pool_restart_request  <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_get_validator_info_request(&self,
+                                        submitter_did: &str) -> Result<String, IndyError> {
+        info!("build_get_validator_info_request >>> submitter_did: {:?}", submitter_did);
+
+        self.crypto_service.validate_did(submitter_did)?;
+
+        let res = self.ledger_service.build_get_validator_info_request(submitter_did)?;
+
+        info!("build_get_validator_info_request  <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_revoc_reg_def_request(&self,
+                                   submitter_did: &str,
+                                   data: &str) -> Result<String, IndyError> {
+        info!("build_revoc_reg_def_request >>> submitter_did: {:?}, data: {:?}", submitter_did, data);
+
+        self.crypto_service.validate_did(submitter_did)?;
+
+        let res = self.ledger_service.build_revoc_reg_def_request(submitter_did, data)?;
+
+        info!("build_revoc_reg_def_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_revoc_reg_entry_request(&self,
+                                     submitter_did: &str,
+                                     revoc_reg_def_id: &str,
+                                     revoc_def_type: &str,
+                                     value: &str) -> Result<String, IndyError> {
+        info!("build_revoc_reg_entry_request >>> submitter_did: {:?}, revoc_reg_def_id: {:?}, revoc_def_type: {:?}, value: {:?}",
+              submitter_did, revoc_reg_def_id, revoc_def_type, value);
+
+        self.crypto_service.validate_did(submitter_did)?;
+
+        let res = self.ledger_service.build_revoc_reg_entry_request(submitter_did, revoc_reg_def_id, revoc_def_type, value)?;
+
+        info!("build_revoc_reg_entry_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_get_revoc_reg_def_request(&self,
+                                       submitter_did: &str,
+                                       id: &str) -> Result<String, IndyError> {
+        info!("build_get_revoc_reg_def_request >>> submitter_did: {:?}, id: {:?}", submitter_did, id);
+
+        self.crypto_service.validate_did(submitter_did)?;
+
+        let res = self.ledger_service.build_get_revoc_reg_def_request(submitter_did, id)?;
+
+        info!("build_get_revoc_reg_def_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_get_revoc_reg_request(&self,
+                                   submitter_did: &str,
+                                   revoc_reg_def_id: &str,
+                                   timestamp: i64) -> Result<String, IndyError> {
+        info!("build_get_revoc_reg_request >>> submitter_did: {:?}, revoc_reg_def_id: {:?}, timestamp: {:?}",
+              submitter_did, revoc_reg_def_id, timestamp);
+
+        self.crypto_service.validate_did(submitter_did)?;
+
+        let res = self.ledger_service.build_get_revoc_reg_request(submitter_did, revoc_reg_def_id, timestamp)?;
+
+        info!("build_get_revoc_reg_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_get_revoc_reg_delta_request(&self,
+                                         submitter_did: &str,
+                                         revoc_reg_def_id: &str,
+                                         from: Option<i64>,
+                                         to: i64) -> Result<String, IndyError> {
+        info!("build_get_revoc_reg_delta_request >>> submitter_did: {:?}, revoc_reg_def_id: {:?}, from: {:?}, to: {:?}",
+              submitter_did, revoc_reg_def_id, from, to);
+
+        self.crypto_service.validate_did(submitter_did)?;
+
+        let res = self.ledger_service.build_get_revoc_reg_delta_request(submitter_did, revoc_reg_def_id, from, to)?;
+
+        info!("build_get_revoc_reg_delta_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn get_schema(&self,
+                  pool_handle: i32,
+                  wallet_handle: i32,
+                  submitter_did: &str,
+                  dest: &str,
+                  data: &str,
+                  options_json: Option<&str>,
+                  cb: Box<Fn(Result<String, IndyError>) + Send>) {
+        let request = try_cb!(self.build_get_schema_request(submitter_did, dest, data), cb);
+        let cache_key = try_cb!(self.ledger_service.get_schema_cache_key(dest, data), cb);
+
+        self._get_cached(pool_handle, wallet_handle, &request, &cache_key, options_json, cb);
+    }
+
+    fn get_claim_def(&self,
+                     pool_handle: i32,
+                     wallet_handle: i32,
+                     submitter_did: &str,
+                     xref: i32,
+                     signature_type: &str,
+                     origin: &str,
+                     options_json: Option<&str>,
+                     cb: Box<Fn(Result<String, IndyError>) + Send>) {
+        let request = try_cb!(self.build_get_claim_def_request(submitter_did, xref, signature_type, origin), cb);
+        let cache_key = self.ledger_service.get_claim_def_cache_key(xref, signature_type, origin);
+
+        self._get_cached(pool_handle, wallet_handle, &request, &cache_key, options_json, cb);
+    }
+
+    fn _get_cached(&self,
+                   pool_handle: i32,
+                   wallet_handle: i32,
+                   request_json: &str,
+                   cache_key: &str,
+                   options_json: Option<&str>,
+                   cb: Box<Fn(Result<String, IndyError>) + Send>) {
+        let options = match options_json {
+            Some(options_json) => try_cb!(LedgerCacheOptions::from_json(options_json)
+                .map_err(|err| CommonError::InvalidStructure(format!("Invalid cache options json: {}", err.description()))), cb),
+            None => LedgerCacheOptions::default()
+        };
+
+        if !options.no_cache {
+            match self.wallet_service.get_not_expired(wallet_handle, cache_key) {
+                Ok(record) => {
+                    if let Some(reply) = try_cb!(self.ledger_service.get_cached_reply(&record, options.min_fresh), cb) {
+                        return cb(Ok(reply));
+                    }
+                }
+                Err(WalletError::NotFound(_)) => {}
+                Err(err) => return cb(Err(IndyError::WalletError(err)))
+            }
+        }
+
+        if options.no_update {
+            return cb(Err(IndyError::WalletError(WalletError::NotFound(cache_key.to_string()))));
+        }
+
+        check_wallet_and_pool_handles_consistency!(self.wallet_service, self.pool_service,
+                                                   wallet_handle, pool_handle, cb);
+
+        let wallet_service = self.wallet_service.clone();
+        let ledger_service = self.ledger_service.clone();
+        let cache_key = cache_key.to_string();
+        let no_store = options.no_store;
+
+        self._submit_request(pool_handle, request_json, Box::new(move |result| {
+            let result = result.and_then(|reply| {
+                if !no_store {
+                    if let Some(record) = ledger_service.build_cache_record(&reply)? {
+                        wallet_service.set(wallet_handle, &cache_key, &record)?;
+                    }
+                }
+                Ok(reply)
+            });
+            cb(result)
+        }));
+    }
+
+    fn purge_cache(&self,
+                   wallet_handle: i32,
+                   cache_prefix: &str,
+                   options_json: Option<&str>) -> Result<(), IndyError> {
+        let options = match options_json {
+            Some(options_json) => LedgerCachePurgeOptions::from_json(options_json)
+                .map_err(|err| CommonError::InvalidStructure(format!("Invalid purge options json: {}", err.description())))?,
+            None => LedgerCachePurgeOptions::default()
+        };
+
+        for (key, record) in self.wallet_service.list(wallet_handle, cache_prefix)? {
+            if self.ledger_service.is_cache_record_expired(&record, options.max_age)? {
+                self.wallet_service.remove(wallet_handle, &key)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn open_ledger_txn_reader(&self,
+                              pool_handle: i32,
+                              submitter_did: &str,
+                              ledger_id: i32,
+                              from: i32,
+                              to: Option<i32>) -> Result<i32, IndyError> {
+        info!("open_ledger_txn_reader >>> pool_handle: {:?}, submitter_did: {:?}, ledger_id: {:?}, from: {:?}, to: {:?}",
+              pool_handle, submitter_did, ledger_id, from, to);
+
+        self.crypto_service.validate_did(submitter_did)?;
+
+        if ledger_id != POOL_LEDGER_ID && ledger_id != DOMAIN_LEDGER_ID && ledger_id != CONFIG_LEDGER_ID {
+            return Err(IndyError::CommonError(CommonError::InvalidStructure(format!("Invalid ledger id: {}", ledger_id))));
+        }
+
+        if from < 1 || to.map(|to| to < from).unwrap_or(false) {
+            return Err(IndyError::CommonError(CommonError::InvalidStructure(format!("Invalid transactions range: {:?} - {:?}", from, to))));
+        }
+
+        let reader_handle = SequenceUtils::get_next_id();
+
+        self.txn_readers.borrow_mut().insert(reader_handle, LedgerTxnReader {
+            pool_handle,
+            submitter_did: submitter_did.to_string(),
+            ledger_id,
+            next_seq_no: from,
+            to,
+            root: None,
+            finished: false,
+            fetching: false,
+        });
+
+        info!("open_ledger_txn_reader <<< reader_handle: {:?}", reader_handle);
+
+        Ok(reader_handle)
+    }
+
+    fn fetch_ledger_txns(&self,
+                         reader_handle: i32,
+                         count: usize,
+                         cb: Box<Fn(Result<String, IndyError>) + Send>) {
+        info!("fetch_ledger_txns >>> reader_handle: {:?}, count: {:?}", reader_handle, count);
+
+        let (start_seq_no, start_root) = {
+            let mut readers = self.txn_readers.borrow_mut();
+
+            let reader = match readers.get_mut(&reader_handle) {
+                Some(reader) => reader,
+                None => return cb(Err(IndyError::CommonError(CommonError::InvalidParam2(
+                    format!("Unknown ledger transactions reader handle: {}", reader_handle)))))
+            };
+
+            if reader.fetching {
+                return cb(Err(IndyError::CommonError(CommonError::InvalidState(
+                    format!("Previous fetch for ledger transactions reader {} isn't finished yet", reader_handle)))));
+            }
+
+            reader.fetching = true;
+
+            (reader.next_seq_no, reader.root.clone())
+        };
+
+        let fetch_id = SequenceUtils::get_next_id();
+
+        self.pending_txn_fetches.borrow_mut().insert(fetch_id, LedgerTxnFetch {
+            reader_handle,
+            start_seq_no,
+            start_root,
+            count,
+            txns: Vec::new(),
+            batch: Vec::new(),
+            replies: HashMap::new(),
+            cb,
+        });
+
+        self._continue_ledger_txns_fetch(fetch_id);
+    }
+
+    fn fetch_ledger_txns_ack(&self, fetch_id: i32, seq_no: i32, result: Result<String, IndyError>) {
+        let mut fetch = match self.pending_txn_fetches.borrow_mut().remove(&fetch_id) {
+            Some(fetch) => fetch,
+            None => return error!("No pending ledger transactions fetch for id: {}", fetch_id)
+        };
+
+        fetch.replies.insert(seq_no, result);
+
+        if fetch.replies.len() < fetch.batch.len() {
+            self.pending_txn_fetches.borrow_mut().insert(fetch_id, fetch);
+            return;
+        }
+
+        let res = {
+            let mut readers = self.txn_readers.borrow_mut();
+            match readers.get_mut(&fetch.reader_handle) {
+                Some(reader) => LedgerCommandExecutor::_process_ledger_txns_batch(&self.ledger_service, reader, &mut fetch),
+                None => Err(IndyError::CommonError(CommonError::InvalidState(
+                    format!("Ledger transactions reader {} was closed", fetch.reader_handle))))
+            }
+        };
+
+        match res {
+            Ok(()) => {
+                self.pending_txn_fetches.borrow_mut().insert(fetch_id, fetch);
+                self._continue_ledger_txns_fetch(fetch_id);
+            }
+            Err(err) => self._complete_ledger_txns_fetch(fetch, Err(err))
+        }
+    }
+
+    fn _process_ledger_txns_batch(ledger_service: &LedgerService,
+                                  reader: &mut LedgerTxnReader,
+                                  fetch: &mut LedgerTxnFetch) -> Result<(), IndyError> {
+        let batch: Vec<i32> = fetch.batch.drain(..).collect();
+        let mut replies: HashMap<i32, Result<String, IndyError>> = fetch.replies.drain().collect();
+
+        // All roots of the batch are checked against the root verified before the batch was sent
+        let mut new_root = reader.root.clone();
+        let mut next_seq_no = reader.next_seq_no;
+        let mut finished = false;
+        let mut txns: Vec<LedgerTxn> = Vec::new();
+
+        for seq_no in batch {
+            let reply = replies.remove(&seq_no)
+                .unwrap_or(Err(IndyError::CommonError(CommonError::InvalidState(format!("No reply for transaction {}", seq_no)))))?;
+
+            let txn = ledger_service.parse_get_txn_response_with_proof(&reply, reader.ledger_id, seq_no,
+                                                                       reader.root.as_ref().map(|&(ref root_hash, ledger_size)| (root_hash.as_str(), ledger_size)))?;
+
+            match txn {
+                Some(txn) => {
+                    if new_root.as_ref().map(|&(_, ledger_size)| txn.ledger_size > ledger_size).unwrap_or(true) {
+                        new_root = Some((txn.root_hash.clone(), txn.ledger_size));
+                    }
+                    txns.push(txn);
+                    next_seq_no = seq_no + 1;
+                }
+                None => {
+                    finished = true;
+                    break;
+                }
+            }
+        }
+
+        // Batch is applied only when all its transactions are verified
+        fetch.txns.extend(txns);
+        reader.next_seq_no = next_seq_no;
+        reader.finished = finished;
+        reader.root = new_root;
+
+        Ok(())
+    }
+
+    fn _continue_ledger_txns_fetch(&self, fetch_id: i32) {
+        let mut fetch = match self.pending_txn_fetches.borrow_mut().remove(&fetch_id) {
+            Some(fetch) => fetch,
+            None => return error!("No pending ledger transactions fetch for id: {}", fetch_id)
+        };
+
+        let next_requests = match self.txn_readers.borrow().get(&fetch.reader_handle) {
+            Some(reader) => {
+                let mut batch_size = ::std::cmp::min(fetch.count.saturating_sub(fetch.txns.len()), MAX_LEDGER_TXNS_BATCH_SIZE);
+
+                if let Some(to) = reader.to {
+                    batch_size = ::std::cmp::min(batch_size, ::std::cmp::max(to - reader.next_seq_no + 1, 0) as usize);
+                }
+
+                if reader.finished || batch_size == 0 {
+                    None
+                } else {
+                    let consistency_from = reader.root.as_ref().map(|&(_, ledger_size)| ledger_size);
+
+                    Some((reader.next_seq_no..reader.next_seq_no + batch_size as i32)
+                        .map(|seq_no|
+                            self.ledger_service.build_get_txn_request(&reader.submitter_did, Some(reader.ledger_id), seq_no, consistency_from)
+                                .map(|request| (seq_no, request))
+                                .map_err(IndyError::from))
+                        .collect::<Result<Vec<(i32, String)>, IndyError>>()
+                        .map(|requests| (reader.pool_handle, requests)))
+                }
+            }
+            None => Some(Err(IndyError::CommonError(CommonError::InvalidState(
+                format!("Ledger transactions reader {} was closed", fetch.reader_handle)))))
+        };
+
+        match next_requests {
+            None => {
+                let res = serde_json::to_string(&fetch.txns)
+                    .map_err(|err| IndyError::CommonError(CommonError::InvalidState(
+                        format!("Can't serialize ledger transactions: {}", err.description()))));
+                self._complete_ledger_txns_fetch(fetch, res)
+            }
+            Some(Ok((pool_handle, requests))) => {
+                fetch.batch = requests.iter().map(|&(seq_no, _)| seq_no).collect();
+                self.pending_txn_fetches.borrow_mut().insert(fetch_id, fetch);
+
+                for (seq_no, request) in requests {
+                    self._submit_request(pool_handle, &request, Box::new(move |result| {
+                        CommandExecutor::instance()
+                            .send(Command::Ledger(LedgerCommand::FetchLedgerTxnsAck(
+                                fetch_id,
+                                seq_no,
+                                result
+                            ))).unwrap();
+                    }));
+                }
+            }
+            Some(Err(err)) => self._complete_ledger_txns_fetch(fetch, Err(err))
+        }
+    }
+
+    fn _complete_ledger_txns_fetch(&self, fetch: LedgerTxnFetch, res: Result<String, IndyError>) {
+        info!("fetch_ledger_txns <<< res: {:?}", res);
+
+        if let Some(reader) = self.txn_readers.borrow_mut().get_mut(&fetch.reader_handle) {
+            reader.fetching = false;
+
+            // Transactions of failed fetch aren't returned, so the next fetch must read them again
+            if res.is_err() {
+                reader.next_seq_no = fetch.start_seq_no;
+                reader.root = fetch.start_root.clone();
+                reader.finished = false;
+            }
+        }
+
+        (fetch.cb)(res)
+    }
+
+    fn close_ledger_txn_reader(&self, reader_handle: i32) -> Result<(), IndyError> {
+        info!("close_ledger_txn_reader >>> reader_handle: {:?}", reader_handle);
+
+        self.txn_readers.borrow_mut().remove(&reader_handle)
+            .ok_or(IndyError::CommonError(CommonError::InvalidParam2(
+                format!("Unknown ledger transactions reader handle: {}", reader_handle))))?;
+
+        info!("close_ledger_txn_reader <<<");
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate rmp_serde;
+    extern crate rust_base58;
+
+    use super::*;
+    use self::rust_base58::ToBase58;
+    use services::ledger::merkletree::merkletree::MerkleTree;
+    use utils::crypto::hash::Hash;
+
+    #[test]
+    fn process_ledger_txns_batch_works_for_failed_reply_in_the_middle() {
+        let ledger_service = LedgerService::new();
+
+        let mut reader = LedgerTxnReader {
+            pool_handle: 1,
+            submitter_did: "VsKV7grR1BUE29mG2Fm2kX".to_string(),
+            ledger_id: DOMAIN_LEDGER_ID,
+            next_seq_no: 1,
+            to: None,
+            root: None,
+            finished: false,
+            fetching: true,
+        };
+
+        let mut replies = HashMap::new();
+        replies.insert(1, Ok(_get_txn_reply()));
+        replies.insert(2, Err(IndyError::PoolError(PoolError::Timeout)));
+
+        let mut fetch = LedgerTxnFetch {
+            reader_handle: 1,
+            start_seq_no: 1,
+            start_root: None,
+            count: 2,
+            txns: Vec::new(),
+            batch: vec![1, 2],
+            replies,
+            cb: Box::new(|_| {}),
+        };
+
+        let res = LedgerCommandExecutor::_process_ledger_txns_batch(&ledger_service, &mut reader, &mut fetch);
+        assert_match!(Err(IndyError::PoolError(PoolError::Timeout)), res);
+
+        assert!(fetch.txns.is_empty());
+        assert_eq!(1, reader.next_seq_no);
+        assert_eq!(None, reader.root);
+        assert!(!reader.finished);
+    }
+
+    // Verifiable GET_TXN reply for the first of two domain ledger transactions
+    fn _get_txn_reply() -> String {
+        let txn = json!({"type": "1", "dest": "VsKV7grR1BUE29mG2Fm2kX", "seqNo": 1});
+        let other_leaf = "other".as_bytes().to_vec();
+
+        let mut mt = MerkleTree::from_vec(vec![]).unwrap();
+        mt.append(rmp_serde::to_vec_named(&txn).unwrap()).unwrap();
+        mt.append(other_leaf.clone()).unwrap();
+
+        let mut data = txn.clone();
+        data["auditPath"] = json!([Hash::hash_leaf(&other_leaf).unwrap().to_vec().to_base58()]);
+        data["rootHash"] = json!(mt.root_hash().to_base58());
+        data["ledgerSize"] = json!(2);
+
+        let state_proof = json!({
+            "multi_signature": {
+                "value": {"ledger_id": DOMAIN_LEDGER_ID, "txn_root_hash": mt.root_hash().to_base58()}
+            }
+        });
+
+        json!({"op": "REPLY", "result": {"type": "3", "seqNo": 1, "data": data, "state_proof": state_proof}}).to_string()
+    }
 }
@@ -3,6 +3,7 @@ mod utils;
 
 #[allow(unused_variables)] /* FIXME */
 pub mod anoncreds;
+pub mod blob_storage;
 pub mod crypto;
 pub mod ledger;
 pub mod pool;
@@ -11,6 +12,7 @@ pub mod wallet;
 pub mod pairwise;
 
 use commands::anoncreds::{AnoncredsCommand, AnoncredsCommandExecutor};
+use commands::blob_storage::{BlobStorageCommand, BlobStorageCommandExecutor};
 use commands::crypto::{CryptoCommand, CryptoCommandExecutor};
 use commands::ledger::{LedgerCommand, LedgerCommandExecutor};
 use commands::pool::{PoolCommand, PoolCommandExecutor};
@@ -21,6 +23,7 @@ use commands::pairwise::{PairwiseCommand, PairwiseCommandExecutor};
 use errors::common::CommonError;
 
 use services::anoncreds::AnoncredsService;
+use services::blob_storage::BlobStorageService;
 use services::pool::PoolService;
 use services::wallet::WalletService;
 use services::crypto::CryptoService;
@@ -35,6 +38,7 @@ use std::sync::{Mutex, MutexGuard};
 pub enum Command {
     Exit,
     Anoncreds(AnoncredsCommand),
+    BlobStorage(BlobStorageCommand),
     Crypto(CryptoCommand),
     Ledger(LedgerCommand),
     Pool(PoolCommand),
@@ -68,12 +72,14 @@ impl CommandExecutor {
                 info!(target: "command_executor", "Worker thread started");
 
                 let anoncreds_service = Rc::new(AnoncredsService::new());
+                let blob_storage_service = Rc::new(BlobStorageService::new());
                 let pool_service = Rc::new(PoolService::new());
                 let wallet_service = Rc::new(WalletService::new());
                 let crypto_service = Rc::new(CryptoService::new());
                 let ledger_service = Rc::new(LedgerService::new());
 
-                let anoncreds_command_executor = AnoncredsCommandExecutor::new(anoncreds_service.clone(), pool_service.clone(), wallet_service.clone());
+                let anoncreds_command_executor = AnoncredsCommandExecutor::new(anoncreds_service.clone(), blob_storage_service.clone(), pool_service.clone(), wallet_service.clone(), ledger_service.clone());
+                let blob_storage_command_executor = BlobStorageCommandExecutor::new(blob_storage_service.clone());
                 let crypto_command_executor = CryptoCommandExecutor::new(wallet_service.clone(), crypto_service.clone());
                 let ledger_command_executor = LedgerCommandExecutor::new(pool_service.clone(), crypto_service.clone(), wallet_service.clone(), ledger_service.clone());
                 let pool_command_executor = PoolCommandExecutor::new(pool_service.clone());
@@ -87,6 +93,10 @@ impl CommandExecutor {
                             info!("AnoncredsCommand command received");
                             anoncreds_command_executor.execute(cmd);
                         }
+                        Ok(Command::BlobStorage(cmd)) => {
+                            info!("BlobStorageCommand command received");
+                            blob_storage_command_executor.execute(cmd);
+                        }
                         Ok(Command::Crypto(cmd)) => {
                             info!("CryptoCommand command received");
                             crypto_command_executor.execute(cmd);
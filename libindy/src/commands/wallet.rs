@@ -32,6 +32,8 @@ pub enum WalletCommand {
                        extern fn(handle: i32,
                                  key_prefix: *const c_char,
                                  values_json_ptr: *mut *const c_char) -> ErrorCode, // list
+                       Option<extern fn(handle: i32,
+                                        key: *const c_char) -> ErrorCode>, // remove
                        extern fn(handle: i32) -> ErrorCode, // close
                        extern fn(name: *const c_char,
                                  config: *const c_char,
@@ -70,10 +72,10 @@ impl WalletCommandExecutor {
     pub fn execute(&self, command: WalletCommand) {
         match command {
             WalletCommand::RegisterWalletType(xtype, create, open, set, get,
-                                              get_not_expired, list, close, delete, free, cb) => {
+                                              get_not_expired, list, remove, close, delete, free, cb) => {
                 info!(target: "wallet_command_executor", "RegisterWalletType command received");
                 self.register_type(&xtype, create, open, set,
-                                   get, get_not_expired, list, close, delete, free, cb);
+                                   get, get_not_expired, list, remove, close, delete, free, cb);
             }
             WalletCommand::Create(pool_name, name, xtype, config, credentials, cb) => {
                 info!(target: "wallet_command_executor", "Create command received");
@@ -123,6 +125,8 @@ impl WalletCommandExecutor {
                      list: extern fn(handle: i32,
                                      key_prefix: *const c_char,
                                      values_json_ptr: *mut *const c_char) -> ErrorCode,
+                     remove: Option<extern fn(handle: i32,
+                                              key: *const c_char) -> ErrorCode>,
                      close: extern fn(handle: i32) -> ErrorCode,
                      delete: extern fn(name: *const c_char,
                                        config: *const c_char,
@@ -135,7 +139,7 @@ impl WalletCommandExecutor {
             .register_type(
                 xtype, create, open, set,
                 get, get_not_expired,
-                list, close, delete, free)
+                list, remove, close, delete, free)
             .map_err(IndyError::from));
     }
 
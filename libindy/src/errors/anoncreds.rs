@@ -21,6 +21,8 @@ pub enum AnoncredsError {
     AccumulatorIsFull(String),
     ClaimRevoked(String),
     ClaimDefAlreadyExists(String),
+    ClaimOfferExpired(String),
+    ClaimOfferNotFound(String),
     CommonError(CommonError)
 }
 
@@ -35,6 +37,8 @@ impl fmt::Display for AnoncredsError {
             AnoncredsError::AccumulatorIsFull(ref description) => write!(f, "Accumulator is full: {}", description),
             AnoncredsError::ClaimRevoked(ref description) => write!(f, "Claim revoked: {}", description),
             AnoncredsError::ClaimDefAlreadyExists(ref description) => write!(f, "Claim definition already exists: {}", description),
+            AnoncredsError::ClaimOfferExpired(ref description) => write!(f, "Claim offer expired: {}", description),
+            AnoncredsError::ClaimOfferNotFound(ref description) => write!(f, "Claim offer not found: {}", description),
             AnoncredsError::CommonError(ref err) => err.fmt(f)
         }
     }
@@ -51,6 +55,8 @@ impl error::Error for AnoncredsError {
             AnoncredsError::AccumulatorIsFull(ref description) => description,
             AnoncredsError::ClaimRevoked(ref description) => description,
             AnoncredsError::ClaimDefAlreadyExists(ref description) => description,
+            AnoncredsError::ClaimOfferExpired(ref description) => description,
+            AnoncredsError::ClaimOfferNotFound(ref description) => description,
             AnoncredsError::CommonError(ref err) => err.description()
         }
     }
@@ -65,6 +71,8 @@ impl error::Error for AnoncredsError {
             AnoncredsError::AccumulatorIsFull(ref description) => None,
             AnoncredsError::ClaimRevoked(ref description) => None,
             AnoncredsError::ClaimDefAlreadyExists(ref description) => None,
+            AnoncredsError::ClaimOfferExpired(ref description) => None,
+            AnoncredsError::ClaimOfferNotFound(ref description) => None,
             AnoncredsError::CommonError(ref err) => Some(err)
         }
     }
@@ -81,6 +89,8 @@ impl ToErrorCode for AnoncredsError {
             AnoncredsError::AccumulatorIsFull(ref description) => ErrorCode::AnoncredsAccumulatorIsFull,
             AnoncredsError::ClaimRevoked(ref description) => ErrorCode::AnoncredsClaimRevoked,
             AnoncredsError::ClaimDefAlreadyExists(ref description) => ErrorCode::AnoncredsClaimDefAlreadyExistsError,
+            AnoncredsError::ClaimOfferExpired(ref description) => ErrorCode::AnoncredsClaimOfferExpiredError,
+            AnoncredsError::ClaimOfferNotFound(ref description) => ErrorCode::AnoncredsClaimOfferNotFoundError,
             AnoncredsError::CommonError(ref err) => err.to_error_code()
         }
     }
@@ -12,6 +12,8 @@ use errors::ToErrorCode;
 #[derive(Debug)]
 pub enum DidError {
     AlreadyExistsError(String),
+    NotFoundError(String),
+    MasterSeedAlreadySetError(String),
     CommonError(CommonError)
 }
 
@@ -19,6 +21,8 @@ impl fmt::Display for DidError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DidError::AlreadyExistsError(ref description) => write!(f, "Did already exists: {}", description),
+            DidError::NotFoundError(ref description) => write!(f, "Did not found: {}", description),
+            DidError::MasterSeedAlreadySetError(ref description) => write!(f, "Master seed already set: {}", description),
             DidError::CommonError(ref err) => err.fmt(f)
         }
     }
@@ -28,6 +32,8 @@ impl error::Error for DidError {
     fn description(&self) -> &str {
         match *self {
             DidError::AlreadyExistsError(ref description) => description,
+            DidError::NotFoundError(ref description) => description,
+            DidError::MasterSeedAlreadySetError(ref description) => description,
             DidError::CommonError(ref err) => err.description()
         }
     }
@@ -35,6 +41,8 @@ impl error::Error for DidError {
     fn cause(&self) -> Option<&error::Error> {
         match *self {
             DidError::AlreadyExistsError(ref description) => None,
+            DidError::NotFoundError(ref description) => None,
+            DidError::MasterSeedAlreadySetError(ref description) => None,
             DidError::CommonError(ref err) => Some(err)
         }
     }
@@ -44,6 +52,8 @@ impl ToErrorCode for DidError {
     fn to_error_code(&self) -> ErrorCode {
         match *self {
             DidError::AlreadyExistsError(ref description) => ErrorCode::DidAlreadyExistsError,
+            DidError::NotFoundError(ref description) => ErrorCode::DidNotFoundError,
+            DidError::MasterSeedAlreadySetError(ref description) => ErrorCode::DidMasterSeedAlreadySetError,
             DidError::CommonError(ref err) => err.to_error_code()
         }
     }
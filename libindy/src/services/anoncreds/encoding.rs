@@ -0,0 +1,152 @@
+extern crate openssl;
+
+use errors::common::CommonError;
+use utils::crypto::hash::Hash;
+
+use self::openssl::bn::BigNum;
+
+use std::collections::HashMap;
+
+/// Version of canonical attribute encoding declared by claim definitions created by this library.
+///
+/// Claim definitions without declared version are legacy ones: encoded values of their claims
+/// are chosen by the issuer, so revealed attributes of them are accepted only if encoded canonically.
+pub const ATTR_ENCODING_V1: &'static str = "1";
+
+/// Returns canonical encoding of raw attribute value.
+///
+/// Non-negative integers in canonical decimal form are encoded as is, so predicates can be
+/// proved over them. Any other value is encoded as big-endian integer of its SHA-256 hash.
+pub fn encode_attribute(raw: &str) -> Result<String, CommonError> {
+    if _is_canonical_integer(raw) {
+        return Ok(raw.to_string());
+    }
+
+    let mut ctx = Hash::new_context()?;
+    ctx.update(raw.as_bytes())?;
+    let hash = ctx.finish2()?;
+
+    let encoded = BigNum::from_slice(&hash)?.to_dec_str()?;
+
+    Ok(encoded.to_string())
+}
+
+/// Checks raw value corresponds to encoded one for the given encoding version.
+/// Values of legacy encoding (`None`) are checked against canonical encoding, so raw value
+/// can't be forged for encoded one chosen by the issuer.
+pub fn check_attribute_encoding(attr_encoding: Option<&str>, raw: &str, encoded: &str) -> Result<bool, CommonError> {
+    match attr_encoding {
+        None | Some(ATTR_ENCODING_V1) => Ok(encode_attribute(raw)? == encoded),
+        Some(attr_encoding) => Err(CommonError::InvalidStructure(format!("Unsupported attribute encoding: {}", attr_encoding)))
+    }
+}
+
+/// Builds claim values as `{attr: [raw, encoded]}` from values where the first element is raw value.
+/// For canonical encoding encoded values passed by the caller are replaced with canonical ones,
+/// for legacy encoding (`None`) they must be passed by the caller as the second element.
+pub fn encode_claim_values(attr_encoding: Option<&str>,
+                           claim_values: &HashMap<String, Vec<String>>) -> Result<HashMap<String, Vec<String>>, CommonError> {
+    let mut encoded_values: HashMap<String, Vec<String>> = HashMap::new();
+
+    for (attr, values) in claim_values {
+        let raw = values.get(0)
+            .ok_or(CommonError::InvalidStructure(format!("Raw value not found for attribute: {}", attr)))?;
+
+        let encoded = match attr_encoding {
+            None => values.get(1).cloned()
+                .ok_or(CommonError::InvalidStructure(format!("Encoded value not found for attribute: {}", attr)))?,
+            Some(ATTR_ENCODING_V1) => encode_attribute(raw)?,
+            Some(attr_encoding) => return Err(CommonError::InvalidStructure(format!("Unsupported attribute encoding: {}", attr_encoding)))
+        };
+
+        encoded_values.insert(attr.clone(), vec![raw.clone(), encoded]);
+    }
+
+    Ok(encoded_values)
+}
+
+fn _is_canonical_integer(raw: &str) -> bool {
+    match raw.parse::<i64>() {
+        Ok(value) => value >= 0 && value.to_string() == raw,
+        Err(_) => false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_attribute_works_for_integer() {
+        assert_eq!("28", encode_attribute("28").unwrap());
+        assert_eq!("0", encode_attribute("0").unwrap());
+        assert_eq!("5000000000", encode_attribute("5000000000").unwrap());
+    }
+
+    #[test]
+    fn encode_attribute_works_for_string() {
+        assert_eq!("99262857098057710338306967609588410025648622308394250666849665532448612202874",
+                   encode_attribute("Alex").unwrap());
+    }
+
+    #[test]
+    fn encode_attribute_works_for_non_canonical_integer() {
+        assert_ne!("028", encode_attribute("028").unwrap());
+        assert_ne!("-28", encode_attribute("-28").unwrap());
+        assert_ne!("+28", encode_attribute("+28").unwrap());
+    }
+
+    #[test]
+    fn check_attribute_encoding_works() {
+        assert!(check_attribute_encoding(Some(ATTR_ENCODING_V1), "28", "28").unwrap());
+        assert!(!check_attribute_encoding(Some(ATTR_ENCODING_V1), "Alex", "1139481716457488690172217916278103335").unwrap());
+    }
+
+    #[test]
+    fn check_attribute_encoding_works_for_legacy_encoding() {
+        assert!(check_attribute_encoding(None, "28", "28").unwrap());
+        assert!(check_attribute_encoding(None, "Alex", &encode_attribute("Alex").unwrap()).unwrap());
+        assert!(!check_attribute_encoding(None, "Alex", "1139481716457488690172217916278103335").unwrap());
+    }
+
+    #[test]
+    fn check_attribute_encoding_works_for_unsupported_encoding() {
+        let res = check_attribute_encoding(Some("2"), "28", "28");
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn encode_claim_values_works() {
+        let mut claim_values: HashMap<String, Vec<String>> = HashMap::new();
+        claim_values.insert("age".to_string(), vec!["28".to_string()]);
+        claim_values.insert("name".to_string(), vec!["Alex".to_string(), "1139481716457488690172217916278103335".to_string()]);
+
+        let encoded_values = encode_claim_values(Some(ATTR_ENCODING_V1), &claim_values).unwrap();
+
+        assert_eq!(vec!["28".to_string(), "28".to_string()], encoded_values["age"]);
+        assert_eq!(vec!["Alex".to_string(), encode_attribute("Alex").unwrap()], encoded_values["name"]);
+    }
+
+    #[test]
+    fn encode_claim_values_works_for_legacy_encoding() {
+        let mut claim_values: HashMap<String, Vec<String>> = HashMap::new();
+        claim_values.insert("name".to_string(), vec!["Alex".to_string(), "1139481716457488690172217916278103335".to_string()]);
+
+        let encoded_values = encode_claim_values(None, &claim_values).unwrap();
+        assert_eq!(claim_values, encoded_values);
+
+        claim_values.insert("age".to_string(), vec!["28".to_string()]);
+
+        let res = encode_claim_values(None, &claim_values);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn encode_claim_values_works_for_missed_raw_value() {
+        let mut claim_values: HashMap<String, Vec<String>> = HashMap::new();
+        claim_values.insert("age".to_string(), vec![]);
+
+        let res = encode_claim_values(Some(ATTR_ENCODING_V1), &claim_values);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+}
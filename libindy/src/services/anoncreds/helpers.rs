@@ -2,15 +2,119 @@ extern crate indy_crypto;
 
 use errors::common::CommonError;
 
-use services::anoncreds::types::{PredicateInfo, SchemaKey};
+use services::anoncreds::types::{ClaimInfo, Filter, PredicateInfo, Restriction, SchemaKey};
 use self::indy_crypto::cl::{issuer, verifier, ClaimSchema, ClaimValues, SubProofRequest};
 
+use std::cmp::Ordering;
 use std::collections::{HashSet, HashMap};
 
 pub fn get_composite_id(issuer_did: &str, schema_key: &SchemaKey) -> String {
     format!("{}:{}:{}:{}", issuer_did, schema_key.name, schema_key.version, schema_key.did)
 }
 
+/// Identifies claim definition in the wallet. Untagged claim definition keeps the schema composite id.
+pub fn get_claim_def_composite_id(issuer_did: &str, schema_key: &SchemaKey, tag: Option<&str>) -> String {
+    match tag {
+        Some(tag) => format!("{}:{}", get_composite_id(issuer_did, schema_key), tag),
+        None => get_composite_id(issuer_did, schema_key)
+    }
+}
+
+pub fn get_claim_def_id(issuer_did: &str, schema_seq_no: i32, tag: Option<&str>) -> String {
+    match tag {
+        Some(tag) => format!("{}:3:CL:{}:{}", issuer_did, schema_seq_no, tag),
+        None => format!("{}:3:CL:{}", issuer_did, schema_seq_no)
+    }
+}
+
+/// Compares dot separated versions component by component: numeric components
+/// numerically ("1.10" > "1.9"), others as strings. Missed components are lower ("1.0" < "1.0.1").
+pub fn compare_versions(version_1: &str, version_2: &str) -> Ordering {
+    let mut components_1 = version_1.trim().split('.');
+    let mut components_2 = version_2.trim().split('.');
+
+    loop {
+        let ordering = match (components_1.next(), components_2.next()) {
+            (Some(component_1), Some(component_2)) => {
+                match (component_1.parse::<u64>(), component_2.parse::<u64>()) {
+                    (Ok(number_1), Ok(number_2)) => number_1.cmp(&number_2),
+                    _ => component_1.cmp(component_2)
+                }
+            }
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (None, None) => return Ordering::Equal
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+pub fn attr_common_view(attr: &str) -> String {
+    attr.replace(" ", "").to_lowercase()
+}
+
+pub fn claim_satisfy_restrictions(claim_info: &ClaimInfo, restrictions: &Option<Vec<Restriction>>) -> bool {
+    match restrictions {
+        &Some(ref restrictions) => restrictions.iter().any(|restriction| claim_satisfy_restriction(claim_info, restriction)),
+        &None => true
+    }
+}
+
+pub fn claim_satisfy_restriction(claim_info: &ClaimInfo, restriction: &Restriction) -> bool {
+    match restriction {
+        &Restriction::Filter(ref filter) => claim_satisfy_filter(claim_info, filter),
+        &Restriction::And(ref operands) => operands.iter().all(|operand| claim_satisfy_restriction(claim_info, operand)),
+        &Restriction::Or(ref operands) => operands.iter().any(|operand| claim_satisfy_restriction(claim_info, operand))
+    }
+}
+
+pub fn claim_satisfy_filter(claim_info: &ClaimInfo, filter: &Filter) -> bool {
+    let mut res = true;
+
+    if let Some(ref issuer_did) = filter.issuer_did {
+        res = res && claim_info.issuer_did == *issuer_did;
+    }
+    if let Some(ref schema_key) = filter.schema_key {
+        if let Some(ref name) = schema_key.name {
+            res = res && claim_info.schema_key.name == *name;
+        }
+        if let Some(ref version) = schema_key.version {
+            res = res && claim_info.schema_key.version == *version;
+        }
+        if let Some(ref min_version) = schema_key.min_version {
+            res = res && compare_versions(&claim_info.schema_key.version, min_version) != Ordering::Less;
+        }
+        if let Some(ref did) = schema_key.did {
+            res = res && claim_info.schema_key.did == *did;
+        }
+    }
+    if let Some(schema_seq_no) = filter.schema_seq_no {
+        res = res && claim_info.schema_seq_no == Some(schema_seq_no);
+    }
+    if let Some(ref claim_def_id) = filter.claim_def_id {
+        res = res && claim_info.schema_seq_no
+            .map(|schema_seq_no| get_claim_def_id(&claim_info.issuer_did, schema_seq_no,
+                                                  claim_info.claim_def_tag.as_ref().map(String::as_str)) == *claim_def_id)
+            .unwrap_or(false);
+    }
+    if let Some(ref claim_def_tag) = filter.claim_def_tag {
+        res = res && claim_info.claim_def_tag.as_ref() == Some(claim_def_tag);
+    }
+    if let Some(rev_reg_seq_no) = filter.rev_reg_seq_no {
+        res = res && claim_info.revoc_reg_seq_no == Some(rev_reg_seq_no);
+    }
+    for (attr_name, attr_value) in filter.attr_values.iter() {
+        let attr_name = attr_common_view(attr_name);
+        res = res && claim_info.attrs.iter()
+            .any(|(name, value)| attr_common_view(name) == attr_name && value == attr_value);
+    }
+
+    res
+}
+
 pub fn build_claim_schema(attrs: &HashSet<String>) -> Result<ClaimSchema, CommonError> {
     let mut claim_schema_builder = issuer::Issuer::new_claim_schema_builder()?;
     for attr in attrs {
@@ -38,8 +142,27 @@ pub fn build_sub_proof_request(attrs_for_claim: &Vec<String>, predicates_for_cla
     }
 
     for predicate in predicates_for_claim {
-        sub_proof_request_builder.add_predicate(&predicate.attr_name, "GE", predicate.value)?;
+        let (p_type, value) = predicate.to_cl_predicate()?;
+        sub_proof_request_builder.add_predicate(&predicate.attr_name, p_type, value)?;
     }
 
     Ok(sub_proof_request_builder.finalize()?)
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_versions_works() {
+        assert_eq!(Ordering::Equal, compare_versions("1.0", "1.0"));
+        assert_eq!(Ordering::Less, compare_versions("1.9", "1.10"));
+        assert_eq!(Ordering::Greater, compare_versions("2", "1.10"));
+        assert_eq!(Ordering::Less, compare_versions("1.0", "1.0.1"));
+    }
+
+    #[test]
+    fn compare_versions_works_for_non_numeric_components() {
+        assert_eq!(Ordering::Less, compare_versions("1.0.alpha", "1.0.beta"));
+        assert_eq!(Ordering::Greater, compare_versions("1.1", "1.0.beta"));
+    }
+}
@@ -1,13 +1,17 @@
 extern crate indy_crypto;
+extern crate serde_json;
 extern crate time;
 
 use errors::anoncreds::AnoncredsError;
 use errors::common::CommonError;
 use services::anoncreds::types::*;
 use services::anoncreds::helpers::*;
+use services::anoncreds::encoding::ATTR_ENCODING_V1;
 use std::collections::HashMap;
 use self::indy_crypto::cl::*;
 use self::indy_crypto::cl::issuer::Issuer as CryptoIssuer;
+use self::indy_crypto::pair::PointG2;
+use self::serde_json::Value;
 
 pub struct Issuer {}
 
@@ -34,14 +38,16 @@ impl Issuer {
 
         let claim_definition_data = ClaimDefinitionData {
             primary: issuer_public_key.get_primary_key()?.clone()?,
-            revocation: issuer_public_key.get_revocation_key()?.clone()
+            revocation: issuer_public_key.get_revocation_key()?.clone(),
+            attr_encoding: Some(ATTR_ENCODING_V1.to_string())
         };
 
         let claim_definition = ClaimDefinition {
             schema_seq_no: schema.seq_no,
             issuer_did: issuer_did.to_owned(),
             signature_type,
-            data: claim_definition_data
+            data: claim_definition_data,
+            tag: None
         };
 
         info!("new_claim_definition <<< claim_definition: {:?}, issuer_private_key: {:?}, issuer_key_correctness_proof: {:?}",
@@ -61,20 +67,22 @@ impl Issuer {
         let revocation_registry = RevocationRegistry {
             issuer_did: issuer_did.to_owned(),
             schema_seq_no,
-            data: rev_reg_pub
+            data: rev_reg_pub,
+            timestamp: Some(time::now_utc().to_timespec().sec),
+            valid_to: None,
+            tails_hash: None
         };
 
         info!("new_revocation_registry <<< revocation_registry: {:?}, revocation_registry_private: {:?}",
               revocation_registry, rev_reg_priv);
 
-        // TODO: solve tails storing
         Ok((revocation_registry, rev_reg_priv))
     }
 
     pub fn new_claim(&self,
                      claim_def_data: &ClaimDefinitionData,
                      issuer_priv_key: &IssuerPrivateKey,
-                     rev_reg: Option<&mut RevocationRegistry>,
+                     mut rev_reg: Option<&mut RevocationRegistry>,
                      rev_reg_priv: Option<&RevocationRegistryPrivate>,
                      nonce: &Nonce,
                      claim_request: &ClaimRequest,
@@ -96,9 +104,13 @@ impl Issuer {
                                                                                       &issuer_pub_key,
                                                                                       &issuer_priv_key,
                                                                                       rev_idx,
-                                                                                      rev_reg.map(|r| &mut r.data),
+                                                                                      rev_reg.as_mut().map(|r| &mut r.data),
                                                                                       rev_reg_priv)?;
 
+        if let Some(rev_reg) = rev_reg {
+            rev_reg.set_timestamp(time::now_utc().to_timespec().sec);
+        }
+
         info!("new_claim <<< claim_signature {:?}, signature_correctness_proof {:?}", claim_signature, signature_correctness_proof);
 
         Ok((claim_signature, signature_correctness_proof))
@@ -110,9 +122,64 @@ impl Issuer {
         CryptoIssuer::revoke_claim(&mut rev_reg.data, rev_idx)?;
 
         let timestamp = time::now_utc().to_timespec().sec;
+        rev_reg.set_timestamp(timestamp);
 
         info!("revoke <<< timestamp: {:?}", timestamp);
 
         Ok(timestamp)
     }
+
+    pub fn recover(&self, rev_reg: &mut RevocationRegistry, rev_idx: u32) -> Result<i64, AnoncredsError> {
+        info!("recover >>> rev_reg: {:?}, rev_idx: {:?}", rev_reg, rev_idx);
+
+        rev_reg.data = Issuer::_recover_claim(&rev_reg.data, rev_idx)?;
+
+        let timestamp = time::now_utc().to_timespec().sec;
+        rev_reg.set_timestamp(timestamp);
+
+        info!("recover <<< timestamp: {:?}", timestamp);
+
+        Ok(timestamp)
+    }
+
+    // Reverts `CryptoIssuer::revoke_claim`: index is returned to the used ones and its tail is added
+    // back to the accumulator. indy-crypto has no such operation and accumulator fields are private,
+    // so it's done over serialized registry. Prover's witness is updated by the changed set of indices.
+    fn _recover_claim(rev_reg_pub: &RevocationRegistryPublic, rev_idx: u32) -> Result<RevocationRegistryPublic, AnoncredsError> {
+        let mut rev_reg_pub = serde_json::to_value(rev_reg_pub)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize revocation registry: {:?}", err)))?;
+
+        let max_claim_num = rev_reg_pub["acc"]["max_claim_num"].as_u64()
+            .ok_or(CommonError::InvalidStructure(format!("Max claim number not found in revocation registry")))?;
+
+        if rev_idx == 0 || rev_idx as u64 > max_claim_num {
+            return Err(AnoncredsError::InvalidUserRevocIndex(format!("User index:{} is out of Accumulator", rev_idx)));
+        }
+
+        let index = max_claim_num + 1 - rev_idx as u64;
+
+        let tail: PointG2 = serde_json::from_value(rev_reg_pub["tails"]["tails_dash"][index.to_string()].clone())
+            .map_err(|err| CommonError::InvalidStructure(format!("Tail by index {} not found in revocation registry: {:?}", index, err)))?;
+        let acc: PointG2 = serde_json::from_value(rev_reg_pub["acc"]["acc"].clone())
+            .map_err(|err| CommonError::InvalidStructure(format!("Accumulator not found in revocation registry: {:?}", err)))?;
+
+        {
+            let used_indices = rev_reg_pub["acc"]["v"].as_array_mut()
+                .ok_or(CommonError::InvalidStructure(format!("Used indices not found in revocation registry")))?;
+
+            if used_indices.iter().any(|idx| idx.as_u64() == Some(rev_idx as u64)) {
+                return Err(AnoncredsError::InvalidUserRevocIndex(format!("User index:{} is not revoked in Accumulator", rev_idx)));
+            }
+
+            used_indices.push(Value::from(rev_idx));
+        }
+
+        rev_reg_pub["acc"]["acc"] = serde_json::to_value(&acc.add(&tail)?)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize accumulator: {:?}", err)))?;
+
+        let rev_reg_pub = serde_json::from_value(rev_reg_pub)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid revocation registry: {:?}", err)))?;
+
+        Ok(rev_reg_pub)
+    }
 }
\ No newline at end of file
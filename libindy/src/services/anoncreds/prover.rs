@@ -3,12 +3,19 @@ extern crate indy_crypto;
 use errors::common::CommonError;
 use errors::anoncreds::AnoncredsError;
 use services::anoncreds::types::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use services::anoncreds::types::{ClaimInfo, RequestedClaims, ProofRequest, PredicateInfo, Identifier};
 
 use self::indy_crypto::cl::*;
 use self::indy_crypto::cl::prover::Prover as CryptoProver;
 use services::anoncreds::helpers::*;
+use utils::crypto::base58::Base58;
+use utils::crypto::pwhash::PwHash;
+use utils::crypto::xsalsa20::XSalsa20;
+use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
+
+// Max number of steps of exact claims selection search, it grows exponentially with number of items
+const CLAIMS_SELECTION_SEARCH_LIMIT: usize = 10000;
 
 pub struct Prover {}
 
@@ -21,6 +28,61 @@ impl Prover {
         Ok(CryptoProver::new_master_secret()?)
     }
 
+    pub fn export_master_secret(&self, master_secret: &MasterSecret, passphrase: &str) -> Result<ExportedMasterSecret, CommonError> {
+        info!("export_master_secret >>>");
+
+        let master_secret_json = master_secret.to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize master secret: {:?}", err)))?;
+
+        let pwhash = PwHash::new();
+        let xsalsa20 = XSalsa20::new();
+
+        let salt = pwhash.gen_salt();
+        let key = pwhash.derive_key(passphrase, &salt)?;
+        let nonce = xsalsa20.gen_nonce();
+
+        let ciphertext = xsalsa20.encrypt(&key, &nonce, master_secret_json.as_bytes());
+
+        let exported_master_secret = ExportedMasterSecret {
+            salt: Base58::encode(&salt),
+            nonce: Base58::encode(&nonce),
+            ciphertext: Base58::encode(&ciphertext)
+        };
+
+        info!("export_master_secret <<<");
+
+        Ok(exported_master_secret)
+    }
+
+    pub fn import_master_secret(&self, exported_master_secret: &ExportedMasterSecret, passphrase: &str) -> Result<MasterSecret, CommonError> {
+        info!("import_master_secret >>>");
+
+        let salt = Base58::decode(&exported_master_secret.salt)?;
+        let nonce = Base58::decode(&exported_master_secret.nonce)?;
+        let ciphertext = Base58::decode(&exported_master_secret.ciphertext)?;
+
+        let xsalsa20 = XSalsa20::new();
+
+        if nonce.len() != xsalsa20.gen_nonce().len() {
+            return Err(CommonError::InvalidStructure(format!("Invalid nonce length: {}", nonce.len())));
+        }
+
+        let key = PwHash::new().derive_key(passphrase, &salt)?;
+
+        // Wrong passphrase is detected by failed authentication of ciphertext
+        let master_secret_json = xsalsa20.decrypt(&key, &nonce, &ciphertext)?;
+
+        let master_secret_json = String::from_utf8(master_secret_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid exported master secret: {:?}", err)))?;
+
+        let master_secret = MasterSecret::from_json(&master_secret_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize master secret: {:?}", err)))?;
+
+        info!("import_master_secret <<<");
+
+        Ok(master_secret)
+    }
+
     pub fn new_claim_request(&self, claim_def_data: &ClaimDefinitionData, master_secret: &MasterSecret, claim_offer: &ClaimOffer,
                              prover_did: &str) -> Result<(ClaimRequest, MasterSecretBlindingData), CommonError> {
         info!("new_claim_request >>> claim_def_data: {:?}, master_secret: {:?}, prover_did: {:?}",
@@ -41,7 +103,8 @@ impl Prover {
             schema_key: claim_offer.schema_key.clone(),
             blinded_ms,
             blinded_ms_correctness_proof,
-            nonce
+            nonce,
+            claim_def_tag: claim_offer.claim_def_tag.clone()
         };
 
         info!("new_claim_request <<< claim_request: {:?}, master_secret_blinding_data: {:?}", claim_request, master_secret_blinding_data);
@@ -83,11 +146,7 @@ impl Prover {
             let mut claims_for_attribute: Vec<ClaimInfo> = Vec::new();
 
             for claim in claims {
-                let mut satisfy = Prover::_claim_value_for_attribute(&claim.attrs, &requested_attr.name).is_some();
-
-                satisfy = satisfy && self._claim_satisfy_restrictions(claim, &requested_attr.restrictions);
-
-                if satisfy { claims_for_attribute.push(claim.clone()); }
+                if self.claim_satisfy_requested_attr(claim, requested_attr) { claims_for_attribute.push(claim.clone()); }
             }
 
             found_attributes.insert(attr_id.clone(), claims_for_attribute);
@@ -97,14 +156,7 @@ impl Prover {
             let mut claims_for_predicate: Vec<ClaimInfo> = Vec::new();
 
             for claim in claims {
-                let mut satisfy = match Prover::_claim_value_for_attribute(&claim.attrs, &requested_predicate.attr_name) {
-                    Some(attribute_value) => Prover::_attribute_satisfy_predicate(&requested_predicate, &attribute_value)?,
-                    None => false
-                };
-
-                satisfy = satisfy && self._claim_satisfy_restrictions(claim, &requested_predicate.restrictions);
-
-                if satisfy { claims_for_predicate.push(claim.clone()); }
+                if self.claim_satisfy_requested_predicate(claim, requested_predicate)? { claims_for_predicate.push(claim.clone()); }
             }
 
             found_predicates.insert(predicate_id.clone(), claims_for_predicate);
@@ -119,6 +171,312 @@ impl Prover {
         Ok(claims_for_proof_request)
     }
 
+    pub fn claim_satisfy_requested_attr(&self, claim: &ClaimInfo, requested_attr: &AttributeInfo) -> bool {
+        Prover::_claim_value_for_attribute(&claim.attrs, &requested_attr.name).is_some() &&
+            self._claim_satisfy_restrictions(claim, &requested_attr.restrictions)
+    }
+
+    pub fn claim_satisfy_requested_predicate(&self, claim: &ClaimInfo, requested_predicate: &PredicateInfo) -> Result<bool, CommonError> {
+        let satisfy = match Prover::_claim_value_for_attribute(&claim.attrs, &requested_predicate.attr_name) {
+            Some(attribute_value) => Prover::_attribute_satisfy_predicate(&requested_predicate, &attribute_value)?,
+            None => false
+        };
+
+        Ok(satisfy && self._claim_satisfy_restrictions(claim, &requested_predicate.restrictions))
+    }
+
+    /// Picks claims for each requested attribute and predicate so that proof can be created
+    /// with the single master secret using as few distinct claims as possible.
+    /// Among equal selections newer claims are preferred. Attributes without restrictions
+    /// that no claim satisfies are self-attested if value is given either by attribute referent
+    /// or by attribute name. `rev_reg_periods` contains validity periods of revocation registry states
+    /// known by prover for revocable claims; claims can be proved non-revoked only in them.
+    /// `revoked_claims` are claims revoked in the known registry state: they are never used
+    /// if non-revocation is requested and are used only as a last resort otherwise.
+    pub fn select_claims_for_proof_req(&self,
+                                       proof_request: &ProofRequest,
+                                       claims: &Vec<ClaimInfo>,
+                                       rev_reg_periods: &HashMap<String, (i64, i64)>,
+                                       revoked_claims: &HashSet<String>,
+                                       self_attested_values: &HashMap<String, String>) -> Result<ClaimsSelection, CommonError> {
+        info!("select_claims_for_proof_req >>> proof_request: {:?}, claims: {:?}, rev_reg_periods: {:?}, revoked_claims: {:?}, self_attested_values: {:?}",
+              proof_request, claims, rev_reg_periods, revoked_claims, self_attested_values);
+
+        let mut unsatisfied: HashMap<String, String> = HashMap::new();
+        let mut self_attested_attributes: HashMap<String, String> = HashMap::new();
+        let mut candidates: Vec<(String, Vec<&ClaimInfo>)> = Vec::new();
+
+        for (attr_referent, attr_info) in &proof_request.requested_attrs {
+            let interval = attr_info.non_revoked.as_ref().or(proof_request.non_revoked.as_ref());
+
+            match Prover::_select_candidates(claims, &attr_info.name, None, &attr_info.restrictions, interval, rev_reg_periods, revoked_claims) {
+                Ok(claims_for_attr) => candidates.push((attr_referent.clone(), claims_for_attr)),
+                Err(reason) => {
+                    let self_attested_value = if attr_info.restrictions.is_none() {
+                        Prover::_self_attested_value(self_attested_values, attr_referent, &attr_info.name)
+                    } else { None };
+
+                    match self_attested_value {
+                        Some(value) => { self_attested_attributes.insert(attr_referent.clone(), value); }
+                        None => { unsatisfied.insert(attr_referent.clone(), reason); }
+                    }
+                }
+            }
+        }
+
+        for (predicate_referent, predicate_info) in &proof_request.requested_predicates {
+            let interval = predicate_info.non_revoked.as_ref().or(proof_request.non_revoked.as_ref());
+
+            match Prover::_select_candidates(claims, &predicate_info.attr_name, Some(predicate_info), &predicate_info.restrictions, interval, rev_reg_periods, revoked_claims) {
+                Ok(claims_for_predicate) => candidates.push((predicate_referent.clone(), claims_for_predicate)),
+                Err(reason) => { unsatisfied.insert(predicate_referent.clone(), reason); }
+            }
+        }
+
+        // Items with fewer candidates are resolved first, it makes the search much smaller
+        candidates.sort_by(|&(ref referent_1, ref claims_1), &(ref referent_2, ref claims_2)|
+            claims_1.len().cmp(&claims_2.len()).then(referent_1.cmp(referent_2)));
+
+        // Claims bound to different master secrets can't be used in the same proof,
+        // so the selection is made for each master secret and the one satisfying most items wins.
+        // Claims without known master secret are considered to fit any.
+        let mut master_secret_names: Vec<Option<String>> = candidates.iter()
+            .flat_map(|&(_, ref claims)| claims.iter().filter_map(|claim| claim.master_secret_name.clone()))
+            .collect::<HashSet<String>>()
+            .into_iter()
+            .map(Some)
+            .collect();
+        master_secret_names.sort();
+        if master_secret_names.is_empty() { master_secret_names.push(None); }
+
+        let mut best: Option<(Option<String>, Vec<(String, Vec<&ClaimInfo>)>, HashMap<String, String>)> = None;
+
+        for master_secret_name in master_secret_names {
+            let candidates_for_ms: Vec<(String, Vec<&ClaimInfo>)> = candidates.iter()
+                .map(|&(ref referent, ref claims)| {
+                    (referent.clone(), claims.iter()
+                        .filter(|claim| master_secret_name.is_none() || claim.master_secret_name.is_none() ||
+                            claim.master_secret_name == master_secret_name)
+                        .map(|claim| *claim)
+                        .collect())
+                })
+                .collect();
+
+            let selection = Prover::_select_min_claims(&candidates_for_ms);
+
+            let is_better = match best {
+                Some((_, _, ref best_selection)) => {
+                    let claims_num = selection.values().collect::<HashSet<&String>>().len();
+                    let best_claims_num = best_selection.values().collect::<HashSet<&String>>().len();
+
+                    selection.len() > best_selection.len() || (selection.len() == best_selection.len() && claims_num < best_claims_num)
+                }
+                None => true
+            };
+
+            if is_better {
+                best = Some((master_secret_name, candidates_for_ms, selection));
+            }
+        }
+
+        let (master_secret_name, candidates, selection) = best
+            .ok_or(CommonError::InvalidState(format!("Claims selection not found")))?;
+
+        let mut requested_attrs: HashMap<String, (String, bool)> = HashMap::new();
+        let mut requested_predicates: HashMap<String, String> = HashMap::new();
+
+        for (referent, _) in candidates {
+            match selection.get(&referent) {
+                Some(claim_referent) if proof_request.requested_attrs.contains_key(&referent) => {
+                    requested_attrs.insert(referent, (claim_referent.clone(), true));
+                }
+                Some(claim_referent) => {
+                    requested_predicates.insert(referent, claim_referent.clone());
+                }
+                None => {
+                    unsatisfied.insert(referent, format!("Claims satisfying it are bound to master secret other than \"{}\"",
+                                                         master_secret_name.as_ref().map(String::as_str).unwrap_or("")));
+                }
+            }
+        }
+
+        let claims_selection = ClaimsSelection {
+            requested_claims: RequestedClaims {
+                self_attested_attributes,
+                requested_attrs,
+                requested_predicates
+            },
+            master_secret_name,
+            unsatisfied
+        };
+
+        info!("select_claims_for_proof_req <<< claims_selection: {:?}", claims_selection);
+
+        Ok(claims_selection)
+    }
+
+    // Returns claims satisfying requested attribute or predicate, not revoked ones first and newest first,
+    // or the reason why there are none.
+    fn _select_candidates<'a>(claims: &'a Vec<ClaimInfo>,
+                              attr_name: &str,
+                              predicate: Option<&PredicateInfo>,
+                              restrictions: &Option<Vec<Restriction>>,
+                              interval: Option<&NonRevokedInterval>,
+                              rev_reg_periods: &HashMap<String, (i64, i64)>,
+                              revoked_claims: &HashSet<String>) -> Result<Vec<&'a ClaimInfo>, String> {
+        let mut candidates: Vec<&ClaimInfo> = claims.iter()
+            .filter(|claim| Prover::_claim_value_for_attribute(&claim.attrs, attr_name).is_some())
+            .collect();
+
+        if candidates.is_empty() {
+            return Err(format!("No claim contains attribute \"{}\"", attr_name));
+        }
+
+        if let Some(predicate) = predicate {
+            candidates.retain(|claim| {
+                Prover::_claim_value_for_attribute(&claim.attrs, attr_name)
+                    .map(|value| Prover::_attribute_satisfy_predicate(predicate, &value).unwrap_or(false))
+                    .unwrap_or(false)
+            });
+
+            if candidates.is_empty() {
+                return Err(format!("No claim satisfies predicate \"{}\" {:?} {}", attr_name, predicate.p_type, predicate.value));
+            }
+        }
+
+        candidates.retain(|claim| claim_satisfy_restrictions(claim, restrictions));
+
+        if candidates.is_empty() {
+            return Err(format!("No claim satisfies restrictions"));
+        }
+
+        if let Some(interval) = interval {
+            candidates.retain(|claim| {
+                !revoked_claims.contains(&claim.referent) &&
+                    rev_reg_periods.get(&claim.referent)
+                        .map(|&(valid_from, valid_to)| interval.overlaps(valid_from, valid_to))
+                        .unwrap_or(false)
+            });
+
+            if candidates.is_empty() {
+                return Err(format!("No claim can be proved non-revoked in interval from {:?} to {:?}", interval.from, interval.to));
+            }
+        }
+
+        candidates.sort_by(|claim_1, claim_2|
+            revoked_claims.contains(&claim_1.referent).cmp(&revoked_claims.contains(&claim_2.referent))
+                .then(claim_2.stored_at.cmp(&claim_1.stored_at))
+                .then(claim_1.referent.cmp(&claim_2.referent)));
+
+        Ok(candidates)
+    }
+
+    fn _self_attested_value(self_attested_values: &HashMap<String, String>, attr_referent: &str, attr_name: &str) -> Option<String> {
+        let attr_name = attr_common_view(attr_name);
+
+        self_attested_values.get(attr_referent)
+            .or(self_attested_values.iter()
+                .find(|&(ref key, _)| attr_common_view(key) == attr_name)
+                .map(|(_, value)| value))
+            .cloned()
+    }
+
+    // Finds the selection with the fewest distinct claims satisfying all items that have candidates.
+    // Candidates are expected to be ordered by preference and items by number of candidates.
+    // Exact search is bounded by CLAIMS_SELECTION_SEARCH_LIMIT steps, if it's exceeded
+    // the greedy set cover is used unless the search already found a selection as small.
+    fn _select_min_claims(candidates: &Vec<(String, Vec<&ClaimInfo>)>) -> HashMap<String, String> {
+        let items: Vec<&(String, Vec<&ClaimInfo>)> = candidates.iter()
+            .filter(|&&(_, ref claims)| !claims.is_empty())
+            .collect();
+
+        let mut best: Option<Vec<&str>> = None;
+        let mut steps_left = CLAIMS_SELECTION_SEARCH_LIMIT;
+        Prover::_select_min_claims_from(&items, 0, &mut Vec::new(), &mut best, &mut steps_left);
+
+        if steps_left == 0 {
+            let greedy = Prover::_select_min_claims_greedy(&items);
+
+            if best.as_ref().map(|best| greedy.len() < best.len()).unwrap_or(true) {
+                best = Some(greedy);
+            }
+        }
+
+        let chosen = best.unwrap_or(Vec::new());
+
+        items.iter()
+            .filter_map(|&&(ref referent, ref claims)| {
+                claims.iter()
+                    .find(|claim| chosen.contains(&claim.referent.as_str()))
+                    .map(|claim| (referent.clone(), claim.referent.clone()))
+            })
+            .collect()
+    }
+
+    fn _select_min_claims_from<'a>(items: &Vec<&(String, Vec<&'a ClaimInfo>)>,
+                                   index: usize,
+                                   chosen: &mut Vec<&'a str>,
+                                   best: &mut Option<Vec<&'a str>>,
+                                   steps_left: &mut usize) {
+        if *steps_left == 0 || best.as_ref().map(|best| chosen.len() >= best.len()).unwrap_or(false) {
+            return;
+        }
+
+        *steps_left -= 1;
+
+        let claims = match items.get(index) {
+            Some(&&(_, ref claims)) => claims,
+            None => {
+                *best = Some(chosen.clone());
+                return;
+            }
+        };
+
+        // Reusing already chosen claim never makes the selection bigger
+        if claims.iter().any(|claim| chosen.contains(&claim.referent.as_str())) {
+            return Prover::_select_min_claims_from(items, index + 1, chosen, best, steps_left);
+        }
+
+        for claim in claims {
+            chosen.push(claim.referent.as_str());
+            Prover::_select_min_claims_from(items, index + 1, chosen, best, steps_left);
+            chosen.pop();
+        }
+    }
+
+    // Repeatedly chooses the claim satisfying most of not yet satisfied items,
+    // ties are resolved by order of items and candidates.
+    fn _select_min_claims_greedy<'a>(items: &Vec<&(String, Vec<&'a ClaimInfo>)>) -> Vec<&'a str> {
+        let mut chosen: Vec<&'a str> = Vec::new();
+        let mut left: Vec<&Vec<&'a ClaimInfo>> = items.iter().map(|&&(_, ref claims)| claims).collect();
+
+        while !left.is_empty() {
+            let mut best_claim: Option<(&'a str, usize)> = None;
+
+            for claims in left.iter() {
+                for claim in claims.iter() {
+                    let covered = left.iter()
+                        .filter(|claims| claims.iter().any(|other| other.referent == claim.referent))
+                        .count();
+
+                    if best_claim.map(|(_, best_covered)| covered > best_covered).unwrap_or(true) {
+                        best_claim = Some((claim.referent.as_str(), covered));
+                    }
+                }
+            }
+
+            let best_claim = match best_claim {
+                Some((best_claim, _)) => best_claim,
+                None => break
+            };
+
+            left.retain(|claims| !claims.iter().any(|claim| claim.referent == best_claim));
+            chosen.push(best_claim);
+        }
+
+        chosen
+    }
+
     pub fn create_proof(&self,
                         claims: &HashMap<String, Claim>,
                         proof_req: &ProofRequest,
@@ -146,6 +504,20 @@ impl Prover {
             let attrs_for_claim = Prover::_get_revealed_attributes_for_claim(referent.as_str(), requested_claims, proof_req)?;
             let predicates_for_claim = Prover::_get_predicates_for_claim(referent.as_str(), requested_claims, proof_req)?;
 
+            for predicate in predicates_for_claim.iter() {
+                let raw_values: HashMap<String, String> = claim.values.iter()
+                    .filter_map(|(attr, values)| values.get(0).map(|raw| (attr.clone(), raw.clone())))
+                    .collect();
+
+                let attribute_value = Prover::_claim_value_for_attribute(&raw_values, &predicate.attr_name)
+                    .ok_or(CommonError::InvalidStructure(format!("Claim {} doesn't contain predicate attribute: {}", referent, predicate.attr_name)))?;
+
+                if !Prover::_attribute_satisfy_predicate(predicate, &attribute_value)? {
+                    return Err(AnoncredsError::CommonError(CommonError::InvalidStructure(
+                        format!("Claim {} doesn't satisfy predicate: {:?}", referent, predicate))));
+                }
+            }
+
             let claim_schema = build_claim_schema(&schema.data.attr_names)?;
             let claim_values = build_claim_values(&claim.values)?;
             let sub_proof_request = build_sub_proof_request(&attrs_for_claim, &predicates_for_claim)?;
@@ -161,7 +533,9 @@ impl Prover {
             identifiers.insert(referent.to_string(), Identifier {
                 schema_key: claim.schema_key.clone(),
                 issuer_did: claim.issuer_did.clone(),
-                rev_reg_seq_no: claim.rev_reg_seq_no.clone()
+                rev_reg_seq_no: claim.rev_reg_seq_no.clone(),
+                timestamp: revocation_registry.and_then(|rev_reg| rev_reg.timestamp),
+                claim_def_tag: claim.claim_def_tag.clone()
             });
         }
 
@@ -189,24 +563,17 @@ impl Prover {
     }
 
     fn _claim_value_for_attribute(claim_attrs: &HashMap<String, String>, requested_attr: &str) -> Option<String> {
-        let _attr_common_view = |attr: &str|
-            attr.replace(" ", "").to_lowercase();
-
-        let requested_attr = _attr_common_view(&requested_attr);
+        let requested_attr = attr_common_view(&requested_attr);
 
         claim_attrs.iter()
-            .find(|&(ref key, ref value)| _attr_common_view(key) == requested_attr)
+            .find(|&(ref key, _)| attr_common_view(key) == requested_attr)
             .map(|(_, value)| value.to_string())
     }
 
-    fn _claim_satisfy_restrictions(&self, claim_info: &ClaimInfo, restrictions: &Option<Vec<Filter>>) -> bool {
+    fn _claim_satisfy_restrictions(&self, claim_info: &ClaimInfo, restrictions: &Option<Vec<Restriction>>) -> bool {
         info!("_claim_satisfy_restrictions >>> claim_info: {:?}, restrictions: {:?}", claim_info, restrictions);
 
-        let res = match restrictions {
-            &Some(ref restrictions) => restrictions.iter().any(|restriction|
-                self.claim_satisfy_restriction(claim_info, &restriction)),
-            &None => true
-        };
+        let res = claim_satisfy_restrictions(claim_info, restrictions);
 
         info!("_claim_satisfy_restrictions <<< res: {:?}", res);
 
@@ -216,22 +583,7 @@ impl Prover {
     pub fn claim_satisfy_restriction(&self, claim_info: &ClaimInfo, restriction: &Filter) -> bool {
         info!("_claim_satisfy_restriction >>> claim_info: {:?}, restriction: {:?}", claim_info, restriction);
 
-        let mut res = true;
-
-        if let Some(issuer_did) = restriction.issuer_did.clone() {
-            res = res && claim_info.issuer_did == issuer_did;
-        }
-        if let Some(ref schema_key) = restriction.schema_key {
-            if let Some(ref name) = schema_key.name {
-                res = res && claim_info.schema_key.name == name.clone();
-            }
-            if let Some(ref version) = schema_key.version {
-                res = res && claim_info.schema_key.version == version.clone();
-            }
-            if let Some(ref did) = schema_key.did {
-                res = res && claim_info.schema_key.did == did.clone();
-            }
-        }
+        let res = claim_satisfy_filter(claim_info, restriction);
 
         info!("_claim_satisfy_restriction >>> res: {:?}", res);
 
@@ -241,14 +593,7 @@ impl Prover {
     fn _attribute_satisfy_predicate(predicate: &PredicateInfo, attribute_value: &String) -> Result<bool, CommonError> {
         info!("_attribute_satisfy_predicate >>> predicate: {:?}, attribute_value: {:?}", predicate, attribute_value);
 
-        let res = match predicate.p_type.as_str() {
-            ">=" => Ok({
-                let attribute_value = attribute_value.parse::<i32>()
-                    .map_err(|err| CommonError::InvalidStructure(format!("Invalid format of predicate attribute: {}", attribute_value)))?;
-                attribute_value >= predicate.value
-            }),
-            _ => return Err(CommonError::InvalidStructure(format!("Invalid predicate type: {:?}", predicate.p_type)))
-        };
+        let res = predicate.is_satisfied_by(attribute_value);
 
         info!("_attribute_satisfy_predicate <<< res: {:?}", res);
 
@@ -1,7 +1,16 @@
 extern crate indy_crypto;
+extern crate serde;
+extern crate serde_json;
+extern crate time;
 
 use errors::common::CommonError;
-use std::collections::{HashMap, HashSet};
+use services::anoncreds::helpers::compare_versions;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use self::serde::ser::{Serialize, Serializer, Error as SError};
+use self::serde::de::{Deserialize, Deserializer, Error as DError};
+use self::serde_json::Value;
 
 use self::indy_crypto::cl::*;
 use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
@@ -9,7 +18,8 @@ use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AttributeInfo {
     pub name: String,
-    pub restrictions: Option<Vec<Filter>>
+    pub restrictions: Option<Vec<Restriction>>,
+    pub non_revoked: Option<NonRevokedInterval>
 }
 
 impl JsonEncodable for AttributeInfo {}
@@ -21,7 +31,18 @@ pub struct ClaimOffer {
     pub issuer_did: String,
     pub schema_key: SchemaKey,
     pub key_correctness_proof: KeyCorrectnessProof,
-    pub nonce: Nonce
+    pub nonce: Nonce,
+    /// Seconds since epoch after which the issuer doesn't accept claim requests for the offer.
+    pub expires_at: Option<i64>,
+    /// Tag of the issuer's claim definition the claim will be issued with (untagged if missed).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claim_def_tag: Option<String>
+}
+
+impl ClaimOffer {
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.map(|expires_at| time::get_time().sec > expires_at).unwrap_or(false)
+    }
 }
 
 impl JsonEncodable for ClaimOffer {}
@@ -29,17 +50,130 @@ impl JsonEncodable for ClaimOffer {}
 impl<'a> JsonDecodable<'a> for ClaimOffer {}
 
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
+#[serde(deny_unknown_fields)]
 pub struct Filter {
     pub issuer_did: Option<String>,
-    pub schema_key: Option<SchemaKeyFilter>
+    pub schema_key: Option<SchemaKeyFilter>,
+    pub schema_seq_no: Option<i32>,
+    pub claim_def_id: Option<String>,
+    pub claim_def_tag: Option<String>,
+    pub rev_reg_seq_no: Option<i32>,
+    #[serde(skip_serializing, skip_deserializing)]
+    pub attr_values: BTreeMap<String, String>
 }
 
 impl<'a> JsonDecodable<'a> for Filter {}
 
+/// Proof request restriction. Plain filter keys are combined with AND,
+/// `{"$and": [...]}` and `{"$or": [...]}` compose nested restrictions and
+/// `"attr::<name>::value": <raw value>` requires attribute value equality.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Restriction {
+    Filter(Filter),
+    And(Vec<Restriction>),
+    Or(Vec<Restriction>)
+}
+
+impl Restriction {
+    fn from_value(value: Value) -> Result<Restriction, String> {
+        let mut map = match value {
+            Value::Object(map) => map,
+            value => return Err(format!("Restriction must be an object: {:?}", value))
+        };
+
+        if map.contains_key("$and") || map.contains_key("$or") {
+            if map.len() != 1 {
+                return Err(format!("Operator must be the only key of restriction: {:?}", map));
+            }
+
+            return match map.remove("$and") {
+                Some(operands) => Ok(Restriction::And(Restriction::_operands_from_value(operands)?)),
+                None => Ok(Restriction::Or(Restriction::_operands_from_value(map.remove("$or").unwrap())?))
+            };
+        }
+
+        let attr_keys: Vec<String> = map.keys()
+            .filter(|key| key.starts_with("attr::"))
+            .cloned()
+            .collect();
+
+        let mut attr_values: BTreeMap<String, String> = BTreeMap::new();
+
+        for key in attr_keys {
+            if !key.ends_with("::value") || key.len() <= "attr::::value".len() {
+                return Err(format!("Invalid attribute restriction: {}", key));
+            }
+
+            let attr_name = key["attr::".len()..key.len() - "::value".len()].to_string();
+
+            match map.remove(&key) {
+                Some(Value::String(value)) => { attr_values.insert(attr_name, value); }
+                value => return Err(format!("Attribute restriction value must be a string: {:?}", value))
+            }
+        }
+
+        let mut filter: Filter = serde_json::from_value(Value::Object(map))
+            .map_err(|err| format!("Invalid restriction: {:?}", err))?;
+        filter.attr_values = attr_values;
+
+        Ok(Restriction::Filter(filter))
+    }
+
+    fn _operands_from_value(operands: Value) -> Result<Vec<Restriction>, String> {
+        match operands {
+            Value::Array(operands) => operands.into_iter().map(Restriction::from_value).collect(),
+            operands => Err(format!("Operator operands must be an array: {:?}", operands))
+        }
+    }
+
+    fn to_value(&self) -> Result<Value, String> {
+        let (operator, operands) = match *self {
+            Restriction::Filter(ref filter) => {
+                let mut value = serde_json::to_value(filter)
+                    .map_err(|err| format!("Cannot serialize filter: {:?}", err))?;
+
+                if let Value::Object(ref mut map) = value {
+                    for (attr_name, attr_value) in filter.attr_values.iter() {
+                        map.insert(format!("attr::{}::value", attr_name), Value::String(attr_value.clone()));
+                    }
+                }
+
+                return Ok(value);
+            }
+            Restriction::And(ref operands) => ("$and", operands),
+            Restriction::Or(ref operands) => ("$or", operands)
+        };
+
+        let operands = operands.iter()
+            .map(Restriction::to_value)
+            .collect::<Result<Vec<Value>, String>>()?;
+
+        let mut map = serde_json::Map::new();
+        map.insert(operator.to_string(), Value::Array(operands));
+
+        Ok(Value::Object(map))
+    }
+}
+
+impl Serialize for Restriction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        self.to_value().map_err(SError::custom)?.serialize(serializer)
+    }
+}
+
+impl<'a> Deserialize<'a> for Restriction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'a> {
+        Restriction::from_value(Value::deserialize(deserializer)?).map_err(DError::custom)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
+#[serde(deny_unknown_fields)]
 pub struct SchemaKeyFilter {
     pub name: Option<String>,
     pub version: Option<String>,
+    /// Lowest acceptable schema version (see `compare_versions`).
+    pub min_version: Option<String>,
     pub did: Option<String>
 }
 
@@ -51,7 +185,14 @@ pub struct ClaimInfo {
     pub attrs: HashMap<String, String>,
     pub schema_key: SchemaKey,
     pub issuer_did: String,
-    pub revoc_reg_seq_no: Option<i32>
+    pub revoc_reg_seq_no: Option<i32>,
+    pub schema_seq_no: Option<i32>,
+    pub master_secret_name: Option<String>,
+    /// Prover's own label of the claim (see indy_prover_set_claim_metadata)
+    pub metadata: Option<String>,
+    /// Time the claim was stored in the wallet (seconds since epoch)
+    pub stored_at: Option<i64>,
+    pub claim_def_tag: Option<String>
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -62,6 +203,8 @@ pub struct ClaimRequest {
     pub blinded_ms: BlindedMasterSecret,
     pub blinded_ms_correctness_proof: BlindedMasterSecretProofCorrectness,
     pub nonce: Nonce,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claim_def_tag: Option<String>
 }
 
 impl JsonEncodable for ClaimRequest {}
@@ -79,6 +222,32 @@ impl JsonEncodable for ClaimRequestMetadata {}
 
 impl<'a> JsonDecodable<'a> for ClaimRequestMetadata {}
 
+/// Prover's data about stored claim kept in single wallet record,
+/// so claim info is read without a wallet round-trip per field.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClaimRecordInfo {
+    pub master_secret_name: Option<String>,
+    pub metadata: Option<String>,
+    pub stored_at: Option<i64>
+}
+
+impl JsonEncodable for ClaimRecordInfo {}
+
+impl<'a> JsonDecodable<'a> for ClaimRecordInfo {}
+
+/// Master secret encrypted with XSalsa20 under key derived from passphrase.
+/// All fields are base58 encoded.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExportedMasterSecret {
+    pub salt: String,
+    pub nonce: String,
+    pub ciphertext: String
+}
+
+impl JsonEncodable for ExportedMasterSecret {}
+
+impl<'a> JsonDecodable<'a> for ExportedMasterSecret {}
+
 #[derive(Deserialize, Debug, Serialize, PartialEq, Clone)]
 pub enum SignatureTypes {
     CL
@@ -91,7 +260,10 @@ pub struct ClaimDefinition {
     #[serde(rename = "origin")]
     pub issuer_did: String,
     pub signature_type: SignatureTypes,
-    pub data: ClaimDefinitionData
+    pub data: ClaimDefinitionData,
+    /// Distinguishes claim definitions of the same issuer for the same schema (i.e. after key rotation)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>
 }
 
 impl ClaimDefinition {
@@ -101,6 +273,7 @@ impl ClaimDefinition {
             issuer_did: self.issuer_did.clone(),
             signature_type: self.signature_type.clone(),
             data: self.data.clone()?,
+            tag: self.tag.clone()
         })
     }
 }
@@ -112,14 +285,18 @@ impl<'a> JsonDecodable<'a> for ClaimDefinition {}
 #[derive(Deserialize, Debug, Serialize, PartialEq)]
 pub struct ClaimDefinitionData {
     pub primary: IssuerPrimaryPublicKey,
-    pub revocation: Option<IssuerRevocationPublicKey>
+    pub revocation: Option<IssuerRevocationPublicKey>,
+    /// Version of attribute encoding of claims, missed for legacy claim definitions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attr_encoding: Option<String>
 }
 
 impl ClaimDefinitionData {
     pub fn clone(&self) -> Result<ClaimDefinitionData, CommonError> {
         Ok(ClaimDefinitionData {
             primary: self.primary.clone()?,
-            revocation: self.revocation.clone()
+            revocation: self.revocation.clone(),
+            attr_encoding: self.attr_encoding.clone()
         })
     }
 }
@@ -136,18 +313,68 @@ pub struct Claim {
     pub signature_correctness_proof: SignatureCorrectnessProof,
     pub issuer_did: String,
     pub rev_reg_seq_no: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claim_def_tag: Option<String>
 }
 
 impl JsonEncodable for Claim {}
 
 impl<'a> JsonDecodable<'a> for Claim {}
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum PredicateType {
+    #[serde(rename = ">=")]
+    GE,
+    #[serde(rename = ">")]
+    GT,
+    #[serde(rename = "<=")]
+    LE,
+    #[serde(rename = "<")]
+    LT
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub struct PredicateInfo {
     pub attr_name: String,
-    pub p_type: String,
-    pub value: i32,
-    pub restrictions: Option<Vec<Filter>>
+    pub p_type: PredicateType,
+    pub value: i64,
+    pub restrictions: Option<Vec<Restriction>>,
+    pub non_revoked: Option<NonRevokedInterval>
+}
+
+impl PredicateInfo {
+    pub fn is_satisfied_by(&self, attribute_value: &str) -> Result<bool, CommonError> {
+        let attribute_value = attribute_value.trim().parse::<i64>()
+            .map_err(|_| CommonError::InvalidStructure(
+                format!("Predicate attribute \"{}\" has non-numeric value: {:?}", self.attr_name, attribute_value)))?;
+
+        Ok(match self.p_type {
+            PredicateType::GE => attribute_value >= self.value,
+            PredicateType::GT => attribute_value > self.value,
+            PredicateType::LE => attribute_value <= self.value,
+            PredicateType::LT => attribute_value < self.value
+        })
+    }
+
+    // CL library (indy-crypto 0.1.6) proves only GE predicates over integers, so strict comparison
+    // is reduced to the non-strict one and the bound is checked against the 32 bits it accepts.
+    // A GE predicate can't express an upper bound of the attribute, so less-than predicates
+    // can be used to search claims but can't be proved yet.
+    pub fn to_cl_predicate(&self) -> Result<(&'static str, i32), CommonError> {
+        let (p_type, value) = match self.p_type {
+            PredicateType::GE => ("GE", Some(self.value)),
+            PredicateType::GT => ("GE", self.value.checked_add(1)),
+            PredicateType::LE | PredicateType::LT => return Err(CommonError::InvalidStructure(
+                format!("Predicate {:?} for attribute \"{}\" can't be proved: CL proofs support only \">=\" and \">\" predicates",
+                        self.p_type, self.attr_name)))
+        };
+
+        match value {
+            Some(value) if value >= i32::min_value() as i64 && value <= i32::max_value() as i64 => Ok((p_type, value as i32)),
+            _ => Err(CommonError::InvalidStructure(
+                format!("Predicate value {} for attribute \"{}\" is out of range supported by CL predicates", self.value, self.attr_name)))
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -166,18 +393,82 @@ pub struct ProofRequest {
     pub name: String,
     pub version: String,
     pub requested_attrs: HashMap<String, AttributeInfo>,
-    pub requested_predicates: HashMap<String, PredicateInfo>
+    pub requested_predicates: HashMap<String, PredicateInfo>,
+    pub non_revoked: Option<NonRevokedInterval>
+}
+
+impl ProofRequest {
+    /// Checks the request is complete: it has a name and a version, asks for at least one attribute
+    /// or predicate, predicates can be proved by CL and non-revoked intervals are not inverted.
+    pub fn validate(&self) -> Result<(), CommonError> {
+        if self.name.is_empty() || self.version.is_empty() {
+            return Err(CommonError::InvalidStructure("Proof request name and version must be set".to_string()));
+        }
+
+        if self.requested_attrs.is_empty() && self.requested_predicates.is_empty() {
+            return Err(CommonError::InvalidStructure("Proof request must contain requested attributes or predicates".to_string()));
+        }
+
+        NonRevokedInterval::validate(&self.non_revoked)?;
+
+        for (referent, attr_info) in self.requested_attrs.iter() {
+            if attr_info.name.is_empty() {
+                return Err(CommonError::InvalidStructure(format!("Requested attribute {} has empty name", referent)));
+            }
+
+            NonRevokedInterval::validate(&attr_info.non_revoked)?;
+        }
+
+        for (referent, predicate_info) in self.requested_predicates.iter() {
+            if predicate_info.attr_name.is_empty() {
+                return Err(CommonError::InvalidStructure(format!("Requested predicate {} has empty attribute name", referent)));
+            }
+
+            predicate_info.to_cl_predicate()?;
+            NonRevokedInterval::validate(&predicate_info.non_revoked)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl JsonEncodable for ProofRequest {}
 
 impl<'a> JsonDecodable<'a> for ProofRequest {}
 
+/// Interval of time (seconds since epoch) the claim must be proved non-revoked in.
+/// Satisfied by any registry state valid at some moment of the interval. Missed bounds are not checked.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct NonRevokedInterval {
+    pub from: Option<i64>,
+    pub to: Option<i64>
+}
+
+impl NonRevokedInterval {
+    /// Checks the interval overlaps validity period of registry state (see `RevocationRegistry::validity_period`).
+    pub fn overlaps(&self, valid_from: i64, valid_to: i64) -> bool {
+        self.from.map(|from| from <= valid_to).unwrap_or(true) &&
+            self.to.map(|to| valid_from <= to).unwrap_or(true)
+    }
+
+    fn validate(interval: &Option<NonRevokedInterval>) -> Result<(), CommonError> {
+        match *interval {
+            Some(NonRevokedInterval { from: Some(from), to: Some(to) }) if from > to =>
+                Err(CommonError::InvalidStructure(format!("Non-revoked interval is inverted: from {} to {}", from, to))),
+            _ => Ok(())
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
 pub struct Identifier {
     pub issuer_did: String,
     pub schema_key: SchemaKey,
-    pub rev_reg_seq_no: Option<i32>
+    pub rev_reg_seq_no: Option<i32>,
+    pub timestamp: Option<i64>,
+    /// Tag of claim definition the claim was issued with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claim_def_tag: Option<String>
 }
 
 impl JsonEncodable for Identifier {}
@@ -191,21 +482,185 @@ pub struct FullProof {
     pub identifiers: HashMap<String, Identifier>
 }
 
+impl FullProof {
+    /// Returns encoded values of revealed attributes proved by primary equality proof of each claim
+    /// as `{claim_referent: {attr_name: encoded}}`.
+    pub fn proved_revealed_attrs(&self) -> Result<HashMap<String, HashMap<String, String>>, CommonError> {
+        // Fields of indy-crypto proof are private, so serialized proof is read into views of its layout
+        let proof = serde_json::to_value(&self.proof)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize proof: {:?}", err)))?;
+
+        let proof: ProofView = serde_json::from_value(proof)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid proof: {:?}", err)))?;
+
+        Ok(proof.proofs.into_iter()
+            .map(|(referent, sub_proof)| (referent, sub_proof.primary_proof.eq_proof.revealed_attrs))
+            .collect())
+    }
+}
+
 impl JsonEncodable for FullProof {}
 
 impl<'a> JsonDecodable<'a> for FullProof {}
 
+#[derive(Deserialize)]
+struct ProofView {
+    proofs: HashMap<String, SubProofView>
+}
+
+#[derive(Deserialize)]
+struct SubProofView {
+    primary_proof: PrimaryProofView
+}
+
+#[derive(Deserialize)]
+struct PrimaryProofView {
+    eq_proof: PrimaryEqualProofView
+}
+
+#[derive(Deserialize)]
+struct PrimaryEqualProofView {
+    revealed_attrs: HashMap<String, String>
+}
+
 #[derive(Deserialize, Debug, Serialize, Clone)]
 pub struct RevocationRegistry {
     pub issuer_did: String,
     pub schema_seq_no: i32,
-    pub data: RevocationRegistryPublic
+    pub data: RevocationRegistryPublic,
+    /// Time the registry state was written, for registries read from the ledger it's txnTime of the reply.
+    pub timestamp: Option<i64>,
+    /// Time the registry state is known to be still current at, for registries read from the ledger
+    /// it's the timestamp the state was requested for. Missed if state may change right after `timestamp`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_to: Option<i64>,
+    /// Base58 encoded sha256 hash of tails blob, tails are read from blob storage by it.
+    pub tails_hash: Option<String>
 }
 
+impl RevocationRegistry {
+    /// Period of time the registry state is known to be valid in.
+    pub fn validity_period(&self) -> Option<(i64, i64)> {
+        self.timestamp.map(|timestamp| (timestamp, ::std::cmp::max(timestamp, self.valid_to.unwrap_or(timestamp))))
+    }
+
+    /// Records update of the registry state.
+    pub fn set_timestamp(&mut self, timestamp: i64) {
+        self.timestamp = Some(timestamp);
+        self.valid_to = None;
+    }
+
+    /// Returns accumulator value in the form it is published on the ledger.
+    pub fn accumulator(&self) -> Result<String, CommonError> {
+        let accumulator = self.data.get_accumulator()
+            .map_err(|err| CommonError::InvalidState(format!("Cannot get accumulator: {:?}", err)))?;
+
+        let accumulator = serde_json::to_value(&accumulator)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize accumulator: {:?}", err)))?;
+
+        accumulator["acc"].as_str()
+            .map(String::from)
+            .ok_or(CommonError::InvalidState(format!("Invalid accumulator: {:?}", accumulator)))
+    }
+}
+
+/// Change of revocation registry state made by issuer, shaped as REVOC_REG_ENTRY value.
+#[derive(Debug, Serialize)]
+pub struct RevocationRegistryDelta {
+    #[serde(rename = "prevAccum")]
+    pub prev_accum: String,
+    pub accum: String,
+    pub issued: Vec<u32>,
+    pub revoked: Vec<u32>
+}
+
+impl JsonEncodable for RevocationRegistryDelta {}
+
 impl JsonEncodable for RevocationRegistry {}
 
 impl<'a> JsonDecodable<'a> for RevocationRegistry {}
 
+/// Issuer side bookkeeping of revocation indices used in revocation registry.
+/// Indices start from 1 as in CL revocation registry.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RevocationRegistryIndices {
+    pub max_claim_num: u32,
+    pub issued: BTreeSet<u32>,
+    #[serde(default)]
+    pub revoked: BTreeSet<u32>,
+    /// All indices below it are issued. Indices are never released, so search of free index
+    /// continues from the previously found one instead of rescanning from 1.
+    #[serde(skip)]
+    lowest_free: u32
+}
+
+impl RevocationRegistryIndices {
+    pub fn new(max_claim_num: u32) -> RevocationRegistryIndices {
+        RevocationRegistryIndices {
+            max_claim_num,
+            issued: BTreeSet::new(),
+            revoked: BTreeSet::new(),
+            lowest_free: 1
+        }
+    }
+
+    /// Restores indices of registry created before indices were tracked.
+    /// Accumulator keeps only indices of not revoked claims, so unused indices below the highest
+    /// used one may belong to revoked claims. They are marked revoked, as issuing them again
+    /// would make revoked claims valid. Revoked indices above the highest used one can't be
+    /// told from free ones.
+    pub fn from_registry(rev_reg: &RevocationRegistry) -> Result<RevocationRegistryIndices, CommonError> {
+        let accumulator = rev_reg.data.get_accumulator()
+            .map_err(|err| CommonError::InvalidState(format!("Cannot get accumulator: {:?}", err)))?;
+
+        let max_claim_num = serde_json::to_value(&accumulator)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize accumulator: {:?}", err)))?
+            ["max_claim_num"].as_u64()
+            .ok_or(CommonError::InvalidState(format!("Invalid accumulator: {:?}", accumulator)))? as u32;
+
+        let mut indices = RevocationRegistryIndices::new(max_claim_num);
+
+        if let Some(max_used) = (1..max_claim_num + 1).rev().find(|idx| accumulator.is_idx_used(*idx)) {
+            for idx in 1..max_used + 1 {
+                indices.issued.insert(idx);
+
+                if !accumulator.is_idx_used(idx) {
+                    indices.revoked.insert(idx);
+                }
+            }
+        }
+
+        Ok(indices)
+    }
+
+    /// Returns the lowest index that isn't issued yet.
+    pub fn next_free(&mut self) -> Option<u32> {
+        let mut idx = ::std::cmp::max(self.lowest_free, 1);
+
+        while idx <= self.max_claim_num && self.issued.contains(&idx) {
+            idx += 1;
+        }
+
+        self.lowest_free = idx;
+
+        if idx <= self.max_claim_num { Some(idx) } else { None }
+    }
+
+    pub fn is_free(&self, idx: u32) -> bool {
+        idx >= 1 && idx <= self.max_claim_num && !self.issued.contains(&idx)
+    }
+}
+
+impl JsonEncodable for RevocationRegistryIndices {}
+
+impl<'a> JsonDecodable<'a> for RevocationRegistryIndices {}
+
+#[derive(Debug, Deserialize)]
+pub struct BatchClaimRequest {
+    pub claim_request: ClaimRequest,
+    pub claim_values: HashMap<String, Vec<String>>
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct RequestedClaims {
     pub self_attested_attributes: HashMap<String, String>,
@@ -217,6 +672,17 @@ impl JsonEncodable for RequestedClaims {}
 
 impl<'a> JsonDecodable<'a> for RequestedClaims {}
 
+/// Claims automatically selected for a proof request. Referents that can't be satisfied
+/// are listed in `unsatisfied` together with the reason.
+#[derive(Debug, Serialize)]
+pub struct ClaimsSelection {
+    pub requested_claims: RequestedClaims,
+    pub master_secret_name: Option<String>,
+    pub unsatisfied: HashMap<String, String>
+}
+
+impl JsonEncodable for ClaimsSelection {}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RequestedProof {
     pub revealed_attrs: HashMap<String, (String, String, String)>,
@@ -240,6 +706,23 @@ pub struct SchemaData {
     pub attr_names: HashSet<String>
 }
 
+impl Schema {
+    /// Compares attribute sets of this schema and the other one (usually the next version).
+    pub fn diff(&self, other: &Schema) -> SchemaDiff {
+        SchemaDiff {
+            same_lineage: self.data.name == other.data.name && self.dest == other.dest,
+            version_cmp: match compare_versions(&self.data.version, &other.data.version) {
+                Ordering::Less => -1,
+                Ordering::Equal => 0,
+                Ordering::Greater => 1
+            },
+            added: other.data.attr_names.difference(&self.data.attr_names).cloned().collect(),
+            removed: self.data.attr_names.difference(&other.data.attr_names).cloned().collect(),
+            common: self.data.attr_names.intersection(&other.data.attr_names).cloned().collect()
+        }
+    }
+}
+
 impl JsonEncodable for Schema {}
 
 impl<'a> JsonDecodable<'a> for Schema {}
@@ -253,5 +736,26 @@ pub struct SchemaKey {
 
 impl JsonEncodable for SchemaKey {}
 
+/// Difference between two schemas: `version_cmp` is -1, 0 or 1 if the first schema version
+/// is lower, equal or greater than the second one, attributes are added or removed by the second schema.
+#[derive(Debug, Serialize)]
+pub struct SchemaDiff {
+    pub same_lineage: bool,
+    pub version_cmp: i32,
+    pub added: BTreeSet<String>,
+    pub removed: BTreeSet<String>,
+    pub common: BTreeSet<String>
+}
+
+impl JsonEncodable for SchemaDiff {}
+
+/// Claim definition in the issuer's history of claim definitions for the same schema.
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct ClaimDefinitionLineageEntry {
+    pub tag: Option<String>,
+    /// Seconds since epoch, missed for claim definitions created before lineage was tracked.
+    pub created_at: Option<i64>
+}
+
 impl<'a> JsonDecodable<'a> for SchemaKey {}
 
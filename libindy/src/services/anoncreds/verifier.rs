@@ -6,6 +6,7 @@ use errors::common::CommonError;
 use self::indy_crypto::cl::IssuerPublicKey;
 use self::indy_crypto::cl::verifier::Verifier as CryptoVerifier;
 use services::anoncreds::helpers::*;
+use services::anoncreds::encoding::check_attribute_encoding;
 
 pub struct Verifier {}
 
@@ -45,13 +46,199 @@ impl Verifier {
                                                  revocation_registry.map(|rev_reg| &rev_reg.data))?;
         }
 
-        let valid = proof_verifier.verify(&full_proof.proof, &proof_req.nonce)?;
+        let valid = proof_verifier.verify(&full_proof.proof, &proof_req.nonce)? &&
+            Verifier::_check_revealed_attributes_encoding(full_proof, proof_req, claim_defs)? &&
+            Verifier::_check_restrictions(full_proof, proof_req, claim_defs, schemas)? &&
+            Verifier::_check_non_revoked_intervals(full_proof, proof_req, revoc_regs)?;
 
         info!("verify <<< valid: {:?}", valid);
 
         Ok(valid)
     }
 
+    // Encoded values of revealed attributes must be the ones proved by CL proof and raw values
+    // must correspond to them according to attribute encoding of claim definition
+    // (canonical one for legacy claim definitions).
+    fn _check_revealed_attributes_encoding(full_proof: &FullProof,
+                                           proof_req: &ProofRequest,
+                                           claim_defs: &HashMap<String, ClaimDefinition>) -> Result<bool, CommonError> {
+        info!("_check_revealed_attributes_encoding >>> full_proof: {:?}, proof_req: {:?}", full_proof, proof_req);
+
+        let proved_revealed_attrs = full_proof.proved_revealed_attrs()?;
+
+        let mut res = true;
+
+        for (attr_referent, &(ref claim_referent, ref raw, ref encoded)) in &full_proof.requested_proof.revealed_attrs {
+            let attr_name = &proof_req.requested_attrs.get(attr_referent)
+                .ok_or(CommonError::InvalidStructure(format!("Revealed attribute {} isn't requested", attr_referent)))?
+                .name;
+
+            let claim_def = claim_defs.get(claim_referent)
+                .ok_or(CommonError::InvalidStructure(format!("Claim definition not found for claim: {}", claim_referent)))?;
+
+            let proved_encoded = proved_revealed_attrs.get(claim_referent)
+                .and_then(|revealed_attrs| revealed_attrs.get(attr_name));
+
+            if proved_encoded != Some(encoded) {
+                info!("Encoded value {:?} of attribute {:?} isn't proved, proved value {:?}", encoded, attr_referent, proved_encoded);
+                res = false;
+                break;
+            }
+
+            if !check_attribute_encoding(claim_def.data.attr_encoding.as_ref().map(String::as_str), raw, encoded)? {
+                info!("Raw value {:?} of attribute {:?} doesn't correspond to encoded value {:?}", raw, attr_referent, encoded);
+                res = false;
+                break;
+            }
+        }
+
+        info!("_check_revealed_attributes_encoding <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _check_restrictions(full_proof: &FullProof,
+                           proof_req: &ProofRequest,
+                           claim_defs: &HashMap<String, ClaimDefinition>,
+                           schemas: &HashMap<String, Schema>) -> Result<bool, CommonError> {
+        info!("_check_restrictions >>> full_proof: {:?}, proof_req: {:?}, claim_defs: {:?}, schemas: {:?}",
+              full_proof, proof_req, claim_defs, schemas);
+
+        let requested_proof = &full_proof.requested_proof;
+
+        let mut referents_for_restrictions: Vec<(Option<&String>, &Option<Vec<Restriction>>)> = Vec::new();
+
+        for (attr_referent, attr_info) in &proof_req.requested_attrs {
+            let referent = requested_proof.revealed_attrs.get(attr_referent).map(|&(ref referent, _, _)| referent)
+                .or(requested_proof.unrevealed_attrs.get(attr_referent));
+            referents_for_restrictions.push((referent, &attr_info.restrictions));
+        }
+
+        for (predicate_referent, predicate_info) in &proof_req.requested_predicates {
+            referents_for_restrictions.push((requested_proof.predicates.get(predicate_referent), &predicate_info.restrictions));
+        }
+
+        let mut res = true;
+
+        for (referent, restrictions) in referents_for_restrictions {
+            if restrictions.is_none() {
+                continue;
+            }
+
+            let claim_info = match referent {
+                Some(referent) => Verifier::_get_claim_info(referent, full_proof, proof_req, claim_defs, schemas)?,
+                None => None
+            };
+
+            res = match claim_info {
+                Some(claim_info) => claim_satisfy_restrictions(&claim_info, restrictions),
+                None => false
+            };
+
+            if !res {
+                break;
+            }
+        }
+
+        info!("_check_restrictions <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _check_non_revoked_intervals(full_proof: &FullProof,
+                                    proof_req: &ProofRequest,
+                                    revoc_regs: &HashMap<String, RevocationRegistry>) -> Result<bool, CommonError> {
+        info!("_check_non_revoked_intervals >>> full_proof: {:?}, proof_req: {:?}, revoc_regs: {:?}",
+              full_proof, proof_req, revoc_regs);
+
+        let requested_proof = &full_proof.requested_proof;
+
+        let mut referents_for_intervals: Vec<(Option<&String>, Option<&NonRevokedInterval>)> = Vec::new();
+
+        for (attr_referent, attr_info) in &proof_req.requested_attrs {
+            let referent = requested_proof.revealed_attrs.get(attr_referent).map(|&(ref referent, _, _)| referent)
+                .or(requested_proof.unrevealed_attrs.get(attr_referent));
+            referents_for_intervals.push((referent, attr_info.non_revoked.as_ref().or(proof_req.non_revoked.as_ref())));
+        }
+
+        for (predicate_referent, predicate_info) in &proof_req.requested_predicates {
+            referents_for_intervals.push((requested_proof.predicates.get(predicate_referent),
+                                          predicate_info.non_revoked.as_ref().or(proof_req.non_revoked.as_ref())));
+        }
+
+        let mut res = true;
+
+        for (referent, interval) in referents_for_intervals {
+            let (referent, interval) = match (referent, interval) {
+                (Some(referent), Some(interval)) => (referent, interval),
+                _ => continue
+            };
+
+            // Proof is verified against accumulator of registry given to verifier, so only its validity period
+            // (read from the ledger with state proof) is trusted, timestamp of identifiers is set by prover
+            res = match revoc_regs.get(referent.as_str()).and_then(RevocationRegistry::validity_period) {
+                Some((valid_from, valid_to)) => interval.overlaps(valid_from, valid_to),
+                None => false
+            };
+
+            if !res {
+                info!("Claim {:?} isn't proved to be non-revoked in interval {:?}", referent, interval);
+                break;
+            }
+        }
+
+        info!("_check_non_revoked_intervals <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    // Builds the view of proved claim from identifiers and verifier's claim definitions and schemas.
+    // Only revealed attribute values are known, so attribute value restrictions require revealing.
+    fn _get_claim_info(referent: &str,
+                       full_proof: &FullProof,
+                       proof_req: &ProofRequest,
+                       claim_defs: &HashMap<String, ClaimDefinition>,
+                       schemas: &HashMap<String, Schema>) -> Result<Option<ClaimInfo>, CommonError> {
+        let identifier = full_proof.identifiers.get(referent)
+            .ok_or(CommonError::InvalidStructure(format!("Identifier not found for claim: {}", referent)))?;
+        let claim_def = claim_defs.get(referent)
+            .ok_or(CommonError::InvalidStructure(format!("Claim definition not found for claim: {}", referent)))?;
+        let schema = schemas.get(referent)
+            .ok_or(CommonError::InvalidStructure(format!("Schema not found for claim: {}", referent)))?;
+
+        if claim_def.issuer_did != identifier.issuer_did ||
+            claim_def.tag != identifier.claim_def_tag ||
+            schema.data.name != identifier.schema_key.name ||
+            schema.data.version != identifier.schema_key.version {
+            info!("Identifier {:?} doesn't correspond to claim definition or schema", identifier);
+            return Ok(None);
+        }
+
+        let mut attrs: HashMap<String, String> = HashMap::new();
+
+        // Raw values are bound to the proof by `_check_revealed_attributes_encoding`
+        for (attr_referent, &(ref requested_referent, ref raw, _)) in &full_proof.requested_proof.revealed_attrs {
+            if referent.eq(requested_referent.as_str()) {
+                if let Some(attr) = proof_req.requested_attrs.get(attr_referent) {
+                    attrs.insert(attr.name.clone(), raw.clone());
+                }
+            }
+        }
+
+        Ok(Some(ClaimInfo {
+            referent: referent.to_string(),
+            attrs,
+            schema_key: identifier.schema_key.clone(),
+            issuer_did: identifier.issuer_did.clone(),
+            revoc_reg_seq_no: identifier.rev_reg_seq_no,
+            schema_seq_no: Some(claim_def.schema_seq_no),
+            master_secret_name: None,
+            metadata: None,
+            stored_at: None,
+            claim_def_tag: claim_def.tag.clone()
+        }))
+    }
+
     fn _get_revealed_attributes_for_claim(referent: &str, requested_proof: &RequestedProof, proof_req: &ProofRequest) -> Result<Vec<String>, CommonError> {
         info!("_get_revealed_attributes_for_claim >>> referent: {:?}, requested_claims: {:?}, proof_req: {:?}",
               referent, requested_proof, proof_req);
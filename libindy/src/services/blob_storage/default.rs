@@ -0,0 +1,130 @@
+extern crate indy_crypto;
+extern crate uuid;
+
+use super::{BlobStorageReader, BlobStorageType, BlobStorageWriter};
+
+use errors::common::CommonError;
+use utils::crypto::base58::Base58;
+use utils::environment::EnvironmentUtils;
+
+use std::fs::{self, DirBuilder, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+
+use self::indy_crypto::utils::json::JsonDecodable;
+use self::uuid::Uuid;
+
+#[derive(Deserialize)]
+struct DefaultBlobStorageConfig {
+    base_dir: Option<String>
+}
+
+impl<'a> JsonDecodable<'a> for DefaultBlobStorageConfig {}
+
+impl DefaultBlobStorageConfig {
+    fn parse(config: &str) -> Result<DefaultBlobStorageConfig, CommonError> {
+        DefaultBlobStorageConfig::from_json(config)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid blob storage config: {:?}", err)))
+    }
+
+    fn base_dir(&self) -> PathBuf {
+        match self.base_dir {
+            Some(ref base_dir) => PathBuf::from(base_dir),
+            None => {
+                let mut path = EnvironmentUtils::indy_home_path();
+                path.push("tails");
+                path
+            }
+        }
+    }
+}
+
+struct DefaultBlobStorageWriter {
+    base_dir: PathBuf,
+    tmp_path: PathBuf,
+    file: BufWriter<File>
+}
+
+impl BlobStorageWriter for DefaultBlobStorageWriter {
+    fn append(&mut self, bytes: &[u8]) -> Result<(), CommonError> {
+        self.file.write_all(bytes).map_err(CommonError::IOError)
+    }
+
+    fn finalize(&mut self, hash: &str) -> Result<(), CommonError> {
+        self.file.flush().map_err(CommonError::IOError)?;
+        self.file.get_ref().sync_all().map_err(CommonError::IOError)?;
+
+        // Blob becomes visible under its hash only when completely written
+        fs::rename(&self.tmp_path, _blob_path(&self.base_dir, hash)?).map_err(CommonError::IOError)
+    }
+}
+
+impl Drop for DefaultBlobStorageWriter {
+    fn drop(&mut self) {
+        // Not finalized blob is removed, finalized one is already renamed
+        let _ = fs::remove_file(&self.tmp_path);
+    }
+}
+
+struct DefaultBlobStorageReader {
+    file: BufReader<File>
+}
+
+impl BlobStorageReader for DefaultBlobStorageReader {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, CommonError> {
+        self.file.read(buf).map_err(CommonError::IOError)
+    }
+}
+
+/// Blobs are stored in base dir by hash. Hash comes from registries provided by other parties,
+/// so it must be a plain base58 string that can't point outside of base dir.
+fn _blob_path(base_dir: &PathBuf, hash: &str) -> Result<PathBuf, CommonError> {
+    if hash.is_empty() || Base58::decode(hash).is_err() {
+        return Err(CommonError::InvalidStructure(format!("Invalid blob hash: {}", hash)));
+    }
+
+    let mut path = base_dir.clone();
+    path.push(hash);
+    Ok(path)
+}
+
+pub struct DefaultBlobStorageType {}
+
+impl DefaultBlobStorageType {
+    pub fn new() -> DefaultBlobStorageType {
+        DefaultBlobStorageType {}
+    }
+}
+
+impl BlobStorageType for DefaultBlobStorageType {
+    fn open_writer(&self, config: &str) -> Result<Box<BlobStorageWriter>, CommonError> {
+        let config = DefaultBlobStorageConfig::parse(config)?;
+        let base_dir = config.base_dir();
+
+        DirBuilder::new()
+            .recursive(true)
+            .create(&base_dir)
+            .map_err(CommonError::IOError)?;
+
+        let mut tmp_path = base_dir.clone();
+        tmp_path.push(format!("{}.tmp", Uuid::new_v4().to_string()));
+
+        let file = File::create(&tmp_path).map_err(CommonError::IOError)?;
+
+        Ok(Box::new(DefaultBlobStorageWriter {
+            base_dir,
+            tmp_path,
+            file: BufWriter::new(file)
+        }))
+    }
+
+    fn open_reader(&self, config: &str, hash: &str) -> Result<Box<BlobStorageReader>, CommonError> {
+        let config = DefaultBlobStorageConfig::parse(config)?;
+
+        let file = File::open(_blob_path(&config.base_dir(), hash)?).map_err(CommonError::IOError)?;
+
+        Ok(Box::new(DefaultBlobStorageReader {
+            file: BufReader::new(file)
+        }))
+    }
+}
@@ -0,0 +1,318 @@
+mod default;
+mod plugged;
+
+use self::default::DefaultBlobStorageType;
+pub use self::plugged::PluggedBlobStorageType;
+
+use errors::common::CommonError;
+use utils::crypto::base58::Base58;
+use utils::crypto::hash::Hash;
+
+use openssl::hash::Hasher;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io;
+
+pub trait BlobStorageWriter {
+    fn append(&mut self, bytes: &[u8]) -> Result<(), CommonError>;
+    /// Persists appended data so it can be read by its hash.
+    fn finalize(&mut self, hash: &str) -> Result<(), CommonError>;
+}
+
+pub trait BlobStorageReader {
+    /// Reads next chunk of blob into buffer. Returns 0 at the end of blob.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, CommonError>;
+}
+
+/// Blob storage is content-addressed: blobs are written and read by their hash only,
+/// so locations from untrusted registries never reach the storage.
+pub trait BlobStorageType {
+    fn open_writer(&self, config: &str) -> Result<Box<BlobStorageWriter>, CommonError>;
+    fn open_reader(&self, config: &str, hash: &str) -> Result<Box<BlobStorageReader>, CommonError>;
+}
+
+/// Writer hashing appended data. Implements `io::Write`, so big blobs can be serialized
+/// into storage by chunks.
+pub struct BlobWriter {
+    writer: Box<BlobStorageWriter>,
+    hasher: Hasher
+}
+
+impl BlobWriter {
+    /// Persists written blob and returns its base58 encoded sha256 hash.
+    pub fn finalize(mut self) -> Result<String, CommonError> {
+        let hash = Base58::encode(&self.hasher.finish2()?);
+        self.writer.finalize(&hash)?;
+        Ok(hash)
+    }
+}
+
+impl io::Write for BlobWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.hasher.update(buf)?;
+        self.writer.append(buf).map_err(_to_io_error)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Reader hashing read data. Implements `io::Read`, so big blobs can be deserialized
+/// from storage by chunks.
+pub struct BlobReader {
+    reader: Box<BlobStorageReader>,
+    hasher: Hasher,
+    hash: String
+}
+
+impl BlobReader {
+    /// Reads the rest of blob and checks that the whole blob corresponds to expected hash.
+    /// Data read before must be dropped if check fails.
+    pub fn finalize(mut self) -> Result<(), CommonError> {
+        let mut buf = [0u8; 4096];
+        loop {
+            let read = self.reader.read(&mut buf)?;
+            if read == 0 {
+                break;
+            }
+            self.hasher.update(&buf[..read])?;
+        }
+
+        if Base58::encode(&self.hasher.finish2()?) != self.hash {
+            return Err(CommonError::InvalidState(format!("Blob doesn't correspond to hash {}", self.hash)));
+        }
+
+        Ok(())
+    }
+}
+
+impl io::Read for BlobReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.reader.read(buf).map_err(_to_io_error)?;
+        self.hasher.update(&buf[..read])?;
+        Ok(read)
+    }
+}
+
+fn _to_io_error(err: CommonError) -> io::Error {
+    match err {
+        CommonError::IOError(err) => err,
+        err => io::Error::new(io::ErrorKind::Other, format!("{:?}", err))
+    }
+}
+
+pub struct BlobStorageService {
+    types: RefCell<HashMap<String, Box<BlobStorageType>>>
+}
+
+impl BlobStorageService {
+    pub fn new() -> BlobStorageService {
+        let mut types: HashMap<String, Box<BlobStorageType>> = HashMap::new();
+        types.insert("default".to_string(), Box::new(DefaultBlobStorageType::new()));
+
+        BlobStorageService {
+            types: RefCell::new(types)
+        }
+    }
+
+    pub fn register_type(&self, xtype: &str, blob_storage_type: Box<BlobStorageType>) -> Result<(), CommonError> {
+        let mut types = self.types.try_borrow_mut()?;
+
+        if types.contains_key(xtype) {
+            return Err(CommonError::InvalidState(format!("Blob storage type already registered: {}", xtype)));
+        }
+
+        types.insert(xtype.to_string(), blob_storage_type);
+        Ok(())
+    }
+
+    pub fn open_writer(&self, xtype: &str, config: &str) -> Result<BlobWriter, CommonError> {
+        let types = self.types.try_borrow()?;
+
+        let blob_storage_type = types.get(xtype)
+            .ok_or(CommonError::InvalidStructure(format!("Unknown blob storage type: {}", xtype)))?;
+
+        Ok(BlobWriter {
+            writer: blob_storage_type.open_writer(config)?,
+            hasher: Hash::new_context()?
+        })
+    }
+
+    pub fn open_reader(&self, xtype: &str, config: &str, hash: &str) -> Result<BlobReader, CommonError> {
+        let types = self.types.try_borrow()?;
+
+        let blob_storage_type = types.get(xtype)
+            .ok_or(CommonError::InvalidStructure(format!("Unknown blob storage type: {}", xtype)))?;
+
+        Ok(BlobReader {
+            reader: blob_storage_type.open_reader(config, hash)?,
+            hasher: Hash::new_context()?,
+            hash: hash.to_string()
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BlobStorageConfig {
+    #[serde(rename = "type")]
+    xtype: Option<String>,
+    config: Option<::serde_json::Value>
+}
+
+/// Parses `{"type": <blob storage type>, "config": <type specific config>}` json,
+/// missed json or fields mean default type with its default config.
+pub fn parse_blob_storage_config(config_json: Option<&str>) -> Result<(String, String), CommonError> {
+    let config: BlobStorageConfig = match config_json {
+        Some(config_json) => ::serde_json::from_str(config_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid blob storage config: {:?}", err)))?,
+        None => BlobStorageConfig { xtype: None, config: None }
+    };
+
+    let xtype = config.xtype.unwrap_or("default".to_string());
+    let config = match config.config {
+        Some(config) => ::serde_json::to_string(&config)
+            .map_err(|err| CommonError::InvalidState(format!("Cannot serialize blob storage config: {:?}", err)))?,
+        None => "{}".to_string()
+    };
+
+    Ok((xtype, config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use utils::environment::EnvironmentUtils;
+
+    struct InmemBlobStorageType {}
+
+    struct InmemBlobStorageWriter {}
+
+    impl BlobStorageWriter for InmemBlobStorageWriter {
+        fn append(&mut self, _bytes: &[u8]) -> Result<(), CommonError> { Ok(()) }
+        fn finalize(&mut self, _hash: &str) -> Result<(), CommonError> { Ok(()) }
+    }
+
+    impl BlobStorageType for InmemBlobStorageType {
+        fn open_writer(&self, _config: &str) -> Result<Box<BlobStorageWriter>, CommonError> {
+            Ok(Box::new(InmemBlobStorageWriter {}))
+        }
+
+        fn open_reader(&self, _config: &str, _hash: &str) -> Result<Box<BlobStorageReader>, CommonError> {
+            Err(CommonError::InvalidState(format!("Not supported")))
+        }
+    }
+
+    fn _config() -> String {
+        format!(r#"{{"base_dir":"{}"}}"#, EnvironmentUtils::tmp_file_path("blob_storage_tests").to_str().unwrap())
+    }
+
+    fn _write_blob(service: &BlobStorageService, data: &[u8]) -> String {
+        let mut writer = service.open_writer("default", &_config()).unwrap();
+        writer.write_all(data).unwrap();
+        writer.finalize().unwrap()
+    }
+
+    #[test]
+    fn write_and_read_blob_works() {
+        let service = BlobStorageService::new();
+
+        let hash = _write_blob(&service, &[1, 2, 3]);
+
+        let mut reader = service.open_reader("default", &_config(), &hash).unwrap();
+        let mut data: Vec<u8> = Vec::new();
+        reader.read_to_end(&mut data).unwrap();
+        reader.finalize().unwrap();
+
+        assert_eq!(vec![1, 2, 3], data);
+    }
+
+    #[test]
+    fn read_blob_works_for_partially_read_blob() {
+        let service = BlobStorageService::new();
+
+        let hash = _write_blob(&service, &[1, 2, 3]);
+
+        let mut reader = service.open_reader("default", &_config(), &hash).unwrap();
+        let mut data = [0u8; 1];
+        reader.read_exact(&mut data).unwrap();
+        reader.finalize().unwrap();
+    }
+
+    #[test]
+    fn read_blob_works_for_other_hash() {
+        let service = BlobStorageService::new();
+
+        let hash = _write_blob(&service, &[1, 2, 3]);
+        let other_hash = _write_blob(&service, &[4, 5, 6]);
+
+        let mut path = EnvironmentUtils::tmp_file_path("blob_storage_tests");
+        path.push(&other_hash);
+        ::std::fs::remove_file(&path).unwrap();
+        let mut blob_path = EnvironmentUtils::tmp_file_path("blob_storage_tests");
+        blob_path.push(&hash);
+        ::std::fs::copy(&blob_path, &path).unwrap();
+
+        let mut reader = service.open_reader("default", &_config(), &other_hash).unwrap();
+        let mut data: Vec<u8> = Vec::new();
+        reader.read_to_end(&mut data).unwrap();
+
+        let res = reader.finalize();
+        assert_match!(Err(CommonError::InvalidState(_)), res);
+    }
+
+    #[test]
+    fn open_reader_works_for_hash_with_path() {
+        let service = BlobStorageService::new();
+
+        let res = service.open_reader("default", &_config(), "/etc/passwd");
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+
+        let res = service.open_reader("default", &_config(), "../tails");
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn open_writer_works_for_unknown_type() {
+        let service = BlobStorageService::new();
+
+        let res = service.open_writer("unknown", &_config());
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn register_type_works() {
+        let service = BlobStorageService::new();
+
+        service.register_type("inmem", Box::new(InmemBlobStorageType {})).unwrap();
+
+        let mut writer = service.open_writer("inmem", "{}").unwrap();
+        writer.write_all(&[1, 2, 3]).unwrap();
+        writer.finalize().unwrap();
+    }
+
+    #[test]
+    fn register_type_works_for_already_registered() {
+        let service = BlobStorageService::new();
+
+        let res = service.register_type("default", Box::new(InmemBlobStorageType {}));
+        assert_match!(Err(CommonError::InvalidState(_)), res);
+    }
+
+    #[test]
+    fn parse_blob_storage_config_works() {
+        assert_eq!(("default".to_string(), "{}".to_string()), parse_blob_storage_config(None).unwrap());
+        assert_eq!(("default".to_string(), "{}".to_string()), parse_blob_storage_config(Some("{}")).unwrap());
+        assert_eq!(("inmem".to_string(), r#"{"base_dir":"/tmp"}"#.to_string()),
+                   parse_blob_storage_config(Some(r#"{"type":"inmem","config":{"base_dir":"/tmp"}}"#)).unwrap());
+    }
+
+    #[test]
+    fn parse_blob_storage_config_works_for_invalid_json() {
+        let res = parse_blob_storage_config(Some(r#"{"type":1}"#));
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+}
@@ -0,0 +1,180 @@
+extern crate libc;
+
+use super::{BlobStorageReader, BlobStorageType, BlobStorageWriter};
+
+use api::ErrorCode;
+use errors::common::CommonError;
+
+use self::libc::c_char;
+
+use std::ffi::CString;
+use std::io;
+
+fn _map_err(err: ErrorCode) -> CommonError {
+    match err {
+        ErrorCode::CommonIOError => CommonError::IOError(io::Error::new(io::ErrorKind::Other, "Plugged blob storage IO error")),
+        err => CommonError::InvalidState(format!("Plugged blob storage error: {:?}", err))
+    }
+}
+
+fn _cstring(value: &str) -> Result<CString, CommonError> {
+    CString::new(value)
+        .map_err(|err| CommonError::InvalidStructure(format!("Invalid string for plugged blob storage: {:?}", err)))
+}
+
+struct PluggedBlobStorageWriter {
+    handle: i32,
+    append_handler: extern fn(handle: i32,
+                              bytes: *const u8,
+                              bytes_len: u32) -> ErrorCode,
+    finalize_handler: extern fn(handle: i32,
+                                hash: *const c_char) -> ErrorCode,
+    close_handler: extern fn(handle: i32) -> ErrorCode
+}
+
+impl BlobStorageWriter for PluggedBlobStorageWriter {
+    fn append(&mut self, bytes: &[u8]) -> Result<(), CommonError> {
+        let err = (self.append_handler)(self.handle, bytes.as_ptr(), bytes.len() as u32);
+
+        if err != ErrorCode::Success {
+            return Err(_map_err(err));
+        }
+
+        Ok(())
+    }
+
+    fn finalize(&mut self, hash: &str) -> Result<(), CommonError> {
+        let hash = _cstring(hash)?;
+
+        let err = (self.finalize_handler)(self.handle, hash.as_ptr());
+
+        if err != ErrorCode::Success {
+            return Err(_map_err(err));
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for PluggedBlobStorageWriter {
+    fn drop(&mut self) {
+        // Storage discards data of writer closed without finalization
+        let _ = (self.close_handler)(self.handle);
+    }
+}
+
+struct PluggedBlobStorageReader {
+    handle: i32,
+    read_handler: extern fn(handle: i32,
+                            buf: *mut u8,
+                            buf_len: u32,
+                            read_len: *mut u32) -> ErrorCode,
+    close_handler: extern fn(handle: i32) -> ErrorCode
+}
+
+impl BlobStorageReader for PluggedBlobStorageReader {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, CommonError> {
+        let mut read_len: u32 = 0;
+
+        let err = (self.read_handler)(self.handle, buf.as_mut_ptr(), buf.len() as u32, &mut read_len);
+
+        if err != ErrorCode::Success {
+            return Err(_map_err(err));
+        }
+
+        if read_len as usize > buf.len() {
+            return Err(CommonError::InvalidState(format!("Plugged blob storage read more than requested: {}", read_len)));
+        }
+
+        Ok(read_len as usize)
+    }
+}
+
+impl Drop for PluggedBlobStorageReader {
+    fn drop(&mut self) {
+        let _ = (self.close_handler)(self.handle);
+    }
+}
+
+pub struct PluggedBlobStorageType {
+    open_writer_handler: extern fn(config: *const c_char,
+                                   handle: *mut i32) -> ErrorCode,
+    append_handler: extern fn(handle: i32,
+                              bytes: *const u8,
+                              bytes_len: u32) -> ErrorCode,
+    finalize_handler: extern fn(handle: i32,
+                                hash: *const c_char) -> ErrorCode,
+    open_reader_handler: extern fn(config: *const c_char,
+                                   hash: *const c_char,
+                                   handle: *mut i32) -> ErrorCode,
+    read_handler: extern fn(handle: i32,
+                            buf: *mut u8,
+                            buf_len: u32,
+                            read_len: *mut u32) -> ErrorCode,
+    close_handler: extern fn(handle: i32) -> ErrorCode
+}
+
+impl PluggedBlobStorageType {
+    pub fn new(open_writer_handler: extern fn(config: *const c_char,
+                                              handle: *mut i32) -> ErrorCode,
+               append_handler: extern fn(handle: i32,
+                                         bytes: *const u8,
+                                         bytes_len: u32) -> ErrorCode,
+               finalize_handler: extern fn(handle: i32,
+                                           hash: *const c_char) -> ErrorCode,
+               open_reader_handler: extern fn(config: *const c_char,
+                                              hash: *const c_char,
+                                              handle: *mut i32) -> ErrorCode,
+               read_handler: extern fn(handle: i32,
+                                       buf: *mut u8,
+                                       buf_len: u32,
+                                       read_len: *mut u32) -> ErrorCode,
+               close_handler: extern fn(handle: i32) -> ErrorCode) -> PluggedBlobStorageType {
+        PluggedBlobStorageType {
+            open_writer_handler,
+            append_handler,
+            finalize_handler,
+            open_reader_handler,
+            read_handler,
+            close_handler
+        }
+    }
+}
+
+impl BlobStorageType for PluggedBlobStorageType {
+    fn open_writer(&self, config: &str) -> Result<Box<BlobStorageWriter>, CommonError> {
+        let config = _cstring(config)?;
+        let mut handle: i32 = 0;
+
+        let err = (self.open_writer_handler)(config.as_ptr(), &mut handle);
+
+        if err != ErrorCode::Success {
+            return Err(_map_err(err));
+        }
+
+        Ok(Box::new(PluggedBlobStorageWriter {
+            handle,
+            append_handler: self.append_handler,
+            finalize_handler: self.finalize_handler,
+            close_handler: self.close_handler
+        }))
+    }
+
+    fn open_reader(&self, config: &str, hash: &str) -> Result<Box<BlobStorageReader>, CommonError> {
+        let config = _cstring(config)?;
+        let hash = _cstring(hash)?;
+        let mut handle: i32 = 0;
+
+        let err = (self.open_reader_handler)(config.as_ptr(), hash.as_ptr(), &mut handle);
+
+        if err != ErrorCode::Success {
+            return Err(_map_err(err));
+        }
+
+        Ok(Box::new(PluggedBlobStorageReader {
+            handle,
+            read_handler: self.read_handler,
+            close_handler: self.close_handler
+        }))
+    }
+}
@@ -0,0 +1,193 @@
+extern crate byteorder;
+extern crate digest;
+extern crate sha2;
+
+use errors::common::CommonError;
+
+use self::byteorder::{BigEndian, ByteOrder};
+use self::digest::{FixedOutput, Input};
+
+use std::fmt;
+
+// Hierarchical deterministic derivation of ed25519 keys as described in SLIP-0010:
+//
+// master key = HMAC-SHA512(key = "ed25519 seed", data = master seed)
+// child key = HMAC-SHA512(key = parent chain code, data = 0x00 || parent key || index (u32, big-endian))
+//
+// Left 32 bytes of HMAC output are the key (used as ed25519 seed), right 32 bytes are the chain code.
+// Only hardened indices (index >= 2^31) are supported for ed25519, so every path
+// component must be hardened: m/0'/1'/2'
+
+const ED25519_CURVE: &'static [u8] = b"ed25519 seed";
+const HARDENED_OFFSET: u32 = 0x80000000;
+
+const MIN_MASTER_SEED_LEN: usize = 16;
+const MAX_MASTER_SEED_LEN: usize = 64;
+
+const SHA512_BLOCK_LEN: usize = 128;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DerivationPath {
+    indices: Vec<u32>
+}
+
+impl DerivationPath {
+    pub fn parse(path: &str) -> Result<DerivationPath, CommonError> {
+        let mut parts = path.split('/');
+
+        if parts.next() != Some("m") {
+            return Err(CommonError::InvalidStructure(format!("Derivation path must start with \"m\": {}", path)));
+        }
+
+        let indices = parts
+            .map(|part| {
+                if !part.ends_with("'") {
+                    return Err(CommonError::InvalidStructure(format!("Only hardened derivation is supported: {}", path)));
+                }
+
+                part[..part.len() - 1].parse::<u32>().ok()
+                    .and_then(|index| if index < HARDENED_OFFSET { Some(index) } else { None })
+                    .ok_or(CommonError::InvalidStructure(format!("Invalid derivation path index: {}", part)))
+            })
+            .collect::<Result<Vec<u32>, CommonError>>()?;
+
+        Ok(DerivationPath { indices })
+    }
+
+    pub fn child(&self, index: u32) -> Result<DerivationPath, CommonError> {
+        if index >= HARDENED_OFFSET {
+            return Err(CommonError::InvalidStructure(format!("Invalid derivation path index: {}", index)));
+        }
+
+        let mut indices = self.indices.clone();
+        indices.push(index);
+
+        Ok(DerivationPath { indices })
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "m")?;
+
+        for index in self.indices.iter() {
+            write!(f, "/{}'", index)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn validate_master_seed(master_seed: &[u8]) -> Result<(), CommonError> {
+    if master_seed.len() < MIN_MASTER_SEED_LEN || master_seed.len() > MAX_MASTER_SEED_LEN {
+        return Err(CommonError::InvalidStructure(
+            format!("Master seed must be from {} to {} bytes long", MIN_MASTER_SEED_LEN, MAX_MASTER_SEED_LEN)));
+    }
+
+    Ok(())
+}
+
+/// Derives 32 bytes seed of ed25519 key pair located by path from the master seed.
+pub fn derive_ed25519_seed(master_seed: &[u8], path: &DerivationPath) -> Result<Vec<u8>, CommonError> {
+    validate_master_seed(master_seed)?;
+
+    let (mut key, mut chain_code) = _split(hmac_sha512(ED25519_CURVE, master_seed));
+
+    for index in path.indices.iter() {
+        let mut index_bytes = [0u8; 4];
+        BigEndian::write_u32(&mut index_bytes, index + HARDENED_OFFSET);
+
+        let mut data = vec![0u8];
+        data.extend_from_slice(&key);
+        data.extend_from_slice(&index_bytes);
+
+        let (child_key, child_chain_code) = _split(hmac_sha512(&chain_code, &data));
+        key = child_key;
+        chain_code = child_chain_code;
+    }
+
+    Ok(key)
+}
+
+fn hmac_sha512(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut key_block = if key.len() > SHA512_BLOCK_LEN {
+        _sha512(&[key])
+    } else {
+        key.to_vec()
+    };
+    key_block.resize(SHA512_BLOCK_LEN, 0);
+
+    let ipad: Vec<u8> = key_block.iter().map(|b| b ^ 0x36).collect();
+    let opad: Vec<u8> = key_block.iter().map(|b| b ^ 0x5c).collect();
+
+    let inner = _sha512(&[&ipad, data]);
+    _sha512(&[&opad, &inner])
+}
+
+fn _sha512(parts: &[&[u8]]) -> Vec<u8> {
+    let mut hasher = sha2::Sha512::default();
+
+    for part in parts {
+        hasher.process(part);
+    }
+
+    hasher.fixed_result().to_vec()
+}
+
+fn _split(mut data: Vec<u8>) -> (Vec<u8>, Vec<u8>) {
+    let chain_code = data.split_off(32);
+    (data, chain_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate hex;
+
+    use self::hex::FromHex;
+
+    // Test vector 1 for ed25519 from SLIP-0010
+    const MASTER_SEED: &'static str = "000102030405060708090a0b0c0d0e0f";
+
+    #[test]
+    fn derive_ed25519_seed_works_for_master_key() {
+        let master_seed = Vec::from_hex(MASTER_SEED).unwrap();
+        let path = DerivationPath::parse("m").unwrap();
+
+        let seed = derive_ed25519_seed(&master_seed, &path).unwrap();
+        assert_eq!(Vec::from_hex("2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7").unwrap(), seed);
+    }
+
+    #[test]
+    fn derive_ed25519_seed_works_for_child_key() {
+        let master_seed = Vec::from_hex(MASTER_SEED).unwrap();
+        let path = DerivationPath::parse("m/0'").unwrap();
+
+        let seed = derive_ed25519_seed(&master_seed, &path).unwrap();
+        assert_eq!(Vec::from_hex("68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3").unwrap(), seed);
+    }
+
+    #[test]
+    fn derive_ed25519_seed_works_for_short_master_seed() {
+        let path = DerivationPath::parse("m/0'").unwrap();
+        assert!(derive_ed25519_seed(&[1, 2, 3], &path).is_err());
+    }
+
+    #[test]
+    fn derivation_path_parse_works() {
+        let path = DerivationPath::parse("m/44'/0'/1'").unwrap();
+        assert_eq!(vec![44, 0, 1], path.indices);
+        assert_eq!("m/44'/0'/1'", path.to_string());
+        assert_eq!("m/44'/0'/1'/5'", path.child(5).unwrap().to_string());
+    }
+
+    #[test]
+    fn derivation_path_parse_works_for_invalid_path() {
+        assert!(DerivationPath::parse("44'/0'").is_err());
+        assert!(DerivationPath::parse("m/0").is_err());
+        assert!(DerivationPath::parse("m/a'").is_err());
+        assert!(DerivationPath::parse("m/2147483648'").is_err());
+        assert!(DerivationPath::parse("m/").is_err());
+    }
+}
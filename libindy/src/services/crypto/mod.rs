@@ -1,13 +1,26 @@
+extern crate hex;
+
 mod ed25519;
+pub mod hd;
+#[cfg(feature = "secp256k1_libsecp256k1")]
+mod secp256k1;
+pub mod stream;
 pub mod types;
 
 use base64;
+use serde_json;
 
 use self::ed25519::ED25519CryptoType;
+use self::hex::ToHex;
+use self::hd::DerivationPath;
+#[cfg(feature = "secp256k1_libsecp256k1")]
+use self::secp256k1::{Secp256k1CryptoType, SECP256K1_CRYPTO_TYPE};
 use self::types::*;
 
 use utils::crypto::base58::Base58;
+use utils::crypto::hash::Hash;
 use utils::crypto::verkey_builder::build_full_verkey;
+use utils::crypto::xsalsa20::XSalsa20;
 
 use errors::common::CommonError;
 use errors::crypto::CryptoError;
@@ -17,6 +30,15 @@ use std::str;
 
 pub const DEFAULT_CRYPTO_TYPE: &'static str = "ed25519";
 
+pub const VERKEY_FORMAT_JWK: &'static str = "jwk";
+pub const VERKEY_FORMAT_MULTIBASE: &'static str = "multibase";
+pub const VERKEY_FORMAT_HEX: &'static str = "hex";
+
+// Multicodec prefixes of public keys in multibase format
+const ED25519_PUB_MULTICODEC: &'static [u8] = &[0xed, 0x01];
+#[cfg(feature = "secp256k1_libsecp256k1")]
+const SECP256K1_PUB_MULTICODEC: &'static [u8] = &[0xe7, 0x01];
+
 trait CryptoType {
     fn encrypt(&self, private_key: &[u8], public_key: &[u8], doc: &[u8], nonce: &[u8]) -> Result<Vec<u8>, CommonError>;
     fn decrypt(&self, private_key: &[u8], public_key: &[u8], doc: &[u8], nonce: &[u8]) -> Result<Vec<u8>, CommonError>;
@@ -37,6 +59,8 @@ impl CryptoService {
     pub fn new() -> CryptoService {
         let mut crypto_types: HashMap<&str, Box<CryptoType>> = HashMap::new();
         crypto_types.insert(DEFAULT_CRYPTO_TYPE, Box::new(ED25519CryptoType::new()));
+        #[cfg(feature = "secp256k1_libsecp256k1")]
+        crypto_types.insert(SECP256K1_CRYPTO_TYPE, Box::new(Secp256k1CryptoType::new()));
 
         CryptoService {
             crypto_types
@@ -62,10 +86,12 @@ impl CryptoService {
         let vk = Base58::encode(&vk);
         let sk = Base58::encode(&sk);
 
-        if !crypto_type_name.eq(DEFAULT_CRYPTO_TYPE) {
+        let vk = if !crypto_type_name.eq(DEFAULT_CRYPTO_TYPE) {
             // Use suffix with crypto type name to store crypto type inside of vk
-            let vk = format!("{}:{}", vk, crypto_type_name);
-        }
+            format!("{}:{}", vk, crypto_type_name)
+        } else {
+            vk
+        };
 
         Ok(Key::new(vk, sk))
     }
@@ -99,10 +125,12 @@ impl CryptoService {
         let vk = Base58::encode(&vk);
         let sk = Base58::encode(&sk);
 
-        if !crypto_type_name.eq(DEFAULT_CRYPTO_TYPE) {
+        let vk = if !crypto_type_name.eq(DEFAULT_CRYPTO_TYPE) {
             // Use suffix with crypto type name to store crypto type inside of vk
-            let vk = format!("{}:{}", vk, crypto_type_name);
-        }
+            format!("{}:{}", vk, crypto_type_name)
+        } else {
+            vk
+        };
 
         Ok((Did::new(did, vk.clone()), Key::new(vk, sk)))
     }
@@ -290,6 +318,48 @@ impl CryptoService {
         Ok(decrypted_doc)
     }
 
+    /// Encrypts document with a new random content key. Returns content key, nonce and encrypted document.
+    /// xsalsa20-poly1305 has no associated data, so sha256 of `aad` is encrypted as the prefix of document
+    /// to authenticate it with the document.
+    pub fn encrypt_content(&self, doc: &[u8], aad: &[u8]) -> Result<(Vec<u8>, Vec<u8>, Vec<u8>), CryptoError> {
+        let xsalsa20 = XSalsa20::new();
+
+        let key = xsalsa20.create_key();
+        let nonce = xsalsa20.gen_nonce();
+
+        let mut content = CryptoService::_aad_digest(aad)?;
+        content.extend_from_slice(doc);
+
+        let encrypted_doc = xsalsa20.encrypt(&key, &nonce, &content);
+
+        Ok((key, nonce, encrypted_doc))
+    }
+
+    /// Decrypts document encrypted by `encrypt_content` and checks it was encrypted with the same `aad`.
+    pub fn decrypt_content(&self, key: &[u8], nonce: &[u8], doc: &[u8], aad: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        if key.len() != 32 || nonce.len() != 24 {
+            return Err(CryptoError::CommonError(
+                CommonError::InvalidStructure(format!("Invalid content key or nonce"))));
+        }
+
+        let mut content = XSalsa20::new().decrypt(key, nonce, doc)?;
+
+        let aad_digest = CryptoService::_aad_digest(aad)?;
+
+        if content.len() < aad_digest.len() || content[..aad_digest.len()] != aad_digest[..] {
+            return Err(CryptoError::CommonError(
+                CommonError::InvalidStructure(format!("Associated data doesn't correspond to encrypted content"))));
+        }
+
+        Ok(content.split_off(aad_digest.len()))
+    }
+
+    fn _aad_digest(aad: &[u8]) -> Result<Vec<u8>, CommonError> {
+        let mut hasher = Hash::new_context()?;
+        hasher.update(aad)?;
+        Ok(hasher.finish2()?.to_vec())
+    }
+
     pub fn convert_seed(&self, seed: Option<&str>) -> Result<Option<Vec<u8>>, CryptoError> {
         Ok(match seed {
             Some(ref seed) =>
@@ -303,6 +373,107 @@ impl CryptoService {
         })
     }
 
+    pub fn validate_master_seed(&self, master_seed: &str) -> Result<(), CryptoError> {
+        let master_seed = self.convert_seed(Some(master_seed))?.unwrap_or(Vec::new());
+        hd::validate_master_seed(&master_seed)?;
+        Ok(())
+    }
+
+    /// Derives seed of the key located by path from the master seed.
+    /// Result is in the same format as seed field of KeyInfo and MyDidInfo.
+    pub fn derive_seed(&self, master_seed: &str, crypto_type: Option<&str>, path: &DerivationPath) -> Result<String, CryptoError> {
+        let crypto_type_name = crypto_type.unwrap_or(DEFAULT_CRYPTO_TYPE);
+
+        if !crypto_type_name.eq(DEFAULT_CRYPTO_TYPE) {
+            return Err(CryptoError::CommonError(
+                CommonError::InvalidStructure(format!("Key derivation isn't supported for crypto: {}", crypto_type_name))));
+        }
+
+        let master_seed = self.convert_seed(Some(master_seed))?.unwrap_or(Vec::new());
+        let seed = hd::derive_ed25519_seed(&master_seed, path)?;
+
+        Ok(base64::encode(&seed))
+    }
+
+    /// Exports verkey as JWK (only for ed25519 keys), multibase (base58btc of multicodec
+    /// prefixed key as used by did:key) or hex of raw key bytes.
+    pub fn export_verkey(&self, vk: &str, format: &str) -> Result<String, CryptoError> {
+        self.validate_key(vk)?;
+
+        let (vk, crypto_type_name) = if vk.contains(":") {
+            let splits: Vec<&str> = vk.split(":").collect();
+            (splits[0], splits[1])
+        } else {
+            (vk, DEFAULT_CRYPTO_TYPE)
+        };
+
+        let vk = Base58::decode(vk)?;
+
+        let res = match format {
+            VERKEY_FORMAT_JWK if crypto_type_name == DEFAULT_CRYPTO_TYPE =>
+                json!({
+                    "kty": "OKP",
+                    "crv": "Ed25519",
+                    "x": base64::encode_config(&vk, base64::URL_SAFE_NO_PAD),
+                }).to_string(),
+            VERKEY_FORMAT_JWK =>
+                return Err(CryptoError::CommonError(
+                    CommonError::InvalidStructure(format!("JWK export isn't supported for crypto: {}", crypto_type_name)))),
+            VERKEY_FORMAT_MULTIBASE => {
+                let prefix = match crypto_type_name {
+                    DEFAULT_CRYPTO_TYPE => ED25519_PUB_MULTICODEC,
+                    #[cfg(feature = "secp256k1_libsecp256k1")]
+                    SECP256K1_CRYPTO_TYPE => SECP256K1_PUB_MULTICODEC,
+                    _ => return Err(CryptoError::CommonError(
+                        CommonError::InvalidStructure(format!("Multibase export isn't supported for crypto: {}", crypto_type_name))))
+                };
+
+                format!("z{}", Base58::encode(&[prefix, &vk[..]].concat()))
+            }
+            VERKEY_FORMAT_HEX => vk.to_hex(),
+            _ => return Err(CryptoError::CommonError(
+                CommonError::InvalidStructure(format!("Unknown verkey export format: {}", format))))
+        };
+
+        Ok(res)
+    }
+
+    /// Imports ed25519 key from JWK with secret key: {"kty": "OKP", "crv": "Ed25519", "d": ..., "x": ...}
+    pub fn import_jwk(&self, jwk: &str) -> Result<Key, CryptoError> {
+        let jwk: serde_json::Value = serde_json::from_str(jwk)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid JWK json: {}", err)))?;
+
+        if jwk["kty"].as_str() != Some("OKP") || jwk["crv"].as_str() != Some("Ed25519") {
+            return Err(CryptoError::CommonError(
+                CommonError::InvalidStructure(format!("Only Ed25519 keys can be imported from JWK"))));
+        }
+
+        let seed = jwk["d"].as_str()
+            .ok_or(CommonError::InvalidStructure(format!("JWK doesn't contain secret key")))?;
+
+        let seed = base64::decode_config(seed, base64::URL_SAFE_NO_PAD)
+            .map_err(|err| CommonError::InvalidStructure(format!("Can't decode JWK secret key from base64url: {:?}", err)))?;
+
+        if seed.len() != 32 {
+            return Err(CryptoError::CommonError(CommonError::InvalidStructure(format!("Invalid JWK secret key"))));
+        }
+
+        let crypto_type = self.crypto_types.get(DEFAULT_CRYPTO_TYPE).unwrap();
+        let (vk, sk) = crypto_type.create_key(Some(&seed))?;
+
+        if let Some(x) = jwk["x"].as_str() {
+            let x = base64::decode_config(x, base64::URL_SAFE_NO_PAD)
+                .map_err(|err| CommonError::InvalidStructure(format!("Can't decode JWK public key from base64url: {:?}", err)))?;
+
+            if x != vk {
+                return Err(CryptoError::CommonError(
+                    CommonError::InvalidStructure(format!("JWK public key doesn't match secret key"))));
+            }
+        }
+
+        Ok(Key::new(Base58::encode(&vk), Base58::encode(&sk)))
+    }
+
     pub fn validate_key(&self, vk: &str) -> Result<(), CryptoError> {
         let (vk, crypto_type_name) = if vk.contains(":") {
             let splits: Vec<&str> = vk.split(":").collect();
@@ -387,6 +558,34 @@ mod tests {
         assert_ne!(did_with_seed.verkey, did_without_seed.verkey)
     }
 
+    #[test]
+    fn create_key_works_for_default_crypto_type() {
+        let service = CryptoService::new();
+        let key_info = KeyInfo::new(None, Some(DEFAULT_CRYPTO_TYPE.to_string()));
+        let key = service.create_key(&key_info).unwrap();
+        assert!(!key.verkey.contains(":"));
+    }
+
+    #[test]
+    #[cfg(feature = "secp256k1_libsecp256k1")]
+    fn create_key_works_for_secp256k1_crypto_type() {
+        let service = CryptoService::new();
+        let key_info = KeyInfo::new(None, Some(SECP256K1_CRYPTO_TYPE.to_string()));
+        let key = service.create_key(&key_info).unwrap();
+        assert!(key.verkey.ends_with(":secp256k1"));
+        service.validate_key(&key.verkey).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "secp256k1_libsecp256k1")]
+    fn create_my_did_works_for_secp256k1_crypto_type() {
+        let service = CryptoService::new();
+        let did_info = MyDidInfo::new(None, None, Some(SECP256K1_CRYPTO_TYPE.to_string()), None);
+        let (my_did, my_key) = service.create_my_did(&did_info).unwrap();
+        assert!(my_did.verkey.ends_with(":secp256k1"));
+        assert_eq!(my_did.verkey, my_key.verkey);
+    }
+
     #[test]
     fn create_their_did_works_without_verkey() {
         let service = CryptoService::new();
@@ -578,4 +777,138 @@ mod tests {
         let decrypted_message = service.decrypt_sealed(&key, &encrypted_message).unwrap();
         assert_eq!(msg, decrypted_message.as_slice());
     }
+    #[test]
+    #[cfg(feature = "secp256k1_libsecp256k1")]
+    fn sign_verify_works_for_secp256k1_crypto_type() {
+        let service = CryptoService::new();
+        let did_info = MyDidInfo::new(None, None, Some(SECP256K1_CRYPTO_TYPE.to_string()), None);
+        let message = r#"message"#;
+        let (my_did, my_key) = service.create_my_did(&did_info).unwrap();
+        let signature = service.sign(&my_key, message.as_bytes()).unwrap();
+        assert!(service.verify(&my_did.verkey, message.as_bytes(), &signature).unwrap());
+        assert!(!service.verify(&my_did.verkey, "other message".as_bytes(), &signature).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "secp256k1_libsecp256k1")]
+    fn encrypt_decrypt_works_for_secp256k1_crypto_type() {
+        let service = CryptoService::new();
+        let msg = "some message";
+        let did_info = MyDidInfo::new(None, None, Some(SECP256K1_CRYPTO_TYPE.to_string()), None);
+        let (my_did, my_key) = service.create_my_did(&did_info).unwrap();
+        let (their_did, their_key) = service.create_my_did(&did_info.clone()).unwrap();
+
+        let (encrypted_message, nonce) = service.encrypt(&my_key, &their_did.verkey, msg.as_bytes()).unwrap();
+        let decrypted_message = service.decrypt(&their_key, &my_did.verkey, &encrypted_message, &nonce).unwrap();
+
+        assert_eq!(msg.as_bytes().to_vec(), decrypted_message);
+    }
+
+    #[test]
+    #[cfg(feature = "secp256k1_libsecp256k1")]
+    fn encrypt_not_works_for_incompatible_crypto_types() {
+        let service = CryptoService::new();
+        let msg = "some message";
+        let (_, my_key) = service.create_my_did(&MyDidInfo::new(None, None, Some(SECP256K1_CRYPTO_TYPE.to_string()), None)).unwrap();
+        let (their_did, _) = service.create_my_did(&MyDidInfo::new(None, None, None, None)).unwrap();
+
+        assert!(service.encrypt(&my_key, &their_did.verkey, msg.as_bytes()).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "secp256k1_libsecp256k1")]
+    fn encrypt_decrypt_sealed_works_for_secp256k1_crypto_type() {
+        let service = CryptoService::new();
+        let msg = "some message".as_bytes();
+        let did_info = MyDidInfo::new(None, None, Some(SECP256K1_CRYPTO_TYPE.to_string()), None);
+        let (did, key) = service.create_my_did(&did_info).unwrap();
+        let encrypted_message = service.encrypt_sealed(&did.verkey, msg).unwrap();
+        let decrypted_message = service.decrypt_sealed(&key, &encrypted_message).unwrap();
+        assert_eq!(msg, decrypted_message.as_slice());
+    }
+
+    #[test]
+    fn derive_seed_works() {
+        let service = CryptoService::new();
+        let master_seed = "0000000000000000000000000Master1";
+
+        let seed = service.derive_seed(master_seed, None, &DerivationPath::parse("m/0'").unwrap()).unwrap();
+        let same_seed = service.derive_seed(master_seed, None, &DerivationPath::parse("m/0'").unwrap()).unwrap();
+        let other_seed = service.derive_seed(master_seed, None, &DerivationPath::parse("m/1'").unwrap()).unwrap();
+
+        assert_eq!(seed, same_seed);
+        assert_ne!(seed, other_seed);
+
+        let key = service.create_key(&KeyInfo::new(Some(seed.clone()), None)).unwrap();
+        let same_key = service.create_key(&KeyInfo::new(Some(same_seed), None)).unwrap();
+        assert_eq!(key.verkey, same_key.verkey);
+    }
+
+    #[test]
+    fn derive_seed_not_works_for_unsupported_crypto_type() {
+        let service = CryptoService::new();
+        let path = DerivationPath::parse("m/0'").unwrap();
+
+        assert!(service.derive_seed("0000000000000000000000000Master1", Some("secp256k1"), &path).is_err());
+    }
+
+    #[test]
+    fn export_verkey_works() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo::new(Some("00000000000000000000000000000My1".to_string()), None)).unwrap();
+        let vk = Base58::decode(&key.verkey).unwrap();
+
+        let jwk = service.export_verkey(&key.verkey, VERKEY_FORMAT_JWK).unwrap();
+        let jwk: serde_json::Value = serde_json::from_str(&jwk).unwrap();
+        assert_eq!("OKP", jwk["kty"].as_str().unwrap());
+        assert_eq!("Ed25519", jwk["crv"].as_str().unwrap());
+        assert_eq!(vk, base64::decode_config(jwk["x"].as_str().unwrap(), base64::URL_SAFE_NO_PAD).unwrap());
+
+        let multibase = service.export_verkey(&key.verkey, VERKEY_FORMAT_MULTIBASE).unwrap();
+        assert!(multibase.starts_with("z6Mk"));
+
+        let hex = service.export_verkey(&key.verkey, VERKEY_FORMAT_HEX).unwrap();
+        assert_eq!(vk.to_hex(), hex);
+    }
+
+    #[test]
+    fn export_verkey_not_works_for_unknown_format() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo::new(None, None)).unwrap();
+
+        assert!(service.export_verkey(&key.verkey, "pem").is_err());
+    }
+
+    #[test]
+    fn import_jwk_works() {
+        let service = CryptoService::new();
+        let seed = "00000000000000000000000000000My1";
+        let key = service.create_key(&KeyInfo::new(Some(seed.to_string()), None)).unwrap();
+
+        let jwk = json!({
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "d": base64::encode_config(seed.as_bytes(), base64::URL_SAFE_NO_PAD),
+            "x": base64::encode_config(&Base58::decode(&key.verkey).unwrap(), base64::URL_SAFE_NO_PAD),
+        }).to_string();
+
+        let imported_key = service.import_jwk(&jwk).unwrap();
+        assert_eq!(key.verkey, imported_key.verkey);
+        assert_eq!(key.signkey, imported_key.signkey);
+    }
+
+    #[test]
+    fn import_jwk_not_works_for_mismatched_public_key() {
+        let service = CryptoService::new();
+        let other_key = service.create_key(&KeyInfo::new(None, None)).unwrap();
+
+        let jwk = json!({
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "d": base64::encode_config("00000000000000000000000000000My1".as_bytes(), base64::URL_SAFE_NO_PAD),
+            "x": base64::encode_config(&Base58::decode(&other_key.verkey).unwrap(), base64::URL_SAFE_NO_PAD),
+        }).to_string();
+
+        assert!(service.import_jwk(&jwk).is_err());
+    }
 }
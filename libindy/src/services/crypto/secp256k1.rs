@@ -0,0 +1,83 @@
+use super::CryptoType;
+use utils::crypto::secp256k1::Secp256k1;
+use utils::crypto::xsalsa20::XSalsa20;
+use errors::common::CommonError;
+
+pub const SECP256K1_CRYPTO_TYPE: &'static str = "secp256k1";
+
+const PUBLIC_KEY_LEN: usize = 33;
+const NONCE_LEN: usize = 24;
+
+/// Keys are 32 bytes secret scalars and 33 bytes compressed points, signatures are compact
+/// 64 bytes ECDSA signatures over sha256 of the document.
+/// Encryption uses ECDH shared secret as xsalsa20-poly1305 key. Sealed box is
+/// ephemeral public key (33 bytes) || nonce (24 bytes) || ciphertext.
+pub struct Secp256k1CryptoType {}
+
+impl Secp256k1CryptoType {
+    pub fn new() -> Secp256k1CryptoType {
+        Secp256k1CryptoType {}
+    }
+}
+
+impl CryptoType for Secp256k1CryptoType {
+    fn encrypt(&self, sk: &[u8], vk: &[u8], doc: &[u8], nonce: &[u8]) -> Result<Vec<u8>, CommonError> {
+        if nonce.len() != NONCE_LEN {
+            return Err(CommonError::InvalidStructure(format!("Invalid nonce")));
+        }
+
+        let key = Secp256k1::shared_secret(sk, vk)?;
+        Ok(XSalsa20::new().encrypt(&key, nonce, doc))
+    }
+
+    fn decrypt(&self, sk: &[u8], vk: &[u8], doc: &[u8], nonce: &[u8]) -> Result<Vec<u8>, CommonError> {
+        if nonce.len() != NONCE_LEN {
+            return Err(CommonError::InvalidStructure(format!("Invalid nonce")));
+        }
+
+        let key = Secp256k1::shared_secret(sk, vk)?;
+        XSalsa20::new().decrypt(&key, nonce, doc)
+    }
+
+    fn gen_nonce(&self) -> Vec<u8> {
+        XSalsa20::new().gen_nonce()
+    }
+
+    fn create_key(&self, seed: Option<&[u8]>) -> Result<(Vec<u8>, Vec<u8>), CommonError> {
+        Secp256k1::create_key_pair(seed)
+    }
+
+    fn validate_key(&self, vk: &[u8]) -> Result<(), CommonError> {
+        Secp256k1::validate_public_key(vk)
+    }
+
+    fn sign(&self, sk: &[u8], doc: &[u8]) -> Result<Vec<u8>, CommonError> {
+        Secp256k1::sign(sk, doc)
+    }
+
+    fn verify(&self, vk: &[u8], doc: &[u8], signature: &[u8]) -> Result<bool, CommonError> {
+        Secp256k1::verify(vk, doc, signature)
+    }
+
+    fn encrypt_sealed(&self, vk: &[u8], doc: &[u8]) -> Result<Vec<u8>, CommonError> {
+        let (ephemeral_vk, ephemeral_sk) = Secp256k1::create_key_pair(None)?;
+        let nonce = self.gen_nonce();
+
+        let mut res = ephemeral_vk;
+        res.extend_from_slice(&nonce);
+        res.extend_from_slice(&self.encrypt(&ephemeral_sk, vk, doc, &nonce)?);
+
+        Ok(res)
+    }
+
+    fn decrypt_sealed(&self, _vk: &[u8], sk: &[u8], doc: &[u8]) -> Result<Vec<u8>, CommonError> {
+        if doc.len() < PUBLIC_KEY_LEN + NONCE_LEN {
+            return Err(CommonError::InvalidStructure(format!("Invalid sealed message")));
+        }
+
+        let (ephemeral_vk, doc) = doc.split_at(PUBLIC_KEY_LEN);
+        let (nonce, doc) = doc.split_at(NONCE_LEN);
+
+        self.decrypt(sk, ephemeral_vk, doc, nonce)
+    }
+}
@@ -0,0 +1,348 @@
+extern crate byteorder;
+extern crate digest;
+extern crate sha2;
+
+use errors::common::CommonError;
+use utils::crypto::xsalsa20::XSalsa20;
+
+use self::byteorder::{BigEndian, ByteOrder};
+use self::digest::{FixedOutput, Input};
+
+// Framing of encrypted stream:
+//
+// stream = header frame || chunk frame * || final chunk frame
+// frame = body length (u32, big-endian) || body
+//
+// Header body is stream key (32 bytes) || nonce prefix (16 bytes) encrypted for the recipient
+// by anonymous-encryption scheme (as indy_crypto_anon_crypt does) or by authenticated-encryption
+// scheme (as indy_crypto_auth_crypt does).
+//
+// Chunk body is xsalsa20-poly1305 encryption of tag (1 byte: 0 - chunk, 1 - final chunk) || data
+// under stream key with nonce = nonce prefix || chunk counter (u64, big-endian, starts from 0).
+// Counter in nonce prevents reordering and dropping of chunks and final tag prevents truncation.
+// Data is split on chunks of CHUNK_SIZE bytes, final chunk contains the rest (possibly empty).
+
+pub const CHUNK_SIZE: usize = 64 * 1024;
+pub const MAX_HEADER_LEN: usize = 4 * 1024;
+
+const KEY_LEN: usize = 32;
+const NONCE_PREFIX_LEN: usize = 16;
+const MAC_LEN: usize = 16;
+const LEN_PREFIX_LEN: usize = 4;
+
+const TAG_CHUNK: u8 = 0;
+const TAG_FINAL: u8 = 1;
+
+const SIGNATURE_CONTEXT: &'static [u8] = b"indy:crypto:sign_stream:sha256:";
+
+pub struct EncryptionStream {
+    key: Vec<u8>,
+    nonce_prefix: Vec<u8>,
+    counter: u64,
+    header: Option<Vec<u8>>,
+    buffer: Vec<u8>
+}
+
+impl EncryptionStream {
+    /// Generates stream key and nonce prefix to be encrypted for the recipient as stream header.
+    pub fn gen_key_material() -> Vec<u8> {
+        let xsalsa20 = XSalsa20::new();
+
+        let mut key_material = xsalsa20.create_key();
+        key_material.extend_from_slice(&xsalsa20.gen_nonce()[..NONCE_PREFIX_LEN]);
+        key_material
+    }
+
+    pub fn new(key_material: &[u8], header: &[u8]) -> Result<EncryptionStream, CommonError> {
+        let (key, nonce_prefix) = _split_key_material(key_material)?;
+
+        Ok(EncryptionStream {
+            key,
+            nonce_prefix,
+            counter: 0,
+            header: Some(_frame(header)),
+            buffer: Vec::new()
+        })
+    }
+
+    pub fn update(&mut self, data: &[u8]) -> Vec<u8> {
+        self.buffer.extend_from_slice(data);
+
+        let mut res = self.header.take().unwrap_or(Vec::new());
+
+        while self.buffer.len() > CHUNK_SIZE {
+            let chunk: Vec<u8> = self.buffer.drain(..CHUNK_SIZE).collect();
+            res.extend(self._seal(TAG_CHUNK, &chunk));
+        }
+
+        res
+    }
+
+    pub fn finish(mut self) -> Vec<u8> {
+        let mut res = self.header.take().unwrap_or(Vec::new());
+
+        let chunk = ::std::mem::replace(&mut self.buffer, Vec::new());
+        res.extend(self._seal(TAG_FINAL, &chunk));
+
+        res
+    }
+
+    fn _seal(&mut self, tag: u8, data: &[u8]) -> Vec<u8> {
+        let mut plaintext = Vec::with_capacity(data.len() + 1);
+        plaintext.push(tag);
+        plaintext.extend_from_slice(data);
+
+        let nonce = _chunk_nonce(&self.nonce_prefix, self.counter);
+        self.counter += 1;
+
+        _frame(&XSalsa20::new().encrypt(&self.key, &nonce, &plaintext))
+    }
+}
+
+pub struct DecryptionStream {
+    key: Option<(Vec<u8>, Vec<u8>)>,
+    counter: u64,
+    finished: bool,
+    buffer: Vec<u8>
+}
+
+impl DecryptionStream {
+    pub fn new() -> DecryptionStream {
+        DecryptionStream {
+            key: None,
+            counter: 0,
+            finished: false,
+            buffer: Vec::new()
+        }
+    }
+
+    pub fn push(&mut self, data: &[u8]) -> Result<(), CommonError> {
+        if self.finished {
+            return Err(CommonError::InvalidStructure(format!("Unexpected data after final chunk")));
+        }
+
+        self.buffer.extend_from_slice(data);
+        Ok(())
+    }
+
+    pub fn has_key(&self) -> bool {
+        self.key.is_some()
+    }
+
+    /// Takes stream header from pushed data if it is already complete.
+    pub fn take_header(&mut self) -> Result<Option<Vec<u8>>, CommonError> {
+        self._take_frame(MAX_HEADER_LEN)
+    }
+
+    pub fn set_key_material(&mut self, key_material: &[u8]) -> Result<(), CommonError> {
+        self.key = Some(_split_key_material(key_material)?);
+        Ok(())
+    }
+
+    /// Decrypts all complete chunks from pushed data.
+    pub fn decrypt_chunks(&mut self) -> Result<Vec<u8>, CommonError> {
+        let mut res = Vec::new();
+
+        while !self.finished {
+            let chunk = match self._take_frame(CHUNK_SIZE + 1 + MAC_LEN)? {
+                Some(chunk) => chunk,
+                None => break
+            };
+
+            let plaintext = {
+                let &(ref key, ref nonce_prefix) = self.key.as_ref()
+                    .ok_or(CommonError::InvalidState(format!("Stream header isn't processed yet")))?;
+
+                XSalsa20::new().decrypt(key, &_chunk_nonce(nonce_prefix, self.counter), &chunk)?
+            };
+
+            self.counter += 1;
+
+            match plaintext.first() {
+                Some(&TAG_CHUNK) => {}
+                Some(&TAG_FINAL) => self.finished = true,
+                _ => return Err(CommonError::InvalidStructure(format!("Invalid chunk tag")))
+            }
+
+            res.extend_from_slice(&plaintext[1..]);
+        }
+
+        if self.finished && !self.buffer.is_empty() {
+            return Err(CommonError::InvalidStructure(format!("Unexpected data after final chunk")));
+        }
+
+        Ok(res)
+    }
+
+    pub fn finish(&self) -> Result<(), CommonError> {
+        if !self.finished {
+            return Err(CommonError::InvalidStructure(format!("Stream is truncated")));
+        }
+
+        Ok(())
+    }
+
+    fn _take_frame(&mut self, max_len: usize) -> Result<Option<Vec<u8>>, CommonError> {
+        if self.buffer.len() < LEN_PREFIX_LEN {
+            return Ok(None);
+        }
+
+        let len = BigEndian::read_u32(&self.buffer[..LEN_PREFIX_LEN]) as usize;
+
+        if len > max_len {
+            return Err(CommonError::InvalidStructure(format!("Invalid frame length: {}", len)));
+        }
+
+        if self.buffer.len() < LEN_PREFIX_LEN + len {
+            return Ok(None);
+        }
+
+        let frame: Vec<u8> = self.buffer.drain(..LEN_PREFIX_LEN + len).skip(LEN_PREFIX_LEN).collect();
+        Ok(Some(frame))
+    }
+}
+
+/// Sha256 digest of streamed data. Streamed signature is signature of the digest prefixed with
+/// fixed context tag, so it can't be taken for signature of the digest itself or of other message
+/// signed by the same key.
+pub struct DigestStream {
+    hasher: sha2::Sha256
+}
+
+impl DigestStream {
+    pub fn new() -> DigestStream {
+        DigestStream {
+            hasher: sha2::Sha256::default()
+        }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.hasher.process(data);
+    }
+
+    /// Returns context tag followed by the digest.
+    pub fn finish(self) -> Vec<u8> {
+        let mut res = SIGNATURE_CONTEXT.to_vec();
+        res.extend_from_slice(&self.hasher.fixed_result());
+        res
+    }
+}
+
+fn _split_key_material(key_material: &[u8]) -> Result<(Vec<u8>, Vec<u8>), CommonError> {
+    if key_material.len() != KEY_LEN + NONCE_PREFIX_LEN {
+        return Err(CommonError::InvalidStructure(format!("Invalid stream key")));
+    }
+
+    Ok((key_material[..KEY_LEN].to_vec(), key_material[KEY_LEN..].to_vec()))
+}
+
+fn _chunk_nonce(nonce_prefix: &[u8], counter: u64) -> Vec<u8> {
+    let mut counter_bytes = [0u8; 8];
+    BigEndian::write_u64(&mut counter_bytes, counter);
+
+    let mut nonce = nonce_prefix.to_vec();
+    nonce.extend_from_slice(&counter_bytes);
+    nonce
+}
+
+fn _frame(body: &[u8]) -> Vec<u8> {
+    let mut len = [0u8; LEN_PREFIX_LEN];
+    BigEndian::write_u32(&mut len, body.len() as u32);
+
+    let mut frame = len.to_vec();
+    frame.extend_from_slice(body);
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _streams(header: &[u8]) -> (EncryptionStream, DecryptionStream) {
+        let key_material = EncryptionStream::gen_key_material();
+
+        let encryption_stream = EncryptionStream::new(&key_material, header).unwrap();
+
+        let mut decryption_stream = DecryptionStream::new();
+        decryption_stream.set_key_material(&key_material).unwrap();
+
+        (encryption_stream, decryption_stream)
+    }
+
+    #[test]
+    fn encrypt_decrypt_works() {
+        let header = vec![1, 2, 3];
+        let data: Vec<u8> = (0..(2 * CHUNK_SIZE + 10)).map(|i| i as u8).collect();
+
+        let (mut encryption_stream, mut decryption_stream) = _streams(&header);
+
+        let mut encrypted = encryption_stream.update(&data[..CHUNK_SIZE + 5]);
+        encrypted.extend(encryption_stream.update(&data[CHUNK_SIZE + 5..]));
+        encrypted.extend(encryption_stream.finish());
+
+        decryption_stream.push(&encrypted[..10]).unwrap();
+        assert_eq!(header, decryption_stream.take_header().unwrap().unwrap());
+
+        let mut decrypted = decryption_stream.decrypt_chunks().unwrap();
+
+        for part in encrypted[10..].chunks(1000) {
+            decryption_stream.push(part).unwrap();
+            decrypted.extend(decryption_stream.decrypt_chunks().unwrap());
+        }
+
+        decryption_stream.finish().unwrap();
+        assert_eq!(data, decrypted);
+    }
+
+    #[test]
+    fn decrypt_works_for_truncated_stream() {
+        let (mut encryption_stream, mut decryption_stream) = _streams(&[1]);
+
+        let encrypted = encryption_stream.update(&vec![0; CHUNK_SIZE + 1]);
+
+        decryption_stream.push(&encrypted).unwrap();
+        decryption_stream.take_header().unwrap().unwrap();
+        decryption_stream.decrypt_chunks().unwrap();
+
+        assert!(decryption_stream.finish().is_err());
+    }
+
+    #[test]
+    fn decrypt_works_for_reordered_chunks() {
+        let (mut encryption_stream, mut decryption_stream) = _streams(&[1]);
+
+        let header_and_first = encryption_stream.update(&vec![0; CHUNK_SIZE + 1]);
+        let second = encryption_stream.update(&vec![1; CHUNK_SIZE]);
+
+        let first_offset = LEN_PREFIX_LEN + 1;
+
+        decryption_stream.push(&header_and_first[..first_offset]).unwrap();
+        decryption_stream.take_header().unwrap().unwrap();
+        decryption_stream.push(&second).unwrap();
+
+        assert!(decryption_stream.decrypt_chunks().is_err());
+    }
+
+    #[test]
+    fn digest_stream_works() {
+        let mut digest_stream = DigestStream::new();
+        digest_stream.update("some ".as_bytes());
+        digest_stream.update("message".as_bytes());
+
+        let mut other_digest_stream = DigestStream::new();
+        other_digest_stream.update("some message".as_bytes());
+
+        assert_eq!(digest_stream.finish(), other_digest_stream.finish());
+    }
+
+    #[test]
+    fn digest_stream_works_for_context_tag() {
+        let mut digest_stream = DigestStream::new();
+        digest_stream.update("some message".as_bytes());
+
+        let digest = digest_stream.finish();
+
+        assert_eq!(SIGNATURE_CONTEXT, &digest[..SIGNATURE_CONTEXT.len()]);
+        assert_eq!(SIGNATURE_CONTEXT.len() + 32, digest.len());
+    }
+}
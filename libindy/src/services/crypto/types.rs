@@ -6,14 +6,16 @@ use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
 #[derive(Serialize, Deserialize)]
 pub struct KeyInfo {
     pub seed: Option<String>,
-    pub crypto_type: Option<String>
+    pub crypto_type: Option<String>,
+    pub derivation_path: Option<String>
 }
 
 impl KeyInfo {
     pub fn new(seed: Option<String>, crypto_type: Option<String>) -> KeyInfo {
         KeyInfo {
             seed,
-            crypto_type
+            crypto_type,
+            derivation_path: None
         }
     }
 }
@@ -27,7 +29,8 @@ pub struct MyDidInfo {
     pub did: Option<String>,
     pub seed: Option<String>,
     pub crypto_type: Option<String>,
-    pub cid: Option<bool>
+    pub cid: Option<bool>,
+    pub derivation_path: Option<String>
 }
 
 impl MyDidInfo {
@@ -37,7 +40,8 @@ impl MyDidInfo {
             did,
             seed,
             crypto_type,
-            cid
+            cid,
+            derivation_path: None
         }
     }
 }
@@ -46,6 +50,17 @@ impl JsonEncodable for MyDidInfo {}
 
 impl<'a> JsonDecodable<'a> for MyDidInfo {}
 
+#[derive(Serialize, Deserialize)]
+pub struct RecoveryOptions {
+    pub did_path: Option<String>,
+    pub key_path: Option<String>,
+    pub count: Option<u32>
+}
+
+impl JsonEncodable for RecoveryOptions {}
+
+impl<'a> JsonDecodable<'a> for RecoveryOptions {}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TheirDidInfo {
     pub did: String,
@@ -118,4 +133,55 @@ impl ComboBox {
     pub fn from_msg_pack(bytes: &[u8]) -> Result<ComboBox, rmp_serde::decode::Error> {
         rmp_serde::decode::from_slice(bytes)
     }
-}
\ No newline at end of file
+}
+/// JWE-like envelope of a message packed for one or several recipients.
+/// The message is encrypted once with random content key (xsalsa20-poly1305, `iv` is the nonce,
+/// `ciphertext` includes the authentication tag and authenticates `protected` as associated data)
+/// and the content key is wrapped separately per recipient.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PackedMessage {
+    pub protected: String,
+    pub recipients: Vec<PackedRecipient>,
+    pub iv: String,
+    pub ciphertext: String
+}
+
+impl JsonEncodable for PackedMessage {}
+
+impl<'a> JsonDecodable<'a> for PackedMessage {}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PackedProtectedHeader {
+    pub enc: String,
+    pub typ: String,
+    pub alg: String
+}
+
+impl JsonEncodable for PackedProtectedHeader {}
+
+impl<'a> JsonDecodable<'a> for PackedProtectedHeader {}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PackedRecipient {
+    pub encrypted_key: String,
+    pub header: PackedRecipientHeader
+}
+
+/// `sender` is sender verkey sealed for the recipient and `iv` is the nonce of
+/// authenticated content key encryption. Both are absent for anonymous packing.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PackedRecipientHeader {
+    pub kid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iv: Option<String>
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UnpackedMessageInfo {
+    pub recipient_verkey: String,
+    pub sender_verkey: Option<String>
+}
+
+impl JsonEncodable for UnpackedMessageInfo {}
@@ -10,10 +10,37 @@ pub const GET_SCHEMA: &'static str = "107";
 pub const GET_CLAIM_DEF: &'static str = "108";
 pub const POOL_UPGRADE: &'static str = "109";
 pub const POOL_CONFIG: &'static str = "111";
+pub const REVOC_REG_DEF: &'static str = "113";
+pub const REVOC_REG_ENTRY: &'static str = "114";
+pub const GET_REVOC_REG_DEF: &'static str = "115";
+pub const GET_REVOC_REG: &'static str = "116";
+pub const GET_REVOC_REG_DELTA: &'static str = "117";
+pub const POOL_RESTART: &'static str = "118";
+pub const GET_VALIDATOR_INFO: &'static str = "119";
 pub const GET_DDO: &'static str = "120";//TODO change number
 
+pub const POOL_LEDGER_ID: i32 = 0;
+pub const DOMAIN_LEDGER_ID: i32 = 1;
+pub const CONFIG_LEDGER_ID: i32 = 2;
+
 pub const STEWARD: &str = "2";
 pub const TRUSTEE: &str = "0";
 pub const TRUST_ANCHOR: &str = "101";
 pub const TGB: &str = "100";
 pub const ROLE_REMOVE: &str = "";
+
+pub const CL_ACCUM: &str = "CL_ACCUM";
+pub const ISSUANCE_BY_DEFAULT: &str = "ISSUANCE_BY_DEFAULT";
+pub const ISSUANCE_ON_DEMAND: &str = "ISSUANCE_ON_DEMAND";
+
+pub const DID_DOC_CONTEXT: &str = "https://w3id.org/did/v1";
+pub const DID_METHOD_PREFIX: &str = "did:sov:";
+pub const ED25519_VERIFICATION_KEY: &str = "Ed25519VerificationKey2018";
+pub const ED25519_SIGNATURE_AUTHENTICATION: &str = "Ed25519SignatureAuthentication2018";
+pub const SECP256K1_VERIFICATION_KEY: &str = "EcdsaSecp256k1VerificationKey2019";
+pub const SECP256K1_SIGNATURE_AUTHENTICATION: &str = "EcdsaSecp256k1SignatureAuthentication2019";
+pub const INDY_AGENT_SERVICE: &str = "IndyAgent";
+pub const ENDPOINT_ATTRIB: &str = "endpoint";
+
+pub const SCHEMA_CACHE_PREFIX: &str = "cache::schema::";
+pub const CLAIM_DEF_CACHE_PREFIX: &str = "cache::claim_def::";
@@ -62,21 +62,29 @@ impl MerkleTree {
     pub fn consistency_proof(&self,
                              new_root_hash: &Vec<u8>, new_size: usize,
                              proof: &Vec<Vec<u8>>) -> Result<bool, CommonError> {
-        if self.count == 0 {
+        MerkleTree::check_consistency(self.root_hash(), self.count, new_root_hash, new_size, proof)
+    }
+
+    /// Checks that the tree with `old_root_hash` and `old_size` is a prefix of
+    /// the tree with `new_root_hash` and `new_size`. Unlike `consistency_proof`
+    /// it doesn't require the old tree itself, so it can be used for roots received from the pool.
+    pub fn check_consistency(old_root_hash: &Vec<u8>, old_size: usize,
+                             new_root_hash: &Vec<u8>, new_size: usize,
+                             proof: &Vec<Vec<u8>>) -> Result<bool, CommonError> {
+        if old_size == 0 {
             // empty old tree
             return Ok(true);
         }
-        if self.count == new_size && self.root_hash() == new_root_hash {
+        if old_size == new_size {
             // identical trees
-            return Ok(true);
+            return Ok(old_root_hash == new_root_hash);
         }
-        if self.count > new_size {
+        if old_size > new_size {
             // old tree is bigger!
-            assert!(false);
             return Ok(false);
         }
 
-        let mut old_node = self.count - 1;
+        let mut old_node = old_size - 1;
         let mut new_node = new_size - 1;
 
         while old_node % 2 != 0 {
@@ -89,28 +97,40 @@ impl MerkleTree {
         let mut new_hash: Vec<u8>;
 
         if old_node != 0 {
-            new_hash = proofs.next().unwrap().to_vec();
+            new_hash = match proofs.next() {
+                Some(hash) => hash.to_vec(),
+                None => return Ok(false)
+            };
             old_hash = new_hash.clone();
         } else {
-            new_hash = self.root_hash().to_vec();
+            new_hash = old_root_hash.to_vec();
             old_hash = new_hash.clone();
         }
 
         while old_node != 0 {
             if old_node % 2 != 0 {
-                let next_proof = proofs.next().unwrap();
+                let next_proof = match proofs.next() {
+                    Some(hash) => hash,
+                    None => return Ok(false)
+                };
                 old_hash = Hash::hash_nodes(next_proof, &old_hash)?.to_vec();
                 new_hash = Hash::hash_nodes(next_proof, &new_hash)?.to_vec();
             } else if old_node < new_node {
-                new_hash = Hash::hash_nodes(&new_hash,
-                                             proofs.next().unwrap())?.to_vec();
+                let next_proof = match proofs.next() {
+                    Some(hash) => hash,
+                    None => return Ok(false)
+                };
+                new_hash = Hash::hash_nodes(&new_hash, next_proof)?.to_vec();
             }
             old_node = old_node / 2;
             new_node = new_node / 2;
         }
 
         while new_node != 0 {
-            let n = proofs.next().unwrap();
+            let n = match proofs.next() {
+                Some(hash) => hash,
+                None => return Ok(false)
+            };
             new_hash = Hash::hash_nodes(&new_hash, n)?.to_vec();
             new_node = new_node / 2;
         }
@@ -120,7 +140,7 @@ impl MerkleTree {
             return Ok(false);
         }
 
-        if old_hash != *self.root_hash() {
+        if old_hash != *old_root_hash {
             // old hash differs
             return Ok(false);
         }
@@ -128,6 +148,38 @@ impl MerkleTree {
         return Ok(true);
     }
 
+    pub fn audit_proof(root_hash: &Vec<u8>, tree_size: usize,
+                       leaf_index: usize, leaf: &TreeLeafData,
+                       audit_path: &Vec<Vec<u8>>) -> Result<bool, CommonError> {
+        if leaf_index >= tree_size {
+            return Ok(false);
+        }
+
+        let mut node = leaf_index;
+        let mut last_node = tree_size - 1;
+        let mut hash = Hash::hash_leaf(leaf)?.to_vec();
+
+        for sibling in audit_path {
+            if last_node == 0 {
+                // path is longer than the tree height
+                return Ok(false);
+            }
+            if node % 2 != 0 || node == last_node {
+                hash = Hash::hash_nodes(sibling, &hash)?.to_vec();
+                while node % 2 == 0 && node != 0 {
+                    node = node / 2;
+                    last_node = last_node / 2;
+                }
+            } else {
+                hash = Hash::hash_nodes(&hash, sibling)?.to_vec();
+            }
+            node = node / 2;
+            last_node = last_node / 2;
+        }
+
+        return Ok(last_node == 0 && hash == *root_hash);
+    }
+
     pub fn append(&mut self, node: TreeLeafData) -> Result<(), CommonError> {
         if self.count == 0 {
             // empty tree
@@ -308,6 +360,35 @@ mod tests {
                                      &proofs).unwrap());
     }
 
+    #[test]
+    fn audit_proof_works() {
+        let values: Vec<Vec<u8>> = vec!["1", "2", "3"].iter().map(|x| String::from(*x).as_bytes().to_vec()).collect();
+        let mut mt = MerkleTree::from_vec(vec![]).unwrap();
+        for value in values.iter() {
+            mt.append(value.clone()).unwrap();
+        }
+        let leaf_hashes: Vec<Vec<u8>> = values.iter().map(|x| Hash::hash_leaf(x).unwrap().to_vec()).collect();
+        let first_pair = Hash::hash_nodes(&leaf_hashes[0], &leaf_hashes[1]).unwrap().to_vec();
+
+        assert!(MerkleTree::audit_proof(mt.root_hash(), 3, 0, &values[0], &vec![leaf_hashes[1].clone(), leaf_hashes[2].clone()]).unwrap());
+        assert!(MerkleTree::audit_proof(mt.root_hash(), 3, 1, &values[1], &vec![leaf_hashes[0].clone(), leaf_hashes[2].clone()]).unwrap());
+        assert!(MerkleTree::audit_proof(mt.root_hash(), 3, 2, &values[2], &vec![first_pair]).unwrap());
+    }
+
+    #[test]
+    fn audit_proof_works_for_invalid_proof() {
+        let values: Vec<Vec<u8>> = vec!["1", "2", "3"].iter().map(|x| String::from(*x).as_bytes().to_vec()).collect();
+        let mt = MerkleTree::from_vec(values.clone()).unwrap();
+        let leaf_hashes: Vec<Vec<u8>> = values.iter().map(|x| Hash::hash_leaf(x).unwrap().to_vec()).collect();
+
+        // wrong sibling order
+        assert!(!MerkleTree::audit_proof(mt.root_hash(), 3, 0, &values[0], &vec![leaf_hashes[2].clone(), leaf_hashes[1].clone()]).unwrap());
+        // wrong leaf
+        assert!(!MerkleTree::audit_proof(mt.root_hash(), 3, 0, &values[2], &vec![leaf_hashes[1].clone(), leaf_hashes[2].clone()]).unwrap());
+        // leaf out of tree
+        assert!(!MerkleTree::audit_proof(mt.root_hash(), 3, 3, &values[0], &vec![]).unwrap());
+    }
+
     #[test]
     fn gen_proof_and_proof_validate_work() {
         let strvals   = vec![ "1", "2", "3", "4", "5", "6", "7", "8", "9", "10" ];
@@ -402,4 +483,33 @@ mod tests {
         mt.append(all_values[8 - 1].clone()).unwrap();
         assert!(mt.consistency_proof(&full_root_hash, 8, &proofs_for_8).unwrap());
     }
+
+    #[test]
+    fn check_consistency_works() {
+        let values: Vec<Vec<u8>> = vec!["1", "2", "3", "4", "5", "6", "7", "8"].iter().map(|x| String::from(*x).as_bytes().to_vec()).collect();
+        let old_mt = MerkleTree::from_vec(values[0..4].to_vec()).unwrap();
+        let new_mt = MerkleTree::from_vec(values.clone()).unwrap();
+        let right_mt = MerkleTree::from_vec(values[4..8].to_vec()).unwrap();
+
+        let proof = vec![right_mt.root_hash().clone()];
+
+        assert!(MerkleTree::check_consistency(old_mt.root_hash(), 4, new_mt.root_hash(), 8, &proof).unwrap());
+        assert!(MerkleTree::check_consistency(new_mt.root_hash(), 8, new_mt.root_hash(), 8, &vec![]).unwrap());
+    }
+
+    #[test]
+    fn check_consistency_works_for_invalid_proof() {
+        let values: Vec<Vec<u8>> = vec!["1", "2", "3", "4", "5", "6", "7", "8"].iter().map(|x| String::from(*x).as_bytes().to_vec()).collect();
+        let old_mt = MerkleTree::from_vec(values[0..4].to_vec()).unwrap();
+        let new_mt = MerkleTree::from_vec(values.clone()).unwrap();
+        let forked_mt = MerkleTree::from_vec(vec!["1", "2", "3", "x"].iter().map(|x| String::from(*x).as_bytes().to_vec()).collect()).unwrap();
+        let right_mt = MerkleTree::from_vec(values[4..8].to_vec()).unwrap();
+
+        let proof = vec![right_mt.root_hash().clone()];
+
+        assert!(!MerkleTree::check_consistency(forked_mt.root_hash(), 4, new_mt.root_hash(), 8, &proof).unwrap());
+        assert!(!MerkleTree::check_consistency(old_mt.root_hash(), 4, new_mt.root_hash(), 8, &vec![]).unwrap());
+        assert!(!MerkleTree::check_consistency(new_mt.root_hash(), 8, old_mt.root_hash(), 4, &proof).unwrap());
+        assert!(!MerkleTree::check_consistency(old_mt.root_hash(), 4, forked_mt.root_hash(), 4, &vec![]).unwrap());
+    }
 }
@@ -1,6 +1,8 @@
 extern crate time;
 extern crate serde_json;
 extern crate indy_crypto;
+extern crate rmp_serde;
+extern crate rust_base58;
 
 pub mod merkletree;
 pub mod types;
@@ -10,7 +12,10 @@ use self::types::*;
 use errors::common::CommonError;
 use serde_json::Value;
 use services::ledger::constants::NYM;
-use self::indy_crypto::utils::json::JsonDecodable;
+use utils::crypto::verkey_builder::build_full_verkey;
+use self::merkletree::merkletree::MerkleTree;
+use self::rust_base58::FromBase58;
+use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
 
 use std::collections::HashMap;
 
@@ -149,8 +154,14 @@ impl LedgerService {
             .map_err(|err| CommonError::InvalidState(format!("Invalid node request json: {:?}", err)))
     }
 
-    pub fn build_get_txn_request(&self, identifier: &str, data: i32) -> Result<String, CommonError> {
-        let operation = GetTxnOperation::new(data);
+    pub fn build_get_txn_request(&self, identifier: &str, ledger_id: Option<i32>, data: i32, consistency_from: Option<usize>) -> Result<String, CommonError> {
+        if let Some(ledger_id) = ledger_id {
+            if ledger_id != constants::POOL_LEDGER_ID && ledger_id != constants::DOMAIN_LEDGER_ID && ledger_id != constants::CONFIG_LEDGER_ID {
+                return Err(CommonError::InvalidStructure(format!("Invalid ledger id: {}", ledger_id)));
+            }
+        }
+
+        let operation = GetTxnOperation::new(data, ledger_id, consistency_from);
         Request::build_request(identifier.to_string(), operation)
             .map_err(|err| CommonError::InvalidState(format!("Invalid get txn request json: {:?}", err)))
     }
@@ -182,6 +193,394 @@ impl LedgerService {
             .map_err(|err| CommonError::InvalidState(format!("Invalid pool_upgrade request json: {:?}", err)))
     }
 
+    pub fn build_pool_restart(&self, identifier: &str, action: &str, datetime: Option<&str>) -> Result<String, CommonError> {
+        if action != "start" && action != "cancel" {
+            return Err(CommonError::InvalidStructure(format!("Invalid action: {}", action)));
+        }
+
+        let operation = PoolRestartOperation::new(action, datetime);
+        Request::build_request(identifier.to_string(), operation)
+            .map_err(|err| CommonError::InvalidState(format!("Invalid pool_restart request json: {:?}", err)))
+    }
+
+    pub fn build_get_validator_info_request(&self, identifier: &str) -> Result<String, CommonError> {
+        let operation = GetValidatorInfoOperation::new();
+        Request::build_request(identifier.to_string(), operation)
+            .map_err(|err| CommonError::InvalidState(format!("Invalid get_validator_info request json: {:?}", err)))
+    }
+
+    pub fn validate_action(&self, request: &str) -> Result<(), CommonError> {
+        let request: Value = serde_json::from_str(request)
+            .map_err(|err| CommonError::InvalidStructure(format!("Request is invalid json: {:?}", err)))?;
+
+        match request["operation"]["type"].as_str() {
+            Some(constants::POOL_RESTART) | Some(constants::GET_VALIDATOR_INFO) => Ok(()),
+            Some(_) => Err(CommonError::InvalidStructure("Request does not match any type of Actions: POOL_RESTART, GET_VALIDATOR_INFO".to_string())),
+            None => Err(CommonError::InvalidStructure("No valid type field in request".to_string()))
+        }
+    }
+
+    pub fn build_revoc_reg_def_request(&self, identifier: &str, data: &str) -> Result<String, CommonError> {
+        let data = RevocRegDefData::from_json(data)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid revocation registry definition json: {:?}", err)))?;
+
+        if data.revoc_def_type != constants::CL_ACCUM {
+            return Err(CommonError::InvalidStructure(format!("Unsupported revocation registry type: {}", data.revoc_def_type)));
+        }
+
+        if data.value.issuance_type != constants::ISSUANCE_BY_DEFAULT && data.value.issuance_type != constants::ISSUANCE_ON_DEMAND {
+            return Err(CommonError::InvalidStructure(format!("Invalid issuance type: {}", data.value.issuance_type)));
+        }
+
+        let operation = RevocRegDefOperation::new(data);
+        Request::build_request(identifier.to_string(), operation)
+            .map_err(|err| CommonError::InvalidState(format!("Invalid revoc_reg_def request json: {:?}", err)))
+    }
+
+    pub fn build_revoc_reg_entry_request(&self, identifier: &str, revoc_reg_def_id: &str, revoc_def_type: &str, value: &str) -> Result<String, CommonError> {
+        let value = RevocRegEntryValue::from_json(value)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid revocation registry entry json: {:?}", err)))?;
+
+        if revoc_def_type != constants::CL_ACCUM {
+            return Err(CommonError::InvalidStructure(format!("Unsupported revocation registry type: {}", revoc_def_type)));
+        }
+
+        let operation = RevocRegEntryOperation::new(revoc_reg_def_id.to_string(), revoc_def_type.to_string(), value);
+        Request::build_request(identifier.to_string(), operation)
+            .map_err(|err| CommonError::InvalidState(format!("Invalid revoc_reg_entry request json: {:?}", err)))
+    }
+
+    pub fn build_get_revoc_reg_def_request(&self, identifier: &str, id: &str) -> Result<String, CommonError> {
+        let operation = GetRevocRegDefOperation::new(id.to_string());
+        Request::build_request(identifier.to_string(), operation)
+            .map_err(|err| CommonError::InvalidState(format!("Invalid get_revoc_reg_def request json: {:?}", err)))
+    }
+
+    pub fn build_get_revoc_reg_request(&self, identifier: &str, revoc_reg_def_id: &str, timestamp: i64) -> Result<String, CommonError> {
+        let operation = GetRevocRegOperation::new(revoc_reg_def_id.to_string(), timestamp);
+        Request::build_request(identifier.to_string(), operation)
+            .map_err(|err| CommonError::InvalidState(format!("Invalid get_revoc_reg request json: {:?}", err)))
+    }
+
+    pub fn build_get_revoc_reg_delta_request(&self, identifier: &str, revoc_reg_def_id: &str, from: Option<i64>, to: i64) -> Result<String, CommonError> {
+        if let Some(from) = from {
+            if from > to {
+                return Err(CommonError::InvalidStructure(format!("Invalid delta interval: from {} is after to {}", from, to)));
+            }
+        }
+
+        let operation = GetRevocRegDeltaOperation::new(revoc_reg_def_id.to_string(), from, to);
+        Request::build_request(identifier.to_string(), operation)
+            .map_err(|err| CommonError::InvalidState(format!("Invalid get_revoc_reg_delta request json: {:?}", err)))
+    }
+
+    /// Parses GET_TXN reply and verifies the audit proof of the transaction against the ledger root.
+    ///
+    /// The root must be covered by the pool BLS multi-signature returned in the reply state proof
+    /// (the signature itself is checked by the pool before the reply is delivered).
+    /// If `trusted_root` (root hash and size of the last verified ledger root) is passed,
+    /// the new root must be the same or the reply must contain a consistency proof from the trusted one.
+    pub fn parse_get_txn_response_with_proof(&self, response: &str, ledger_id: i32, seq_no: i32,
+                                             trusted_root: Option<(&str, usize)>) -> Result<Option<LedgerTxn>, CommonError> {
+        let response: Reply<GetTxnReplyResult> = Reply::from_json(response)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid GetTxnReplyResult json: {:?}", err)))?;
+
+        let mut txn = match response.result.data {
+            Some(Value::Object(txn)) => txn,
+            Some(Value::Null) | None => return Ok(None),
+            Some(_) => return Err(CommonError::InvalidStructure(format!("Invalid transaction in GET_TXN reply")))
+        };
+
+        // Audit proof is returned by the node next to the transaction fields
+        let (audit_path, root_hash, ledger_size) = match (txn.remove("auditPath"), txn.remove("rootHash"), txn.remove("ledgerSize")) {
+            (Some(Value::Array(audit_path)), Some(Value::String(root_hash)), Some(ledger_size)) => {
+                let ledger_size = ledger_size.as_u64()
+                    .ok_or(CommonError::InvalidStructure(format!("Invalid ledger size in GET_TXN reply")))?;
+                (audit_path, root_hash, ledger_size as usize)
+            }
+            _ => return Err(CommonError::InvalidStructure(format!("No audit proof in GET_TXN reply for transaction {}", seq_no)))
+        };
+
+        let consistency_proof = txn.remove("consistencyProof");
+
+        let signed_value = response.result.state_proof.as_ref()
+            .map(|state_proof| &state_proof["multi_signature"]["value"]);
+
+        let root_signed = match signed_value {
+            Some(value) => value["txn_root_hash"].as_str() == Some(root_hash.as_str())
+                && value["ledger_id"].as_i64() == Some(ledger_id as i64),
+            None => false
+        };
+
+        if !root_signed {
+            return Err(CommonError::InvalidStructure(format!("Ledger root in GET_TXN reply for transaction {} isn't covered by the pool multi-signature", seq_no)));
+        }
+
+        let audit_path = LedgerService::_parse_hashes(&audit_path)?;
+
+        let root_hash_bytes = root_hash.from_base58()
+            .map_err(|err| CommonError::InvalidStructure(format!("Can't decode ledger root hash: {}", err)))?;
+
+        if let Some((trusted_root_hash, trusted_ledger_size)) = trusted_root {
+            let trusted_root_hash = trusted_root_hash.from_base58()
+                .map_err(|err| CommonError::InvalidState(format!("Can't decode trusted ledger root hash: {}", err)))?;
+
+            let consistency_proof = match consistency_proof {
+                Some(Value::Array(consistency_proof)) => LedgerService::_parse_hashes(&consistency_proof)?,
+                _ => Vec::new()
+            };
+
+            if !MerkleTree::check_consistency(&trusted_root_hash, trusted_ledger_size, &root_hash_bytes, ledger_size, &consistency_proof)? {
+                return Err(CommonError::InvalidStructure(format!("Ledger root in GET_TXN reply for transaction {} isn't consistent with the previously verified one", seq_no)));
+            }
+        }
+
+        let txn = Value::Object(txn);
+
+        let leaf = rmp_serde::to_vec_named(&txn)
+            .map_err(|err| CommonError::InvalidState(format!("Can't serialize transaction: {:?}", err)))?;
+
+        if seq_no < 1 || !MerkleTree::audit_proof(&root_hash_bytes, ledger_size, (seq_no - 1) as usize, &leaf, &audit_path)? {
+            return Err(CommonError::InvalidStructure(format!("Audit proof verification failed for transaction {}", seq_no)));
+        }
+
+        Ok(Some(LedgerTxn {
+            seq_no,
+            ledger_id,
+            txn,
+            root_hash,
+            ledger_size
+        }))
+    }
+
+    fn _parse_hashes(hashes: &Vec<Value>) -> Result<Vec<Vec<u8>>, CommonError> {
+        hashes.iter()
+            .map(|hash| hash.as_str()
+                .ok_or(CommonError::InvalidStructure(format!("Invalid hash in GET_TXN reply")))
+                .and_then(|hash| hash.from_base58()
+                    .map_err(|err| CommonError::InvalidStructure(format!("Can't decode hash in GET_TXN reply: {}", err)))))
+            .collect()
+    }
+
+    /// Converts GET_SCHEMA reply to schema json used by anoncreds.
+    pub fn parse_get_schema_response(&self, response: &str) -> Result<String, CommonError> {
+        let response: Reply<GetSchemaReplyResult> = Reply::from_json(response)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid GetSchemaReplyResult json: {:?}", err)))?;
+
+        let (seq_no, data) = match (response.result.seq_no, response.result.data) {
+            (Some(seq_no), Some(data)) => (seq_no, data),
+            _ => return Err(CommonError::InvalidState(format!("Schema not found")))
+        };
+
+        let attr_names = data.attr_names
+            .ok_or(CommonError::InvalidState(format!("Schema not found")))?;
+
+        let schema = json!({
+            "seqNo": seq_no,
+            "dest": response.result.dest,
+            "data": {
+                "name": data.name,
+                "version": data.version,
+                "attr_names": attr_names
+            }
+        });
+
+        Ok(schema.to_string())
+    }
+
+    /// Converts GET_CLAIM_DEF reply to claim definition json used by anoncreds.
+    pub fn parse_get_claim_def_response(&self, response: &str) -> Result<String, CommonError> {
+        let response: Reply<GetClaimDefReplyResult> = Reply::from_json(response)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid GetClaimDefReplyResult json: {:?}", err)))?;
+
+        let data = match response.result.data {
+            Some(Value::Null) | None => return Err(CommonError::InvalidState(format!("Claim definition not found"))),
+            Some(data) => data
+        };
+
+        let claim_def = json!({
+            "ref": response.result._ref,
+            "origin": response.result.origin,
+            "signature_type": response.result.signature_type,
+            "data": data
+        });
+
+        Ok(claim_def.to_string())
+    }
+
+    pub fn parse_get_revoc_reg_def_response(&self, response: &str) -> Result<(String, String), CommonError> {
+        let response: Reply<GetRevocRegDefReplyResult> = Reply::from_json(response)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid GetRevocRegDefReplyResult json: {:?}", err)))?;
+
+        let data = response.result.data
+            .ok_or(CommonError::InvalidState(format!("Revocation registry definition not found")))?;
+
+        let data_json = data.to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Can't serialize revocation registry definition: {:?}", err)))?;
+
+        Ok((data.id, data_json))
+    }
+
+    pub fn parse_get_revoc_reg_response(&self, response: &str) -> Result<(String, String, u64), CommonError> {
+        let response: Reply<GetRevocRegReplyResult> = Reply::from_json(response)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid GetRevocRegReplyResult json: {:?}", err)))?;
+
+        let data = response.result.data
+            .ok_or(CommonError::InvalidState(format!("Revocation registry not found")))?;
+
+        let timestamp = response.result.txn_time
+            .ok_or(CommonError::InvalidStructure(format!("Revocation registry reply doesn't contain txnTime")))?;
+
+        let value_json = data.value.to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Can't serialize revocation registry: {:?}", err)))?;
+
+        Ok((data.revoc_reg_def_id, value_json, timestamp))
+    }
+
+    pub fn parse_get_revoc_reg_delta_response(&self, response: &str) -> Result<(String, String, u64), CommonError> {
+        let response: Reply<GetRevocRegDeltaReplyResult> = Reply::from_json(response)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid GetRevocRegDeltaReplyResult json: {:?}", err)))?;
+
+        let data = response.result.data
+            .ok_or(CommonError::InvalidState(format!("Revocation registry delta not found")))?;
+
+        let delta = RevocRegDelta {
+            prev_accum: data.value.accum_from.map(|accum_from| accum_from.value.accum),
+            accum: data.value.accum_to.value.accum,
+            issued: data.value.issued,
+            revoked: data.value.revoked
+        };
+
+        let delta_json = delta.to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Can't serialize revocation registry delta: {:?}", err)))?;
+
+        Ok((data.revoc_reg_def_id, delta_json, data.value.accum_to.txn_time))
+    }
+
+    pub fn build_did_document(&self, did: &str, nym_data: &GetNymResultData, attribs: &Vec<(String, String)>) -> Result<DidDocument, CommonError> {
+        let verkey = build_full_verkey(&nym_data.dest, nym_data.verkey.as_ref().map(String::as_str))?;
+
+        // Strip the optional crypto type suffix, DID Document keys carry the type separately
+        let (verkey, key_type, authentication_type) = {
+            let mut splits = verkey.splitn(2, ":");
+            let verkey = splits.next().unwrap_or("").to_string();
+
+            let (key_type, authentication_type) = match splits.next() {
+                None | Some("ed25519") => (constants::ED25519_VERIFICATION_KEY, constants::ED25519_SIGNATURE_AUTHENTICATION),
+                Some("secp256k1") => (constants::SECP256K1_VERIFICATION_KEY, constants::SECP256K1_SIGNATURE_AUTHENTICATION),
+                Some(crypto_type) => return Err(CommonError::InvalidStructure(format!("Unsupported verkey crypto type: {}", crypto_type)))
+            };
+
+            (verkey, key_type, authentication_type)
+        };
+
+        let id = format!("{}{}", constants::DID_METHOD_PREFIX, did);
+        let key_id = format!("{}#key-1", id);
+
+        let public_key = vec![DidDocPublicKey {
+            id: key_id.clone(),
+            _type: key_type.to_string(),
+            owner: id.clone(),
+            public_key_base58: verkey.clone()
+        }];
+
+        let authentication = vec![DidDocAuthentication {
+            _type: authentication_type.to_string(),
+            public_key: key_id
+        }];
+
+        let mut service: Vec<DidDocService> = Vec::new();
+
+        for &(ref name, ref data) in attribs {
+            let data: Value = serde_json::from_str(data)
+                .map_err(|err| CommonError::InvalidStructure(format!("Invalid {} attrib data: {:?}", name, err)))?;
+
+            if name == constants::ENDPOINT_ATTRIB {
+                let endpoint: Endpoint = serde_json::from_value(data[name].clone())
+                    .map_err(|err| CommonError::InvalidStructure(format!("Invalid endpoint attrib data: {:?}", err)))?;
+
+                let recipient_key = match endpoint.verkey {
+                    Some(ref endpoint_verkey) => build_full_verkey(did, Some(endpoint_verkey))?,
+                    None => verkey.clone()
+                };
+
+                service.push(DidDocService {
+                    id: format!("{};indy", id),
+                    _type: constants::INDY_AGENT_SERVICE.to_string(),
+                    service_endpoint: endpoint.ha,
+                    recipient_keys: Some(vec![recipient_key])
+                });
+            } else if let (Some(_type), Some(service_endpoint)) = (data[name]["type"].as_str(), data[name]["serviceEndpoint"].as_str()) {
+                // Only attribs that carry service description are services, the attrib name is just an id fragment
+                service.push(DidDocService {
+                    id: format!("{};{}", id, name),
+                    _type: _type.to_string(),
+                    service_endpoint: service_endpoint.to_string(),
+                    recipient_keys: None
+                });
+            } else {
+                warn!("build_did_document: skip {} attrib that doesn't contain service description", name);
+            }
+        }
+
+        Ok(DidDocument {
+            context: constants::DID_DOC_CONTEXT.to_string(),
+            id,
+            public_key,
+            authentication,
+            service
+        })
+    }
+
+    pub fn get_schema_cache_key(&self, dest: &str, data: &str) -> Result<String, CommonError> {
+        let data = GetSchemaOperationData::from_json(data)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid data json: {:?}", err)))?;
+        Ok(format!("{}{}:{}:{}", constants::SCHEMA_CACHE_PREFIX, dest, data.name, data.version))
+    }
+
+    pub fn get_claim_def_cache_key(&self, _ref: i32, signature_type: &str, origin: &str) -> String {
+        format!("{}{}:{}:{}", constants::CLAIM_DEF_CACHE_PREFIX, origin, _ref, signature_type)
+    }
+
+    pub fn build_cache_record(&self, reply: &str) -> Result<Option<String>, CommonError> {
+        let reply_json: Value = serde_json::from_str(reply)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid reply json: {:?}", err)))?;
+
+        // Only successful replies for existing ledger objects are cached
+        if reply_json["op"].as_str() != Some("REPLY") || reply_json["result"]["data"].is_null() {
+            return Ok(None);
+        }
+
+        let record = LedgerCacheRecord {
+            reply: reply.to_string(),
+            time_fetched: time::get_time().sec
+        };
+
+        record.to_json()
+            .map(Some)
+            .map_err(|err| CommonError::InvalidState(format!("Can't serialize LedgerCacheRecord: {:?}", err)))
+    }
+
+    pub fn get_cached_reply(&self, record: &str, min_fresh: Option<i64>) -> Result<Option<String>, CommonError> {
+        let record = LedgerCacheRecord::from_json(record)
+            .map_err(|err| CommonError::InvalidState(format!("Invalid LedgerCacheRecord json: {:?}", err)))?;
+
+        match min_fresh {
+            Some(min_fresh) if time::get_time().sec - record.time_fetched > min_fresh => Ok(None),
+            _ => Ok(Some(record.reply))
+        }
+    }
+
+    pub fn is_cache_record_expired(&self, record: &str, max_age: Option<i64>) -> Result<bool, CommonError> {
+        let record = LedgerCacheRecord::from_json(record)
+            .map_err(|err| CommonError::InvalidState(format!("Invalid LedgerCacheRecord json: {:?}", err)))?;
+
+        match max_age {
+            Some(max_age) => Ok(time::get_time().sec - record.time_fetched > max_age),
+            None => Ok(true)
+        }
+    }
+
     fn get_req_id() -> u64 {
         time::get_time().sec as u64 * (1e9 as u64) + time::get_time().nsec as u64
     }
@@ -261,6 +660,295 @@ mod tests {
         assert!(get_ddo_request.contains(expected_result));
     }
 
+    const REVOC_REG_DEF_ID: &'static str = "NcYxiDXkpYi6ov5FcYDi1e:4:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:CL_ACCUM:tag1";
+
+    #[test]
+    fn build_revoc_reg_def_request_works() {
+        let ledger_service = LedgerService::new();
+        let identifier = "identifier";
+        let data = format!(r#"{{"id":"{}","revocDefType":"CL_ACCUM","tag":"tag1","credDefId":"NcYxiDXkpYi6ov5FcYDi1e:3:CL:1","value":{{"issuanceType":"ISSUANCE_ON_DEMAND","maxCredNum":5,"publicKeys":{{"accumKey":{{"z":"1 0000"}}}}}}}}"#, REVOC_REG_DEF_ID);
+
+        let expected_result = format!(r#""operation":{{"type":"113","id":"{}","revocDefType":"CL_ACCUM","tag":"tag1","credDefId":"NcYxiDXkpYi6ov5FcYDi1e:3:CL:1","value":{{"issuanceType":"ISSUANCE_ON_DEMAND","maxCredNum":5,"publicKeys":{{"accumKey":{{"z":"1 0000"}}}}}}}}"#, REVOC_REG_DEF_ID);
+
+        let request = ledger_service.build_revoc_reg_def_request(identifier, &data).unwrap();
+        assert!(request.contains(&expected_result));
+    }
+
+    #[test]
+    fn build_revoc_reg_def_request_works_for_invalid_issuance_type() {
+        let ledger_service = LedgerService::new();
+        let identifier = "identifier";
+        let data = format!(r#"{{"id":"{}","revocDefType":"CL_ACCUM","tag":"tag1","credDefId":"NcYxiDXkpYi6ov5FcYDi1e:3:CL:1","value":{{"issuanceType":"ISSUANCE_NEVER","maxCredNum":5,"publicKeys":{{}}}}}}"#, REVOC_REG_DEF_ID);
+
+        let res = ledger_service.build_revoc_reg_def_request(identifier, &data);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn build_revoc_reg_entry_request_works() {
+        let ledger_service = LedgerService::new();
+        let identifier = "identifier";
+        let value = r#"{"accum":"123456789","issued":[1,2],"revoked":[]}"#;
+
+        let expected_result = format!(r#""operation":{{"type":"114","revocRegDefId":"{}","revocDefType":"CL_ACCUM","value":{{"accum":"123456789","issued":[1,2],"revoked":[]}}}}"#, REVOC_REG_DEF_ID);
+
+        let request = ledger_service.build_revoc_reg_entry_request(identifier, REVOC_REG_DEF_ID, "CL_ACCUM", value).unwrap();
+        assert!(request.contains(&expected_result));
+    }
+
+    #[test]
+    fn build_get_revoc_reg_def_request_works() {
+        let ledger_service = LedgerService::new();
+        let identifier = "identifier";
+
+        let expected_result = format!(r#""operation":{{"type":"115","id":"{}"}}"#, REVOC_REG_DEF_ID);
+
+        let request = ledger_service.build_get_revoc_reg_def_request(identifier, REVOC_REG_DEF_ID).unwrap();
+        assert!(request.contains(&expected_result));
+    }
+
+    #[test]
+    fn build_get_revoc_reg_request_works() {
+        let ledger_service = LedgerService::new();
+        let identifier = "identifier";
+
+        let expected_result = format!(r#""operation":{{"type":"116","revocRegDefId":"{}","timestamp":100}}"#, REVOC_REG_DEF_ID);
+
+        let request = ledger_service.build_get_revoc_reg_request(identifier, REVOC_REG_DEF_ID, 100).unwrap();
+        assert!(request.contains(&expected_result));
+    }
+
+    #[test]
+    fn build_get_revoc_reg_delta_request_works() {
+        let ledger_service = LedgerService::new();
+        let identifier = "identifier";
+
+        let expected_result = format!(r#""operation":{{"type":"117","revocRegDefId":"{}","from":50,"to":100}}"#, REVOC_REG_DEF_ID);
+
+        let request = ledger_service.build_get_revoc_reg_delta_request(identifier, REVOC_REG_DEF_ID, Some(50), 100).unwrap();
+        assert!(request.contains(&expected_result));
+
+        let res = ledger_service.build_get_revoc_reg_delta_request(identifier, REVOC_REG_DEF_ID, Some(150), 100);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn parse_get_revoc_reg_response_works() {
+        let ledger_service = LedgerService::new();
+        let response = format!(r#"{{"op":"REPLY","result":{{"type":"116","revocRegDefId":"{0}","timestamp":100,"seqNo":10,"txnTime":90,"data":{{"revocDefType":"CL_ACCUM","revocRegDefId":"{0}","value":{{"accum":"123456789"}}}}}}}}"#, REVOC_REG_DEF_ID);
+
+        let (id, revoc_reg_json, timestamp) = ledger_service.parse_get_revoc_reg_response(&response).unwrap();
+        assert_eq!(REVOC_REG_DEF_ID, id);
+        assert_eq!(r#"{"accum":"123456789"}"#, revoc_reg_json);
+        assert_eq!(90, timestamp);
+    }
+
+    #[test]
+    fn parse_get_revoc_reg_delta_response_works() {
+        let ledger_service = LedgerService::new();
+        let response = format!(r#"{{"op":"REPLY","result":{{"type":"117","revocRegDefId":"{0}","data":{{"revocDefType":"CL_ACCUM","revocRegDefId":"{0}","value":{{"accum_from":{{"value":{{"accum":"1"}},"txnTime":50}},"accum_to":{{"value":{{"accum":"2"}},"txnTime":90}},"issued":[],"revoked":[3]}}}}}}}}"#, REVOC_REG_DEF_ID);
+
+        let (id, delta_json, timestamp) = ledger_service.parse_get_revoc_reg_delta_response(&response).unwrap();
+        assert_eq!(REVOC_REG_DEF_ID, id);
+        assert_eq!(r#"{"prevAccum":"1","accum":"2","issued":[],"revoked":[3]}"#, delta_json);
+        assert_eq!(90, timestamp);
+    }
+
+    #[test]
+    fn parse_get_schema_response_works() {
+        let ledger_service = LedgerService::new();
+        let response = r#"{"op":"REPLY","result":{"type":"107","dest":"NcYxiDXkpYi6ov5FcYDi1e","seqNo":15,"data":{"name":"gvt","version":"1.0","attr_names":["name"]}}}"#;
+
+        let schema_json = ledger_service.parse_get_schema_response(response).unwrap();
+
+        let expected_schema = json!({"seqNo": 15, "dest": "NcYxiDXkpYi6ov5FcYDi1e", "data": {"name": "gvt", "version": "1.0", "attr_names": ["name"]}});
+        assert_eq!(expected_schema, ::serde_json::from_str::<Value>(&schema_json).unwrap());
+    }
+
+    #[test]
+    fn parse_get_schema_response_works_for_not_found_schema() {
+        let ledger_service = LedgerService::new();
+        let response = r#"{"op":"REPLY","result":{"type":"107","dest":"NcYxiDXkpYi6ov5FcYDi1e","seqNo":null,"data":{"name":"gvt","version":"1.0"}}}"#;
+
+        let res = ledger_service.parse_get_schema_response(response);
+        assert_match!(Err(CommonError::InvalidState(_)), res);
+    }
+
+    #[test]
+    fn parse_get_claim_def_response_works() {
+        let ledger_service = LedgerService::new();
+        let response = r#"{"op":"REPLY","result":{"type":"108","ref":15,"origin":"NcYxiDXkpYi6ov5FcYDi1e","signature_type":"CL","seqNo":20,"data":{"primary":{"n":"1"}}}}"#;
+
+        let claim_def_json = ledger_service.parse_get_claim_def_response(response).unwrap();
+
+        let expected_claim_def = json!({"ref": 15, "origin": "NcYxiDXkpYi6ov5FcYDi1e", "signature_type": "CL", "data": {"primary": {"n": "1"}}});
+        assert_eq!(expected_claim_def, ::serde_json::from_str::<Value>(&claim_def_json).unwrap());
+    }
+
+    #[test]
+    fn parse_get_claim_def_response_works_for_not_found_claim_def() {
+        let ledger_service = LedgerService::new();
+        let response = r#"{"op":"REPLY","result":{"type":"108","ref":15,"origin":"NcYxiDXkpYi6ov5FcYDi1e","signature_type":"CL","seqNo":null,"data":null}}"#;
+
+        let res = ledger_service.parse_get_claim_def_response(response);
+        assert_match!(Err(CommonError::InvalidState(_)), res);
+    }
+
+    #[test]
+    fn build_cache_record_works() {
+        let ledger_service = LedgerService::new();
+        let reply = r#"{"op":"REPLY","result":{"type":"107","data":{"name":"gvt","version":"1.0","attr_names":["name"]}}}"#;
+
+        let record = ledger_service.build_cache_record(reply).unwrap().unwrap();
+
+        assert_eq!(Some(reply.to_string()), ledger_service.get_cached_reply(&record, None).unwrap());
+        assert_eq!(Some(reply.to_string()), ledger_service.get_cached_reply(&record, Some(100)).unwrap());
+        assert!(!ledger_service.is_cache_record_expired(&record, Some(100)).unwrap());
+        assert!(ledger_service.is_cache_record_expired(&record, None).unwrap());
+    }
+
+    #[test]
+    fn build_cache_record_works_for_absent_data() {
+        let ledger_service = LedgerService::new();
+        let reply = r#"{"op":"REPLY","result":{"type":"107","data":null}}"#;
+
+        assert_eq!(None, ledger_service.build_cache_record(reply).unwrap());
+    }
+
+    #[test]
+    fn build_cache_record_works_for_reqnack() {
+        let ledger_service = LedgerService::new();
+        let reply = r#"{"op":"REQNACK","reason":"some reason"}"#;
+
+        assert_eq!(None, ledger_service.build_cache_record(reply).unwrap());
+    }
+
+    #[test]
+    fn get_cached_reply_works_for_stale_record() {
+        let ledger_service = LedgerService::new();
+        let record = r#"{"reply":"{}","time_fetched":0}"#;
+
+        assert_eq!(None, ledger_service.get_cached_reply(record, Some(100)).unwrap());
+        assert!(ledger_service.is_cache_record_expired(record, Some(100)).unwrap());
+    }
+
+    #[test]
+    fn get_schema_cache_key_works() {
+        let ledger_service = LedgerService::new();
+
+        let key = ledger_service.get_schema_cache_key("NcYxiDXkpYi6ov5FcYDi1e", r#"{"name":"gvt","version":"1.0"}"#).unwrap();
+        assert_eq!("cache::schema::NcYxiDXkpYi6ov5FcYDi1e:gvt:1.0", key);
+    }
+
+    #[test]
+    fn build_did_document_works_for_abbreviated_verkey() {
+        let ledger_service = LedgerService::new();
+        let did = "8wZcEriaNLNKtteJvx7f8i";
+        let nym_data = GetNymResultData {
+            identifier: None,
+            dest: did.to_string(),
+            role: None,
+            verkey: Some("~NcYxiDXkpYi6ov5FcYDi1e".to_string())
+        };
+
+        let did_doc = ledger_service.build_did_document(did, &nym_data, &Vec::new()).unwrap();
+
+        assert_eq!("did:sov:8wZcEriaNLNKtteJvx7f8i", did_doc.id);
+        assert_eq!(1, did_doc.public_key.len());
+        assert_eq!("5L2HBnzbu6Auh2pkDRbFt5f4prvgE2LzknkuYLsKkacp", did_doc.public_key[0].public_key_base58);
+        assert_eq!(did_doc.public_key[0].id, did_doc.authentication[0].public_key);
+        assert!(did_doc.service.is_empty());
+    }
+
+    #[test]
+    fn build_did_document_works_for_secp256k1_verkey() {
+        let ledger_service = LedgerService::new();
+        let did = "8wZcEriaNLNKtteJvx7f8i";
+        let nym_data = GetNymResultData {
+            identifier: None,
+            dest: did.to_string(),
+            role: None,
+            verkey: Some("5L2HBnzbu6Auh2pkDRbFt5f4prvgE2LzknkuYLsKkacp:secp256k1".to_string())
+        };
+
+        let did_doc = ledger_service.build_did_document(did, &nym_data, &Vec::new()).unwrap();
+
+        assert_eq!("5L2HBnzbu6Auh2pkDRbFt5f4prvgE2LzknkuYLsKkacp", did_doc.public_key[0].public_key_base58);
+        assert_eq!("EcdsaSecp256k1VerificationKey2019", did_doc.public_key[0]._type);
+        assert_eq!("EcdsaSecp256k1SignatureAuthentication2019", did_doc.authentication[0]._type);
+    }
+
+    #[test]
+    fn build_did_document_works_for_unknown_crypto_type() {
+        let ledger_service = LedgerService::new();
+        let did = "8wZcEriaNLNKtteJvx7f8i";
+        let nym_data = GetNymResultData {
+            identifier: None,
+            dest: did.to_string(),
+            role: None,
+            verkey: Some("5L2HBnzbu6Auh2pkDRbFt5f4prvgE2LzknkuYLsKkacp:unknown".to_string())
+        };
+
+        let res = ledger_service.build_did_document(did, &nym_data, &Vec::new());
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn build_did_document_works_for_endpoint() {
+        let ledger_service = LedgerService::new();
+        let did = "8wZcEriaNLNKtteJvx7f8i";
+        let verkey = "5L2HBnzbu6Auh2pkDRbFt5f4prvgE2LzknkuYLsKkacp";
+        let nym_data = GetNymResultData {
+            identifier: None,
+            dest: did.to_string(),
+            role: None,
+            verkey: Some(verkey.to_string())
+        };
+        let attribs = vec![("endpoint".to_string(), r#"{"endpoint":{"ha":"127.0.0.1:5555"}}"#.to_string()),
+                           ("hub".to_string(), r#"{"hub":{"type":"IdentityHub","serviceEndpoint":"https://hub.example.com"}}"#.to_string())];
+
+        let did_doc = ledger_service.build_did_document(did, &nym_data, &attribs).unwrap();
+
+        assert_eq!(2, did_doc.service.len());
+        assert_eq!("127.0.0.1:5555", did_doc.service[0].service_endpoint);
+        assert_eq!(Some(vec![verkey.to_string()]), did_doc.service[0].recipient_keys);
+        assert_eq!("did:sov:8wZcEriaNLNKtteJvx7f8i;hub", did_doc.service[1].id);
+        assert_eq!("IdentityHub", did_doc.service[1]._type);
+        assert_eq!("https://hub.example.com", did_doc.service[1].service_endpoint);
+    }
+
+    #[test]
+    fn build_did_document_works_for_attrib_without_service_description() {
+        let ledger_service = LedgerService::new();
+        let did = "8wZcEriaNLNKtteJvx7f8i";
+        let nym_data = GetNymResultData {
+            identifier: None,
+            dest: did.to_string(),
+            role: None,
+            verkey: Some("5L2HBnzbu6Auh2pkDRbFt5f4prvgE2LzknkuYLsKkacp".to_string())
+        };
+        let attribs = vec![("url".to_string(), r#"{"url":"https://example.com"}"#.to_string())];
+
+        let did_doc = ledger_service.build_did_document(did, &nym_data, &attribs).unwrap();
+        assert!(did_doc.service.is_empty());
+    }
+
+    #[test]
+    fn build_did_document_works_for_invalid_endpoint() {
+        let ledger_service = LedgerService::new();
+        let did = "8wZcEriaNLNKtteJvx7f8i";
+        let nym_data = GetNymResultData {
+            identifier: None,
+            dest: did.to_string(),
+            role: None,
+            verkey: None
+        };
+        let attribs = vec![("endpoint".to_string(), r#"{"endpoint":{"address":"127.0.0.1:5555"}}"#.to_string())];
+
+        let res = ledger_service.build_did_document(did, &nym_data, &attribs);
+        assert!(res.is_err());
+    }
+
     #[test]
     fn build_attrib_request_works_for_miss_attrib_field() {
         let ledger_service = LedgerService::new();
@@ -446,9 +1134,256 @@ mod tests {
 
         let expected_result = r#""identifier":"identifier","operation":{"type":"3","data":1},"protocolVersion":1"#;
 
-        let get_txn_request = ledger_service.build_get_txn_request(identifier, 1);
+        let get_txn_request = ledger_service.build_get_txn_request(identifier, None, 1, None);
         assert!(get_txn_request.is_ok());
         let get_txn_request = get_txn_request.unwrap();
         assert!(get_txn_request.contains(expected_result));
     }
+
+    #[test]
+    fn build_pool_restart_works() {
+        let ledger_service = LedgerService::new();
+        let identifier = "identifier";
+
+        let expected_result = r#""identifier":"identifier","operation":{"type":"118","action":"start","datetime":"2020-01-25T12:49:05.258870+00:00"}"#;
+
+        let pool_restart_request = ledger_service.build_pool_restart(identifier, "start", Some("2020-01-25T12:49:05.258870+00:00")).unwrap();
+        assert!(pool_restart_request.contains(expected_result));
+    }
+
+    #[test]
+    fn build_pool_restart_works_for_cancel_without_datetime() {
+        let ledger_service = LedgerService::new();
+        let identifier = "identifier";
+
+        let expected_result = r#""identifier":"identifier","operation":{"type":"118","action":"cancel"}"#;
+
+        let pool_restart_request = ledger_service.build_pool_restart(identifier, "cancel", None).unwrap();
+        assert!(pool_restart_request.contains(expected_result));
+    }
+
+    #[test]
+    fn build_pool_restart_works_for_invalid_action() {
+        let ledger_service = LedgerService::new();
+        let identifier = "identifier";
+
+        let res = ledger_service.build_pool_restart(identifier, "stop", None);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn build_get_validator_info_request_works() {
+        let ledger_service = LedgerService::new();
+        let identifier = "identifier";
+
+        let expected_result = r#""identifier":"identifier","operation":{"type":"119"}"#;
+
+        let get_validator_info_request = ledger_service.build_get_validator_info_request(identifier).unwrap();
+        assert!(get_validator_info_request.contains(expected_result));
+    }
+
+    #[test]
+    fn validate_action_works() {
+        let ledger_service = LedgerService::new();
+
+        let request = ledger_service.build_get_validator_info_request("identifier").unwrap();
+        ledger_service.validate_action(&request).unwrap();
+
+        let request = ledger_service.build_pool_restart("identifier", "start", None).unwrap();
+        ledger_service.validate_action(&request).unwrap();
+    }
+
+    #[test]
+    fn validate_action_works_for_not_action_request() {
+        let ledger_service = LedgerService::new();
+
+        let request = ledger_service.build_get_txn_request("identifier", None, 1, None).unwrap();
+        let res = ledger_service.validate_action(&request);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    fn _get_txn_reply(txn: &Value, other_leaf: &Vec<u8>, seq_no: i32) -> Value {
+        use super::rust_base58::ToBase58;
+        use utils::crypto::hash::Hash;
+
+        let leaf = rmp_serde::to_vec_named(txn).unwrap();
+        let mut leaves = vec![other_leaf.clone(), leaf.clone()];
+        if seq_no == 1 {
+            leaves.reverse();
+        }
+        let mut mt = MerkleTree::from_vec(vec![]).unwrap();
+        for value in leaves {
+            mt.append(value).unwrap();
+        }
+
+        let mut data = txn.clone();
+        data["auditPath"] = json!([Hash::hash_leaf(other_leaf).unwrap().to_vec().to_base58()]);
+        data["rootHash"] = json!(mt.root_hash().to_base58());
+        data["ledgerSize"] = json!(2);
+
+        let state_proof = json!({
+            "multi_signature": {
+                "signature": "signature",
+                "participants": ["Node1", "Node2", "Node3"],
+                "value": {"ledger_id": constants::DOMAIN_LEDGER_ID, "txn_root_hash": mt.root_hash().to_base58()}
+            }
+        });
+
+        json!({"op": "REPLY", "result": {"type": "3", "seqNo": seq_no, "data": data, "state_proof": state_proof}})
+    }
+
+    #[test]
+    fn parse_get_txn_response_with_proof_works() {
+        let ledger_service = LedgerService::new();
+
+        let txn = json!({"type": "1", "dest": "VsKV7grR1BUE29mG2Fm2kX", "seqNo": 2});
+        let reply = _get_txn_reply(&txn, &"other".as_bytes().to_vec(), 2);
+
+        let ledger_txn = ledger_service.parse_get_txn_response_with_proof(&reply.to_string(), constants::DOMAIN_LEDGER_ID, 2, None).unwrap().unwrap();
+        assert_eq!(txn, ledger_txn.txn);
+        assert_eq!(2, ledger_txn.seq_no);
+        assert_eq!(2, ledger_txn.ledger_size);
+        assert_eq!(reply["result"]["data"]["rootHash"].as_str().unwrap(), ledger_txn.root_hash);
+    }
+
+    #[test]
+    fn parse_get_txn_response_with_proof_works_for_tampered_txn() {
+        let ledger_service = LedgerService::new();
+
+        let txn = json!({"type": "1", "dest": "VsKV7grR1BUE29mG2Fm2kX", "seqNo": 2});
+        let mut reply = _get_txn_reply(&txn, &"other".as_bytes().to_vec(), 2);
+        reply["result"]["data"]["dest"] = json!("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW");
+
+        let res = ledger_service.parse_get_txn_response_with_proof(&reply.to_string(), constants::DOMAIN_LEDGER_ID, 2, None);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn parse_get_txn_response_with_proof_works_for_wrong_seq_no() {
+        let ledger_service = LedgerService::new();
+
+        let txn = json!({"type": "1", "dest": "VsKV7grR1BUE29mG2Fm2kX", "seqNo": 2});
+        let reply = _get_txn_reply(&txn, &"other".as_bytes().to_vec(), 2);
+
+        let res = ledger_service.parse_get_txn_response_with_proof(&reply.to_string(), constants::DOMAIN_LEDGER_ID, 1, None);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn parse_get_txn_response_with_proof_works_for_unsigned_root() {
+        let ledger_service = LedgerService::new();
+
+        let txn = json!({"type": "1", "dest": "VsKV7grR1BUE29mG2Fm2kX", "seqNo": 2});
+        let mut reply = _get_txn_reply(&txn, &"other".as_bytes().to_vec(), 2);
+        reply["result"].as_object_mut().unwrap().remove("state_proof");
+
+        let res = ledger_service.parse_get_txn_response_with_proof(&reply.to_string(), constants::DOMAIN_LEDGER_ID, 2, None);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn parse_get_txn_response_with_proof_works_for_other_signed_root() {
+        let ledger_service = LedgerService::new();
+
+        let txn = json!({"type": "1", "dest": "VsKV7grR1BUE29mG2Fm2kX", "seqNo": 2});
+        let mut reply = _get_txn_reply(&txn, &"other".as_bytes().to_vec(), 2);
+        reply["result"]["state_proof"]["multi_signature"]["value"]["txn_root_hash"] = json!("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW");
+
+        let res = ledger_service.parse_get_txn_response_with_proof(&reply.to_string(), constants::DOMAIN_LEDGER_ID, 2, None);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn parse_get_txn_response_with_proof_works_for_other_signed_ledger() {
+        let ledger_service = LedgerService::new();
+
+        let txn = json!({"type": "1", "dest": "VsKV7grR1BUE29mG2Fm2kX", "seqNo": 2});
+        let reply = _get_txn_reply(&txn, &"other".as_bytes().to_vec(), 2);
+
+        let res = ledger_service.parse_get_txn_response_with_proof(&reply.to_string(), constants::POOL_LEDGER_ID, 2, None);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn parse_get_txn_response_with_proof_works_for_consistent_root() {
+        use super::rust_base58::ToBase58;
+        use utils::crypto::hash::Hash;
+
+        let ledger_service = LedgerService::new();
+
+        let txn = json!({"type": "1", "dest": "VsKV7grR1BUE29mG2Fm2kX", "seqNo": 2});
+        let other_leaf = "other".as_bytes().to_vec();
+        let mut reply = _get_txn_reply(&txn, &other_leaf, 2);
+
+        let trusted_mt = MerkleTree::from_vec(vec![other_leaf.clone()]).unwrap();
+        let trusted_root_hash = trusted_mt.root_hash().to_base58();
+        let txn_leaf = rmp_serde::to_vec_named(&txn).unwrap();
+        reply["result"]["data"]["consistencyProof"] = json!([Hash::hash_leaf(&txn_leaf).unwrap().to_vec().to_base58()]);
+
+        let ledger_txn = ledger_service.parse_get_txn_response_with_proof(&reply.to_string(), constants::DOMAIN_LEDGER_ID, 2,
+                                                                          Some((&trusted_root_hash, 1))).unwrap().unwrap();
+        assert_eq!(txn, ledger_txn.txn);
+
+        let root_hash = ledger_txn.root_hash.clone();
+        ledger_service.parse_get_txn_response_with_proof(&reply.to_string(), constants::DOMAIN_LEDGER_ID, 2,
+                                                         Some((&root_hash, 2))).unwrap().unwrap();
+    }
+
+    #[test]
+    fn parse_get_txn_response_with_proof_works_for_inconsistent_root() {
+        use super::rust_base58::ToBase58;
+
+        let ledger_service = LedgerService::new();
+
+        let txn = json!({"type": "1", "dest": "VsKV7grR1BUE29mG2Fm2kX", "seqNo": 2});
+        let reply = _get_txn_reply(&txn, &"other".as_bytes().to_vec(), 2);
+
+        let forked_mt = MerkleTree::from_vec(vec!["forked".as_bytes().to_vec()]).unwrap();
+        let forked_root_hash = forked_mt.root_hash().to_base58();
+
+        let res = ledger_service.parse_get_txn_response_with_proof(&reply.to_string(), constants::DOMAIN_LEDGER_ID, 2,
+                                                                   Some((&forked_root_hash, 1)));
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+
+        let res = ledger_service.parse_get_txn_response_with_proof(&reply.to_string(), constants::DOMAIN_LEDGER_ID, 2,
+                                                                   Some((&forked_root_hash, 2)));
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn parse_get_txn_response_with_proof_works_for_missed_proof() {
+        let ledger_service = LedgerService::new();
+
+        let reply = json!({"op": "REPLY", "result": {"type": "3", "seqNo": 1, "data": {"type": "1"}}});
+
+        let res = ledger_service.parse_get_txn_response_with_proof(&reply.to_string(), constants::DOMAIN_LEDGER_ID, 1, None);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn parse_get_txn_response_with_proof_works_for_no_txn() {
+        let ledger_service = LedgerService::new();
+
+        let reply = json!({"op": "REPLY", "result": {"type": "3", "seqNo": 10, "data": null}});
+
+        assert!(ledger_service.parse_get_txn_response_with_proof(&reply.to_string(), constants::DOMAIN_LEDGER_ID, 10, None).unwrap().is_none());
+    }
+
+    #[test]
+    fn build_get_txn_request_works_for_ledger_id() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = r#""operation":{"type":"3","data":1,"ledgerId":0}"#;
+
+        let get_txn_request = ledger_service.build_get_txn_request("identifier", Some(constants::POOL_LEDGER_ID), 1, None).unwrap();
+        assert!(get_txn_request.contains(expected_result));
+    }
+
+    #[test]
+    fn build_get_txn_request_works_for_invalid_ledger_id() {
+        let ledger_service = LedgerService::new();
+
+        let res = ledger_service.build_get_txn_request("identifier", Some(5), 1, None);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
 }
@@ -257,14 +257,17 @@ impl JsonEncodable for ClaimDefOperation {}
 pub struct ClaimDefOperationData {
     pub primary: IssuerPrimaryPublicKey,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub revocation: Option<IssuerRevocationPublicKey>
+    pub revocation: Option<IssuerRevocationPublicKey>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attr_encoding: Option<String>
 }
 
 impl ClaimDefOperationData {
-    pub fn new(primary: IssuerPrimaryPublicKey, revocation: Option<IssuerRevocationPublicKey>) -> ClaimDefOperationData {
+    pub fn new(primary: IssuerPrimaryPublicKey, revocation: Option<IssuerRevocationPublicKey>, attr_encoding: Option<String>) -> ClaimDefOperationData {
         ClaimDefOperationData {
             primary,
-            revocation
+            revocation,
+            attr_encoding
         }
     }
 }
@@ -296,6 +299,161 @@ impl GetClaimDefOperation {
 
 impl JsonEncodable for GetClaimDefOperation {}
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocRegDefValue {
+    pub issuance_type: String,
+    pub max_cred_num: u32,
+    pub public_keys: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tails_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tails_location: Option<String>
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocRegDefData {
+    pub id: String,
+    pub revoc_def_type: String,
+    pub tag: String,
+    pub cred_def_id: String,
+    pub value: RevocRegDefValue
+}
+
+impl JsonEncodable for RevocRegDefData {}
+
+impl<'a> JsonDecodable<'a> for RevocRegDefData {}
+
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocRegDefOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub id: String,
+    pub revoc_def_type: String,
+    pub tag: String,
+    pub cred_def_id: String,
+    pub value: RevocRegDefValue
+}
+
+impl RevocRegDefOperation {
+    pub fn new(data: RevocRegDefData) -> RevocRegDefOperation {
+        RevocRegDefOperation {
+            _type: REVOC_REG_DEF.to_string(),
+            id: data.id,
+            revoc_def_type: data.revoc_def_type,
+            tag: data.tag,
+            cred_def_id: data.cred_def_id,
+            value: data.value
+        }
+    }
+}
+
+impl JsonEncodable for RevocRegDefOperation {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocRegEntryValue {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prev_accum: Option<String>,
+    pub accum: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issued: Option<Vec<u32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revoked: Option<Vec<u32>>
+}
+
+impl JsonEncodable for RevocRegEntryValue {}
+
+impl<'a> JsonDecodable<'a> for RevocRegEntryValue {}
+
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocRegEntryOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub revoc_reg_def_id: String,
+    pub revoc_def_type: String,
+    pub value: RevocRegEntryValue
+}
+
+impl RevocRegEntryOperation {
+    pub fn new(revoc_reg_def_id: String, revoc_def_type: String, value: RevocRegEntryValue) -> RevocRegEntryOperation {
+        RevocRegEntryOperation {
+            _type: REVOC_REG_ENTRY.to_string(),
+            revoc_reg_def_id,
+            revoc_def_type,
+            value
+        }
+    }
+}
+
+impl JsonEncodable for RevocRegEntryOperation {}
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct GetRevocRegDefOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub id: String
+}
+
+impl GetRevocRegDefOperation {
+    pub fn new(id: String) -> GetRevocRegDefOperation {
+        GetRevocRegDefOperation {
+            _type: GET_REVOC_REG_DEF.to_string(),
+            id
+        }
+    }
+}
+
+impl JsonEncodable for GetRevocRegDefOperation {}
+
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRevocRegOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub revoc_reg_def_id: String,
+    pub timestamp: i64
+}
+
+impl GetRevocRegOperation {
+    pub fn new(revoc_reg_def_id: String, timestamp: i64) -> GetRevocRegOperation {
+        GetRevocRegOperation {
+            _type: GET_REVOC_REG.to_string(),
+            revoc_reg_def_id,
+            timestamp
+        }
+    }
+}
+
+impl JsonEncodable for GetRevocRegOperation {}
+
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRevocRegDeltaOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub revoc_reg_def_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<i64>,
+    pub to: i64
+}
+
+impl GetRevocRegDeltaOperation {
+    pub fn new(revoc_reg_def_id: String, from: Option<i64>, to: i64) -> GetRevocRegDeltaOperation {
+        GetRevocRegDeltaOperation {
+            _type: GET_REVOC_REG_DELTA.to_string(),
+            revoc_reg_def_id,
+            from,
+            to
+        }
+    }
+}
+
+impl JsonEncodable for GetRevocRegDeltaOperation {}
+
 #[derive(Serialize, PartialEq, Debug)]
 pub struct NodeOperation {
     #[serde(rename = "type")]
@@ -365,20 +523,51 @@ impl JsonEncodable for GetDdoOperation {}
 pub struct GetTxnOperation {
     #[serde(rename = "type")]
     pub _type: String,
-    pub data: i32
+    pub data: i32,
+    #[serde(rename = "ledgerId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ledger_id: Option<i32>,
+    #[serde(rename = "consistencyFrom")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consistency_from: Option<usize>
 }
 
 impl GetTxnOperation {
-    pub fn new(data: i32) -> GetTxnOperation {
+    pub fn new(data: i32, ledger_id: Option<i32>, consistency_from: Option<usize>) -> GetTxnOperation {
         GetTxnOperation {
             _type: GET_TXN.to_string(),
-            data
+            data,
+            ledger_id,
+            consistency_from
         }
     }
 }
 
 impl JsonEncodable for GetTxnOperation {}
 
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTxnReplyResult {
+    pub seq_no: Option<i32>,
+    pub data: Option<serde_json::Value>,
+    #[serde(rename = "state_proof")]
+    pub state_proof: Option<serde_json::Value>
+}
+
+impl<'a> JsonDecodable<'a> for GetTxnReplyResult {}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerTxn {
+    pub seq_no: i32,
+    pub ledger_id: i32,
+    pub txn: serde_json::Value,
+    pub root_hash: String,
+    pub ledger_size: usize
+}
+
+impl JsonEncodable for LedgerTxn {}
+
 #[derive(Deserialize, Eq, PartialEq, Debug)]
 pub struct Reply<T> {
     pub op: String,
@@ -387,6 +576,114 @@ pub struct Reply<T> {
 
 impl<'a, T: JsonDecodable<'a>> JsonDecodable<'a> for Reply<T> {}
 
+#[derive(Deserialize, Debug)]
+pub struct GetSchemaReplyData {
+    pub name: String,
+    pub version: String,
+    pub attr_names: Option<Vec<String>>
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSchemaReplyResult {
+    pub seq_no: Option<i32>,
+    pub dest: String,
+    pub data: Option<GetSchemaReplyData>
+}
+
+impl<'a> JsonDecodable<'a> for GetSchemaReplyResult {}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetClaimDefReplyResult {
+    pub seq_no: Option<i32>,
+    #[serde(rename = "ref")]
+    pub _ref: i32,
+    pub origin: String,
+    #[serde(rename = "signature_type")]
+    pub signature_type: String,
+    pub data: Option<serde_json::Value>
+}
+
+impl<'a> JsonDecodable<'a> for GetClaimDefReplyResult {}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRevocRegDefReplyResult {
+    pub seq_no: Option<i32>,
+    pub txn_time: Option<u64>,
+    pub data: Option<RevocRegDefData>
+}
+
+impl<'a> JsonDecodable<'a> for GetRevocRegDefReplyResult {}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocRegEntryData {
+    pub revoc_def_type: String,
+    pub revoc_reg_def_id: String,
+    pub value: RevocRegEntryValue
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRevocRegReplyResult {
+    pub revoc_reg_def_id: String,
+    pub seq_no: Option<i32>,
+    pub txn_time: Option<u64>,
+    pub data: Option<RevocRegEntryData>
+}
+
+impl<'a> JsonDecodable<'a> for GetRevocRegReplyResult {}
+
+#[derive(Deserialize, Debug)]
+pub struct RevocRegAccum {
+    pub value: RevocRegEntryValue,
+    #[serde(rename = "txnTime")]
+    pub txn_time: u64
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RevocRegDeltaValue {
+    pub accum_from: Option<RevocRegAccum>,
+    pub accum_to: RevocRegAccum,
+    #[serde(default)]
+    pub issued: Vec<u32>,
+    #[serde(default)]
+    pub revoked: Vec<u32>
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocRegDeltaData {
+    pub revoc_def_type: String,
+    pub revoc_reg_def_id: String,
+    pub value: RevocRegDeltaValue
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRevocRegDeltaReplyResult {
+    pub revoc_reg_def_id: String,
+    pub data: Option<RevocRegDeltaData>
+}
+
+impl<'a> JsonDecodable<'a> for GetRevocRegDeltaReplyResult {}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RevocRegDelta {
+    #[serde(rename = "prevAccum")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prev_accum: Option<String>,
+    pub accum: String,
+    pub issued: Vec<u32>,
+    pub revoked: Vec<u32>
+}
+
+impl JsonEncodable for RevocRegDelta {}
+
+impl<'a> JsonDecodable<'a> for RevocRegDelta {}
+
 #[derive(Deserialize, Eq, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct GetNymReplyResult {
@@ -453,6 +750,106 @@ impl JsonEncodable for Endpoint {}
 
 impl<'a> JsonDecodable<'a> for Endpoint {}
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DidDocument {
+    #[serde(rename = "@context")]
+    pub context: String,
+    pub id: String,
+    #[serde(rename = "publicKey")]
+    pub public_key: Vec<DidDocPublicKey>,
+    pub authentication: Vec<DidDocAuthentication>,
+    pub service: Vec<DidDocService>
+}
+
+impl JsonEncodable for DidDocument {}
+
+impl<'a> JsonDecodable<'a> for DidDocument {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DidDocPublicKey {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub owner: String,
+    #[serde(rename = "publicKeyBase58")]
+    pub public_key_base58: String
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DidDocAuthentication {
+    #[serde(rename = "type")]
+    pub _type: String,
+    #[serde(rename = "publicKey")]
+    pub public_key: String
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DidDocService {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub _type: String,
+    #[serde(rename = "serviceEndpoint")]
+    pub service_endpoint: String,
+    #[serde(rename = "recipientKeys")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_keys: Option<Vec<String>>
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DidResolutionOptions {
+    #[serde(default)]
+    pub no_cache: bool,
+    #[serde(default = "DidResolutionOptions::default_attribs")]
+    pub attribs: Vec<String>
+}
+
+impl DidResolutionOptions {
+    fn default_attribs() -> Vec<String> {
+        vec![ENDPOINT_ATTRIB.to_string()]
+    }
+}
+
+impl Default for DidResolutionOptions {
+    fn default() -> Self {
+        DidResolutionOptions {
+            no_cache: false,
+            attribs: DidResolutionOptions::default_attribs()
+        }
+    }
+}
+
+impl<'a> JsonDecodable<'a> for DidResolutionOptions {}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LedgerCacheRecord {
+    pub reply: String,
+    pub time_fetched: i64
+}
+
+impl JsonEncodable for LedgerCacheRecord {}
+
+impl<'a> JsonDecodable<'a> for LedgerCacheRecord {}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct LedgerCacheOptions {
+    #[serde(default)]
+    pub no_cache: bool,
+    #[serde(default)]
+    pub no_update: bool,
+    #[serde(default)]
+    pub no_store: bool,
+    pub min_fresh: Option<i64>
+}
+
+impl<'a> JsonDecodable<'a> for LedgerCacheOptions {}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct LedgerCachePurgeOptions {
+    pub max_age: Option<i64>
+}
+
+impl<'a> JsonDecodable<'a> for LedgerCachePurgeOptions {}
+
 #[derive(Serialize, PartialEq, Debug)]
 pub struct PoolConfigOperation {
     #[serde(rename = "type")]
@@ -510,4 +907,42 @@ impl PoolUpgradeOperation {
     }
 }
 
-impl JsonEncodable for PoolUpgradeOperation {}
\ No newline at end of file
+impl JsonEncodable for PoolUpgradeOperation {}
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct PoolRestartOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub action: String,
+    //start, cancel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datetime: Option<String>
+}
+
+impl PoolRestartOperation {
+    pub fn new(action: &str, datetime: Option<&str>) -> PoolRestartOperation {
+        PoolRestartOperation {
+            _type: POOL_RESTART.to_string(),
+            action: action.to_string(),
+            datetime: datetime.map(String::from)
+        }
+    }
+}
+
+impl JsonEncodable for PoolRestartOperation {}
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct GetValidatorInfoOperation {
+    #[serde(rename = "type")]
+    pub _type: String
+}
+
+impl GetValidatorInfoOperation {
+    pub fn new() -> GetValidatorInfoOperation {
+        GetValidatorInfoOperation {
+            _type: GET_VALIDATOR_INFO.to_string()
+        }
+    }
+}
+
+impl JsonEncodable for GetValidatorInfoOperation {}
\ No newline at end of file
@@ -1,4 +1,5 @@
 pub mod anoncreds;
+pub mod blob_storage;
 pub mod pool;
 pub mod wallet;
 pub mod ledger;
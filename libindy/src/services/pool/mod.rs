@@ -99,6 +99,18 @@ impl PoolWorkerHandler {
         }
     }
 
+    fn send_action(&mut self, cmd: &str, cmd_id: i32, nodes: Option<&Vec<String>>, timeout: Option<i32>) -> Result<(), PoolError> {
+        match self {
+            &mut PoolWorkerHandler::CatchupHandler(_) => {
+                Err(PoolError::CommonError(
+                    CommonError::InvalidState("Try send action while CatchUp.".to_string())))
+            }
+            &mut PoolWorkerHandler::TransactionHandler(ref mut ch) => {
+                ch.try_send_action(cmd, cmd_id, nodes, timeout)
+            }
+        }
+    }
+
     fn flush_requests(&mut self, status: Result<(), PoolError>) -> Result<(), PoolError> {
         match self {
             &mut PoolWorkerHandler::CatchupHandler(ref mut ch) => ch.flush_requests(status),
@@ -276,6 +288,15 @@ impl PoolWorker {
                             })
                     })?;
                 }
+                &ZMQLoopAction::ActionToSend(ref action) => {
+                    self.handler.send_action(action.request.as_str(), action.id, action.nodes.as_ref(), action.timeout).or_else(|err| {
+                        CommandExecutor::instance()
+                            .send(Command::Ledger(LedgerCommand::SubmitAck(action.id, Err(err))))
+                            .map_err(|_| {
+                                CommonError::InvalidState("Can't send ACK cmd".to_string())
+                            })
+                    })?;
+                }
                 &ZMQLoopAction::Timeout => {
                     self.handler.process_timeout()?;
                 }
@@ -317,6 +338,19 @@ impl PoolWorker {
                 actions.push(ZMQLoopAction::Terminate(id));
             } else if "refresh".eq(cmd_s.as_str()) {
                 actions.push(ZMQLoopAction::Refresh(id));
+            } else if "action".eq(cmd_s.as_str()) {
+                let request = cmd.get(2).and_then(|request| String::from_utf8(request.clone()).ok())
+                    .ok_or(CommonError::InvalidState("Invalid action received".to_string()))?;
+                let nodes = cmd.get(3)
+                    .and_then(|nodes| serde_json::from_slice::<Vec<String>>(nodes.as_slice()).ok());
+                let timeout = cmd.get(4).map(|timeout| LittleEndian::read_i32(timeout.as_slice()))
+                    .and_then(|timeout| if timeout < 0 { None } else { Some(timeout) });
+                actions.push(ZMQLoopAction::ActionToSend(ActionToSend {
+                    id: id,
+                    request: request,
+                    nodes: nodes,
+                    timeout: timeout,
+                }));
             } else {
                 actions.push(ZMQLoopAction::RequestToSend(RequestToSend {
                     id: id,
@@ -435,6 +469,14 @@ impl Pool {
         Ok(self.cmd_sock.send_multipart(&[json.as_bytes(), &buf], zmq::DONTWAIT)?)
     }
 
+    pub fn send_action(&self, cmd_id: i32, json: &str, nodes: Option<&str>, timeout: Option<i32>) -> Result<(), PoolError> {
+        let mut buf = [0u8; 4];
+        LittleEndian::write_i32(&mut buf, cmd_id);
+        let mut timeout_buf = [0u8; 4];
+        LittleEndian::write_i32(&mut timeout_buf, timeout.unwrap_or(-1));
+        Ok(self.cmd_sock.send_multipart(&["action".as_bytes(), &buf, json.as_bytes(), nodes.unwrap_or("").as_bytes(), &timeout_buf], zmq::DONTWAIT)?)
+    }
+
     pub fn close(&self, cmd_id: i32) -> Result<(), PoolError> {
         let mut buf = [0u8; 4];
         LittleEndian::write_i32(&mut buf, cmd_id);
@@ -655,6 +697,23 @@ impl PoolService {
         Ok(cmd_id)
     }
 
+    pub fn send_action(&self, handle: i32, json: &str, nodes: Option<&str>, timeout: Option<i32>) -> Result<i32, PoolError> {
+        if let Some(nodes) = nodes {
+            let nodes = serde_json::from_str::<Vec<String>>(nodes)
+                .map_err(|err| CommonError::InvalidStructure(format!("Invalid nodes json: {}", err.description())))?;
+
+            if nodes.is_empty() {
+                return Err(PoolError::CommonError(CommonError::InvalidStructure("Empty nodes list".to_string())));
+            }
+        }
+
+        let cmd_id: i32 = SequenceUtils::get_next_id();
+        self.open_pools.try_borrow().map_err(CommonError::from)?
+            .get(&handle).ok_or(PoolError::InvalidHandle(format!("No pool with requested handle {}", handle)))?
+            .send_action(cmd_id, json, nodes, timeout)?;
+        Ok(cmd_id)
+    }
+
     pub fn close(&self, handle: i32) -> Result<i32, PoolError> {
         let cmd_id: i32 = SequenceUtils::get_next_id();
         self.open_pools.try_borrow_mut().map_err(CommonError::from)?
@@ -29,9 +29,12 @@ use services::ledger::constants;
 use services::ledger::merkletree::merkletree::MerkleTree;
 use self::indy_crypto::bls::Generator;
 
-const REQUESTS_FOR_STATE_PROOFS: [&'static str; 4] = [constants::GET_NYM, constants::GET_SCHEMA, constants::GET_CLAIM_DEF, constants::GET_ATTR];
+const REQUESTS_FOR_STATE_PROOFS: [&'static str; 7] = [constants::GET_NYM, constants::GET_SCHEMA, constants::GET_CLAIM_DEF, constants::GET_ATTR,
+    constants::GET_REVOC_REG_DEF, constants::GET_REVOC_REG, constants::GET_REVOC_REG_DELTA];
 const RESENDABLE_REQUEST_TIMEOUT: i64 = 1;
 const REQUEST_TIMEOUT: i64 = 100;
+const ACTION_NODE_TIMEOUT: i64 = 10;
+const NODE_REPLY_TIMEOUT: &'static str = "timeout";
 
 pub struct TransactionHandler {
     gen: Generator,
@@ -44,10 +47,18 @@ impl TransactionHandler {
     pub fn process_msg(&mut self, msg: Message, raw_msg: &String, src_ind: usize) -> Result<Option<MerkleTree>, PoolError> {
         match msg {
             Message::Reply(reply) => {
-                self.process_reply(reply.result.req_id, raw_msg);
+                if self.is_action(reply.result.req_id) {
+                    self.process_action_reply(reply.result.req_id, raw_msg, src_ind);
+                } else {
+                    self.process_reply(reply.result.req_id, raw_msg);
+                }
             }
             Message::Reject(response) | Message::ReqNACK(response) => {
-                self.process_reject(&response, raw_msg);
+                if self.is_action(response.req_id) {
+                    self.process_action_reply(response.req_id, raw_msg, src_ind);
+                } else {
+                    self.process_reject(&response, raw_msg);
+                }
             }
             _ => {
                 warn!("unhandled msg {:?}", msg);
@@ -108,6 +119,21 @@ impl TransactionHandler {
                     signature_valid
                 }
             }
+        } || {
+            TransactionHandler::parse_reply_for_txn_root_checking(&msg_result)
+                .map(|(signature, participants, value)| {
+                    debug!("TransactionHandler::process_reply: Ledger root signature is present");
+
+                    let signature_valid = state_proof::verify_proof_signature(
+                        signature,
+                        participants.as_slice(),
+                        &value,
+                        self.nodes.as_slice(), self.f, &self.gen).map_err(|err| warn!("{:?}", err)).unwrap_or(false);
+
+                    debug!("TransactionHandler::process_reply: ledger root signature_valid: {:?}", signature_valid);
+                    signature_valid
+                })
+                .unwrap_or(false)
         };
 
         debug!("TransactionHandler::process_reply: consensus_reached {}", consensus_reached);
@@ -130,6 +156,46 @@ impl TransactionHandler {
         trace!("TransactionHandler::process_reply: <<<");
     }
 
+    fn is_action(&self, req_id: u64) -> bool {
+        self.pending_commands.get(&req_id)
+            .map(|pend_cmd| pend_cmd.node_replies.is_some())
+            .unwrap_or(false)
+    }
+
+    fn process_action_reply(&mut self, req_id: u64, raw_msg: &str, src_ind: usize) {
+        trace!("TransactionHandler::process_action_reply: >>> req_id: {:?}, raw_msg: {:?}, src_ind: {:?}", req_id, raw_msg, src_ind);
+
+        let node_name = match self.nodes.get(src_ind) {
+            Some(node) => node.name.clone(),
+            None => return warn!("TransactionHandler::process_action_reply: <<< Unknown node index {}", src_ind)
+        };
+
+        let all_replied = match self.pending_commands.get_mut(&req_id).and_then(|pend_cmd| pend_cmd.node_replies.as_mut()) {
+            Some(node_replies) => {
+                if !node_replies.nodes.contains(&node_name) {
+                    return warn!("TransactionHandler::process_action_reply: <<< Unexpected reply from node {}", node_name);
+                }
+                // Node is already reported as timed out or replied before
+                if node_replies.replies.contains_key(&node_name) {
+                    return debug!("TransactionHandler::process_action_reply: <<< Skip late reply from node {}", node_name);
+                }
+                node_replies.replies.insert(node_name, raw_msg.to_string());
+                node_replies.replies.len() == node_replies.nodes.len()
+            }
+            None => return warn!("TransactionHandler::process_action_reply: <<< No pending action for request")
+        };
+
+        debug!("TransactionHandler::process_action_reply: all_replied {}", all_replied);
+
+        if all_replied {
+            if let Some(mut pend_cmd) = self.pending_commands.remove(&req_id) {
+                pend_cmd.ack_node_replies().map_err(map_err_trace!()).ok();
+            }
+        }
+
+        trace!("TransactionHandler::process_action_reply: <<<");
+    }
+
     //TODO correct handling of Reject
     fn process_reject(&mut self, response: &Response, raw_msg: &String) {
         let req_id = response.req_id;
@@ -185,6 +251,7 @@ impl TransactionHandler {
             replies: HashMap::new(),
             resendable_request: None,
             full_cmd_timeout: Some(time::now_utc().add(Duration::seconds(REQUEST_TIMEOUT))),
+            node_replies: None,
         };
 
         if REQUESTS_FOR_STATE_PROOFS.contains(&req_json["operation"]["type"].as_str().unwrap_or("")) {
@@ -207,6 +274,65 @@ impl TransactionHandler {
         Ok(())
     }
 
+    pub fn try_send_action(&mut self, req_str: &str, cmd_id: i32, nodes: Option<&Vec<String>>, timeout: Option<i32>) -> Result<(), PoolError> {
+        info!("action {:?}", req_str);
+        let req_json: SJsonValue = serde_json::from_str(req_str)
+            .map_err(|err|
+                CommonError::InvalidStructure(
+                    format!("Invalid request json: {}", err.description())))?;
+
+        let req_id: u64 = req_json["reqId"]
+            .as_u64()
+            .ok_or(CommonError::InvalidStructure("No reqId in request".to_string()))?;
+
+        if self.pending_commands.contains_key(&req_id) {
+            return Err(PoolError::CommonError(CommonError::InvalidStructure(
+                "Request with same request ID already sent".to_string())));
+        }
+
+        let nodes: Vec<String> = match nodes {
+            Some(nodes) => {
+                if let Some(unknown) = nodes.iter().find(|name| !self.nodes.iter().any(|node| node.name.eq(*name))) {
+                    return Err(PoolError::CommonError(CommonError::InvalidStructure(
+                        format!("Unknown node: {}", unknown))));
+                }
+                nodes.clone()
+            }
+            None => self.nodes.iter().map(|node| node.name.clone()).collect()
+        };
+
+        // Nothing would ever reply, so don't wait for the timeout
+        if nodes.is_empty() {
+            return Err(PoolError::CommonError(CommonError::InvalidStructure(
+                "No nodes to send the action to".to_string())));
+        }
+
+        let timeout = timeout.map(|timeout| timeout as i64).unwrap_or(ACTION_NODE_TIMEOUT);
+
+        // Every node has its own deadline, so the slow one is reported as timed out without waiting for others
+        let mut deadlines: HashMap<String, Tm> = HashMap::new();
+
+        for node in self.nodes.iter().filter(|node| nodes.contains(&node.name)) {
+            node.send_str(req_str)?;
+            deadlines.insert(node.name.clone(), time::now_utc().add(Duration::seconds(timeout)));
+        }
+
+        let new_request = CommandProcess {
+            parent_cmd_ids: vec!(cmd_id),
+            nack_cnt: 0,
+            replies: HashMap::new(),
+            resendable_request: None,
+            full_cmd_timeout: None,
+            node_replies: Some(NodeReplies {
+                nodes,
+                replies: HashMap::new(),
+                deadlines,
+            }),
+        };
+        self.pending_commands.insert(req_id, new_request);
+        Ok(())
+    }
+
     pub fn flush_requests(&mut self, status: Result<(), PoolError>) -> Result<(), PoolError> {
         match status {
             Ok(()) => {
@@ -236,7 +362,7 @@ impl TransactionHandler {
         };
 
         if !REQUESTS_FOR_STATE_PROOFS.contains(&xtype) {
-            //TODO GET_DDO, GET_TXN
+            //TODO GET_DDO, GET_TXN root is checked by parse_reply_for_txn_root_checking
             trace!("TransactionHandler::parse_reply_for_proof_checking: <<< type not supported");
             return None;
         }
@@ -326,13 +452,25 @@ impl TransactionHandler {
                     return None;
                 }
             }
+            constants::GET_REVOC_REG_DEF | constants::GET_REVOC_REG | constants::GET_REVOC_REG_DELTA => {
+                trace!("TransactionHandler::parse_reply_for_proof_checking: {:?}", xtype);
+                "".to_string()
+            }
             _ => {
                 trace!("TransactionHandler::parse_reply_for_proof_checking: <<< Unknown transaction");
                 return None;
             }
         };
 
-        let key = if let Some(dest) = json_msg["dest"].as_str().or(json_msg["origin"].as_str()) {
+        // Revocation registry state paths are built from ids instead of dest:
+        // definition is stored by its id and accumulator by "6:<revocation registry definition id>"
+        let dest = match xtype {
+            constants::GET_REVOC_REG_DEF => json_msg["id"].as_str().map(String::from),
+            constants::GET_REVOC_REG | constants::GET_REVOC_REG_DELTA => json_msg["revocRegDefId"].as_str().map(|id| format!("6:{}", id)),
+            _ => json_msg["dest"].as_str().or(json_msg["origin"].as_str()).map(String::from)
+        };
+
+        let key = if let Some(dest) = dest {
             let mut dest = if xtype == constants::GET_NYM {
                 let mut hasher = sha2::Sha256::default();
                 hasher.process(dest.as_bytes());
@@ -366,7 +504,13 @@ impl TransactionHandler {
         if let Some(data) = data {
             let mut value = json!({});
 
-            let (seq_no, time) = (json_msg["seqNo"].clone(), json_msg["txnTime"].clone());
+            // Delta reply proves the resulting accumulator entry that carries its own seqNo and txnTime
+            let (seq_no, time) = if xtype.eq(constants::GET_REVOC_REG_DELTA) {
+                (parsed_data["value"]["accum_to"]["seqNo"].clone(), parsed_data["value"]["accum_to"]["txnTime"].clone())
+            } else {
+                (json_msg["seqNo"].clone(), json_msg["txnTime"].clone())
+            };
+
             if xtype.eq(constants::GET_NYM) {
                 value["seqNo"] = seq_no;
                 value["txnTime"] = time;
@@ -388,9 +532,24 @@ impl TransactionHandler {
                     hasher.process(data.as_bytes());
                     value["val"] = SJsonValue::String(hasher.fixed_result().to_hex());
                 }
-                constants::GET_CLAIM_DEF => {
+                constants::GET_CLAIM_DEF | constants::GET_REVOC_REG_DEF | constants::GET_REVOC_REG => {
                     value["val"] = parsed_data;
                 }
+                constants::GET_REVOC_REG_DELTA => {
+                    // Only the resulting accumulator is stored in the state,
+                    // issued and revoked lists are bound to it by the accumulator value itself
+                    let mut accum_to = parsed_data["value"]["accum_to"].clone();
+
+                    match accum_to.as_object_mut() {
+                        Some(map) => {
+                            map.remove("seqNo");
+                            map.remove("txnTime");
+                        }
+                        None => return Err("Invalid data for GET_REVOC_REG_DELTA".to_string())
+                    }
+
+                    value["val"] = accum_to;
+                }
                 constants::GET_SCHEMA => {
                     if let Some(map) = parsed_data.as_object() {
                         let mut map = map.clone();
@@ -437,12 +596,26 @@ impl TransactionHandler {
         }
     }
 
+    fn parse_reply_for_txn_root_checking(json_msg: &SJsonValue) -> Option<(&str, Vec<&str>, Vec<u8>)> {
+        if json_msg["type"].as_str() != Some(constants::GET_TXN) {
+            return None;
+        }
+
+        match json_msg["data"]["rootHash"].as_str() {
+            Some(root_hash) if json_msg["state_proof"]["multi_signature"]["value"]["txn_root_hash"].as_str() == Some(root_hash) =>
+                TransactionHandler::parse_reply_for_proof_signature_checking(json_msg),
+            _ => None
+        }
+    }
+
     pub fn get_upcoming_timeout(&self) -> Option<time::Tm> {
         self.pending_commands.iter().fold(None, |acc, (_, ref cur)| {
             let resend_tm: Option<Tm> = cur.resendable_request.as_ref()
                 .and_then(|resend: &ResendableRequest| resend.next_try_send_time);
             let full_tm = cur.full_cmd_timeout;
-            let tms = [resend_tm, full_tm, acc];
+            let node_tm: Option<Tm> = cur.node_replies.as_ref()
+                .and_then(|node_replies| node_replies.next_deadline());
+            let tms = [resend_tm, full_tm, node_tm, acc];
             tms.iter().fold(None, |acc, cur| {
                 match (acc, *cur) {
                     (None, cur) => cur,
@@ -454,9 +627,11 @@ impl TransactionHandler {
     }
 
     pub fn process_timeout(&mut self) -> Result<(), PoolError> {
+        let now = time::now_utc();
+
         let timeout_cmds: Vec<u64> = self.pending_commands.iter_mut()
             .filter(|&(_, ref cur)| match cur.full_cmd_timeout {
-                Some(tm) => tm <= time::now_utc(),
+                Some(tm) => tm <= now,
                 None => false
             })
             .map(|(k, cmd)| {
@@ -467,6 +642,27 @@ impl TransactionHandler {
             self.pending_commands.remove(&cmd);
         }
 
+        let completed_actions: Vec<u64> = self.pending_commands.iter_mut()
+            .filter_map(|(k, cmd)| {
+                let all_replied = match cmd.node_replies {
+                    Some(ref mut node_replies) => {
+                        node_replies.mark_timed_out(now);
+                        node_replies.replies.len() == node_replies.nodes.len()
+                    }
+                    None => false
+                };
+
+                if all_replied {
+                    cmd.ack_node_replies().map_err(map_err_trace!()).ok();
+                    Some(*k)
+                } else {
+                    None
+                }
+            }).collect();
+        for cmd in completed_actions {
+            self.pending_commands.remove(&cmd);
+        }
+
         for (_, pc) in &mut self.pending_commands {
             let is_timeout = pc.resendable_request.as_ref()
                 .and_then(|resend| resend.next_try_send_time)
@@ -521,6 +717,48 @@ impl CommandProcess {
         self.parent_cmd_ids.clear();
         Ok(())
     }
+
+    fn ack_node_replies(&mut self) -> Result<(), CommonError> {
+        let replies: HashMap<String, String> = match self.node_replies {
+            Some(ref node_replies) => node_replies.nodes.iter()
+                .map(|name| (name.clone(), node_replies.replies.get(name).cloned().unwrap_or(NodeReplies::timeout_reply(name))))
+                .collect(),
+            None => return Err(CommonError::InvalidState("Command doesn't collect node replies".to_string()))
+        };
+
+        let replies = serde_json::to_string(&replies)
+            .map_err(|err| CommonError::InvalidState(format!("Can't serialize node replies: {}", err.description())))?;
+
+        for cmd_id in &self.parent_cmd_ids {
+            CommandExecutor::instance()
+                .send(Command::Ledger(LedgerCommand::SubmitAck(*cmd_id, Ok(replies.clone()))))
+                .map_err(|err| CommonError::InvalidState("Can't send ACK cmd".to_string()))?;
+        }
+        self.parent_cmd_ids.clear();
+        Ok(())
+    }
+}
+
+impl NodeReplies {
+    fn next_deadline(&self) -> Option<Tm> {
+        self.deadlines.iter()
+            .filter(|&(name, _)| !self.replies.contains_key(name))
+            .map(|(_, deadline)| *deadline)
+            .min()
+    }
+
+    fn mark_timed_out(&mut self, now: Tm) {
+        for (name, deadline) in self.deadlines.iter() {
+            if *deadline <= now && !self.replies.contains_key(name) {
+                debug!("NodeReplies::mark_timed_out: node {} hasn't replied in time", name);
+                self.replies.insert(name.clone(), NodeReplies::timeout_reply(name));
+            }
+        }
+    }
+
+    fn timeout_reply(name: &str) -> String {
+        json!({"node": name, "error": NODE_REPLY_TIMEOUT}).to_string()
+    }
 }
 
 #[cfg(test)]
@@ -538,6 +776,7 @@ mod tests {
             nack_cnt: 0,
             resendable_request: None,
             full_cmd_timeout: None,
+            node_replies: None,
         };
         let json = json!({"value":1});
         pc.replies.insert(HashableValue { inner: json.clone() }, 1);
@@ -560,6 +799,7 @@ mod tests {
             nack_cnt: 0,
             resendable_request: None,
             full_cmd_timeout: None,
+            node_replies: None,
         };
         let json1 = json!({"value":1});
         let json2 = json!({"value":2});
@@ -593,10 +833,223 @@ mod tests {
             parent_cmd_ids: vec!(cmd_id),
             resendable_request: None,
             full_cmd_timeout: pending_cmd.full_cmd_timeout /* just copy for eq check other fields*/,
+            node_replies: None,
         };
         assert_eq!(pending_cmd, &exp_command_process);
         let diff: Duration = expected_timeout.sub(pending_cmd.full_cmd_timeout.unwrap());
         assert!(diff <= Duration::milliseconds(10));
         assert!(diff >= Duration::zero());
     }
+
+    #[test]
+    fn transaction_handler_try_send_action_works_for_no_nodes() {
+        let mut th: TransactionHandler = Default::default();
+
+        let cmd = "{\"reqId\": 2}";
+
+        let res = th.try_send_action(cmd, 1, None, Some(5));
+        assert_match!(Err(PoolError::CommonError(CommonError::InvalidStructure(_))), res);
+        assert_eq!(th.pending_commands.len(), 0);
+    }
+
+    #[test]
+    fn transaction_handler_try_send_action_works_for_unknown_node() {
+        let mut th: TransactionHandler = Default::default();
+        th.nodes.push(_remote_node("Node1"));
+
+        let cmd = "{\"reqId\": 2}";
+
+        let res = th.try_send_action(cmd, 1, Some(&vec!["Node5".to_string()]), None);
+        assert_match!(Err(PoolError::CommonError(CommonError::InvalidStructure(_))), res);
+        assert_eq!(th.pending_commands.len(), 0);
+    }
+
+    #[test]
+    fn transaction_handler_process_action_reply_works() {
+        let mut th: TransactionHandler = Default::default();
+        th.nodes.push(_remote_node("Node1"));
+        th.nodes.push(_remote_node("Node2"));
+        let pc = CommandProcess {
+            parent_cmd_ids: Vec::new(),
+            replies: HashMap::new(),
+            nack_cnt: 0,
+            resendable_request: None,
+            full_cmd_timeout: None,
+            node_replies: Some(NodeReplies {
+                nodes: vec!["Node1".to_string(), "Node2".to_string()],
+                replies: HashMap::new(),
+                deadlines: HashMap::new(),
+            }),
+        };
+        let req_id = 1;
+        th.pending_commands.insert(req_id, pc);
+        let reply = json!({"op": "REPLY", "result": {"reqId": req_id}}).to_string();
+
+        th.process_action_reply(req_id, &reply, 1);
+
+        assert!(th.is_action(req_id));
+        let node_replies = th.pending_commands.get(&req_id).unwrap().node_replies.as_ref().unwrap();
+        assert_eq!(node_replies.replies.get("Node2").unwrap(), &reply);
+
+        th.process_action_reply(req_id, &reply, 0);
+
+        assert_eq!(th.pending_commands.len(), 0);
+    }
+
+    #[test]
+    fn transaction_handler_process_timeout_works_for_timed_out_action_node() {
+        let mut th: TransactionHandler = Default::default();
+        th.nodes.push(_remote_node("Node1"));
+        th.nodes.push(_remote_node("Node2"));
+        th.nodes.push(_remote_node("Node3"));
+
+        let mut deadlines = HashMap::new();
+        deadlines.insert("Node1".to_string(), time::now_utc().add(Duration::seconds(100)));
+        deadlines.insert("Node2".to_string(), time::now_utc().sub(Duration::seconds(1)));
+        deadlines.insert("Node3".to_string(), time::now_utc().add(Duration::seconds(100)));
+
+        let pc = CommandProcess {
+            parent_cmd_ids: Vec::new(),
+            replies: HashMap::new(),
+            nack_cnt: 0,
+            resendable_request: None,
+            full_cmd_timeout: None,
+            node_replies: Some(NodeReplies {
+                nodes: vec!["Node1".to_string(), "Node2".to_string(), "Node3".to_string()],
+                replies: HashMap::new(),
+                deadlines,
+            }),
+        };
+        let req_id = 1;
+        th.pending_commands.insert(req_id, pc);
+        let reply = json!({"op": "REPLY", "result": {"reqId": req_id}}).to_string();
+
+        th.process_action_reply(req_id, &reply, 0);
+        th.process_timeout().unwrap();
+
+        {
+            let node_replies = th.pending_commands.get(&req_id).unwrap().node_replies.as_ref().unwrap();
+            assert_eq!(node_replies.replies.get("Node1").unwrap(), &reply);
+            let timeout_reply: serde_json::Value = serde_json::from_str(node_replies.replies.get("Node2").unwrap()).unwrap();
+            assert_eq!(json!({"node": "Node2", "error": "timeout"}), timeout_reply);
+            assert!(!node_replies.replies.contains_key("Node3"));
+        }
+
+        th.process_action_reply(req_id, &reply, 1);
+        assert_eq!(th.get_upcoming_timeout(), th.pending_commands.get(&req_id).unwrap().node_replies.as_ref().unwrap().deadlines.get("Node3").cloned());
+
+        th.process_action_reply(req_id, &reply, 2);
+        assert_eq!(th.pending_commands.len(), 0);
+    }
+
+    fn _remote_node(name: &str) -> RemoteNode {
+        RemoteNode {
+            name: name.to_string(),
+            public_key: Vec::new(),
+            zaddr: String::new(),
+            zsock: None,
+            is_blacklisted: false,
+            blskey: None,
+        }
+    }
+
+    #[test]
+    fn transaction_handler_parse_reply_for_proof_checking_works_for_get_revoc_reg_def() {
+        let id = "NcYxiDXkpYi6ov5FcYDi1e:4:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:CL_ACCUM:tag1";
+        let json_msg = json!({
+            "type": constants::GET_REVOC_REG_DEF,
+            "id": id,
+            "seqNo": 10,
+            "txnTime": 90,
+            "data": {"id": id, "revocDefType": "CL_ACCUM"},
+            "state_proof": {"proof_nodes": "proof", "root_hash": "root_hash"}
+        });
+
+        let (proof, root_hash, key, value) = TransactionHandler::parse_reply_for_proof_checking(&json_msg).unwrap();
+
+        assert_eq!("proof", proof);
+        assert_eq!("root_hash", root_hash);
+        assert_eq!(id.as_bytes().to_vec(), key);
+        let value: SJsonValue = serde_json::from_str(&value.unwrap()).unwrap();
+        assert_eq!(json!({"lsn": 10, "lut": 90, "val": {"id": id, "revocDefType": "CL_ACCUM"}}), value);
+    }
+
+    #[test]
+    fn transaction_handler_parse_reply_for_proof_checking_works_for_get_revoc_reg() {
+        let id = "NcYxiDXkpYi6ov5FcYDi1e:4:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:CL_ACCUM:tag1";
+        let json_msg = json!({
+            "type": constants::GET_REVOC_REG,
+            "revocRegDefId": id,
+            "seqNo": 10,
+            "txnTime": 90,
+            "data": {"revocRegDefId": id, "value": {"accum": "1"}},
+            "state_proof": {"proof_nodes": "proof", "root_hash": "root_hash"}
+        });
+
+        let (_, _, key, _) = TransactionHandler::parse_reply_for_proof_checking(&json_msg).unwrap();
+
+        assert_eq!(format!("6:{}", id).as_bytes().to_vec(), key);
+    }
+
+    #[test]
+    fn transaction_handler_parse_reply_for_proof_checking_works_for_get_revoc_reg_delta() {
+        let id = "NcYxiDXkpYi6ov5FcYDi1e:4:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:CL_ACCUM:tag1";
+        let json_msg = json!({
+            "type": constants::GET_REVOC_REG_DELTA,
+            "revocRegDefId": id,
+            "data": {
+                "revocDefType": "CL_ACCUM",
+                "revocRegDefId": id,
+                "value": {
+                    "accum_from": {"revocRegDefId": id, "value": {"accum": "1"}, "seqNo": 5, "txnTime": 50},
+                    "accum_to": {"revocRegDefId": id, "value": {"accum": "2"}, "seqNo": 10, "txnTime": 90},
+                    "issued": [],
+                    "revoked": [3]
+                }
+            },
+            "state_proof": {"proof_nodes": "proof", "root_hash": "root_hash"}
+        });
+
+        let (_, _, key, value) = TransactionHandler::parse_reply_for_proof_checking(&json_msg).unwrap();
+
+        assert_eq!(format!("6:{}", id).as_bytes().to_vec(), key);
+        let value: SJsonValue = serde_json::from_str(&value.unwrap()).unwrap();
+        assert_eq!(json!({"lsn": 10, "lut": 90, "val": {"revocRegDefId": id, "value": {"accum": "2"}}}), value);
+    }
+
+    #[test]
+    fn transaction_handler_parse_reply_for_txn_root_checking_works() {
+        let json_msg = json!({
+            "type": constants::GET_TXN,
+            "data": {"type": "1", "rootHash": "root_hash", "ledgerSize": 2, "auditPath": []},
+            "state_proof": {
+                "multi_signature": {
+                    "signature": "signature",
+                    "participants": ["Node1", "Node2", "Node3"],
+                    "value": {"ledger_id": 1, "txn_root_hash": "root_hash"}
+                }
+            }
+        });
+
+        let (signature, participants, _) = TransactionHandler::parse_reply_for_txn_root_checking(&json_msg).unwrap();
+        assert_eq!("signature", signature);
+        assert_eq!(vec!["Node1", "Node2", "Node3"], participants);
+    }
+
+    #[test]
+    fn transaction_handler_parse_reply_for_txn_root_checking_works_for_other_signed_root() {
+        let json_msg = json!({
+            "type": constants::GET_TXN,
+            "data": {"type": "1", "rootHash": "root_hash", "ledgerSize": 2, "auditPath": []},
+            "state_proof": {
+                "multi_signature": {
+                    "signature": "signature",
+                    "participants": ["Node1", "Node2", "Node3"],
+                    "value": {"ledger_id": 1, "txn_root_hash": "other_root_hash"}
+                }
+            }
+        });
+
+        assert!(TransactionHandler::parse_reply_for_txn_root_checking(&json_msg).is_none());
+    }
 }
@@ -307,11 +307,20 @@ pub struct CommandProcess {
     pub parent_cmd_ids: Vec<i32>,
     pub resendable_request: Option<ResendableRequest>,
     pub full_cmd_timeout: Option<time::Tm>,
+    pub node_replies: Option<NodeReplies>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct NodeReplies {
+    pub nodes: Vec<String>,
+    pub replies: HashMap<String /* node name */, String /* raw reply */>,
+    pub deadlines: HashMap<String /* node name */, time::Tm>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum ZMQLoopAction {
     RequestToSend(RequestToSend),
+    ActionToSend(ActionToSend),
     MessageToProcess(MessageToProcess),
     Terminate(i32),
     Refresh(i32),
@@ -324,6 +333,14 @@ pub struct RequestToSend {
     pub id: i32,
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub struct ActionToSend {
+    pub request: String,
+    pub id: i32,
+    pub nodes: Option<Vec<String>>,
+    pub timeout: Option<i32>,
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct MessageToProcess {
     pub message: String,
@@ -9,6 +9,7 @@ use errors::wallet::WalletError;
 use utils::environment::EnvironmentUtils;
 
 use self::rusqlcipher::Connection;
+use self::rusqlcipher::types::ToSql;
 use self::time::Timespec;
 
 use std::error::Error;
@@ -129,6 +130,46 @@ impl Wallet for DefaultWallet {
         Ok(key_values)
     }
 
+    fn list_page(&self, key_prefix: &str, from_key: Option<&str>, limit: usize) -> Result<Vec<(String, String)>, WalletError> {
+        if self.credentials.rekey.is_some() {
+            return Err(WalletError::CommonError(CommonError::InvalidStructure(format!("Invalid wallet credentials json"))));
+        }
+
+        // Prefix is matched as key range instead of LIKE pattern, so scan over primary key uses index
+        let connection = _open_connection(self.name.as_str(), &self.credentials)?;
+        let key_prefix = key_prefix.to_string();
+        let from_key = from_key.unwrap_or("").to_string();
+        let limit = limit as i64;
+        let upper_bound = _prefix_upper_bound(&key_prefix);
+
+        let mut params: Vec<&ToSql> = vec![&key_prefix, &from_key, &limit];
+        let sql = match upper_bound {
+            Some(ref upper_bound) => {
+                params.push(upper_bound);
+                "SELECT key, value, time_created FROM wallet WHERE key >= ?1 AND key > ?2 AND key < ?4 order by key LIMIT ?3"
+            }
+            None => "SELECT key, value, time_created FROM wallet WHERE key >= ?1 AND key > ?2 order by key LIMIT ?3"
+        };
+
+        let mut stmt = connection.prepare(sql)?;
+        let records = stmt.query_map(&params, |row| {
+            DefaultWalletRecord {
+                key: row.get(0),
+                value: row.get(1),
+                time_created: row.get(2)
+            }
+        })?;
+
+        let mut key_values = Vec::new();
+
+        for record in records {
+            let key_value = record?;
+            key_values.push((key_value.key, key_value.value));
+        }
+
+        Ok(key_values)
+    }
+
     fn get_not_expired(&self, key: &str) -> Result<String, WalletError> {
         if self.credentials.rekey.is_some() {
             return Err(WalletError::CommonError(CommonError::InvalidStructure(format!("Invalid wallet credentials json"))));
@@ -153,6 +194,23 @@ impl Wallet for DefaultWallet {
         return Ok(record.value);
     }
 
+    fn delete(&self, key: &str) -> Result<(), WalletError> {
+        if self.credentials.rekey.is_some() {
+            return Err(WalletError::CommonError(CommonError::InvalidStructure(format!("Invalid wallet credentials json"))));
+        }
+
+        let deleted = _open_connection(self.name.as_str(), &self.credentials)?
+            .execute(
+                "DELETE FROM wallet WHERE key = ?1",
+                &[&key.to_string()])?;
+
+        if deleted == 0 {
+            return Err(WalletError::NotFound(key.to_string()));
+        }
+
+        Ok(())
+    }
+
     fn close(&self) -> Result<(), WalletError> { Ok(()) }
 
     fn get_pool_name(&self) -> String {
@@ -238,6 +296,24 @@ fn _db_path(name: &str) -> PathBuf {
     path
 }
 
+/// Returns the least string greater than all strings starting with `prefix`
+/// or None if there is no such string (empty prefix).
+fn _prefix_upper_bound(prefix: &str) -> Option<String> {
+    let mut chars: Vec<char> = prefix.chars().collect();
+
+    while let Some(last) = chars.pop() {
+        // Surrogates aren't chars, so next char after them is U+E000
+        let next = if last as u32 == 0xD7FF { 0xE000 } else { last as u32 + 1 };
+
+        if let Some(next) = ::std::char::from_u32(next) {
+            chars.push(next);
+            return Some(chars.into_iter().collect());
+        }
+    }
+
+    None
+}
+
 fn _open_connection(name: &str, credentials: &DefaultWalletCredentials) -> Result<Connection, WalletError> {
     let path = _db_path(name);
     if !path.parent().unwrap().exists() {
@@ -464,6 +540,26 @@ mod tests {
         TestUtils::cleanup_indy_home();
     }
 
+    #[test]
+    fn default_wallet_delete_works() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        wallet.set("key1", "value1").unwrap();
+        wallet.delete("key1").unwrap();
+
+        let value = wallet.get("key1");
+        assert_match!(Err(WalletError::NotFound(_)), value);
+
+        let res = wallet.delete("key1");
+        assert_match!(Err(WalletError::NotFound(_)), res);
+
+        TestUtils::cleanup_indy_home();
+    }
+
     #[test]
     fn default_wallet_list_works() {
         TestUtils::cleanup_indy_home();
@@ -490,6 +586,57 @@ mod tests {
         TestUtils::cleanup_indy_home();
     }
 
+    #[test]
+    fn default_wallet_list_page_works() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        wallet.set("key1::subkey3", "value3").unwrap();
+        wallet.set("key1::subkey1", "value1").unwrap();
+        wallet.set("key1::subkey2", "value2").unwrap();
+        wallet.set("key2::subkey1", "value4").unwrap();
+
+        let key_values = wallet.list_page("key1::", None, 2).unwrap();
+        assert_eq!(vec![("key1::subkey1".to_string(), "value1".to_string()),
+                        ("key1::subkey2".to_string(), "value2".to_string())], key_values);
+
+        let key_values = wallet.list_page("key1::", Some("key1::subkey2"), 2).unwrap();
+        assert_eq!(vec![("key1::subkey3".to_string(), "value3".to_string())], key_values);
+
+        let key_values = wallet.list_page("key1::", Some("key1::subkey3"), 2).unwrap();
+        assert!(key_values.is_empty());
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn default_wallet_list_page_works_for_prefix_with_like_wildcards() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        wallet.set("claim_info::1", "value1").unwrap();
+        wallet.set("claimXinfo::2", "value2").unwrap();
+        wallet.set("CLAIM_INFO::3", "value3").unwrap();
+
+        let key_values = wallet.list_page("claim_info::", None, 10).unwrap();
+        assert_eq!(vec![("claim_info::1".to_string(), "value1".to_string())], key_values);
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn prefix_upper_bound_works() {
+        assert_eq!(Some("key;".to_string()), _prefix_upper_bound("key:"));
+        assert_eq!(Some("l".to_string()), _prefix_upper_bound("k\u{10FFFF}"));
+        assert_eq!(None, _prefix_upper_bound(""));
+    }
+
     #[test]
     fn default_wallet_get_pool_name_works() {
         TestUtils::cleanup_indy_home();
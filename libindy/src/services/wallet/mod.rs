@@ -26,7 +26,19 @@ pub trait Wallet {
     fn set(&self, key: &str, value: &str) -> Result<(), WalletError>;
     fn get(&self, key: &str) -> Result<String, WalletError>;
     fn list(&self, key_prefix: &str) -> Result<Vec<(String, String)>, WalletError>;
+    /// Returns up to `limit` records with keys greater than `from_key` ordered by key.
+    /// Wallets that can't query by key range read full list anyway, so they return
+    /// all remaining records at once and callers must not expect page to be limited.
+    fn list_page(&self, key_prefix: &str, from_key: Option<&str>, _limit: usize) -> Result<Vec<(String, String)>, WalletError> {
+        let mut key_values = self.list(key_prefix)?;
+        key_values.sort();
+
+        Ok(key_values.into_iter()
+            .filter(|&(ref key, _)| from_key.map(|from_key| key.as_str() > from_key).unwrap_or(true))
+            .collect())
+    }
     fn get_not_expired(&self, key: &str) -> Result<String, WalletError>;
+    fn delete(&self, key: &str) -> Result<(), WalletError>;
     fn close(&self) -> Result<(), WalletError>;
     fn get_pool_name(&self) -> String;
     fn get_name(&self) -> String;
@@ -119,6 +131,8 @@ impl WalletService {
                          list: extern fn(handle: i32,
                                          key_prefix: *const c_char,
                                          values_json_ptr: *mut *const c_char) -> ErrorCode,
+                         remove: Option<extern fn(handle: i32,
+                                                  key: *const c_char) -> ErrorCode>,
                          close: extern fn(handle: i32) -> ErrorCode,
                          delete: extern fn(name: *const c_char,
                                            config: *const c_char,
@@ -134,7 +148,7 @@ impl WalletService {
         wallet_types.insert(xtype.to_string(),
                             Box::new(
                                 PluggedWalletType::new(create, open, set, get,
-                                                       get_not_expired, list, close, delete, free)));
+                                                       get_not_expired, list, remove, close, delete, free)));
         Ok(())
     }
 
@@ -301,6 +315,13 @@ impl WalletService {
         }
     }
 
+    pub fn list_page(&self, handle: i32, key_prefix: &str, from_key: Option<&str>, limit: usize) -> Result<Vec<(String, String)>, WalletError> {
+        match self.wallets.borrow().get(&handle) {
+            Some(wallet) => wallet.list_page(key_prefix, from_key, limit),
+            None => Err(WalletError::InvalidHandle(handle.to_string()))
+        }
+    }
+
     pub fn get_not_expired(&self, handle: i32, key: &str) -> Result<String, WalletError> {
         match self.wallets.borrow().get(&handle) {
             Some(wallet) => wallet.get_not_expired(key),
@@ -308,6 +329,13 @@ impl WalletService {
         }
     }
 
+    pub fn remove(&self, handle: i32, key: &str) -> Result<(), WalletError> {
+        match self.wallets.borrow().get(&handle) {
+            Some(wallet) => wallet.delete(key),
+            None => Err(WalletError::InvalidHandle(handle.to_string()))
+        }
+    }
+
     pub fn get_pool_name(&self, handle: i32) -> Result<String, WalletError> {
         match self.wallets.borrow().get(&handle) {
             Some(wallet) => Ok(wallet.get_pool_name()),
@@ -360,6 +388,7 @@ mod tests {
                 InmemWallet::get,
                 InmemWallet::get_not_expired,
                 InmemWallet::list,
+                Some(InmemWallet::remove),
                 InmemWallet::close,
                 InmemWallet::delete,
                 InmemWallet::free
@@ -396,6 +425,7 @@ mod tests {
                 InmemWallet::get,
                 InmemWallet::get_not_expired,
                 InmemWallet::list,
+                Some(InmemWallet::remove),
                 InmemWallet::close,
                 InmemWallet::delete,
                 InmemWallet::free
@@ -470,6 +500,7 @@ mod tests {
                 InmemWallet::get,
                 InmemWallet::get_not_expired,
                 InmemWallet::list,
+                Some(InmemWallet::remove),
                 InmemWallet::close,
                 InmemWallet::delete,
                 InmemWallet::free
@@ -511,6 +542,7 @@ mod tests {
                 InmemWallet::get,
                 InmemWallet::get_not_expired,
                 InmemWallet::list,
+                Some(InmemWallet::remove),
                 InmemWallet::close,
                 InmemWallet::delete,
                 InmemWallet::free
@@ -539,6 +571,7 @@ mod tests {
                 InmemWallet::get,
                 InmemWallet::get_not_expired,
                 InmemWallet::list,
+                Some(InmemWallet::remove),
                 InmemWallet::close,
                 InmemWallet::delete,
                 InmemWallet::free
@@ -610,6 +643,7 @@ mod tests {
                 InmemWallet::get,
                 InmemWallet::get_not_expired,
                 InmemWallet::list,
+                Some(InmemWallet::remove),
                 InmemWallet::close,
                 InmemWallet::delete,
                 InmemWallet::free
@@ -655,6 +689,7 @@ mod tests {
                 InmemWallet::get,
                 InmemWallet::get_not_expired,
                 InmemWallet::list,
+                Some(InmemWallet::remove),
                 InmemWallet::close,
                 InmemWallet::delete,
                 InmemWallet::free
@@ -720,6 +755,7 @@ mod tests {
                 InmemWallet::get,
                 InmemWallet::get_not_expired,
                 InmemWallet::list,
+                Some(InmemWallet::remove),
                 InmemWallet::close,
                 InmemWallet::delete,
                 InmemWallet::free
@@ -751,6 +787,7 @@ mod tests {
                 InmemWallet::get,
                 InmemWallet::get_not_expired,
                 InmemWallet::list,
+                Some(InmemWallet::remove),
                 InmemWallet::close,
                 InmemWallet::delete,
                 InmemWallet::free
@@ -842,6 +879,7 @@ mod tests {
                 InmemWallet::get,
                 InmemWallet::get_not_expired,
                 InmemWallet::list,
+                Some(InmemWallet::remove),
                 InmemWallet::close,
                 InmemWallet::delete,
                 InmemWallet::free
@@ -875,6 +913,7 @@ mod tests {
                 InmemWallet::get,
                 InmemWallet::get_not_expired,
                 InmemWallet::list,
+                Some(InmemWallet::remove),
                 InmemWallet::close,
                 InmemWallet::delete,
                 InmemWallet::free
@@ -921,6 +960,65 @@ mod tests {
         TestUtils::cleanup_indy_home();
     }
 
+    #[test]
+    fn wallet_service_list_page_works() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_service = WalletService::new();
+        wallet_service.create("pool1", None, "wallet1", None, None).unwrap();
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+
+        wallet_service.set(wallet_handle, "key1::subkey1", "value1").unwrap();
+        wallet_service.set(wallet_handle, "key1::subkey2", "value2").unwrap();
+        wallet_service.set(wallet_handle, "key1::subkey3", "value3").unwrap();
+        wallet_service.set(wallet_handle, "key2::subkey1", "value4").unwrap();
+
+        let key_values = wallet_service.list_page(wallet_handle, "key1::", None, 2).unwrap();
+        assert_eq!(vec![("key1::subkey1".to_string(), "value1".to_string()),
+                        ("key1::subkey2".to_string(), "value2".to_string())], key_values);
+
+        let key_values = wallet_service.list_page(wallet_handle, "key1::", Some("key1::subkey2"), 2).unwrap();
+        assert_eq!(vec![("key1::subkey3".to_string(), "value3".to_string())], key_values);
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn wallet_service_list_page_works_for_plugged() {
+        TestUtils::cleanup_indy_home();
+        InmemWallet::cleanup();
+
+        let wallet_service = WalletService::new();
+        wallet_service
+            .register_type(
+                "inmem",
+                InmemWallet::create,
+                InmemWallet::open,
+                InmemWallet::set,
+                InmemWallet::get,
+                InmemWallet::get_not_expired,
+                InmemWallet::list,
+                Some(InmemWallet::remove),
+                InmemWallet::close,
+                InmemWallet::delete,
+                InmemWallet::free
+            )
+            .unwrap();
+        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+
+        wallet_service.set(wallet_handle, "key1::subkey1", "value1").unwrap();
+        wallet_service.set(wallet_handle, "key1::subkey2", "value2").unwrap();
+        wallet_service.set(wallet_handle, "key1::subkey3", "value3").unwrap();
+
+        let key_values = wallet_service.list_page(wallet_handle, "key1::", Some("key1::subkey1"), 1).unwrap();
+        assert_eq!(vec![("key1::subkey2".to_string(), "value2".to_string()),
+                        ("key1::subkey3".to_string(), "value3".to_string())], key_values);
+
+        TestUtils::cleanup_indy_home();
+        InmemWallet::cleanup();
+    }
+
     #[test]
     fn wallet_service_list_works_for_plugged() {
         TestUtils::cleanup_indy_home();
@@ -937,6 +1035,7 @@ mod tests {
                 InmemWallet::get,
                 InmemWallet::get_not_expired,
                 InmemWallet::list,
+                Some(InmemWallet::remove),
                 InmemWallet::close,
                 InmemWallet::delete,
                 InmemWallet::free
@@ -965,6 +1064,59 @@ mod tests {
         InmemWallet::cleanup();
     }
 
+    #[test]
+    fn wallet_service_remove_works() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_service = WalletService::new();
+        wallet_service.create("pool1", None, "wallet1", None, None).unwrap();
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+
+        wallet_service.set(wallet_handle, "key1", "value1").unwrap();
+        wallet_service.remove(wallet_handle, "key1").unwrap();
+
+        assert_match!(Err(WalletError::NotFound(_)), wallet_service.get(wallet_handle, "key1"));
+        assert_match!(Err(WalletError::NotFound(_)), wallet_service.remove(wallet_handle, "key1"));
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn wallet_service_remove_works_for_plugged() {
+        TestUtils::cleanup_indy_home();
+        InmemWallet::cleanup();
+
+        let wallet_service = WalletService::new();
+
+        wallet_service
+            .register_type(
+                "inmem",
+                InmemWallet::create,
+                InmemWallet::open,
+                InmemWallet::set,
+                InmemWallet::get,
+                InmemWallet::get_not_expired,
+                InmemWallet::list,
+                Some(InmemWallet::remove),
+                InmemWallet::close,
+                InmemWallet::delete,
+                InmemWallet::free
+            )
+            .unwrap();
+
+        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+
+        wallet_service.set(wallet_handle, "key1", "value1").unwrap();
+        wallet_service.remove(wallet_handle, "key1").unwrap();
+
+        assert_match!(Err(WalletError::PluggedWallerError(ErrorCode::WalletNotFoundError)), wallet_service.get(wallet_handle, "key1"));
+        assert_match!(Err(WalletError::NotFound(_)), wallet_service.remove(wallet_handle, "key1"));
+
+        TestUtils::cleanup_indy_home();
+        InmemWallet::cleanup();
+    }
+
     #[test]
     fn wallet_service_get_pool_name_works() {
         TestUtils::cleanup_indy_home();
@@ -996,6 +1148,7 @@ mod tests {
                 InmemWallet::get,
                 InmemWallet::get_not_expired,
                 InmemWallet::list,
+                Some(InmemWallet::remove),
                 InmemWallet::close,
                 InmemWallet::delete,
                 InmemWallet::free
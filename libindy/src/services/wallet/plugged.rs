@@ -45,6 +45,8 @@ struct PluggedWallet {
     list_handler: extern fn(handle: i32,
                             key_prefix: *const c_char,
                             values_json_ptr: *mut *const c_char) -> ErrorCode,
+    remove_handler: Option<extern fn(handle: i32,
+                                     key: *const c_char) -> ErrorCode>,
     close_handler: extern fn(handle: i32) -> ErrorCode,
     free_handler: extern fn(handle: i32,
                             value: *const c_char) -> ErrorCode
@@ -66,6 +68,8 @@ impl PluggedWallet {
            list_handler: extern fn(xhandle: i32,
                                    key_prefix: *const c_char,
                                    values_json_ptr: *mut *const c_char) -> ErrorCode,
+           remove_handler: Option<extern fn(xhandle: i32,
+                                            key: *const c_char) -> ErrorCode>,
            close_handler: extern fn(xhandle: i32) -> ErrorCode,
            free_handler: extern fn(xhandle: i32,
                                    value: *const c_char) -> ErrorCode) -> PluggedWallet {
@@ -77,6 +81,7 @@ impl PluggedWallet {
             get_handler: get_handler,
             list_handler: list_handler,
             get_not_expired_handler: get_not_expired_handler,
+            remove_handler: remove_handler,
             close_handler: close_handler,
             free_handler: free_handler
         }
@@ -180,6 +185,25 @@ impl Wallet for PluggedWallet {
         Ok(result)
     }
 
+    fn delete(&self, key: &str) -> Result<(), WalletError> {
+        // Wallet type can be registered without records remove handler
+        let remove_handler = self.remove_handler
+            .ok_or(WalletError::CommonError(
+                CommonError::InvalidState(format!("Deletion of {} record isn't supported by plugged wallet", key))))?;
+
+        let ckey = CString::new(key)?;
+
+        let err = remove_handler(self.handle,
+                                 ckey.as_ptr());
+
+        // Callers use NotFound to skip already removed records the same way as for default wallet
+        match err {
+            ErrorCode::Success => Ok(()),
+            ErrorCode::WalletNotFoundError => Err(WalletError::NotFound(key.to_string())),
+            err => Err(WalletError::PluggedWallerError(err))
+        }
+    }
+
     fn close(&self) -> Result<(), WalletError> {
         let err = (self.close_handler)(self.handle);
 
@@ -220,6 +244,8 @@ pub struct PluggedWalletType {
     list_handler: extern fn(handle: i32,
                             key_prefix: *const c_char,
                             values_json_ptr: *mut *const c_char) -> ErrorCode,
+    remove_handler: Option<extern fn(handle: i32,
+                                     key: *const c_char) -> ErrorCode>,
     close_handler: extern fn(handle: i32) -> ErrorCode,
     delete_handler: extern fn(name: *const c_char,
                               config: *const c_char,
@@ -249,6 +275,8 @@ impl PluggedWalletType {
                list_handler: extern fn(handle: i32,
                                        key_prefix: *const c_char,
                                        values_json_ptr: *mut *const c_char) -> ErrorCode,
+               remove_handler: Option<extern fn(handle: i32,
+                                                key: *const c_char) -> ErrorCode>,
                close_handler: extern fn(handle: i32) -> ErrorCode,
                delete_handler: extern fn(name: *const c_char,
                                          config: *const c_char,
@@ -262,6 +290,7 @@ impl PluggedWalletType {
             get_handler: get_handler,
             get_not_expired_handler: get_not_expired_handler,
             list_handler: list_handler,
+            remove_handler: remove_handler,
             close_handler: close_handler,
             delete_handler: delete_handler,
             free_handler: free_handler
@@ -356,6 +385,7 @@ impl WalletType for PluggedWalletType {
                 self.get_handler,
                 self.get_not_expired_handler,
                 self.list_handler,
+                self.remove_handler,
                 self.close_handler,
                 self.free_handler)))
     }
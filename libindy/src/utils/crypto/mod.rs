@@ -10,6 +10,10 @@ pub mod base58;
 #[path = "xsalsa20/sodium.rs"]
 pub mod xsalsa20;
 
+#[cfg(feature = "pwhash_sodium")]
+#[path = "pwhash/sodium.rs"]
+pub mod pwhash;
+
 #[cfg(feature = "hash_openssl")]
 #[path = "hash/openssl.rs"]
 pub mod hash;
@@ -20,4 +24,8 @@ pub mod verkey_builder;
 
 #[cfg(feature = "sealedbox_sodium")]
 #[path = "sealedbox/sodium.rs"]
-pub mod sealedbox;
\ No newline at end of file
+pub mod sealedbox;
+
+#[cfg(feature = "secp256k1_libsecp256k1")]
+#[path = "secp256k1/libsecp256k1.rs"]
+pub mod secp256k1;
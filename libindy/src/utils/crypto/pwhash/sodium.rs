@@ -0,0 +1,63 @@
+extern crate sodiumoxide;
+
+use errors::common::CommonError;
+
+use self::sodiumoxide::crypto::pwhash;
+use self::sodiumoxide::crypto::secretbox;
+
+pub struct PwHash {}
+
+impl PwHash {
+    pub fn new() -> PwHash {
+        PwHash {}
+    }
+
+    pub fn gen_salt(&self) -> Vec<u8> {
+        pwhash::gen_salt()[..].to_vec()
+    }
+
+    /// Derives key suitable for XSalsa20 encryption from passphrase.
+    pub fn derive_key(&self, passphrase: &str, salt: &[u8]) -> Result<Vec<u8>, CommonError> {
+        let salt = pwhash::Salt::from_slice(salt)
+            .ok_or(CommonError::InvalidStructure(format!("Invalid salt length: {}", salt.len())))?;
+
+        let mut key = vec![0u8; secretbox::KEYBYTES];
+
+        pwhash::derive_key(&mut key,
+                           passphrase.as_bytes(),
+                           &salt,
+                           pwhash::OPSLIMIT_INTERACTIVE,
+                           pwhash::MEMLIMIT_INTERACTIVE)
+            .map_err(|_| CommonError::InvalidState(format!("Unable to derive key from passphrase")))?;
+
+        Ok(key)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derive_key_works() {
+        let pwhash = PwHash::new();
+        let salt = pwhash.gen_salt();
+
+        let key1 = pwhash.derive_key("passphrase", &salt).unwrap();
+        let key2 = pwhash.derive_key("passphrase", &salt).unwrap();
+        let key3 = pwhash.derive_key("other passphrase", &salt).unwrap();
+
+        assert_eq!(secretbox::KEYBYTES, key1.len());
+        assert_eq!(key1, key2);
+        assert_ne!(key1, key3);
+    }
+
+    #[test]
+    fn derive_key_works_for_invalid_salt() {
+        let pwhash = PwHash::new();
+
+        let res = pwhash.derive_key("passphrase", &[1, 2, 3]);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+}
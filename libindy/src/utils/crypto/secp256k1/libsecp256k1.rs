@@ -0,0 +1,139 @@
+extern crate digest;
+extern crate secp256k1;
+extern crate sha2;
+extern crate sodiumoxide;
+
+use errors::common::CommonError;
+
+use self::digest::{FixedOutput, Input};
+use self::secp256k1::{ecdh, Message, PublicKey, Secp256k1 as Context, SecretKey, Signature};
+use self::sodiumoxide::randombytes;
+
+pub struct Secp256k1 {}
+
+impl Secp256k1 {
+    pub fn create_key_pair(seed: Option<&[u8]>) -> Result<(Vec<u8>, Vec<u8>), CommonError> {
+        if seed.is_some() && seed.unwrap().len() != 32 {
+            return Err(CommonError::InvalidStructure(format!("Invalid seed")));
+        }
+
+        let context = Context::new();
+
+        let secret_key = SecretKey::from_slice(&context, seed.unwrap_or(&randombytes::randombytes(32)[..]))
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid seed: {:?}", err)))?;
+        let public_key = PublicKey::from_secret_key(&context, &secret_key)
+            .map_err(|err| CommonError::InvalidStructure(format!("Unable to create public key: {:?}", err)))?;
+
+        Ok((public_key.serialize()[..].to_vec(), secret_key[..].to_vec()))
+    }
+
+    pub fn sign(private_key: &[u8], doc: &[u8]) -> Result<Vec<u8>, CommonError> {
+        let context = Context::new();
+
+        let secret_key = Secp256k1::_secret_key(&context, private_key)?;
+        let message = Secp256k1::_message(doc)?;
+
+        let signature = context.sign(&message, &secret_key)
+            .map_err(|err| CommonError::InvalidStructure(format!("Unable to sign data: {:?}", err)))?;
+
+        Ok(signature.serialize_compact(&context)[..].to_vec())
+    }
+
+    pub fn verify(public_key: &[u8], doc: &[u8], sign: &[u8]) -> Result<bool, CommonError> {
+        if sign.len() != 64 {
+            return Err(CommonError::InvalidStructure(format!("Invalid signature")));
+        }
+
+        let context = Context::new();
+
+        let public_key = Secp256k1::_public_key(&context, public_key)?;
+        let message = Secp256k1::_message(doc)?;
+        let signature = Signature::from_compact(&context, sign)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid signature: {:?}", err)))?;
+
+        Ok(context.verify(&message, &signature, &public_key).is_ok())
+    }
+
+    /// Computes ECDH shared secret (sha256 of the compressed shared point) usable as symmetric key.
+    pub fn shared_secret(private_key: &[u8], public_key: &[u8]) -> Result<Vec<u8>, CommonError> {
+        let context = Context::new();
+
+        let secret_key = Secp256k1::_secret_key(&context, private_key)?;
+        let public_key = Secp256k1::_public_key(&context, public_key)?;
+
+        Ok(ecdh::SharedSecret::new(&context, &public_key, &secret_key)[..].to_vec())
+    }
+
+    pub fn validate_public_key(public_key: &[u8]) -> Result<(), CommonError> {
+        Secp256k1::_public_key(&Context::new(), public_key)?;
+        Ok(())
+    }
+
+    fn _secret_key(context: &Context, private_key: &[u8]) -> Result<SecretKey, CommonError> {
+        if private_key.len() != 32 {
+            return Err(CommonError::InvalidStructure(format!("Invalid sign key")));
+        }
+
+        SecretKey::from_slice(context, private_key)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid sign key: {:?}", err)))
+    }
+
+    fn _public_key(context: &Context, public_key: &[u8]) -> Result<PublicKey, CommonError> {
+        if public_key.len() != 33 {
+            return Err(CommonError::InvalidStructure(format!("Invalid verkey")));
+        }
+
+        PublicKey::from_slice(context, public_key)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid verkey: {:?}", err)))
+    }
+
+    fn _message(doc: &[u8]) -> Result<Message, CommonError> {
+        let mut hasher = sha2::Sha256::default();
+        hasher.process(doc);
+
+        Message::from_slice(&hasher.fixed_result()[..])
+            .map_err(|err| CommonError::InvalidState(format!("Unable to build message digest: {:?}", err)))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_verify_works() {
+        let seed = randombytes::randombytes(32);
+        let text = randombytes::randombytes(16);
+
+        let (public_key, secret_key) = Secp256k1::create_key_pair(Some(&seed)).unwrap();
+        let signature = Secp256k1::sign(&secret_key, &text).unwrap();
+
+        assert!(Secp256k1::verify(&public_key, &text, &signature).unwrap());
+        assert!(!Secp256k1::verify(&public_key, &randombytes::randombytes(16), &signature).unwrap());
+    }
+
+    #[test]
+    fn create_key_pair_works_for_seed() {
+        let seed = randombytes::randombytes(32);
+
+        let (public_key, secret_key) = Secp256k1::create_key_pair(Some(&seed)).unwrap();
+        let (public_key_2, secret_key_2) = Secp256k1::create_key_pair(Some(&seed)).unwrap();
+
+        assert_eq!(33, public_key.len());
+        assert_eq!(public_key, public_key_2);
+        assert_eq!(secret_key, secret_key_2);
+    }
+
+    #[test]
+    fn shared_secret_works() {
+        let (alice_pk, alice_sk) = Secp256k1::create_key_pair(None).unwrap();
+        let (bob_pk, bob_sk) = Secp256k1::create_key_pair(None).unwrap();
+
+        let alice_secret = Secp256k1::shared_secret(&alice_sk, &bob_pk).unwrap();
+        let bob_secret = Secp256k1::shared_secret(&bob_sk, &alice_pk).unwrap();
+
+        assert_eq!(32, alice_secret.len());
+        assert_eq!(alice_secret, bob_secret);
+    }
+}
@@ -0,0 +1,128 @@
+extern crate libc;
+
+use api::ErrorCode;
+use utils::cstring::CStringUtils;
+use utils::sequence::SequenceUtils;
+
+use self::libc::c_char;
+
+use std::collections::HashMap;
+use std::slice;
+use std::sync::Mutex;
+
+#[derive(Debug)]
+struct InmemBlobReader {
+    hash: String,
+    position: usize
+}
+
+lazy_static! {
+    static ref INMEM_BLOBS: Mutex<HashMap<String, Vec<u8>>> = Default::default();
+}
+
+lazy_static! {
+    static ref INMEM_BLOB_WRITERS: Mutex<HashMap<i32, Vec<u8>>> = Default::default();
+}
+
+lazy_static! {
+    static ref INMEM_BLOB_READERS: Mutex<HashMap<i32, InmemBlobReader>> = Default::default();
+}
+
+pub struct InmemBlobStorage {}
+
+impl InmemBlobStorage {
+    pub extern "C" fn open_writer(_: *const c_char,
+                                  handle: *mut i32) -> ErrorCode {
+        let mut writers = INMEM_BLOB_WRITERS.lock().unwrap();
+
+        let xhandle = SequenceUtils::get_next_id();
+        writers.insert(xhandle, Vec::new());
+
+        unsafe { *handle = xhandle };
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn append(xhandle: i32,
+                             bytes: *const u8,
+                             bytes_len: u32) -> ErrorCode {
+        let mut writers = INMEM_BLOB_WRITERS.lock().unwrap();
+
+        let data = match writers.get_mut(&xhandle) {
+            Some(data) => data,
+            None => return ErrorCode::CommonInvalidState
+        };
+
+        data.extend_from_slice(unsafe { slice::from_raw_parts(bytes, bytes_len as usize) });
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn finalize(xhandle: i32,
+                               hash: *const c_char) -> ErrorCode {
+        check_useful_c_str!(hash, ErrorCode::CommonInvalidStructure);
+
+        let mut writers = INMEM_BLOB_WRITERS.lock().unwrap();
+
+        let data = match writers.remove(&xhandle) {
+            Some(data) => data,
+            None => return ErrorCode::CommonInvalidState
+        };
+
+        INMEM_BLOBS.lock().unwrap().insert(hash, data);
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn open_reader(_: *const c_char,
+                                  hash: *const c_char,
+                                  handle: *mut i32) -> ErrorCode {
+        check_useful_c_str!(hash, ErrorCode::CommonInvalidStructure);
+
+        if !INMEM_BLOBS.lock().unwrap().contains_key(&hash) {
+            return ErrorCode::CommonIOError;
+        }
+
+        let mut readers = INMEM_BLOB_READERS.lock().unwrap();
+
+        let xhandle = SequenceUtils::get_next_id();
+        readers.insert(xhandle, InmemBlobReader { hash, position: 0 });
+
+        unsafe { *handle = xhandle };
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn read(xhandle: i32,
+                           buf: *mut u8,
+                           buf_len: u32,
+                           read_len: *mut u32) -> ErrorCode {
+        let mut readers = INMEM_BLOB_READERS.lock().unwrap();
+
+        let reader = match readers.get_mut(&xhandle) {
+            Some(reader) => reader,
+            None => return ErrorCode::CommonInvalidState
+        };
+
+        let blobs = INMEM_BLOBS.lock().unwrap();
+
+        let data = match blobs.get(&reader.hash) {
+            Some(data) => data,
+            None => return ErrorCode::CommonIOError
+        };
+
+        let len = ::std::cmp::min(buf_len as usize, data.len() - reader.position);
+        unsafe { slice::from_raw_parts_mut(buf, len) }.copy_from_slice(&data[reader.position..reader.position + len]);
+        reader.position += len;
+
+        unsafe { *read_len = len as u32 };
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn close(xhandle: i32) -> ErrorCode {
+        // Writer closed before finalization discards its data
+        INMEM_BLOB_WRITERS.lock().unwrap().remove(&xhandle);
+        INMEM_BLOB_READERS.lock().unwrap().remove(&xhandle);
+        ErrorCode::Success
+    }
+
+    pub fn contains(hash: &str) -> bool {
+        INMEM_BLOBS.lock().unwrap().contains_key(hash)
+    }
+}
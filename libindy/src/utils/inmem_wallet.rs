@@ -245,6 +245,33 @@ impl InmemWallet {
         ErrorCode::Success
     }
 
+    pub extern "C" fn remove(xhandle: i32,
+                             key: *const c_char) -> ErrorCode {
+        check_useful_c_str!(key, ErrorCode::CommonInvalidStructure);
+
+        let handles = INMEM_WALLET_HANDLES.lock().unwrap();
+
+        if !handles.contains_key(&xhandle) {
+            return ErrorCode::CommonInvalidState;
+        }
+
+        let wallet_context = handles.get(&xhandle).unwrap();
+
+        let mut wallets = INMEM_WALLETS.lock().unwrap();
+
+        if !wallets.contains_key(&wallet_context.name) {
+            return ErrorCode::CommonInvalidState;
+        }
+
+        let wallet = wallets.get_mut(&wallet_context.name).unwrap();
+
+        if wallet.remove(&key).is_none() {
+            return ErrorCode::WalletNotFoundError;
+        }
+
+        ErrorCode::Success
+    }
+
     pub extern "C" fn close(xhandle: i32) -> ErrorCode {
         let mut handles = INMEM_WALLET_HANDLES.lock().unwrap();
 
@@ -277,6 +304,15 @@ impl InmemWallet {
         ErrorCode::Success
     }
 
+    /// Removes records bypassing libindy, to emulate wallets written by older versions.
+    pub fn remove_records(wallet_name: &str, key_prefix: &str) {
+        let mut wallets = INMEM_WALLETS.lock().unwrap();
+
+        if let Some(wallet) = wallets.get_mut(wallet_name) {
+            wallet.retain(|key, _| !key.starts_with(key_prefix));
+        }
+    }
+
     pub fn cleanup() {
         let mut wallets = INMEM_WALLETS.lock().unwrap();
         wallets.clear();
@@ -15,6 +15,8 @@ pub mod logger;
 
 pub mod inmem_wallet;
 
+pub mod inmem_blob_storage;
+
 #[allow(unused_macros)]
 #[macro_use]
 pub mod result;
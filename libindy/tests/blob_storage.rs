@@ -0,0 +1,53 @@
+extern crate indy;
+
+// Workaround to share some utils code based on indy sdk types between tests and indy sdk
+use indy::api as api;
+
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+#[macro_use]
+extern crate lazy_static;
+
+#[macro_use]
+mod utils;
+
+use utils::blob_storage::BlobStorageUtils;
+use utils::test::TestUtils;
+
+use indy::api::ErrorCode;
+
+mod high_cases {
+    use super::*;
+
+    mod register_blob_storage_type {
+        use super::*;
+
+        #[test]
+        fn indy_register_blob_storage_type_works() {
+            TestUtils::cleanup_storage();
+
+            BlobStorageUtils::register_blob_storage_type("inmem", false).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+}
+
+mod medium_cases {
+    use super::*;
+
+    mod register_blob_storage_type {
+        use super::*;
+
+        #[test]
+        fn indy_register_blob_storage_type_works_for_already_registered_type() {
+            TestUtils::cleanup_storage();
+
+            let res = BlobStorageUtils::register_blob_storage_type("default", true);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidState);
+
+            TestUtils::cleanup_storage();
+        }
+    }
+}
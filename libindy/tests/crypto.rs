@@ -20,6 +20,7 @@ use utils::crypto::CryptoUtils;
 use utils::did::DidUtils;
 use utils::pool::PoolUtils;
 use utils::test::TestUtils;
+use utils::inmem_wallet::InmemWallet;
 use utils::constants::*;
 
 use indy::api::ErrorCode;
@@ -62,6 +63,59 @@ mod high_cases {
             TestUtils::cleanup_storage();
         }
 
+        #[test]
+        fn indy_create_key_works_for_derivation_path() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let other_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            DidUtils::set_master_seed(wallet_handle, MASTER_SEED).unwrap();
+            DidUtils::set_master_seed(other_wallet_handle, MASTER_SEED).unwrap();
+
+            let verkey = CryptoUtils::create_key_for_json(wallet_handle, r#"{"derivation_path":"m/1'/0'"}"#).unwrap();
+            let same_verkey = CryptoUtils::create_key_for_json(other_wallet_handle, r#"{"derivation_path":"m/1'/0'"}"#).unwrap();
+            let other_verkey = CryptoUtils::create_key_for_json(wallet_handle, r#"{"derivation_path":"m/1'/1'"}"#).unwrap();
+
+            assert_eq!(verkey, same_verkey);
+            assert_ne!(verkey, other_verkey);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            WalletUtils::close_wallet(other_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_create_key_works_for_derivation_path_without_master_seed() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = CryptoUtils::create_key_for_json(wallet_handle, r#"{"derivation_path":"m/1'/0'"}"#);
+            assert_eq!(ErrorCode::WalletNotFoundError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_create_key_works_for_not_hardened_derivation_path() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            DidUtils::set_master_seed(wallet_handle, MASTER_SEED).unwrap();
+
+            let res = CryptoUtils::create_key_for_json(wallet_handle, r#"{"derivation_path":"m/1/0"}"#);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
         #[test]
         fn indy_create_key_works_for_invalid_wallet_handle() {
             TestUtils::cleanup_storage();
@@ -590,4 +644,644 @@ mod high_cases {
             TestUtils::cleanup_storage();
         }
     }
+
+    mod pack_msg {
+        use super::*;
+
+        #[test]
+        fn indy_crypto_pack_msg_works_for_authcrypt() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let sender_vk = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+            let receiver_keys = format!(r#"["{}", "{}"]"#, VERKEY_MY2, VERKEY_TRUSTEE);
+
+            let packed_msg = CryptoUtils::pack_msg(wallet_handle, MESSAGE.as_bytes(), &receiver_keys, Some(&sender_vk)).unwrap();
+
+            let packed_msg: serde_json::Value = serde_json::from_slice(&packed_msg).unwrap();
+            let recipients = packed_msg["recipients"].as_array().unwrap();
+            assert_eq!(2, recipients.len());
+            assert_eq!(VERKEY_MY2, recipients[0]["header"]["kid"].as_str().unwrap());
+            assert!(recipients[0]["header"]["sender"].is_string());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_pack_msg_works_for_anoncrypt() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let receiver_keys = format!(r#"["{}", "{}"]"#, VERKEY_MY2, VERKEY_TRUSTEE);
+
+            let packed_msg = CryptoUtils::pack_msg(wallet_handle, MESSAGE.as_bytes(), &receiver_keys, None).unwrap();
+
+            let packed_msg: serde_json::Value = serde_json::from_slice(&packed_msg).unwrap();
+            let recipients = packed_msg["recipients"].as_array().unwrap();
+            assert_eq!(2, recipients.len());
+            assert!(recipients[0]["header"]["sender"].is_null());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_pack_msg_works_for_unknown_sender_verkey() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let receiver_keys = format!(r#"["{}"]"#, VERKEY_TRUSTEE);
+
+            let res = CryptoUtils::pack_msg(wallet_handle, MESSAGE.as_bytes(), &receiver_keys, Some(VERKEY_MY2));
+            assert_eq!(ErrorCode::WalletNotFoundError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_pack_msg_works_for_invalid_receiver_keys() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = CryptoUtils::pack_msg(wallet_handle, MESSAGE.as_bytes(), "[]", None);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            let res = CryptoUtils::pack_msg(wallet_handle, MESSAGE.as_bytes(), VERKEY_TRUSTEE, None);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            let receiver_keys = format!(r#"["{}"]"#, INVALID_BASE58_VERKEY);
+            let res = CryptoUtils::pack_msg(wallet_handle, MESSAGE.as_bytes(), &receiver_keys, None);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
+    mod unpack_msg {
+        use super::*;
+
+        #[test]
+        fn indy_crypto_unpack_msg_works_for_authcrypt() {
+            TestUtils::cleanup_storage();
+
+            let sender_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let recipient1_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let recipient2_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let sender_vk = CryptoUtils::create_key(sender_wallet_handle, Some(MY1_SEED)).unwrap();
+            let recipient1_vk = CryptoUtils::create_key(recipient1_wallet_handle, Some(MY2_SEED)).unwrap();
+            let recipient2_vk = CryptoUtils::create_key(recipient2_wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let receiver_keys = format!(r#"["{}", "{}"]"#, recipient1_vk, recipient2_vk);
+
+            let packed_msg = CryptoUtils::pack_msg(sender_wallet_handle, MESSAGE.as_bytes(), &receiver_keys, Some(&sender_vk)).unwrap();
+
+            for &(wallet_handle, ref recipient_vk) in [(recipient1_wallet_handle, &recipient1_vk), (recipient2_wallet_handle, &recipient2_vk)].iter() {
+                let (info, msg) = CryptoUtils::unpack_msg(wallet_handle, &packed_msg).unwrap();
+                assert_eq!(MESSAGE.as_bytes().to_vec(), msg);
+
+                let info: serde_json::Value = serde_json::from_str(&info).unwrap();
+                assert_eq!(recipient_vk.as_str(), info["recipient_verkey"].as_str().unwrap());
+                assert_eq!(sender_vk, info["sender_verkey"].as_str().unwrap());
+            }
+
+            WalletUtils::close_wallet(sender_wallet_handle).unwrap();
+            WalletUtils::close_wallet(recipient1_wallet_handle).unwrap();
+            WalletUtils::close_wallet(recipient2_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_unpack_msg_works_for_anoncrypt() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let recipient_vk = CryptoUtils::create_key(wallet_handle, Some(MY2_SEED)).unwrap();
+            let receiver_keys = format!(r#"["{}", "{}"]"#, VERKEY_TRUSTEE, recipient_vk);
+
+            let packed_msg = CryptoUtils::pack_msg(wallet_handle, MESSAGE.as_bytes(), &receiver_keys, None).unwrap();
+
+            let (info, msg) = CryptoUtils::unpack_msg(wallet_handle, &packed_msg).unwrap();
+            assert_eq!(MESSAGE.as_bytes().to_vec(), msg);
+
+            let info: serde_json::Value = serde_json::from_str(&info).unwrap();
+            assert_eq!(recipient_vk, info["recipient_verkey"].as_str().unwrap());
+            assert!(info["sender_verkey"].is_null());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_unpack_msg_works_for_unknown_recipients() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let receiver_keys = format!(r#"["{}", "{}"]"#, VERKEY_TRUSTEE, VERKEY_MY2);
+
+            let packed_msg = CryptoUtils::pack_msg(wallet_handle, MESSAGE.as_bytes(), &receiver_keys, None).unwrap();
+
+            let res = CryptoUtils::unpack_msg(wallet_handle, &packed_msg);
+            assert_eq!(ErrorCode::WalletNotFoundError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_unpack_msg_works_for_altered_protected_header() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let sender_vk = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+            let recipient_vk = CryptoUtils::create_key(wallet_handle, Some(MY2_SEED)).unwrap();
+            let receiver_keys = format!(r#"["{}"]"#, recipient_vk);
+
+            for sender_vk in [None, Some(sender_vk.as_str())].iter() {
+                let packed_msg = CryptoUtils::pack_msg(wallet_handle, MESSAGE.as_bytes(), &receiver_keys, *sender_vk).unwrap();
+
+                let mut packed_msg: serde_json::Value = serde_json::from_slice(&packed_msg).unwrap();
+                let protected = base64::decode(packed_msg["protected"].as_str().unwrap()).unwrap();
+                let protected = String::from_utf8(protected).unwrap().replace("JWM/1.0", "JWM/2.0");
+                packed_msg["protected"] = serde_json::Value::String(base64::encode(protected.as_bytes()));
+
+                let res = CryptoUtils::unpack_msg(wallet_handle, packed_msg.to_string().as_bytes());
+                assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+            }
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_unpack_msg_works_for_invalid_msg() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = CryptoUtils::unpack_msg(wallet_handle, "unpacked message".as_bytes());
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
+    mod sign_stream {
+        use super::*;
+
+        #[test]
+        fn indy_crypto_sign_stream_works() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let my_vk = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let msg = vec![7u8; 100000];
+            let signature = CryptoUtils::sign_stream(wallet_handle, &my_vk, &[&msg[..30000], &msg[30000..]]).unwrap();
+
+            let valid = CryptoUtils::verify_stream(&my_vk, &[&msg[..], &[]], &signature).unwrap();
+            assert!(valid);
+
+            let valid = CryptoUtils::verify_stream(&my_vk, &[&msg[1..]], &signature).unwrap();
+            assert!(!valid);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_sign_stream_works_for_unknown_key() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = CryptoUtils::sign_stream_init(wallet_handle, VERKEY_MY2);
+            assert_eq!(ErrorCode::WalletNotFoundError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_sign_stream_finish_works_for_finished_stream() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let my_vk = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let stream_handle = CryptoUtils::sign_stream_init(wallet_handle, &my_vk).unwrap();
+            CryptoUtils::sign_stream_finish(stream_handle).unwrap();
+
+            let res = CryptoUtils::sign_stream_finish(stream_handle);
+            assert_eq!(ErrorCode::CommonInvalidParam2, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_close_stream_works() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let my_vk = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let stream_handle = CryptoUtils::sign_stream_init(wallet_handle, &my_vk).unwrap();
+            CryptoUtils::sign_stream_update(stream_handle, MESSAGE.as_bytes()).unwrap();
+
+            CryptoUtils::close_stream(stream_handle).unwrap();
+
+            let res = CryptoUtils::sign_stream_finish(stream_handle);
+            assert_eq!(ErrorCode::CommonInvalidParam2, res.unwrap_err());
+
+            let res = CryptoUtils::close_stream(stream_handle);
+            assert_eq!(ErrorCode::CommonInvalidParam2, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
+    mod crypt_stream {
+        use super::*;
+
+        #[test]
+        fn indy_crypto_auth_crypt_stream_works() {
+            TestUtils::cleanup_storage();
+
+            let sender_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let recipient_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let sender_vk = CryptoUtils::create_key(sender_wallet_handle, Some(MY1_SEED)).unwrap();
+            let recipient_vk = CryptoUtils::create_key(recipient_wallet_handle, Some(MY2_SEED)).unwrap();
+
+            let msg: Vec<u8> = (0..200000).map(|i| i as u8).collect();
+
+            let stream_handle = CryptoUtils::auth_crypt_stream_init(sender_wallet_handle, &sender_vk, &recipient_vk).unwrap();
+            let encrypted_msg = CryptoUtils::crypt_stream(stream_handle, &[&msg[..70000], &msg[70000..]]).unwrap();
+
+            let stream_handle = CryptoUtils::auth_decrypt_stream_init(recipient_wallet_handle, &recipient_vk).unwrap();
+            let parts: Vec<&[u8]> = encrypted_msg.chunks(1000).collect();
+            let (decrypted_sender_vk, decrypted_msg) = CryptoUtils::decrypt_stream(stream_handle, &parts).unwrap();
+
+            assert_eq!(msg, decrypted_msg);
+            assert_eq!(Some(sender_vk), decrypted_sender_vk);
+
+            WalletUtils::close_wallet(sender_wallet_handle).unwrap();
+            WalletUtils::close_wallet(recipient_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_anon_crypt_stream_works() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let recipient_vk = CryptoUtils::create_key(wallet_handle, Some(MY2_SEED)).unwrap();
+
+            let stream_handle = CryptoUtils::anon_crypt_stream_init(&recipient_vk).unwrap();
+            let encrypted_msg = CryptoUtils::crypt_stream(stream_handle, &[MESSAGE.as_bytes()]).unwrap();
+
+            let stream_handle = CryptoUtils::anon_decrypt_stream_init(wallet_handle, &recipient_vk).unwrap();
+            let (decrypted_sender_vk, decrypted_msg) = CryptoUtils::decrypt_stream(stream_handle, &[&encrypted_msg]).unwrap();
+
+            assert_eq!(MESSAGE.as_bytes().to_vec(), decrypted_msg);
+            assert_eq!(None, decrypted_sender_vk);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_decrypt_stream_works_for_truncated_stream() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let recipient_vk = CryptoUtils::create_key(wallet_handle, Some(MY2_SEED)).unwrap();
+
+            let msg = vec![7u8; 100000];
+
+            let stream_handle = CryptoUtils::anon_crypt_stream_init(&recipient_vk).unwrap();
+            let encrypted_msg = CryptoUtils::crypt_stream_update(stream_handle, &msg).unwrap();
+            CryptoUtils::crypt_stream_finish(stream_handle).unwrap();
+
+            let stream_handle = CryptoUtils::anon_decrypt_stream_init(wallet_handle, &recipient_vk).unwrap();
+            let res = CryptoUtils::decrypt_stream(stream_handle, &[&encrypted_msg]);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_decrypt_stream_works_for_other_recipient() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let my_vk = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let stream_handle = CryptoUtils::anon_crypt_stream_init(VERKEY_MY2).unwrap();
+            let encrypted_msg = CryptoUtils::crypt_stream(stream_handle, &[MESSAGE.as_bytes()]).unwrap();
+
+            let stream_handle = CryptoUtils::anon_decrypt_stream_init(wallet_handle, &my_vk).unwrap();
+            let res = CryptoUtils::decrypt_stream_update(stream_handle, &encrypted_msg);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            let res = CryptoUtils::decrypt_stream_finish(stream_handle);
+            assert_eq!(ErrorCode::CommonInvalidParam2, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_crypt_stream_update_works_for_sign_stream_handle() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let my_vk = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let stream_handle = CryptoUtils::sign_stream_init(wallet_handle, &my_vk).unwrap();
+
+            let res = CryptoUtils::crypt_stream_update(stream_handle, MESSAGE.as_bytes());
+            assert_eq!(ErrorCode::CommonInvalidParam2, res.unwrap_err());
+
+            let res = CryptoUtils::crypt_stream_finish(stream_handle);
+            assert_eq!(ErrorCode::CommonInvalidParam2, res.unwrap_err());
+
+            CryptoUtils::sign_stream_finish(stream_handle).unwrap();
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
+    mod list_keys {
+        use super::*;
+
+        #[test]
+        fn indy_list_keys_works() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let verkey = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+            CryptoUtils::set_key_metadata(wallet_handle, &verkey, METADATA).unwrap();
+
+            let keys_json = CryptoUtils::list_keys(wallet_handle).unwrap();
+            let keys: Vec<serde_json::Value> = serde_json::from_str(&keys_json).unwrap();
+
+            assert_eq!(1, keys.len());
+            assert_eq!(VERKEY_MY1, keys[0]["verkey"].as_str().unwrap());
+            assert_eq!(METADATA, keys[0]["metadata"].as_str().unwrap());
+            assert!(keys[0]["derivation_path"].is_null());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_list_keys_works_for_empty_wallet() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let keys_json = CryptoUtils::list_keys(wallet_handle).unwrap();
+            let keys: Vec<serde_json::Value> = serde_json::from_str(&keys_json).unwrap();
+            assert!(keys.is_empty());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
+    mod delete_key {
+        use super::*;
+
+        #[test]
+        fn indy_delete_key_works() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let verkey = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+            CryptoUtils::set_key_metadata(wallet_handle, &verkey, METADATA).unwrap();
+
+            CryptoUtils::delete_key(wallet_handle, &verkey).unwrap();
+
+            let res = CryptoUtils::get_key_metadata(wallet_handle, &verkey);
+            assert_eq!(ErrorCode::WalletNotFoundError, res.unwrap_err());
+
+            let res = CryptoUtils::sign(wallet_handle, &verkey, MESSAGE.as_bytes());
+            assert_eq!(ErrorCode::WalletNotFoundError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_delete_key_works_for_plugged_wallet() {
+            TestUtils::cleanup_storage();
+            InmemWallet::cleanup();
+
+            WalletUtils::register_wallet_type(INMEM_TYPE, false).unwrap();
+            WalletUtils::create_wallet(POOL, WALLET, Some(INMEM_TYPE), None, None).unwrap();
+            let wallet_handle = WalletUtils::open_wallet(WALLET, None, None).unwrap();
+
+            let verkey = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+            let other_verkey = CryptoUtils::create_key(wallet_handle, Some(MY2_SEED)).unwrap();
+            CryptoUtils::set_key_metadata(wallet_handle, &verkey, METADATA).unwrap();
+
+            CryptoUtils::delete_key(wallet_handle, &verkey).unwrap();
+
+            let res = CryptoUtils::get_key_metadata(wallet_handle, &verkey);
+            assert_eq!(ErrorCode::WalletNotFoundError, res.unwrap_err());
+
+            let keys_json = CryptoUtils::list_keys(wallet_handle).unwrap();
+            let keys: Vec<serde_json::Value> = serde_json::from_str(&keys_json).unwrap();
+            assert_eq!(1, keys.len());
+            assert_eq!(other_verkey, keys[0]["verkey"].as_str().unwrap());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            InmemWallet::cleanup();
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_delete_key_works_for_plugged_wallet_without_remove_handler() {
+            TestUtils::cleanup_storage();
+            InmemWallet::cleanup();
+
+            WalletUtils::register_wallet_type_without_remove("inmem_without_remove").unwrap();
+            WalletUtils::create_wallet(POOL, WALLET, Some("inmem_without_remove"), None, None).unwrap();
+            let wallet_handle = WalletUtils::open_wallet(WALLET, None, None).unwrap();
+
+            let verkey = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let res = CryptoUtils::delete_key(wallet_handle, &verkey);
+            assert_eq!(ErrorCode::CommonInvalidState, res.unwrap_err());
+
+            CryptoUtils::sign(wallet_handle, &verkey, MESSAGE.as_bytes()).unwrap();
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            InmemWallet::cleanup();
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_delete_key_works_for_key_used_by_did() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (_, verkey) = DidUtils::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let res = CryptoUtils::delete_key(wallet_handle, &verkey);
+            assert_eq!(ErrorCode::CryptoKeyUsedByDidError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_delete_key_works_for_unknown_key() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = CryptoUtils::delete_key(wallet_handle, VERKEY);
+            assert_eq!(ErrorCode::WalletNotFoundError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
+    mod export_verkey {
+        use super::*;
+
+        #[test]
+        fn indy_export_verkey_works_for_jwk() {
+            let jwk = CryptoUtils::export_verkey(VERKEY_MY1, "jwk").unwrap();
+            let jwk: serde_json::Value = serde_json::from_str(&jwk).unwrap();
+
+            assert_eq!("OKP", jwk["kty"].as_str().unwrap());
+            assert_eq!("Ed25519", jwk["crv"].as_str().unwrap());
+            assert_eq!("6cZ2bZKmKiUiF9MLKCV8IIYIEsOLHsJG5qBJ9SrQYBk", jwk["x"].as_str().unwrap());
+        }
+
+        #[test]
+        fn indy_export_verkey_works_for_multibase() {
+            let multibase = CryptoUtils::export_verkey(VERKEY_MY1, "multibase").unwrap();
+            assert_eq!("z6MkvBpZTRb7tjuUF5AkmhG1JDV928hZbg5KAQJcogvhz9ax", multibase);
+        }
+
+        #[test]
+        fn indy_export_verkey_works_for_hex() {
+            let hex = CryptoUtils::export_verkey(VERKEY_MY1, "hex").unwrap();
+            assert_eq!("e9c6766d92a62a252217d30b28257c20860812c38b1ec246e6a049f52ad06019", hex);
+        }
+
+        #[test]
+        fn indy_export_verkey_works_for_unknown_format() {
+            let res = CryptoUtils::export_verkey(VERKEY_MY1, "pem");
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+        }
+    }
+
+    mod import_key_from_jwk {
+        use super::*;
+
+        const MY1_JWK: &'static str = r#"{"kty":"OKP","crv":"Ed25519","d":"MDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDBNeTE","x":"6cZ2bZKmKiUiF9MLKCV8IIYIEsOLHsJG5qBJ9SrQYBk"}"#;
+
+        #[test]
+        fn indy_import_key_from_jwk_works() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let verkey = CryptoUtils::import_key_from_jwk(wallet_handle, MY1_JWK).unwrap();
+            assert_eq!(VERKEY_MY1, verkey);
+
+            let signature = CryptoUtils::sign(wallet_handle, &verkey, MESSAGE.as_bytes()).unwrap();
+            assert!(CryptoUtils::verify(&verkey, MESSAGE.as_bytes(), &signature).unwrap());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_import_key_from_jwk_works_for_mismatched_public_key() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let jwk = MY1_JWK.replace("6cZ2", "7cZ2");
+
+            let res = CryptoUtils::import_key_from_jwk(wallet_handle, &jwk);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_import_key_from_jwk_works_for_invalid_key_type() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = CryptoUtils::import_key_from_jwk(wallet_handle, r#"{"kty":"EC","crv":"P-256","d":"MDAw"}"#);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
 }
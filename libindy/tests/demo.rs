@@ -180,6 +180,7 @@ fn anoncreds_demo_works() {
                                 wallet_handle,
                                 CString::new(xclaim_json).unwrap().as_ptr(),
                                 null(),
+                                null(),
                                 prover_store_claim_callback);
 
     assert_eq!(ErrorCode::Success, err);
@@ -247,6 +248,7 @@ fn anoncreds_demo_works() {
                                  CString::new(master_secret_name).unwrap().as_ptr(),
                                  CString::new(claim_defs_json.clone()).unwrap().as_ptr(),
                                  CString::new(revoc_regs_jsons.clone()).unwrap().as_ptr(),
+                                 null(),
                                  prover_create_proof_callback);
 
     assert_eq!(ErrorCode::Success, err);
@@ -261,6 +263,7 @@ fn anoncreds_demo_works() {
                                    CString::new(schemas_json).unwrap().as_ptr(),
                                    CString::new(claim_defs_json).unwrap().as_ptr(),
                                    CString::new(revoc_regs_jsons).unwrap().as_ptr(),
+                                   null(),
                                    verifier_verify_proof_callback);
 
     assert_eq!(ErrorCode::Success, err);
@@ -453,6 +453,160 @@ mod high_cases {
         }
     }
 
+    mod resolve_did {
+        use super::*;
+
+        #[test]
+        fn indy_resolve_did_works() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let attrib_data = format!(r#"{{"endpoint":{{"ha":"{}", "verkey":"{}"}}}}"#, ENDPOINT, VERKEY_TRUSTEE);
+            let attrib_request = LedgerUtils::build_attrib_request(&trustee_did, &trustee_did,
+                                                                   None, Some(&attrib_data), None).unwrap();
+
+            LedgerUtils::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &attrib_request).unwrap();
+
+            thread::sleep(std::time::Duration::from_millis(1000));
+
+            let did_doc_json = DidUtils::resolve_did(pool_handle, wallet_handle, &trustee_did, &trustee_did, None).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc_json).unwrap();
+
+            assert_eq!(format!("did:sov:{}", trustee_did), did_doc["id"].as_str().unwrap());
+            assert_eq!(VERKEY_TRUSTEE, did_doc["publicKey"][0]["publicKeyBase58"].as_str().unwrap());
+            assert_eq!(did_doc["publicKey"][0]["id"], did_doc["authentication"][0]["publicKey"]);
+            assert_eq!(ENDPOINT, did_doc["service"][0]["serviceEndpoint"].as_str().unwrap());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_from_cache() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let did_doc_json = DidUtils::resolve_did(pool_handle, wallet_handle, &trustee_did, &trustee_did, Some(r#"{"attribs":[]}"#)).unwrap();
+
+            PoolUtils::close(pool_handle).unwrap();
+
+            let cached_did_doc_json = DidUtils::resolve_did(pool_handle, wallet_handle, &trustee_did, &trustee_did, Some(r#"{"attribs":[]}"#)).unwrap();
+            assert_eq!(did_doc_json, cached_did_doc_json);
+
+            let res = DidUtils::resolve_did(pool_handle, wallet_handle, &trustee_did, &trustee_did, Some(r#"{"attribs":[],"no_cache":true}"#));
+            assert_eq!(ErrorCode::PoolLedgerInvalidPoolHandle, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_cached_document_with_other_attribs() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let attrib_data = format!(r#"{{"endpoint":{{"ha":"{}", "verkey":"{}"}}}}"#, ENDPOINT, VERKEY_TRUSTEE);
+            let attrib_request = LedgerUtils::build_attrib_request(&trustee_did, &trustee_did,
+                                                                   None, Some(&attrib_data), None).unwrap();
+
+            LedgerUtils::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &attrib_request).unwrap();
+
+            thread::sleep(std::time::Duration::from_millis(1000));
+
+            let did_doc_json = DidUtils::resolve_did(pool_handle, wallet_handle, &trustee_did, &trustee_did, Some(r#"{"attribs":[]}"#)).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc_json).unwrap();
+            assert!(did_doc["service"].as_array().unwrap().is_empty());
+
+            let did_doc_json = DidUtils::resolve_did(pool_handle, wallet_handle, &trustee_did, &trustee_did, Some(r#"{"attribs":["endpoint"]}"#)).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc_json).unwrap();
+            assert_eq!(ENDPOINT, did_doc["service"][0]["serviceEndpoint"].as_str().unwrap());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_unknown_did() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (my_did, _) = DidUtils::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let res = DidUtils::resolve_did(pool_handle, wallet_handle, &my_did, DID, None);
+            assert_eq!(ErrorCode::DidNotFoundError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_invalid_options() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = DidUtils::resolve_did(-1, wallet_handle, DID, DID, Some(r#"{"no_cache":"yes"}"#));
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_invalid_pool_handle() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = DidUtils::resolve_did(pool_handle + 1, wallet_handle, DID, DID, None);
+            assert_eq!(ErrorCode::PoolLedgerInvalidPoolHandle, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_incompatible_wallet_and_pool() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet("other_pool", None).unwrap();
+
+            let res = DidUtils::resolve_did(pool_handle, wallet_handle, DID, DID, None);
+            assert_eq!(ErrorCode::WalletIncompatiblePoolError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
     mod set_did_metadata {
         use super::*;
 
@@ -1104,4 +1258,159 @@ mod high_cases {
             assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
         }
     }
+
+    mod master_seed {
+        use super::*;
+
+        #[test]
+        fn indy_create_and_store_my_did_works_for_derivation_path() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let other_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            DidUtils::set_master_seed(wallet_handle, MASTER_SEED).unwrap();
+            DidUtils::set_master_seed(other_wallet_handle, MASTER_SEED).unwrap();
+
+            let (did, verkey) = DidUtils::create_my_did(wallet_handle, r#"{"derivation_path":"m/0'/0'"}"#).unwrap();
+            let (same_did, same_verkey) = DidUtils::create_my_did(other_wallet_handle, r#"{"derivation_path":"m/0'/0'"}"#).unwrap();
+            let (other_did, _) = DidUtils::create_my_did(wallet_handle, r#"{"derivation_path":"m/0'/1'"}"#).unwrap();
+
+            assert_eq!(did, same_did);
+            assert_eq!(verkey, same_verkey);
+            assert_ne!(did, other_did);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            WalletUtils::close_wallet(other_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_create_and_store_my_did_works_for_derivation_path_and_seed() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            DidUtils::set_master_seed(wallet_handle, MASTER_SEED).unwrap();
+
+            let res = DidUtils::create_my_did(wallet_handle, &format!(r#"{{"derivation_path":"m/0'/0'", "seed":"{}"}}"#, MY1_SEED));
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_create_and_store_my_did_works_for_derivation_path_without_master_seed() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = DidUtils::create_my_did(wallet_handle, r#"{"derivation_path":"m/0'/0'"}"#);
+            assert_eq!(ErrorCode::WalletNotFoundError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_set_master_seed_works_for_already_set_seed() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            DidUtils::set_master_seed(wallet_handle, MASTER_SEED).unwrap();
+
+            let res = DidUtils::set_master_seed(wallet_handle, MY1_SEED);
+            assert_eq!(ErrorCode::DidMasterSeedAlreadySetError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_set_master_seed_works_for_short_seed() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = DidUtils::set_master_seed(wallet_handle, "short seed");
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_recover_from_master_seed_works() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            DidUtils::set_master_seed(wallet_handle, MASTER_SEED).unwrap();
+
+            let (did, verkey) = DidUtils::create_my_did(wallet_handle, r#"{"derivation_path":"m/0'/2'"}"#).unwrap();
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            let recovered_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            DidUtils::set_master_seed(recovered_wallet_handle, MASTER_SEED).unwrap();
+
+            let recovered_json = DidUtils::recover_from_master_seed(recovered_wallet_handle, r#"{"did_path":"m/0'", "key_path":"m/1'", "count":3}"#).unwrap();
+            let recovered: serde_json::Value = serde_json::from_str(&recovered_json).unwrap();
+
+            let dids = recovered["dids"].as_array().unwrap();
+            assert_eq!(3, dids.len());
+            assert_eq!(3, recovered["keys"].as_array().unwrap().len());
+
+            assert_eq!(did, dids[2]["did"].as_str().unwrap());
+            assert_eq!(verkey, dids[2]["verkey"].as_str().unwrap());
+            assert_eq!("m/0'/2'", dids[2]["derivation_path"].as_str().unwrap());
+
+            let recovered_verkey = DidUtils::key_for_local_did(recovered_wallet_handle, &did).unwrap();
+            assert_eq!(verkey, recovered_verkey);
+
+            WalletUtils::close_wallet(recovered_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_recover_from_master_seed_works_without_paths() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            DidUtils::set_master_seed(wallet_handle, MASTER_SEED).unwrap();
+
+            let res = DidUtils::recover_from_master_seed(wallet_handle, r#"{"count":3}"#);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_recover_from_master_seed_works_for_too_big_count() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            DidUtils::set_master_seed(wallet_handle, MASTER_SEED).unwrap();
+
+            let res = DidUtils::recover_from_master_seed(wallet_handle, r#"{"did_path":"m/0'","count":4294967295}"#);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
 }
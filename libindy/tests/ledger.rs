@@ -36,6 +36,7 @@ use utils::constants::*;
 use self::openssl::hash::{MessageDigest, Hasher};
 use self::hex::ToHex;
 use self::sodiumoxide::crypto::secretbox;
+use std::collections::HashMap;
 
 mod high_cases {
     use super::*;
@@ -703,6 +704,126 @@ mod high_cases {
         }
     }
 
+    mod schema_cache {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_schema_works_from_cache() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (did, _) = DidUtils::create_store_and_publish_my_did_from_trustee(wallet_handle, pool_handle).unwrap();
+
+            let schema_request = LedgerUtils::build_schema_request(&did, SCHEMA_DATA).unwrap();
+            let schema_req_resp = LedgerUtils::sign_and_submit_request(pool_handle, wallet_handle, &did, &schema_request).unwrap();
+
+            let get_schema_request = LedgerUtils::build_get_schema_request(&did, &did, GET_SCHEMA_DATA).unwrap();
+            LedgerUtils::submit_request_with_retries(pool_handle, &get_schema_request, &schema_req_resp).unwrap();
+
+            let get_schema_response = LedgerUtils::get_schema(pool_handle, wallet_handle, &did, &did, GET_SCHEMA_DATA, None).unwrap();
+
+            let cached_get_schema_response = LedgerUtils::get_schema(pool_handle, wallet_handle, &did, &did, GET_SCHEMA_DATA,
+                                                                     Some(r#"{"no_update":true}"#)).unwrap();
+            assert_eq!(get_schema_response, cached_get_schema_response);
+
+            let cached_get_schema_response: Reply<GetSchemaReplyResult> = serde_json::from_str(&cached_get_schema_response).unwrap();
+            assert!(cached_get_schema_response.result.data.is_some());
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_schema_works_for_no_store() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (did, _) = DidUtils::create_store_and_publish_my_did_from_trustee(wallet_handle, pool_handle).unwrap();
+
+            let schema_request = LedgerUtils::build_schema_request(&did, SCHEMA_DATA).unwrap();
+            let schema_req_resp = LedgerUtils::sign_and_submit_request(pool_handle, wallet_handle, &did, &schema_request).unwrap();
+
+            let get_schema_request = LedgerUtils::build_get_schema_request(&did, &did, GET_SCHEMA_DATA).unwrap();
+            LedgerUtils::submit_request_with_retries(pool_handle, &get_schema_request, &schema_req_resp).unwrap();
+
+            LedgerUtils::get_schema(pool_handle, wallet_handle, &did, &did, GET_SCHEMA_DATA, Some(r#"{"no_store":true}"#)).unwrap();
+
+            let res = LedgerUtils::get_schema(pool_handle, wallet_handle, &did, &did, GET_SCHEMA_DATA, Some(r#"{"no_update":true}"#));
+            assert_eq!(ErrorCode::WalletNotFoundError, res.unwrap_err());
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_purge_schema_cache_works() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (did, _) = DidUtils::create_store_and_publish_my_did_from_trustee(wallet_handle, pool_handle).unwrap();
+
+            let schema_request = LedgerUtils::build_schema_request(&did, SCHEMA_DATA).unwrap();
+            let schema_req_resp = LedgerUtils::sign_and_submit_request(pool_handle, wallet_handle, &did, &schema_request).unwrap();
+
+            let get_schema_request = LedgerUtils::build_get_schema_request(&did, &did, GET_SCHEMA_DATA).unwrap();
+            LedgerUtils::submit_request_with_retries(pool_handle, &get_schema_request, &schema_req_resp).unwrap();
+
+            LedgerUtils::get_schema(pool_handle, wallet_handle, &did, &did, GET_SCHEMA_DATA, None).unwrap();
+
+            LedgerUtils::purge_schema_cache(wallet_handle, Some(r#"{"max_age":1000}"#)).unwrap();
+            LedgerUtils::get_schema(pool_handle, wallet_handle, &did, &did, GET_SCHEMA_DATA, Some(r#"{"no_update":true}"#)).unwrap();
+
+            LedgerUtils::purge_schema_cache(wallet_handle, None).unwrap();
+            let res = LedgerUtils::get_schema(pool_handle, wallet_handle, &did, &did, GET_SCHEMA_DATA, Some(r#"{"no_update":true}"#));
+            assert_eq!(ErrorCode::WalletNotFoundError, res.unwrap_err());
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_get_schema_works_for_invalid_options() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = LedgerUtils::get_schema(-1, wallet_handle, IDENTIFIER, DEST, GET_SCHEMA_DATA, Some(r#"{"min_fresh":"1"}"#));
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_purge_claim_def_cache_works_for_empty_cache() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            LedgerUtils::purge_claim_def_cache(wallet_handle, None).unwrap();
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
     mod node_request {
         use super::*;
 
@@ -856,6 +977,165 @@ mod high_cases {
 
             TestUtils::cleanup_storage();
         }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_verify_proof_with_ledger_works() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let issuer_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let prover_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (did, _) = DidUtils::create_store_and_publish_my_did_from_trustee(issuer_wallet_handle, pool_handle).unwrap();
+
+            // Issuer publishes schema and claim definition
+            let schema_data = r#"{"name":"gvt","version":"1.0","attr_names":["age","sex","height","name"]}"#;
+            let schema_request = LedgerUtils::build_schema_request(&did, schema_data).unwrap();
+            let schema_req_resp = LedgerUtils::sign_and_submit_request(pool_handle, issuer_wallet_handle, &did, &schema_request).unwrap();
+
+            let get_schema_request = LedgerUtils::build_get_schema_request(&did, &did, r#"{"name":"gvt","version":"1.0"}"#).unwrap();
+            let get_schema_response = LedgerUtils::submit_request_with_retries(pool_handle, &get_schema_request, &schema_req_resp).unwrap();
+            let get_schema_response: Reply<GetSchemaReplyResult> = serde_json::from_str(&get_schema_response).unwrap();
+            let schema_seq_no = get_schema_response.result.seq_no.unwrap();
+
+            let schema_json = format!(r#"{{"seqNo":{},"dest":"{}","data":{}}}"#, schema_seq_no, did, schema_data);
+
+            let claim_def_json = AnoncredsUtils::issuer_create_claim_definition(issuer_wallet_handle, &did, &schema_json, None, false).unwrap();
+            let claim_def: serde_json::Value = serde_json::from_str(&claim_def_json).unwrap();
+
+            let claim_def_request = LedgerUtils::build_claim_def_txn(&did, schema_seq_no, SIGNATURE_TYPE, &claim_def["data"].to_string()).unwrap();
+            let claim_def_req_resp = LedgerUtils::sign_and_submit_request(pool_handle, issuer_wallet_handle, &did, &claim_def_request).unwrap();
+
+            let get_claim_def_request = LedgerUtils::build_get_claim_def_txn(&did, schema_seq_no, SIGNATURE_TYPE, &did).unwrap();
+            LedgerUtils::submit_request_with_retries(pool_handle, &get_claim_def_request, &claim_def_req_resp).unwrap();
+
+            // Prover gets claim
+            AnoncredsUtils::prover_create_master_secret(prover_wallet_handle, "master_secret").unwrap();
+
+            let claim_offer_json = AnoncredsUtils::issuer_create_claim_offer(issuer_wallet_handle, &schema_json, &did, DID_MY1).unwrap();
+            let claim_req_json = AnoncredsUtils::prover_create_and_store_claim_req(prover_wallet_handle, DID_MY1, &claim_offer_json,
+                                                                                   &claim_def_json, "master_secret").unwrap();
+
+            let (_, claim_json) = AnoncredsUtils::issuer_create_claim(issuer_wallet_handle, &claim_req_json,
+                                                                      &AnoncredsUtils::gvt_claim_values_json(), None).unwrap();
+            AnoncredsUtils::prover_store_claim(prover_wallet_handle, &claim_json, None).unwrap();
+
+            // Prover creates proof
+            let proof_req_json = AnoncredsUtils::proof_request_attr();
+
+            let claims_json = AnoncredsUtils::prover_get_claims_for_proof_req(prover_wallet_handle, proof_req_json).unwrap();
+            let claim = AnoncredsUtils::get_claim_for_attr_referent(&claims_json, "attr1_referent");
+
+            let requested_claims_json = format!(r#"{{"self_attested_attributes":{{}},"requested_attrs":{{"attr1_referent":["{}", true]}},"requested_predicates":{{}}}}"#,
+                                                claim.referent);
+
+            let proof_json = AnoncredsUtils::prover_create_proof(prover_wallet_handle,
+                                                                 proof_req_json,
+                                                                 &requested_claims_json,
+                                                                 &format!(r#"{{"{}":{}}}"#, claim.referent, schema_json),
+                                                                 "master_secret",
+                                                                 &format!(r#"{{"{}":{}}}"#, claim.referent, claim_def_json),
+                                                                 "{}").unwrap();
+
+            // Verifier verifies proof with schema and claim definition taken from the ledger
+            let (valid, resolved_json) = AnoncredsUtils::verifier_verify_proof_with_ledger(pool_handle, &did, proof_req_json, &proof_json).unwrap();
+            assert!(valid);
+
+            let resolved: serde_json::Value = serde_json::from_str(&resolved_json).unwrap();
+            assert_eq!(schema_seq_no as i64, resolved["schemas"][&claim.referent]["seqNo"].as_i64().unwrap());
+            assert_eq!(did, resolved["claim_defs"][&claim.referent]["origin"].as_str().unwrap());
+            assert!(resolved["revoc_regs"].as_object().unwrap().is_empty());
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(issuer_wallet_handle).unwrap();
+            WalletUtils::close_wallet(prover_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
+    mod revoc_reg_requests {
+        use super::*;
+
+        const REVOC_REG_DEF_ID: &'static str = "NcYxiDXkpYi6ov5FcYDi1e:4:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:CL_ACCUM:tag1";
+
+        #[test]
+        fn indy_build_revoc_reg_def_request_works() {
+            let data = format!(r#"{{"id":"{}","revocDefType":"CL_ACCUM","tag":"tag1","credDefId":"NcYxiDXkpYi6ov5FcYDi1e:3:CL:1","value":{{"issuanceType":"ISSUANCE_ON_DEMAND","maxCredNum":5,"publicKeys":{{}}}}}}"#, REVOC_REG_DEF_ID);
+
+            let expected_result = format!(r#""operation":{{"type":"113","id":"{}","revocDefType":"CL_ACCUM","tag":"tag1","credDefId":"NcYxiDXkpYi6ov5FcYDi1e:3:CL:1","value":{{"issuanceType":"ISSUANCE_ON_DEMAND","maxCredNum":5,"publicKeys":{{}}}}}}"#, REVOC_REG_DEF_ID);
+
+            let request = LedgerUtils::build_revoc_reg_def_request(IDENTIFIER, &data).unwrap();
+            assert!(request.contains(&expected_result));
+        }
+
+        #[test]
+        fn indy_build_revoc_reg_def_request_works_for_invalid_data() {
+            let res = LedgerUtils::build_revoc_reg_def_request(IDENTIFIER, r#"{"id":"1"}"#);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+        }
+
+        #[test]
+        fn indy_build_revoc_reg_entry_request_works() {
+            let expected_result = format!(r#""operation":{{"type":"114","revocRegDefId":"{}","revocDefType":"CL_ACCUM","value":{{"accum":"123456789"}}}}"#, REVOC_REG_DEF_ID);
+
+            let request = LedgerUtils::build_revoc_reg_entry_request(IDENTIFIER, REVOC_REG_DEF_ID, "CL_ACCUM", r#"{"accum":"123456789"}"#).unwrap();
+            assert!(request.contains(&expected_result));
+        }
+
+        #[test]
+        fn indy_build_get_revoc_reg_def_request_works() {
+            let expected_result = format!(r#""operation":{{"type":"115","id":"{}"}}"#, REVOC_REG_DEF_ID);
+
+            let request = LedgerUtils::build_get_revoc_reg_def_request(IDENTIFIER, REVOC_REG_DEF_ID).unwrap();
+            assert!(request.contains(&expected_result));
+        }
+
+        #[test]
+        fn indy_build_get_revoc_reg_request_works() {
+            let expected_result = format!(r#""operation":{{"type":"116","revocRegDefId":"{}","timestamp":100}}"#, REVOC_REG_DEF_ID);
+
+            let request = LedgerUtils::build_get_revoc_reg_request(IDENTIFIER, REVOC_REG_DEF_ID, 100).unwrap();
+            assert!(request.contains(&expected_result));
+        }
+
+        #[test]
+        fn indy_build_get_revoc_reg_delta_request_works_without_from() {
+            let expected_result = format!(r#""operation":{{"type":"117","revocRegDefId":"{}","to":100}}"#, REVOC_REG_DEF_ID);
+
+            let request = LedgerUtils::build_get_revoc_reg_delta_request(IDENTIFIER, REVOC_REG_DEF_ID, None, 100).unwrap();
+            assert!(request.contains(&expected_result));
+        }
+
+        #[test]
+        fn indy_parse_get_revoc_reg_def_response_works() {
+            let response = format!(r#"{{"op":"REPLY","result":{{"type":"115","id":"{0}","seqNo":10,"txnTime":90,"data":{{"id":"{0}","revocDefType":"CL_ACCUM","tag":"tag1","credDefId":"NcYxiDXkpYi6ov5FcYDi1e:3:CL:1","value":{{"issuanceType":"ISSUANCE_ON_DEMAND","maxCredNum":5,"publicKeys":{{}}}}}}}}}}"#, REVOC_REG_DEF_ID);
+
+            let (id, revoc_reg_def_json) = LedgerUtils::parse_get_revoc_reg_def_response(&response).unwrap();
+            assert_eq!(REVOC_REG_DEF_ID, id);
+
+            let revoc_reg_def: serde_json::Value = serde_json::from_str(&revoc_reg_def_json).unwrap();
+            assert_eq!("tag1", revoc_reg_def["tag"].as_str().unwrap());
+        }
+
+        #[test]
+        fn indy_parse_get_revoc_reg_response_works_for_not_found() {
+            let response = format!(r#"{{"op":"REPLY","result":{{"type":"116","revocRegDefId":"{}","timestamp":100,"data":null}}}}"#, REVOC_REG_DEF_ID);
+
+            let res = LedgerUtils::parse_get_revoc_reg_response(&response);
+            assert_eq!(ErrorCode::CommonInvalidState, res.unwrap_err());
+        }
+
+        #[test]
+        fn indy_parse_get_revoc_reg_delta_response_works() {
+            let response = format!(r#"{{"op":"REPLY","result":{{"type":"117","revocRegDefId":"{0}","data":{{"revocDefType":"CL_ACCUM","revocRegDefId":"{0}","value":{{"accum_to":{{"value":{{"accum":"2"}},"txnTime":90}},"issued":[1],"revoked":[]}}}}}}}}"#, REVOC_REG_DEF_ID);
+
+            let (id, delta_json, timestamp) = LedgerUtils::parse_get_revoc_reg_delta_response(&response).unwrap();
+            assert_eq!(REVOC_REG_DEF_ID, id);
+            assert_eq!(r#"{"accum":"2","issued":[1],"revoked":[]}"#, delta_json);
+            assert_eq!(90, timestamp);
+        }
     }
 
     mod get_txn_requests {
@@ -935,6 +1215,99 @@ mod high_cases {
         }
     }
 
+    mod ledger_txn_reader {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_ledger_txn_reader_works_for_pool_ledger() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+
+            let reader_handle = LedgerUtils::open_ledger_txn_reader(pool_handle, DID_TRUSTEE, 0, 1, None).unwrap();
+
+            let txns = LedgerUtils::fetch_ledger_txns(reader_handle, 2).unwrap();
+            let txns: Vec<serde_json::Value> = serde_json::from_str(&txns).unwrap();
+            assert_eq!(2, txns.len());
+            assert_eq!(1, txns[0]["seqNo"].as_i64().unwrap());
+            assert_eq!(2, txns[1]["seqNo"].as_i64().unwrap());
+            assert_eq!(0, txns[0]["ledgerId"].as_i64().unwrap());
+
+            let txns = LedgerUtils::fetch_ledger_txns(reader_handle, 10).unwrap();
+            let txns: Vec<serde_json::Value> = serde_json::from_str(&txns).unwrap();
+            assert_eq!(2, txns.len());
+            assert_eq!(3, txns[0]["seqNo"].as_i64().unwrap());
+
+            let txns = LedgerUtils::fetch_ledger_txns(reader_handle, 10).unwrap();
+            assert_eq!("[]", txns);
+
+            LedgerUtils::close_ledger_txn_reader(reader_handle).unwrap();
+
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_ledger_txn_reader_works_for_range() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+
+            let reader_handle = LedgerUtils::open_ledger_txn_reader(pool_handle, DID_TRUSTEE, 1, 2, Some(3)).unwrap();
+
+            let txns = LedgerUtils::fetch_ledger_txns(reader_handle, 10).unwrap();
+            let txns: Vec<serde_json::Value> = serde_json::from_str(&txns).unwrap();
+            assert_eq!(2, txns.len());
+            assert_eq!(2, txns[0]["seqNo"].as_i64().unwrap());
+            assert_eq!(3, txns[1]["seqNo"].as_i64().unwrap());
+
+            LedgerUtils::close_ledger_txn_reader(reader_handle).unwrap();
+
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_open_ledger_txn_reader_works_for_invalid_ledger_id() {
+            TestUtils::cleanup_storage();
+
+            let res = LedgerUtils::open_ledger_txn_reader(1, DID_TRUSTEE, 5, 1, None);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_open_ledger_txn_reader_works_for_invalid_range() {
+            TestUtils::cleanup_storage();
+
+            let res = LedgerUtils::open_ledger_txn_reader(1, DID_TRUSTEE, 1, 5, Some(2));
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_fetch_ledger_txns_works_for_closed_reader() {
+            TestUtils::cleanup_storage();
+
+            let reader_handle = LedgerUtils::open_ledger_txn_reader(1, DID_TRUSTEE, 1, 1, None).unwrap();
+            LedgerUtils::close_ledger_txn_reader(reader_handle).unwrap();
+
+            let res = LedgerUtils::fetch_ledger_txns(reader_handle, 1);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidParam2);
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
     mod pool_config {
         use super::*;
 
@@ -1099,6 +1472,173 @@ mod high_cases {
             TestUtils::cleanup_storage();
         }
     }
+
+    mod pool_restart {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_build_pool_restart_request_works_for_start_action() {
+            TestUtils::cleanup_storage();
+
+            let expected_result = r#""operation":{"type":"118","action":"start","datetime":"0""#;
+            let request = LedgerUtils::build_pool_restart_request(DID_TRUSTEE, "start", Some("0")).unwrap();
+            assert!(request.contains(expected_result));
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_build_pool_restart_request_works_for_cancel_action() {
+            TestUtils::cleanup_storage();
+
+            let expected_result = r#""operation":{"type":"118","action":"cancel"}"#;
+            let request = LedgerUtils::build_pool_restart_request(DID_TRUSTEE, "cancel", None).unwrap();
+            assert!(request.contains(expected_result));
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_build_pool_restart_request_works_for_invalid_action() {
+            TestUtils::cleanup_storage();
+
+            let res = LedgerUtils::build_pool_restart_request(DID_TRUSTEE, "stop", None);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_pool_restart_request_works_for_cancel() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let request = LedgerUtils::build_pool_restart_request(&trustee_did, "cancel", None).unwrap();
+            let request = LedgerUtils::sign_request(wallet_handle, &trustee_did, &request).unwrap();
+            let response = LedgerUtils::submit_action(pool_handle, &request, None, None).unwrap();
+
+            let response: HashMap<String, String> = serde_json::from_str(&response).unwrap();
+            assert_eq!(4, response.len());
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
+    mod validator_info {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_build_get_validator_info_request_works() {
+            TestUtils::cleanup_storage();
+
+            let expected_result = r#""operation":{"type":"119"}"#;
+            let request = LedgerUtils::build_get_validator_info_request(DID_TRUSTEE).unwrap();
+            assert!(request.contains(expected_result));
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_validator_info_request_works() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let request = LedgerUtils::build_get_validator_info_request(&trustee_did).unwrap();
+            let request = LedgerUtils::sign_request(wallet_handle, &trustee_did, &request).unwrap();
+            let response = LedgerUtils::submit_action(pool_handle, &request, None, None).unwrap();
+
+            let response: HashMap<String, String> = serde_json::from_str(&response).unwrap();
+            assert_eq!(4, response.len());
+            for node in vec!["Node1", "Node2", "Node3", "Node4"] {
+                let reply = response.get(node).unwrap();
+                serde_json::from_str::<serde_json::Value>(reply).unwrap();
+            }
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_validator_info_request_works_for_nodes_filter() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let request = LedgerUtils::build_get_validator_info_request(&trustee_did).unwrap();
+            let request = LedgerUtils::sign_request(wallet_handle, &trustee_did, &request).unwrap();
+            let response = LedgerUtils::submit_action(pool_handle, &request, Some(r#"["Node1", "Node2"]"#), Some(5)).unwrap();
+
+            let response: HashMap<String, String> = serde_json::from_str(&response).unwrap();
+            assert_eq!(2, response.len());
+            assert!(response.contains_key("Node1"));
+            assert!(response.contains_key("Node2"));
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_action_works_for_empty_nodes_list() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let request = LedgerUtils::build_get_validator_info_request(&trustee_did).unwrap();
+            let request = LedgerUtils::sign_request(wallet_handle, &trustee_did, &request).unwrap();
+            let res = LedgerUtils::submit_action(pool_handle, &request, Some("[]"), Some(5));
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_action_works_for_not_action_request() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+
+            let request = LedgerUtils::build_get_txn_request(DID_TRUSTEE, 1).unwrap();
+            let res = LedgerUtils::submit_action(pool_handle, &request, None, None);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
 }
 
 mod medium_cases {
@@ -4,6 +4,7 @@ use indy::api::ErrorCode;
 use indy::api::anoncreds::*;
 
 use utils::callback::CallbackUtils;
+use utils::did::DidUtils;
 use utils::wallet::WalletUtils;
 use utils::test::TestUtils;
 use utils::types::{ClaimsForProofRequest, ClaimInfo, Schema, SchemaData, SchemaKey};
@@ -48,6 +49,65 @@ impl AnoncredsUtils {
         super::results::result_to_string(err, receiver)
     }
 
+    pub fn issuer_create_claim_definition_with_tag(wallet_handle: i32, issuer_did: &str, schema: &str, create_non_revoc: bool, tag: &str) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let schema = CString::new(schema).unwrap();
+        let issuer_did = CString::new(issuer_did).unwrap();
+        let tag = CString::new(tag).unwrap();
+
+        let err =
+            indy_issuer_create_and_store_claim_def_with_tag(command_handle,
+                                                            wallet_handle,
+                                                            issuer_did.as_ptr(),
+                                                            schema.as_ptr(),
+                                                            null(),
+                                                            create_non_revoc,
+                                                            tag.as_ptr(),
+                                                            cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn issuer_set_active_claim_definition(wallet_handle: i32, issuer_did: &str, schema: &str, tag: Option<&str>) -> Result<(), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
+
+        let schema = CString::new(schema).unwrap();
+        let issuer_did = CString::new(issuer_did).unwrap();
+        let tag_str = tag.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+
+        let err = indy_issuer_set_active_claim_def(command_handle,
+                                                   wallet_handle,
+                                                   issuer_did.as_ptr(),
+                                                   schema.as_ptr(),
+                                                   if tag.is_some() { tag_str.as_ptr() } else { null() },
+                                                   cb);
+
+        super::results::result_to_empty(err, receiver)
+    }
+
+    pub fn issuer_list_claim_definitions(wallet_handle: i32, issuer_did: &str, schema: &str) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let schema = CString::new(schema).unwrap();
+        let issuer_did = CString::new(issuer_did).unwrap();
+
+        let err = indy_issuer_list_claim_defs(command_handle, wallet_handle, issuer_did.as_ptr(), schema.as_ptr(), cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn issuer_compare_schemas(schema: &str, other_schema: &str) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let schema = CString::new(schema).unwrap();
+        let other_schema = CString::new(other_schema).unwrap();
+
+        let err = indy_issuer_compare_schemas(command_handle, schema.as_ptr(), other_schema.as_ptr(), cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
     pub fn prover_create_master_secret(wallet_handle: i32, master_secret_name: &str) -> Result<(), ErrorCode> {
         let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
 
@@ -58,6 +118,39 @@ impl AnoncredsUtils {
         super::results::result_to_empty(err, receiver)
     }
 
+    pub fn prover_list_master_secrets(wallet_handle: i32) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let err = indy_prover_list_master_secrets(command_handle, wallet_handle, cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn prover_export_master_secret(wallet_handle: i32, master_secret_name: &str, passphrase: &str) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let master_secret_name = CString::new(master_secret_name).unwrap();
+        let passphrase = CString::new(passphrase).unwrap();
+
+        let err = indy_prover_export_master_secret(command_handle, wallet_handle, master_secret_name.as_ptr(), passphrase.as_ptr(), cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn prover_import_master_secret(wallet_handle: i32, master_secret_name: &str, exported_master_secret_json: &str,
+                                       passphrase: &str) -> Result<(), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
+
+        let master_secret_name = CString::new(master_secret_name).unwrap();
+        let exported_master_secret_json = CString::new(exported_master_secret_json).unwrap();
+        let passphrase = CString::new(passphrase).unwrap();
+
+        let err = indy_prover_import_master_secret(command_handle, wallet_handle, master_secret_name.as_ptr(),
+                                                   exported_master_secret_json.as_ptr(), passphrase.as_ptr(), cb);
+
+        super::results::result_to_empty(err, receiver)
+    }
+
     pub fn issuer_create_claim_offer(wallet_handle: i32, schema: &str, issuer_did: &str, prover_did: &str) -> Result<String, ErrorCode> {
         let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
 
@@ -76,6 +169,45 @@ impl AnoncredsUtils {
         super::results::result_to_string(err, receiver)
     }
 
+    pub fn issuer_create_claim_offer_with_expiration(wallet_handle: i32, schema: &str, issuer_did: &str, prover_did: &str,
+                                                     expires_in: u32) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let schema = CString::new(schema).unwrap();
+        let issuer_did = CString::new(issuer_did).unwrap();
+        let prover_did = CString::new(prover_did).unwrap();
+
+        let err =
+            indy_issuer_create_claim_offer_with_expiration(command_handle,
+                                                           wallet_handle,
+                                                           schema.as_ptr(),
+                                                           issuer_did.as_ptr(),
+                                                           prover_did.as_ptr(),
+                                                           expires_in,
+                                                           cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn issuer_withdraw_claim_offer(wallet_handle: i32, issuer_did: &str, schema: &str, claim_def_tag: Option<&str>, prover_did: &str) -> Result<(), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
+
+        let issuer_did = CString::new(issuer_did).unwrap();
+        let schema = CString::new(schema).unwrap();
+        let claim_def_tag_str = claim_def_tag.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+        let prover_did = CString::new(prover_did).unwrap();
+
+        let err = indy_issuer_withdraw_claim_offer(command_handle,
+                                                   wallet_handle,
+                                                   issuer_did.as_ptr(),
+                                                   schema.as_ptr(),
+                                                   if claim_def_tag.is_some() { claim_def_tag_str.as_ptr() } else { null() },
+                                                   prover_did.as_ptr(),
+                                                   cb);
+
+        super::results::result_to_empty(err, receiver)
+    }
+
     pub fn prover_store_claim_offer(wallet_handle: i32, claim_offer_json: &str) -> Result<(), ErrorCode> {
         let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
 
@@ -135,21 +267,73 @@ impl AnoncredsUtils {
         super::results::result_to_string_string(err, receiver)
     }
 
+    pub fn issuer_create_claims(wallet_handle: i32, claim_reqs_json: &str) -> Result<(String, String), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string_string();
+
+        let claim_reqs_json = CString::new(claim_reqs_json).unwrap();
+
+        let err = indy_issuer_create_claims(command_handle, wallet_handle, claim_reqs_json.as_ptr(), cb);
+
+        super::results::result_to_string_string(err, receiver)
+    }
+
     pub fn prover_store_claim(wallet_handle: i32, claim_json: &str, rev_reg_json: Option<&str>) -> Result<(), ErrorCode> {
+        AnoncredsUtils::prover_store_claim_with_tails_config(wallet_handle, claim_json, rev_reg_json, None)
+    }
+
+    pub fn prover_store_claim_with_tails_config(wallet_handle: i32, claim_json: &str, rev_reg_json: Option<&str>,
+                                                tails_reader_config_json: Option<&str>) -> Result<(), ErrorCode> {
         let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
 
         let claim_json = CString::new(claim_json).unwrap();
         let rev_reg_json_str = rev_reg_json.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+        let tails_reader_config_json_str = tails_reader_config_json.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
 
         let err = indy_prover_store_claim(command_handle,
                                           wallet_handle,
                                           claim_json.as_ptr(),
                                           if rev_reg_json.is_some() { rev_reg_json_str.as_ptr() } else { null() },
+                                          if tails_reader_config_json.is_some() { tails_reader_config_json_str.as_ptr() } else { null() },
                                           cb);
 
         super::results::result_to_empty(err, receiver)
     }
 
+    pub fn prover_delete_claim(wallet_handle: i32, referent: &str) -> Result<(), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
+
+        let referent = CString::new(referent).unwrap();
+
+        let err = indy_prover_delete_claim(command_handle, wallet_handle, referent.as_ptr(), cb);
+
+        super::results::result_to_empty(err, receiver)
+    }
+
+    pub fn prover_set_claim_metadata(wallet_handle: i32, referent: &str, metadata: Option<&str>) -> Result<(), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
+
+        let referent = CString::new(referent).unwrap();
+        let metadata_str = metadata.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+
+        let err = indy_prover_set_claim_metadata(command_handle,
+                                                 wallet_handle,
+                                                 referent.as_ptr(),
+                                                 if metadata.is_some() { metadata_str.as_ptr() } else { null() },
+                                                 cb);
+
+        super::results::result_to_empty(err, receiver)
+    }
+
+    pub fn prover_get_claim_metadata(wallet_handle: i32, referent: &str) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let referent = CString::new(referent).unwrap();
+
+        let err = indy_prover_get_claim_metadata(command_handle, wallet_handle, referent.as_ptr(), cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
     pub fn prover_get_claims(wallet_handle: i32, filter_json: &str) -> Result<String, ErrorCode> {
         let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
 
@@ -173,9 +357,85 @@ impl AnoncredsUtils {
         super::results::result_to_string(err, receiver)
     }
 
+    pub fn prover_select_claims_for_proof_req(wallet_handle: i32, proof_request_json: &str, self_attested_values_json: Option<&str>) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let proof_request_json = CString::new(proof_request_json).unwrap();
+        let self_attested_values_json_str = self_attested_values_json.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+
+        let err = indy_prover_select_claims_for_proof_req(command_handle,
+                                                          wallet_handle,
+                                                          proof_request_json.as_ptr(),
+                                                          if self_attested_values_json.is_some() { self_attested_values_json_str.as_ptr() } else { null() },
+                                                          cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn prover_search_claims(wallet_handle: i32, filter_json: &str) -> Result<i32, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_i32();
+
+        let filter_json = CString::new(filter_json).unwrap();
+
+        let err = indy_prover_search_claims(command_handle, wallet_handle, filter_json.as_ptr(), cb);
+
+        super::results::result_to_int(err, receiver)
+    }
+
+    pub fn prover_fetch_claims(search_handle: i32, count: u32) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let err = indy_prover_fetch_claims(command_handle, search_handle, count, cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn prover_close_claims_search(search_handle: i32) -> Result<(), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
+
+        let err = indy_prover_close_claims_search(command_handle, search_handle, cb);
+
+        super::results::result_to_empty(err, receiver)
+    }
+
+    pub fn prover_search_claims_for_proof_req(wallet_handle: i32, proof_request_json: &str) -> Result<i32, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_i32();
+
+        let proof_request_json = CString::new(proof_request_json).unwrap();
+
+        let err = indy_prover_search_claims_for_proof_req(command_handle, wallet_handle, proof_request_json.as_ptr(), cb);
+
+        super::results::result_to_int(err, receiver)
+    }
+
+    pub fn prover_fetch_claims_for_proof_req(search_handle: i32, item_referent: &str, count: u32) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let item_referent = CString::new(item_referent).unwrap();
+
+        let err = indy_prover_fetch_claims_for_proof_req(command_handle, search_handle, item_referent.as_ptr(), count, cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn prover_close_claims_search_for_proof_req(search_handle: i32) -> Result<(), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
+
+        let err = indy_prover_close_claims_search_for_proof_req(command_handle, search_handle, cb);
+
+        super::results::result_to_empty(err, receiver)
+    }
+
     pub fn prover_create_proof(wallet_handle: i32, proof_req_json: &str, requested_claims_json: &str,
                                schemas_json: &str, master_secret_name: &str, claim_defs_json: &str,
                                revoc_regs_json: &str) -> Result<String, ErrorCode> {
+        AnoncredsUtils::prover_create_proof_with_tails_config(wallet_handle, proof_req_json, requested_claims_json, schemas_json,
+                                                              master_secret_name, claim_defs_json, revoc_regs_json, None)
+    }
+
+    pub fn prover_create_proof_with_tails_config(wallet_handle: i32, proof_req_json: &str, requested_claims_json: &str,
+                                                 schemas_json: &str, master_secret_name: &str, claim_defs_json: &str,
+                                                 revoc_regs_json: &str, tails_reader_config_json: Option<&str>) -> Result<String, ErrorCode> {
         let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
 
         let proof_req_json = CString::new(proof_req_json).unwrap();
@@ -184,6 +444,7 @@ impl AnoncredsUtils {
         let master_secret_name = CString::new(master_secret_name).unwrap();
         let claim_defs_json = CString::new(claim_defs_json).unwrap();
         let revoc_regs_json = CString::new(revoc_regs_json).unwrap();
+        let tails_reader_config_json_str = tails_reader_config_json.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
 
         let err = indy_prover_create_proof(command_handle,
                                            wallet_handle,
@@ -193,6 +454,7 @@ impl AnoncredsUtils {
                                            master_secret_name.as_ptr(),
                                            claim_defs_json.as_ptr(),
                                            revoc_regs_json.as_ptr(),
+                                           if tails_reader_config_json.is_some() { tails_reader_config_json_str.as_ptr() } else { null() },
                                            cb);
 
         super::results::result_to_string(err, receiver)
@@ -200,6 +462,13 @@ impl AnoncredsUtils {
 
     pub fn verifier_verify_proof(proof_request_json: &str, proof_json: &str,
                                  schemas_json: &str, claim_defs_json: &str, revoc_regs_json: &str) -> Result<bool, ErrorCode> {
+        AnoncredsUtils::verifier_verify_proof_with_tails_config(proof_request_json, proof_json, schemas_json, claim_defs_json,
+                                                                revoc_regs_json, None)
+    }
+
+    pub fn verifier_verify_proof_with_tails_config(proof_request_json: &str, proof_json: &str, schemas_json: &str,
+                                                   claim_defs_json: &str, revoc_regs_json: &str,
+                                                   tails_reader_config_json: Option<&str>) -> Result<bool, ErrorCode> {
         let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_bool();
 
         let proof_request_json = CString::new(proof_request_json).unwrap();
@@ -207,6 +476,7 @@ impl AnoncredsUtils {
         let schemas_json = CString::new(schemas_json).unwrap();
         let claim_defs_json = CString::new(claim_defs_json).unwrap();
         let revoc_regs_json = CString::new(revoc_regs_json).unwrap();
+        let tails_reader_config_json_str = tails_reader_config_json.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
 
         let err = indy_verifier_verify_proof(command_handle,
                                              proof_request_json.as_ptr(),
@@ -214,22 +484,92 @@ impl AnoncredsUtils {
                                              schemas_json.as_ptr(),
                                              claim_defs_json.as_ptr(),
                                              revoc_regs_json.as_ptr(),
+                                             if tails_reader_config_json.is_some() { tails_reader_config_json_str.as_ptr() } else { null() },
                                              cb);
 
         super::results::result_to_bool(err, receiver)
     }
 
+    pub fn verifier_verify_proof_with_ledger(pool_handle: i32, submitter_did: &str, proof_request_json: &str,
+                                             proof_json: &str) -> Result<(bool, String), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_bool_string();
+
+        let submitter_did = CString::new(submitter_did).unwrap();
+        let proof_request_json = CString::new(proof_request_json).unwrap();
+        let proof_json = CString::new(proof_json).unwrap();
+
+        let err = indy_verifier_verify_proof_with_ledger(command_handle,
+                                                         pool_handle,
+                                                         submitter_did.as_ptr(),
+                                                         proof_request_json.as_ptr(),
+                                                         proof_json.as_ptr(),
+                                                         null(),
+                                                         cb);
+
+        super::results::result_to_bool_string(err, receiver)
+    }
+
+    pub fn verifier_generate_nonce() -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let err = indy_verifier_generate_nonce(command_handle, cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn verifier_build_proof_request(name: &str, version: &str, requested_attrs_json: &str,
+                                        requested_predicates_json: &str, non_revoked_json: Option<&str>) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let name = CString::new(name).unwrap();
+        let version = CString::new(version).unwrap();
+        let requested_attrs_json = CString::new(requested_attrs_json).unwrap();
+        let requested_predicates_json = CString::new(requested_predicates_json).unwrap();
+        let non_revoked_json_str = non_revoked_json.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+
+        let err = indy_verifier_build_proof_request(command_handle,
+                                                    name.as_ptr(),
+                                                    version.as_ptr(),
+                                                    requested_attrs_json.as_ptr(),
+                                                    requested_predicates_json.as_ptr(),
+                                                    if non_revoked_json.is_some() { non_revoked_json_str.as_ptr() } else { null() },
+                                                    cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn verifier_inspect_proof(proof_request_json: &str, proof_json: &str) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let proof_request_json = CString::new(proof_request_json).unwrap();
+        let proof_json = CString::new(proof_json).unwrap();
+
+        let err = indy_verifier_inspect_proof(command_handle,
+                                              proof_request_json.as_ptr(),
+                                              proof_json.as_ptr(),
+                                              cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
     pub fn indy_issuer_create_and_store_revoc_reg(wallet_handle: i32, issuer_did: &str, schema_json: &str, max_claim_num: u32) -> Result<String, ErrorCode> {
+        AnoncredsUtils::indy_issuer_create_and_store_revoc_reg_with_tails_config(wallet_handle, issuer_did, schema_json, max_claim_num, None)
+    }
+
+    pub fn indy_issuer_create_and_store_revoc_reg_with_tails_config(wallet_handle: i32, issuer_did: &str, schema_json: &str, max_claim_num: u32,
+                                                                    tails_writer_config_json: Option<&str>) -> Result<String, ErrorCode> {
         let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
 
         let issuer_did = CString::new(issuer_did).unwrap();
         let schema_json = CString::new(schema_json).unwrap();
+        let tails_writer_config_json_str = tails_writer_config_json.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
 
         let err = indy_issuer_create_and_store_revoc_reg(command_handle,
                                                          wallet_handle,
                                                          issuer_did.as_ptr(),
                                                          schema_json.as_ptr(),
                                                          max_claim_num,
+                                                         if tails_writer_config_json.is_some() { tails_writer_config_json_str.as_ptr() } else { null() },
                                                          cb);
 
         super::results::result_to_string(err, receiver)
@@ -252,6 +592,59 @@ impl AnoncredsUtils {
         super::results::result_to_string(err, receiver)
     }
 
+    pub fn issuer_revoke_claim_with_tag(wallet_handle: i32, issuer_did: &str, schema_json: &str, claim_def_tag: &str, user_revoc_index: u32) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let issuer_did = CString::new(issuer_did).unwrap();
+        let schema_json = CString::new(schema_json).unwrap();
+        let claim_def_tag = CString::new(claim_def_tag).unwrap();
+
+        let err = indy_issuer_revoke_claim_with_tag(command_handle,
+                                                    wallet_handle,
+                                                    issuer_did.as_ptr(),
+                                                    schema_json.as_ptr(),
+                                                    claim_def_tag.as_ptr(),
+                                                    user_revoc_index,
+                                                    cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn issuer_recover_claim(wallet_handle: i32, issuer_did: &str, schema_json: &str, claim_def_tag: Option<&str>, user_revoc_index: u32) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let issuer_did = CString::new(issuer_did).unwrap();
+        let schema_json = CString::new(schema_json).unwrap();
+        let claim_def_tag_str = claim_def_tag.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+
+        let err = indy_issuer_recover_claim(command_handle,
+                                            wallet_handle,
+                                            issuer_did.as_ptr(),
+                                            schema_json.as_ptr(),
+                                            if claim_def_tag.is_some() { claim_def_tag_str.as_ptr() } else { null() },
+                                            user_revoc_index,
+                                            cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn issuer_get_revoc_reg_indices(wallet_handle: i32, issuer_did: &str, schema_json: &str, claim_def_tag: Option<&str>) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let issuer_did = CString::new(issuer_did).unwrap();
+        let schema_json = CString::new(schema_json).unwrap();
+        let claim_def_tag_str = claim_def_tag.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+
+        let err = indy_issuer_get_revoc_reg_indices(command_handle,
+                                                    wallet_handle,
+                                                    issuer_did.as_ptr(),
+                                                    schema_json.as_ptr(),
+                                                    if claim_def_tag.is_some() { claim_def_tag_str.as_ptr() } else { null() },
+                                                    cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
     pub fn get_composite_id(issuer_did: &str, schema_key: &SchemaKey) -> String {
         format!("{}:{}:{}:{}", issuer_did, schema_key.name, schema_key.version, schema_key.did)
     }
@@ -509,6 +902,16 @@ impl AnoncredsUtils {
         claims_for_referent[0].clone()
     }
 
+    /// Issuer fulfills claim offer only once, so every issued claim needs its own offer and claim request.
+    /// Offer is created for a new prover DID, so other outstanding offers of the issuer are kept.
+    pub fn create_claim_req_for_new_offer(issuer_wallet_handle: i32, prover_wallet_handle: i32, issuer_did: &str,
+                                          schema_json: &str, claim_def_json: &str) -> Result<String, ErrorCode> {
+        let (prover_did, _) = DidUtils::create_and_store_my_did(prover_wallet_handle, None)?;
+        let claim_offer_json = AnoncredsUtils::issuer_create_claim_offer(issuer_wallet_handle, schema_json, issuer_did, &prover_did)?;
+        AnoncredsUtils::prover_create_and_store_claim_req(prover_wallet_handle, &prover_did, &claim_offer_json, claim_def_json,
+                                                          COMMON_MASTER_SECRET)
+    }
+
     pub fn init_common_wallet() -> (i32, &'static str, &'static str, &'static str, &'static str) {
         lazy_static! {
             static ref COMMON_WALLET_INIT: Once = ONCE_INIT;
@@ -544,30 +947,21 @@ impl AnoncredsUtils {
                                                                                         &AnoncredsUtils::gvt_schema_json(),
                                                                                         ISSUER_DID, DID_MY1).unwrap();
 
-                //6. Prover store Issuer1 GVT ClaimOffer
-                AnoncredsUtils::prover_store_claim_offer(WALLET_HANDLE, &issuer1_gvt_claim_offer).unwrap();
-
-                //7. Issuer1 Create XYZ ClaimOffer
+                //6. Issuer1 Create XYZ ClaimOffer
                 let issuer1_xyz_claim_offer = AnoncredsUtils::issuer_create_claim_offer(WALLET_HANDLE,
                                                                                         &AnoncredsUtils::xyz_schema_json(),
                                                                                         ISSUER_DID, DID_MY1).unwrap();
 
-                //8. Prover store Issuer1 XYZ ClaimOffer
-                AnoncredsUtils::prover_store_claim_offer(WALLET_HANDLE, &issuer1_xyz_claim_offer).unwrap();
-
-                //9. Issuer2 Create GVT ClaimOffer
+                //7. Issuer2 Create GVT ClaimOffer
                 let issuer2_gvt_claim_offer = AnoncredsUtils::issuer_create_claim_offer(WALLET_HANDLE,
                                                                                         &AnoncredsUtils::gvt_schema_json(),
                                                                                         DID, DID_MY1).unwrap();
 
-                //10. Prover store Issuer2 GVT ClaimOffer
-                AnoncredsUtils::prover_store_claim_offer(WALLET_HANDLE, &issuer2_gvt_claim_offer).unwrap();
-
-                //11. Create MasterSecret
+                //8. Create MasterSecret
                 AnoncredsUtils::prover_create_master_secret(WALLET_HANDLE, COMMON_MASTER_SECRET).unwrap();
 
                 // Issuer1 issue GVT Claim
-                //12. Create and Store Claim Request
+                //9. Create and Store Claim Request
                 let issuer1_gvt_claim_req = AnoncredsUtils::prover_create_and_store_claim_req(WALLET_HANDLE,
                                                                                               DID_MY1,
                                                                                               &issuer1_gvt_claim_offer,
@@ -575,14 +969,14 @@ impl AnoncredsUtils {
                                                                                               COMMON_MASTER_SECRET).unwrap();
                 let claim_values_json = AnoncredsUtils::gvt_claim_values_json();
 
-                //13. Issuer1 creates GVT Claim
+                //10. Issuer1 creates GVT Claim
                 let (_, claim_json) = AnoncredsUtils::issuer_create_claim(WALLET_HANDLE, &issuer1_gvt_claim_req, &claim_values_json, None).unwrap();
 
-                //14. Store Claim
+                //11. Store Claim
                 AnoncredsUtils::prover_store_claim(WALLET_HANDLE, &claim_json, None).unwrap();
 
                 // Issuer1 issue XYZ Claim
-                //15. Create and Store Claim Request
+                //12. Create and Store Claim Request
                 let issuer1_xyz_claim_req = AnoncredsUtils::prover_create_and_store_claim_req(WALLET_HANDLE,
                                                                                               DID_MY1,
                                                                                               &issuer1_xyz_claim_offer,
@@ -590,14 +984,14 @@ impl AnoncredsUtils {
                                                                                               COMMON_MASTER_SECRET).unwrap();
                 let claim_values_json = AnoncredsUtils::xyz_claim_values_json();
 
-                //16. Create XYZ Claim
+                //13. Create XYZ Claim
                 let (_, claim_2_json) = AnoncredsUtils::issuer_create_claim(WALLET_HANDLE, &issuer1_xyz_claim_req, &claim_values_json, None).unwrap();
 
-                //17. Store Claim
+                //14. Store Claim
                 AnoncredsUtils::prover_store_claim(WALLET_HANDLE, &claim_2_json, None).unwrap();
 
                 // Issuer2 issue GVT Claim
-                //18. Create and Store Claim Request
+                //15. Create and Store Claim Request
                 let issuer2_gvt_claim_req = AnoncredsUtils::prover_create_and_store_claim_req(WALLET_HANDLE,
                                                                                               DID_MY1,
                                                                                               &issuer2_gvt_claim_offer,
@@ -605,12 +999,17 @@ impl AnoncredsUtils {
                                                                                               COMMON_MASTER_SECRET).unwrap();
                 let claim_values_json = AnoncredsUtils::gvt2_claim_values_json();
 
-                //19. Create XYZ Claim
+                //16. Create XYZ Claim
                 let (_, claim_3_json) = AnoncredsUtils::issuer_create_claim(WALLET_HANDLE, &issuer2_gvt_claim_req, &claim_values_json, None).unwrap();
 
-                //20. Store Claim
+                //17. Store Claim
                 AnoncredsUtils::prover_store_claim(WALLET_HANDLE, &claim_3_json, None).unwrap();
 
+                //18. Prover store ClaimOffers (stored claim removes its offer, so offers are stored after claims)
+                AnoncredsUtils::prover_store_claim_offer(WALLET_HANDLE, &issuer1_gvt_claim_offer).unwrap();
+                AnoncredsUtils::prover_store_claim_offer(WALLET_HANDLE, &issuer1_xyz_claim_offer).unwrap();
+                AnoncredsUtils::prover_store_claim_offer(WALLET_HANDLE, &issuer2_gvt_claim_offer).unwrap();
+
                 let res = mem::transmute(&issuer1_gvt_claim_def_json as &str);
                 mem::forget(issuer1_gvt_claim_def_json);
                 CLAIM_DEF_JSON = res;
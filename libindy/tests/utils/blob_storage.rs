@@ -0,0 +1,46 @@
+use indy::api::ErrorCode;
+use indy::api::blob_storage::*;
+
+use utils::callback::CallbackUtils;
+use utils::inmem_blob_storage::InmemBlobStorage;
+
+use std::collections::HashSet;
+use std::ffi::CString;
+use std::sync::Mutex;
+
+pub struct BlobStorageUtils {}
+
+impl BlobStorageUtils {
+    pub fn register_blob_storage_type(xtype: &str, force_create: bool) -> Result<(), ErrorCode> {
+        lazy_static! {
+            static ref REGISTERED_TYPES: Mutex<HashSet<String>> = Default::default();
+        }
+
+        let mut types = REGISTERED_TYPES.lock().unwrap();
+
+        if types.contains(xtype) & !force_create {
+            // blob storage type can be registered only once per process
+            return Ok(());
+        }
+
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
+
+        let xxtype = CString::new(xtype).unwrap();
+
+        let err = indy_register_blob_storage_type(
+            command_handle,
+            xxtype.as_ptr(),
+            Some(InmemBlobStorage::open_writer),
+            Some(InmemBlobStorage::append),
+            Some(InmemBlobStorage::finalize),
+            Some(InmemBlobStorage::open_reader),
+            Some(InmemBlobStorage::read),
+            Some(InmemBlobStorage::close),
+            cb
+        );
+
+        types.insert(xtype.to_string());
+
+        super::results::result_to_empty(err, receiver)
+    }
+}
@@ -99,6 +99,34 @@ impl CallbackUtils {
         (receiver, command_handle, Some(_callback))
     }
 
+    pub fn _closure_to_cb_ec_bool_string() -> (Receiver<(ErrorCode, bool, String)>, i32,
+                                               Option<extern fn(command_handle: i32, err: ErrorCode,
+                                                                valid: bool,
+                                                                c_str: *const c_char)>) {
+        let (sender, receiver) = channel();
+
+        lazy_static! {
+            static ref CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode, bool, String) + Send>>> = Default::default();
+        }
+
+        let closure = Box::new(move |err, val1, val2| {
+            sender.send((err, val1, val2)).unwrap();
+        });
+
+        extern "C" fn _callback(command_handle: i32, err: ErrorCode, valid: bool, c_str: *const c_char) {
+            let mut callbacks = CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            let str = unsafe { CStr::from_ptr(c_str).to_str().unwrap().to_string() };
+            cb(err, valid, str)
+        }
+
+        let mut callbacks = CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (receiver, command_handle, Some(_callback))
+    }
+
     pub fn _closure_to_cb_ec_string() -> (Receiver<(ErrorCode, String)>, i32,
                                           Option<extern fn(command_handle: i32,
                                                            err: ErrorCode,
@@ -157,6 +185,37 @@ impl CallbackUtils {
         (receiver, command_handle, Some(_callback))
     }
 
+    pub fn _closure_to_cb_ec_string_string_u64() -> (Receiver<(ErrorCode, String, String, u64)>, i32,
+                                                     Option<extern fn(command_handle: i32,
+                                                                      err: ErrorCode,
+                                                                      str1: *const c_char,
+                                                                      str2: *const c_char,
+                                                                      num: u64)>) {
+        let (sender, receiver) = channel();
+
+        lazy_static! {
+            static ref CALLBACKS: Mutex < HashMap < i32, Box < FnMut(ErrorCode, String, String, u64) + Send > >> = Default::default();
+    }
+
+        let closure = Box::new(move |err, val1, val2, val3| {
+            sender.send((err, val1, val2, val3)).unwrap();
+        });
+
+        extern "C" fn _callback(command_handle: i32, err: ErrorCode, str1: *const c_char, str2: *const c_char, num: u64) {
+            let mut callbacks = CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            let str1 = unsafe { CStr::from_ptr(str1).to_str().unwrap().to_string() };
+            let str2 = unsafe { CStr::from_ptr(str2).to_str().unwrap().to_string() };
+            cb(err, str1, str2, num)
+        }
+
+        let mut callbacks = CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (receiver, command_handle, Some(_callback))
+    }
+
     pub fn _closure_to_cb_ec_string_opt_string() -> (Receiver<(ErrorCode, String, Option<String>)>, i32,
                                                      Option<extern fn(command_handle: i32,
                                                                       err: ErrorCode,
@@ -189,6 +248,36 @@ impl CallbackUtils {
         (receiver, command_handle, Some(_callback))
     }
 
+    pub fn _closure_to_cb_ec_opt_string() -> (Receiver<(ErrorCode, Option<String>)>, i32,
+                                              Option<extern fn(command_handle: i32,
+                                                               err: ErrorCode,
+                                                               c_str: *const c_char)>) {
+        let (sender, receiver) = channel();
+
+        lazy_static! {
+            static ref CALLBACKS: Mutex < HashMap < i32, Box < FnMut(ErrorCode, Option<String>) + Send > >> = Default::default();
+    }
+
+        let closure = Box::new(move |err, val| {
+            sender.send((err, val)).unwrap();
+        });
+
+        extern "C" fn _callback(command_handle: i32, err: ErrorCode, c_str: *const c_char) {
+            let mut callbacks = CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            let val = if !c_str.is_null() {
+                unsafe { Some(CStr::from_ptr(c_str).to_str().unwrap().to_string()) }
+            } else { None };
+            cb(err, val)
+        }
+
+        let mut callbacks = CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (receiver, command_handle, Some(_callback))
+    }
+
     pub fn _closure_to_cb_ec_vec_u8() -> (Receiver<(ErrorCode, Vec<u8>)>, i32,
                                           Option<extern fn(command_handle: i32,
                                                            err: ErrorCode,
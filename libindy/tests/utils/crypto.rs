@@ -1,6 +1,7 @@
 extern crate libc;
 
 use std::ffi::CString;
+use std::ptr::null;
 
 use indy::api::crypto::*;
 use indy::api::ErrorCode;
@@ -24,6 +25,19 @@ impl CryptoUtils {
         super::results::result_to_string(err, receiver)
     }
 
+    pub fn create_key_for_json(wallet_handle: i32, key_json: &str) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let key_json = CString::new(key_json).unwrap();
+
+        let err = indy_create_key(command_handle,
+                                  wallet_handle,
+                                  key_json.as_ptr(),
+                                  cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
     pub fn set_key_metadata(wallet_handle: i32, verkey: &str, metadata: &str) -> Result<(), ErrorCode> {
         let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
 
@@ -143,4 +157,275 @@ impl CryptoUtils {
 
         super::results::result_to_vec_u8(err, receiver)
     }
+    pub fn pack_msg(wallet_handle: i32, msg: &[u8], receiver_keys: &str, sender_vk: Option<&str>) -> Result<Vec<u8>, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_vec_u8();
+
+        let receiver_keys = CString::new(receiver_keys).unwrap();
+        let sender_vk_str = sender_vk.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+
+        let err =
+            indy_crypto_pack_msg(command_handle,
+                                 wallet_handle,
+                                 msg.as_ptr() as *const u8,
+                                 msg.len() as u32,
+                                 receiver_keys.as_ptr(),
+                                 if sender_vk.is_some() { sender_vk_str.as_ptr() } else { null() },
+                                 cb);
+
+        super::results::result_to_vec_u8(err, receiver)
+    }
+
+    pub fn unpack_msg(wallet_handle: i32, packed_msg: &[u8]) -> Result<(String, Vec<u8>), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string_vec_u8();
+
+        let err =
+            indy_crypto_unpack_msg(command_handle,
+                                   wallet_handle,
+                                   packed_msg.as_ptr() as *const u8,
+                                   packed_msg.len() as u32,
+                                   cb);
+
+        super::results::result_to_string_vec_u8(err, receiver)
+    }
+
+    pub fn sign_stream_init(wallet_handle: i32, my_vk: &str) -> Result<i32, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_i32();
+
+        let my_vk = CString::new(my_vk).unwrap();
+
+        let err = indy_crypto_sign_stream_init(command_handle, wallet_handle, my_vk.as_ptr(), cb);
+
+        super::results::result_to_int(err, receiver)
+    }
+
+    pub fn sign_stream_update(stream_handle: i32, msg: &[u8]) -> Result<(), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
+
+        let err =
+            indy_crypto_sign_stream_update(command_handle,
+                                           stream_handle,
+                                           msg.as_ptr() as *const u8,
+                                           msg.len() as u32,
+                                           cb);
+
+        super::results::result_to_empty(err, receiver)
+    }
+
+    pub fn sign_stream_finish(stream_handle: i32) -> Result<Vec<u8>, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_vec_u8();
+
+        let err = indy_crypto_sign_stream_finish(command_handle, stream_handle, cb);
+
+        super::results::result_to_vec_u8(err, receiver)
+    }
+
+    pub fn sign_stream(wallet_handle: i32, my_vk: &str, msg_parts: &[&[u8]]) -> Result<Vec<u8>, ErrorCode> {
+        let stream_handle = CryptoUtils::sign_stream_init(wallet_handle, my_vk)?;
+
+        for msg in msg_parts {
+            CryptoUtils::sign_stream_update(stream_handle, msg)?;
+        }
+
+        CryptoUtils::sign_stream_finish(stream_handle)
+    }
+
+    pub fn verify_stream_init(their_vk: &str) -> Result<i32, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_i32();
+
+        let their_vk = CString::new(their_vk).unwrap();
+
+        let err = indy_crypto_verify_stream_init(command_handle, their_vk.as_ptr(), cb);
+
+        super::results::result_to_int(err, receiver)
+    }
+
+    pub fn verify_stream_update(stream_handle: i32, msg: &[u8]) -> Result<(), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
+
+        let err =
+            indy_crypto_verify_stream_update(command_handle,
+                                             stream_handle,
+                                             msg.as_ptr() as *const u8,
+                                             msg.len() as u32,
+                                             cb);
+
+        super::results::result_to_empty(err, receiver)
+    }
+
+    pub fn verify_stream_finish(stream_handle: i32, signature: &[u8]) -> Result<bool, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_bool();
+
+        let err =
+            indy_crypto_verify_stream_finish(command_handle,
+                                             stream_handle,
+                                             signature.as_ptr() as *const u8,
+                                             signature.len() as u32,
+                                             cb);
+
+        super::results::result_to_bool(err, receiver)
+    }
+
+    pub fn verify_stream(their_vk: &str, msg_parts: &[&[u8]], signature: &[u8]) -> Result<bool, ErrorCode> {
+        let stream_handle = CryptoUtils::verify_stream_init(their_vk)?;
+
+        for msg in msg_parts {
+            CryptoUtils::verify_stream_update(stream_handle, msg)?;
+        }
+
+        CryptoUtils::verify_stream_finish(stream_handle, signature)
+    }
+
+    pub fn auth_crypt_stream_init(wallet_handle: i32, my_vk: &str, their_vk: &str) -> Result<i32, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_i32();
+
+        let my_vk = CString::new(my_vk).unwrap();
+        let their_vk = CString::new(their_vk).unwrap();
+
+        let err = indy_crypto_auth_crypt_stream_init(command_handle, wallet_handle, my_vk.as_ptr(), their_vk.as_ptr(), cb);
+
+        super::results::result_to_int(err, receiver)
+    }
+
+    pub fn anon_crypt_stream_init(their_vk: &str) -> Result<i32, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_i32();
+
+        let their_vk = CString::new(their_vk).unwrap();
+
+        let err = indy_crypto_anon_crypt_stream_init(command_handle, their_vk.as_ptr(), cb);
+
+        super::results::result_to_int(err, receiver)
+    }
+
+    pub fn crypt_stream_update(stream_handle: i32, msg: &[u8]) -> Result<Vec<u8>, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_vec_u8();
+
+        let err =
+            indy_crypto_crypt_stream_update(command_handle,
+                                            stream_handle,
+                                            msg.as_ptr() as *const u8,
+                                            msg.len() as u32,
+                                            cb);
+
+        super::results::result_to_vec_u8(err, receiver)
+    }
+
+    pub fn crypt_stream_finish(stream_handle: i32) -> Result<Vec<u8>, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_vec_u8();
+
+        let err = indy_crypto_crypt_stream_finish(command_handle, stream_handle, cb);
+
+        super::results::result_to_vec_u8(err, receiver)
+    }
+
+    pub fn crypt_stream(stream_handle: i32, msg_parts: &[&[u8]]) -> Result<Vec<u8>, ErrorCode> {
+        let mut encrypted_msg = Vec::new();
+
+        for msg in msg_parts {
+            encrypted_msg.extend(CryptoUtils::crypt_stream_update(stream_handle, msg)?);
+        }
+
+        encrypted_msg.extend(CryptoUtils::crypt_stream_finish(stream_handle)?);
+
+        Ok(encrypted_msg)
+    }
+
+    pub fn auth_decrypt_stream_init(wallet_handle: i32, my_vk: &str) -> Result<i32, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_i32();
+
+        let my_vk = CString::new(my_vk).unwrap();
+
+        let err = indy_crypto_auth_decrypt_stream_init(command_handle, wallet_handle, my_vk.as_ptr(), cb);
+
+        super::results::result_to_int(err, receiver)
+    }
+
+    pub fn anon_decrypt_stream_init(wallet_handle: i32, my_vk: &str) -> Result<i32, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_i32();
+
+        let my_vk = CString::new(my_vk).unwrap();
+
+        let err = indy_crypto_anon_decrypt_stream_init(command_handle, wallet_handle, my_vk.as_ptr(), cb);
+
+        super::results::result_to_int(err, receiver)
+    }
+
+    pub fn decrypt_stream_update(stream_handle: i32, encrypted_msg: &[u8]) -> Result<Vec<u8>, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_vec_u8();
+
+        let err =
+            indy_crypto_decrypt_stream_update(command_handle,
+                                              stream_handle,
+                                              encrypted_msg.as_ptr() as *const u8,
+                                              encrypted_msg.len() as u32,
+                                              cb);
+
+        super::results::result_to_vec_u8(err, receiver)
+    }
+
+    pub fn decrypt_stream_finish(stream_handle: i32) -> Result<Option<String>, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_opt_string();
+
+        let err = indy_crypto_decrypt_stream_finish(command_handle, stream_handle, cb);
+
+        super::results::result_to_opt_string(err, receiver)
+    }
+
+    pub fn decrypt_stream(stream_handle: i32, encrypted_parts: &[&[u8]]) -> Result<(Option<String>, Vec<u8>), ErrorCode> {
+        let mut msg = Vec::new();
+
+        for encrypted_msg in encrypted_parts {
+            msg.extend(CryptoUtils::decrypt_stream_update(stream_handle, encrypted_msg)?);
+        }
+
+        let sender_vk = CryptoUtils::decrypt_stream_finish(stream_handle)?;
+
+        Ok((sender_vk, msg))
+    }
+
+    pub fn close_stream(stream_handle: i32) -> Result<(), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
+
+        let err = indy_crypto_close_stream(command_handle, stream_handle, cb);
+
+        super::results::result_to_empty(err, receiver)
+    }
+
+    pub fn list_keys(wallet_handle: i32) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let err = indy_list_keys(command_handle, wallet_handle, cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn delete_key(wallet_handle: i32, verkey: &str) -> Result<(), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
+
+        let verkey = CString::new(verkey).unwrap();
+
+        let err = indy_delete_key(command_handle, wallet_handle, verkey.as_ptr(), cb);
+
+        super::results::result_to_empty(err, receiver)
+    }
+
+    pub fn export_verkey(verkey: &str, format: &str) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let verkey = CString::new(verkey).unwrap();
+        let format = CString::new(format).unwrap();
+
+        let err = indy_export_verkey(command_handle, verkey.as_ptr(), format.as_ptr(), cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn import_key_from_jwk(wallet_handle: i32, jwk: &str) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let jwk = CString::new(jwk).unwrap();
+
+        let err = indy_import_key_from_jwk(command_handle, wallet_handle, jwk.as_ptr(), cb);
+
+        super::results::result_to_string(err, receiver)
+    }
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 extern crate libc;
 
 use std::ffi::CString;
+use std::ptr::null;
 
 use indy::api::did::*;
 use indy::api::ErrorCode;
@@ -140,6 +141,20 @@ impl DidUtils {
         super::results::result_to_string_opt_string(err, receiver)
     }
 
+    pub fn resolve_did(pool_handle: i32, wallet_handle: i32, submitter_did: &str, did: &str, options_json: Option<&str>) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let submitter_did = CString::new(submitter_did).unwrap();
+        let did = CString::new(did).unwrap();
+        let options_json_str = options_json.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+
+        let err = indy_resolve_did(command_handle, pool_handle, wallet_handle, submitter_did.as_ptr(), did.as_ptr(),
+                                   if options_json.is_some() { options_json_str.as_ptr() } else { null() },
+                                   cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
     pub fn set_did_metadata(wallet_handle: i32, did: &str, metadata: &str) -> Result<(), ErrorCode> {
         let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
 
@@ -171,4 +186,24 @@ impl DidUtils {
 
         super::results::result_to_string(err, receiver)
     }
+
+    pub fn set_master_seed(wallet_handle: i32, master_seed: &str) -> Result<(), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
+
+        let master_seed = CString::new(master_seed).unwrap();
+
+        let err = indy_set_master_seed(command_handle, wallet_handle, master_seed.as_ptr(), cb);
+
+        super::results::result_to_empty(err, receiver)
+    }
+
+    pub fn recover_from_master_seed(wallet_handle: i32, recovery_json: &str) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let recovery_json = CString::new(recovery_json).unwrap();
+
+        let err = indy_recover_from_master_seed(command_handle, wallet_handle, recovery_json.as_ptr(), cb);
+
+        super::results::result_to_string(err, receiver)
+    }
 }
\ No newline at end of file
@@ -46,6 +46,22 @@ impl LedgerUtils {
         super::results::result_to_string(err, receiver)
     }
 
+    pub fn submit_action(pool_handle: i32, request_json: &str, nodes: Option<&str>, timeout: Option<i32>) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let request_json = CString::new(request_json).unwrap();
+        let nodes_str = nodes.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+
+        let err = indy_submit_action(command_handle,
+                                     pool_handle,
+                                     request_json.as_ptr(),
+                                     if nodes.is_some() { nodes_str.as_ptr() } else { null() },
+                                     timeout.unwrap_or(-1),
+                                     cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
     pub fn sign_request(wallet_handle: i32, submitter_did: &str, request_json: &str) -> Result<String, ErrorCode> {
         let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
 
@@ -306,4 +322,226 @@ impl LedgerUtils {
 
         super::results::result_to_string(err, receiver)
     }
+
+    pub fn build_pool_restart_request(submitter_did: &str, action: &str, datetime: Option<&str>) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let submitter_did = CString::new(submitter_did).unwrap();
+        let action = CString::new(action).unwrap();
+        let datetime_str = datetime.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+
+        let err =
+            indy_build_pool_restart_request(command_handle,
+                                            submitter_did.as_ptr(),
+                                            action.as_ptr(),
+                                            if datetime.is_some() { datetime_str.as_ptr() } else { null() },
+                                            cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn build_get_validator_info_request(submitter_did: &str) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let submitter_did = CString::new(submitter_did).unwrap();
+
+        let err = indy_build_get_validator_info_request(command_handle, submitter_did.as_ptr(), cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn build_revoc_reg_def_request(submitter_did: &str, data: &str) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let submitter_did = CString::new(submitter_did).unwrap();
+        let data = CString::new(data).unwrap();
+
+        let err = indy_build_revoc_reg_def_request(command_handle, submitter_did.as_ptr(), data.as_ptr(), cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn build_revoc_reg_entry_request(submitter_did: &str, revoc_reg_def_id: &str, revoc_def_type: &str, value: &str) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let submitter_did = CString::new(submitter_did).unwrap();
+        let revoc_reg_def_id = CString::new(revoc_reg_def_id).unwrap();
+        let revoc_def_type = CString::new(revoc_def_type).unwrap();
+        let value = CString::new(value).unwrap();
+
+        let err = indy_build_revoc_reg_entry_request(command_handle, submitter_did.as_ptr(), revoc_reg_def_id.as_ptr(),
+                                                     revoc_def_type.as_ptr(), value.as_ptr(), cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn build_get_revoc_reg_def_request(submitter_did: &str, id: &str) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let submitter_did = CString::new(submitter_did).unwrap();
+        let id = CString::new(id).unwrap();
+
+        let err = indy_build_get_revoc_reg_def_request(command_handle, submitter_did.as_ptr(), id.as_ptr(), cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn build_get_revoc_reg_request(submitter_did: &str, revoc_reg_def_id: &str, timestamp: i64) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let submitter_did = CString::new(submitter_did).unwrap();
+        let revoc_reg_def_id = CString::new(revoc_reg_def_id).unwrap();
+
+        let err = indy_build_get_revoc_reg_request(command_handle, submitter_did.as_ptr(), revoc_reg_def_id.as_ptr(), timestamp, cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn build_get_revoc_reg_delta_request(submitter_did: &str, revoc_reg_def_id: &str, from: Option<i64>, to: i64) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let submitter_did = CString::new(submitter_did).unwrap();
+        let revoc_reg_def_id = CString::new(revoc_reg_def_id).unwrap();
+
+        let err = indy_build_get_revoc_reg_delta_request(command_handle, submitter_did.as_ptr(), revoc_reg_def_id.as_ptr(),
+                                                         from.unwrap_or(-1), to, cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn parse_get_revoc_reg_def_response(get_revoc_reg_def_response: &str) -> Result<(String, String), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string_string();
+
+        let get_revoc_reg_def_response = CString::new(get_revoc_reg_def_response).unwrap();
+
+        let err = indy_parse_get_revoc_reg_def_response(command_handle, get_revoc_reg_def_response.as_ptr(), cb);
+
+        super::results::result_to_string_string(err, receiver)
+    }
+
+    pub fn parse_get_revoc_reg_response(get_revoc_reg_response: &str) -> Result<(String, String, u64), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string_string_u64();
+
+        let get_revoc_reg_response = CString::new(get_revoc_reg_response).unwrap();
+
+        let err = indy_parse_get_revoc_reg_response(command_handle, get_revoc_reg_response.as_ptr(), cb);
+
+        super::results::result_to_string_string_u64(err, receiver)
+    }
+
+    pub fn parse_get_revoc_reg_delta_response(get_revoc_reg_delta_response: &str) -> Result<(String, String, u64), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string_string_u64();
+
+        let get_revoc_reg_delta_response = CString::new(get_revoc_reg_delta_response).unwrap();
+
+        let err = indy_parse_get_revoc_reg_delta_response(command_handle, get_revoc_reg_delta_response.as_ptr(), cb);
+
+        super::results::result_to_string_string_u64(err, receiver)
+    }
+
+    pub fn get_schema(pool_handle: i32, wallet_handle: i32, submitter_did: &str, dest: &str, data: &str,
+                      options_json: Option<&str>) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let submitter_did = CString::new(submitter_did).unwrap();
+        let dest = CString::new(dest).unwrap();
+        let data = CString::new(data).unwrap();
+        let options_json_str = options_json.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+
+        let err =
+            indy_get_schema(command_handle,
+                            pool_handle,
+                            wallet_handle,
+                            submitter_did.as_ptr(),
+                            dest.as_ptr(),
+                            data.as_ptr(),
+                            if options_json.is_some() { options_json_str.as_ptr() } else { null() },
+                            cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn get_claim_def(pool_handle: i32, wallet_handle: i32, submitter_did: &str, xref: i32, signature_type: &str, origin: &str,
+                         options_json: Option<&str>) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let submitter_did = CString::new(submitter_did).unwrap();
+        let signature_type = CString::new(signature_type).unwrap();
+        let origin = CString::new(origin).unwrap();
+        let options_json_str = options_json.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+
+        let err =
+            indy_get_claim_def(command_handle,
+                               pool_handle,
+                               wallet_handle,
+                               submitter_did.as_ptr(),
+                               xref,
+                               signature_type.as_ptr(),
+                               origin.as_ptr(),
+                               if options_json.is_some() { options_json_str.as_ptr() } else { null() },
+                               cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn purge_schema_cache(wallet_handle: i32, options_json: Option<&str>) -> Result<(), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
+
+        let options_json_str = options_json.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+
+        let err =
+            indy_purge_schema_cache(command_handle,
+                                    wallet_handle,
+                                    if options_json.is_some() { options_json_str.as_ptr() } else { null() },
+                                    cb);
+
+        super::results::result_to_empty(err, receiver)
+    }
+
+    pub fn purge_claim_def_cache(wallet_handle: i32, options_json: Option<&str>) -> Result<(), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
+
+        let options_json_str = options_json.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+
+        let err =
+            indy_purge_claim_def_cache(command_handle,
+                                       wallet_handle,
+                                       if options_json.is_some() { options_json_str.as_ptr() } else { null() },
+                                       cb);
+
+        super::results::result_to_empty(err, receiver)
+    }
+
+    pub fn open_ledger_txn_reader(pool_handle: i32, submitter_did: &str, ledger_id: i32, from: i32, to: Option<i32>) -> Result<i32, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_i32();
+
+        let submitter_did = CString::new(submitter_did).unwrap();
+
+        let err =
+            indy_open_ledger_txn_reader(command_handle,
+                                        pool_handle,
+                                        submitter_did.as_ptr(),
+                                        ledger_id,
+                                        from,
+                                        to.unwrap_or(-1),
+                                        cb);
+
+        super::results::result_to_int(err, receiver)
+    }
+
+    pub fn fetch_ledger_txns(reader_handle: i32, count: u32) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec_string();
+
+        let err = indy_fetch_ledger_txns(command_handle, reader_handle, count, cb);
+
+        super::results::result_to_string(err, receiver)
+    }
+
+    pub fn close_ledger_txn_reader(reader_handle: i32) -> Result<(), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
+
+        let err = indy_close_ledger_txn_reader(command_handle, reader_handle, cb);
+
+        super::results::result_to_empty(err, receiver)
+    }
 }
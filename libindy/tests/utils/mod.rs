@@ -33,4 +33,9 @@ pub mod sequence;
 pub mod cstring;
 
 #[path = "../../src/utils/inmem_wallet.rs"]
-pub mod inmem_wallet;
\ No newline at end of file
+pub mod inmem_wallet;
+
+#[path = "../../src/utils/inmem_blob_storage.rs"]
+pub mod inmem_blob_storage;
+
+pub mod blob_storage;
\ No newline at end of file
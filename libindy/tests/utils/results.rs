@@ -44,6 +44,20 @@ pub fn result_to_bool(err: ErrorCode, receiver: Receiver<(ErrorCode, bool)>) ->
     Ok(val)
 }
 
+pub fn result_to_bool_string(err: ErrorCode, receiver: Receiver<(ErrorCode, bool, String)>) -> Result<(bool, String), ErrorCode> {
+    if err != ErrorCode::Success {
+        return Err(err);
+    }
+
+    let (err, val, val2) = receiver.recv().unwrap();
+
+    if err != ErrorCode::Success {
+        return Err(err);
+    }
+
+    Ok((val, val2))
+}
+
 pub fn result_to_string(err: ErrorCode, receiver: Receiver<(ErrorCode, String)>) -> Result<String, ErrorCode> {
     if err != ErrorCode::Success {
         return Err(err);
@@ -72,6 +86,20 @@ pub fn result_to_string_string(err: ErrorCode, receiver: Receiver<(ErrorCode, St
     Ok((val, val2))
 }
 
+pub fn result_to_string_string_u64(err: ErrorCode, receiver: Receiver<(ErrorCode, String, String, u64)>) -> Result<(String, String, u64), ErrorCode> {
+    if err != ErrorCode::Success {
+        return Err(err);
+    }
+
+    let (err, val, val2, val3) = receiver.recv().unwrap();
+
+    if err != ErrorCode::Success {
+        return Err(err);
+    }
+
+    Ok((val, val2, val3))
+}
+
 pub fn result_to_string_opt_string(err: ErrorCode, receiver: Receiver<(ErrorCode, String, Option<String>)>) -> Result<(String, Option<String>), ErrorCode> {
     if err != ErrorCode::Success {
         return Err(err);
@@ -86,6 +114,20 @@ pub fn result_to_string_opt_string(err: ErrorCode, receiver: Receiver<(ErrorCode
     Ok((val, val2))
 }
 
+pub fn result_to_opt_string(err: ErrorCode, receiver: Receiver<(ErrorCode, Option<String>)>) -> Result<Option<String>, ErrorCode> {
+    if err != ErrorCode::Success {
+        return Err(err);
+    }
+
+    let (err, val) = receiver.recv().unwrap();
+
+    if err != ErrorCode::Success {
+        return Err(err);
+    }
+
+    Ok(val)
+}
+
 pub fn result_to_vec_u8(err: ErrorCode, receiver: Receiver<(ErrorCode, Vec<u8>)>) -> Result<Vec<u8>, ErrorCode> {
     if err != ErrorCode::Success {
         return Err(err);
@@ -21,6 +21,13 @@ pub struct ClaimDefinitionData {
     pub revocation: Option<serde_json::Value>,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct RevocationRegistryIndices {
+    pub max_claim_num: u32,
+    pub issued: Vec<u32>,
+    pub revoked: Vec<u32>
+}
+
 #[derive(Deserialize, Eq, PartialEq, Debug)]
 pub enum ResponseType {
     REQNACK,
@@ -163,7 +170,8 @@ pub struct ClaimOffer {
     pub issuer_did: String,
     pub schema_key: SchemaKey,
     pub key_correctness_proof: KeyCorrectnessProof,
-    pub nonce: Nonce
+    pub nonce: Nonce,
+    pub claim_def_tag: Option<String>
 }
 
 #[derive(Debug, Deserialize, Serialize, Eq, PartialEq)]
@@ -182,7 +190,25 @@ pub struct ClaimInfo {
     pub referent: String,
     pub schema_key: SchemaKey,
     pub issuer_did: String,
-    pub revoc_reg_seq_no: Option<i32>
+    pub revoc_reg_seq_no: Option<i32>,
+    pub master_secret_name: Option<String>,
+    pub metadata: Option<String>,
+    pub stored_at: Option<i64>,
+    pub claim_def_tag: Option<String>
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RequestedClaims {
+    pub self_attested_attributes: HashMap<String, String>,
+    pub requested_attrs: HashMap<String, (String, bool)>,
+    pub requested_predicates: HashMap<String, String>
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ClaimsSelection {
+    pub requested_claims: RequestedClaims,
+    pub master_secret_name: Option<String>,
+    pub unsatisfied: HashMap<String, String>
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -29,7 +29,7 @@ impl WalletUtils {
 
         let xxtype = CString::new(xtype).unwrap();
 
-        let err = indy_register_wallet_type(
+        let err = indy_register_wallet_type_with_remove(
             command_handle,
             xxtype.as_ptr(),
             Some(InmemWallet::create),
@@ -38,6 +38,7 @@ impl WalletUtils {
             Some(InmemWallet::get),
             Some(InmemWallet::get_not_expired),
             Some(InmemWallet::list),
+            Some(InmemWallet::remove),
             Some(InmemWallet::close),
             Some(InmemWallet::delete),
             Some(InmemWallet::free),
@@ -49,6 +50,29 @@ impl WalletUtils {
         super::results::result_to_empty(err, receiver)
     }
 
+    pub fn register_wallet_type_without_remove(xtype: &str) -> Result<(), ErrorCode> {
+        let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
+
+        let xtype = CString::new(xtype).unwrap();
+
+        let err = indy_register_wallet_type(
+            command_handle,
+            xtype.as_ptr(),
+            Some(InmemWallet::create),
+            Some(InmemWallet::open),
+            Some(InmemWallet::set),
+            Some(InmemWallet::get),
+            Some(InmemWallet::get_not_expired),
+            Some(InmemWallet::list),
+            Some(InmemWallet::close),
+            Some(InmemWallet::delete),
+            Some(InmemWallet::free),
+            cb
+        );
+
+        super::results::result_to_empty(err, receiver)
+    }
+
     pub fn create_wallet(pool_name: &str, wallet_name: &str, xtype: Option<&str>, config: Option<&str>, credentials: Option<&str>) -> Result<(), ErrorCode> {
         let (receiver, command_handle, cb) = CallbackUtils::_closure_to_cb_ec();
 
@@ -354,6 +354,97 @@ mod medium_cases {
             TestUtils::cleanup_storage();
             InmemWallet::cleanup();
         }
+
+        #[test]
+        fn indy_register_wallet_type_with_remove_does_not_work_with_null_params() {
+            TestUtils::cleanup_storage();
+            InmemWallet::cleanup();
+
+            let xtype = CString::new(INMEM_TYPE).unwrap();
+            let res = indy_register_wallet_type_with_remove(1, xtype.as_ptr(), None, None, None, None, None,
+                                                            None, None, None, None, None, None);
+            assert_eq!(res, ErrorCode::CommonInvalidParam3);
+
+            extern "C" fn callback(_: *const c_char, _: *const c_char,
+                                   _: *const c_char) -> ErrorCode {
+                ErrorCode::Success
+            }
+
+            let res = indy_register_wallet_type_with_remove(1, xtype.as_ptr(), Some(callback), None, None, None,
+                                                            None, None, None, None, None, None, None);
+            assert_eq!(res, ErrorCode::CommonInvalidParam4);
+
+            extern "C" fn callback1(_: *const c_char, _: *const c_char, _: *const c_char,
+                                    _: *const c_char, _: *mut i32) -> ErrorCode {
+                ErrorCode::Success
+            }
+
+            let res = indy_register_wallet_type_with_remove(1, xtype.as_ptr(), Some(callback), Some(callback1),
+                                                            None, None, None, None, None, None, None, None, None);
+            assert_eq!(res, ErrorCode::CommonInvalidParam5);
+
+            extern "C" fn callback2(_: i32, _: *const c_char, _: *const c_char) -> ErrorCode {
+                ErrorCode::Success
+            }
+
+            let res = indy_register_wallet_type_with_remove(1, xtype.as_ptr(), Some(callback), Some(callback1),
+                                                            Some(callback2), None, None, None, None, None,
+                                                            None, None, None);
+            assert_eq!(res, ErrorCode::CommonInvalidParam6);
+
+            extern "C" fn callback3(_: i32, _: *const c_char, _: *mut *const c_char) -> ErrorCode {
+                ErrorCode::Success
+            }
+
+            let res = indy_register_wallet_type_with_remove(1, xtype.as_ptr(), Some(callback), Some(callback1),
+                                                            Some(callback2), Some(callback3), None, None, None,
+                                                            None, None, None, None);
+            assert_eq!(res, ErrorCode::CommonInvalidParam7);
+
+            let res = indy_register_wallet_type_with_remove(1, xtype.as_ptr(), Some(callback), Some(callback1),
+                                                            Some(callback2), Some(callback3), Some(callback3),
+                                                            None, None, None, None, None, None);
+            assert_eq!(res, ErrorCode::CommonInvalidParam8);
+
+            let res = indy_register_wallet_type_with_remove(1, xtype.as_ptr(), Some(callback), Some(callback1),
+                                                            Some(callback2), Some(callback3), Some(callback3),
+                                                            Some(callback3), None, None, None, None, None);
+            assert_eq!(res, ErrorCode::CommonInvalidParam9);
+
+            extern "C" fn callback5(_: i32, _: *const c_char) -> ErrorCode {
+                ErrorCode::Success
+            }
+
+            let res = indy_register_wallet_type_with_remove(1, xtype.as_ptr(), Some(callback), Some(callback1),
+                                                            Some(callback2), Some(callback3), Some(callback3),
+                                                            Some(callback3), Some(callback5), None, None, None, None);
+            assert_eq!(res, ErrorCode::CommonInvalidParam10);
+
+            extern "C" fn callback4(_: i32) -> ErrorCode {
+                ErrorCode::Success
+            }
+
+            let res = indy_register_wallet_type_with_remove(1, xtype.as_ptr(), Some(callback), Some(callback1),
+                                                            Some(callback2), Some(callback3), Some(callback3),
+                                                            Some(callback3), Some(callback5), Some(callback4),
+                                                            None, None, None);
+            assert_eq!(res, ErrorCode::CommonInvalidParam11);
+
+            let res = indy_register_wallet_type_with_remove(1, xtype.as_ptr(), Some(callback), Some(callback1),
+                                                            Some(callback2), Some(callback3), Some(callback3),
+                                                            Some(callback3), Some(callback5), Some(callback4),
+                                                            Some(callback), None, None);
+            assert_eq!(res, ErrorCode::CommonInvalidParam12);
+
+            let res = indy_register_wallet_type_with_remove(1, xtype.as_ptr(), Some(callback), Some(callback1),
+                                                            Some(callback2), Some(callback3), Some(callback3),
+                                                            Some(callback3), Some(callback5), Some(callback4),
+                                                            Some(callback), Some(callback5), None);
+            assert_eq!(res, ErrorCode::CommonInvalidParam13);
+
+            TestUtils::cleanup_storage();
+            InmemWallet::cleanup();
+        }
     }
 
     mod create_wallet {